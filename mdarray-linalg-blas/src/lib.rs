@@ -56,6 +56,7 @@ extern crate cblas_inject as cblas_sys;
 pub mod matmul;
 pub use matmul::{gemm, gemm_uninit};
 pub mod matvec;
+pub mod trisolve;
 
 #[derive(Default)]
 pub struct Blas;
@@ -3,7 +3,7 @@ use std::mem::MaybeUninit;
 use cblas_sys::{CBLAS_SIDE, CBLAS_UPLO};
 use mdarray::{Dense, Dim, DynRank, Layout, Slice, Tensor};
 use mdarray_linalg::matmul::{
-    Axes, ContractBuilder, MatMul, MatMulBuilder, Side, Triangle, Type, _contract,
+    Axes, ContractBuilder, MatMul, MatMulBuilder, Side, Triangle, Type, _contract, _contract_into,
 };
 use num_complex::ComplexFloat;
 use num_traits::{MulAdd, One, Zero};
@@ -36,6 +36,7 @@ where
     a: &'a Slice<T, DynRank, La>,
     b: &'a Slice<T, DynRank, Lb>,
     axes: Axes,
+    order: Option<Box<[usize]>>,
 }
 
 impl<'a, T, La, Lb, D0, D1, D2> MatMulBuilder<'a, T, La, Lb, D0, D1, D2>
@@ -134,12 +135,28 @@ where
         self
     }
 
+    fn output_order(mut self, order: impl Into<Box<[usize]>>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
     fn eval(self) -> Tensor<T> {
-        _contract(Blas, self.a, self.b, self.axes, self.alpha)
+        _contract(Blas, self.a, self.b, self.axes, self.alpha, self.order.as_deref())
+    }
+
+    fn write<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>) {
+        let order = self.order.as_deref();
+        _contract_into(Blas, self.a, self.b, self.axes, self.alpha, order, None, c);
+    }
+
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>) {
+        let order = self.order.as_deref();
+        _contract_into(Blas, self.a, self.b, self.axes, self.alpha, order, Some(T::one()), c);
     }
 
-    fn write(self, _c: &mut Slice<T>) {
-        todo!()
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>, beta: T) {
+        let order = self.order.as_deref();
+        _contract_into(Blas, self.a, self.b, self.axes, self.alpha, order, Some(beta), c);
     }
 }
 
@@ -182,6 +199,7 @@ where
             a,
             b,
             axes: Axes::All,
+            order: None,
         }
     }
 
@@ -202,6 +220,7 @@ where
             a,
             b,
             axes: Axes::LastFirst { k: (n) },
+            order: None,
         }
     }
 
@@ -224,6 +243,7 @@ where
             a,
             b,
             axes: Axes::Specific(axes_a.into(), axes_b.into()),
+            order: None,
         }
     }
 }
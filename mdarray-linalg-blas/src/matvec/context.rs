@@ -3,16 +3,20 @@ use std::ops::{Add, Mul};
 use cblas_sys::CBLAS_UPLO;
 use mdarray::{Dim, Layout, Shape, Slice, Tensor};
 use mdarray_linalg::{
+    banded::BandMatrix,
     matmul::{Triangle, Type},
-    matvec::{Argmax, MatVec, MatVecBuilder, Outer, OuterBuilder, VecOps},
+    matvec::{Argmax, MatVec, MatVecBuilder, Outer, OuterBuilder, StructuredMatVec, VecOps},
+    packed::PackedMatrix,
     utils::unravel_index,
 };
 use num_complex::ComplexFloat;
 use num_traits::Zero;
 
 use super::{
-    scalar::BlasScalar,
-    simple::{amax, asum, axpy, dotc, dotu, gemv, ger, her, nrm2, syr},
+    scalar::{BlasScalar, StructuredBlasScalar},
+    simple::{
+        amax, asum, axpy, dotc, dotu, gbmv, gemv, ger, hbmv, her, hpmv, nrm2, syr, tbmv, tpmv,
+    },
 };
 use crate::Blas;
 
@@ -296,3 +300,60 @@ where
         }
     }
 }
+
+impl<T> StructuredMatVec<T> for Blas
+where
+    T: StructuredBlasScalar + ComplexFloat,
+{
+    fn gbmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    ) {
+        gbmv(alpha, a, x, beta, y);
+    }
+
+    fn hbmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    ) {
+        hbmv(alpha, a, x, beta, y);
+    }
+
+    fn tbmv<D: Dim, Lx: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        triangle: Triangle,
+        unit_diag: bool,
+        x: &mut Slice<T, (D,), Lx>,
+    ) {
+        tbmv(a, triangle, unit_diag, x);
+    }
+
+    fn hpmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &PackedMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    ) {
+        hpmv(alpha, a, x, beta, y);
+    }
+
+    fn tpmv<D: Dim, Lx: Layout>(
+        &self,
+        a: &PackedMatrix<T>,
+        unit_diag: bool,
+        x: &mut Slice<T, (D,), Lx>,
+    ) {
+        tpmv(a, unit_diag, x);
+    }
+}
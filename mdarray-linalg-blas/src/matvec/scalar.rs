@@ -1630,3 +1630,262 @@ impl BlasScalar for Complex<f64> {
         }
     }
 }
+
+/// Banded and packed matrix-vector products. The Hermitian routines are
+/// the symmetric ones (`sbmv`, `spmv`) for real types.
+#[allow(clippy::too_many_arguments)]
+pub trait StructuredBlasScalar: BlasScalar {
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_gbmv(
+        layout: CBLAS_LAYOUT,
+        transa: CBLAS_TRANSPOSE,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: Self,
+        y: *mut Self,
+        incy: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_hbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        x: *const Self,
+        incx: i32,
+        beta: Self,
+        y: *mut Self,
+        incy: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_tbmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: i32,
+        k: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_hpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        n: i32,
+        alpha: Self,
+        ap: *const Self,
+        x: *const Self,
+        incx: i32,
+        beta: Self,
+        y: *mut Self,
+        incy: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_tpmv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: i32,
+        ap: *const Self,
+        x: *mut Self,
+        incx: i32,
+    );
+}
+
+// Complex scalars are passed by pointer, real ones by value
+macro_rules! impl_structured_blas_scalar {
+    (
+        $t:ty, $gbmv:ident, $hbmv:ident, $tbmv:ident, $hpmv:ident, $tpmv:ident,
+        $alpha:ident, $beta:ident => $alpha_arg:expr, $beta_arg:expr
+    ) => {
+        impl StructuredBlasScalar for $t {
+            unsafe fn cblas_gbmv(
+                layout: CBLAS_LAYOUT,
+                transa: CBLAS_TRANSPOSE,
+                m: i32,
+                n: i32,
+                kl: i32,
+                ku: i32,
+                $alpha: $t,
+                a: *const $t,
+                lda: i32,
+                x: *const $t,
+                incx: i32,
+                $beta: $t,
+                y: *mut $t,
+                incy: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$gbmv(
+                        layout,
+                        transa,
+                        m,
+                        n,
+                        kl,
+                        ku,
+                        $alpha_arg,
+                        a as *const _,
+                        lda,
+                        x as *const _,
+                        incx,
+                        $beta_arg,
+                        y as *mut _,
+                        incy,
+                    )
+                }
+            }
+
+            unsafe fn cblas_hbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: i32,
+                k: i32,
+                $alpha: $t,
+                a: *const $t,
+                lda: i32,
+                x: *const $t,
+                incx: i32,
+                $beta: $t,
+                y: *mut $t,
+                incy: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$hbmv(
+                        layout,
+                        uplo,
+                        n,
+                        k,
+                        $alpha_arg,
+                        a as *const _,
+                        lda,
+                        x as *const _,
+                        incx,
+                        $beta_arg,
+                        y as *mut _,
+                        incy,
+                    )
+                }
+            }
+
+            unsafe fn cblas_tbmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: i32,
+                k: i32,
+                a: *const $t,
+                lda: i32,
+                x: *mut $t,
+                incx: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$tbmv(
+                        layout,
+                        uplo,
+                        transa,
+                        diag,
+                        n,
+                        k,
+                        a as *const _,
+                        lda,
+                        x as *mut _,
+                        incx,
+                    )
+                }
+            }
+
+            unsafe fn cblas_hpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                n: i32,
+                $alpha: $t,
+                ap: *const $t,
+                x: *const $t,
+                incx: i32,
+                $beta: $t,
+                y: *mut $t,
+                incy: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$hpmv(
+                        layout,
+                        uplo,
+                        n,
+                        $alpha_arg,
+                        ap as *const _,
+                        x as *const _,
+                        incx,
+                        $beta_arg,
+                        y as *mut _,
+                        incy,
+                    )
+                }
+            }
+
+            unsafe fn cblas_tpmv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: i32,
+                ap: *const $t,
+                x: *mut $t,
+                incx: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$tpmv(
+                        layout,
+                        uplo,
+                        transa,
+                        diag,
+                        n,
+                        ap as *const _,
+                        x as *mut _,
+                        incx,
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_structured_blas_scalar!(
+    f32, cblas_sgbmv, cblas_ssbmv, cblas_stbmv, cblas_sspmv, cblas_stpmv,
+    alpha, beta => alpha, beta
+);
+impl_structured_blas_scalar!(
+    f64, cblas_dgbmv, cblas_dsbmv, cblas_dtbmv, cblas_dspmv, cblas_dtpmv,
+    alpha, beta => alpha, beta
+);
+impl_structured_blas_scalar!(
+    Complex<f32>, cblas_cgbmv, cblas_chbmv, cblas_ctbmv, cblas_chpmv, cblas_ctpmv,
+    alpha, beta => &alpha as *const _ as *const _, &beta as *const _ as *const _
+);
+impl_structured_blas_scalar!(
+    Complex<f64>, cblas_zgbmv, cblas_zhbmv, cblas_ztbmv, cblas_zhpmv, cblas_ztpmv,
+    alpha, beta => &alpha as *const _ as *const _, &beta as *const _ as *const _
+);
@@ -1,11 +1,14 @@
-use std::any::TypeId;
+use std::{any::TypeId, ops::Range};
 
-use cblas_sys::{CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_UPLO};
+use cblas_sys::{CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_TRANSPOSE, CBLAS_UPLO};
 use mdarray::{Dim, Layout, Shape, Slice};
-use mdarray_linalg::{into_i32, trans_stride};
+use mdarray_linalg::{
+    banded::BandMatrix, into_i32, matmul::Triangle, matvec::structured_matvec_dims,
+    packed::PackedMatrix, trans_stride,
+};
 use num_complex::{Complex, ComplexFloat};
 
-use super::scalar::BlasScalar;
+use super::scalar::{BlasScalar, StructuredBlasScalar};
 
 pub fn gemv<T, D0: Dim, D1: Dim, La, Lx, Ly>(
     alpha: T,
@@ -341,3 +344,173 @@ where
 
     (unsafe { T::cblas_amax(n, x.as_ptr(), incx) } as usize)
 }
+
+/// Copies the rows `rows` of the band storage of A into a column-major
+/// buffer with leading dimension `rows.len()`, as expected by the BLAS
+/// band routines
+fn band_col_major<T: ComplexFloat>(a: &BandMatrix<T>, rows: Range<usize>) -> Vec<T> {
+    let band = a.band();
+    (0..a.n())
+        .flat_map(|j| rows.clone().map(move |r| band[[r, j]]))
+        .collect()
+}
+
+fn diag(unit_diag: bool) -> CBLAS_DIAG {
+    if unit_diag {
+        CBLAS_DIAG::CblasUnit
+    } else {
+        CBLAS_DIAG::CblasNonUnit
+    }
+}
+
+fn uplo(triangle: Triangle) -> CBLAS_UPLO {
+    match triangle {
+        Triangle::Upper => CBLAS_UPLO::CblasUpper,
+        Triangle::Lower => CBLAS_UPLO::CblasLower,
+    }
+}
+
+pub fn gbmv<T, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &BandMatrix<T>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) where
+    T: StructuredBlasScalar + ComplexFloat,
+{
+    let (n, kl, ku) = (a.n(), a.kl(), a.ku());
+    structured_matvec_dims(n, x, y);
+    let ab = band_col_major(a, 0..kl + ku + 1);
+
+    unsafe {
+        T::cblas_gbmv(
+            CBLAS_LAYOUT::CblasColMajor,
+            CBLAS_TRANSPOSE::CblasNoTrans,
+            into_i32(n),
+            into_i32(n),
+            into_i32(kl),
+            into_i32(ku),
+            alpha,
+            ab.as_ptr(),
+            into_i32(kl + ku + 1),
+            x.as_ptr(),
+            into_i32(x.stride(0)),
+            beta,
+            y.as_mut_ptr(),
+            into_i32(y.stride(0)),
+        )
+    }
+}
+
+pub fn hbmv<T, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &BandMatrix<T>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) where
+    T: StructuredBlasScalar + ComplexFloat,
+{
+    let (n, kl, ku) = (a.n(), a.kl(), a.ku());
+    structured_matvec_dims(n, x, y);
+    let ab = band_col_major(a, ku..ku + kl + 1);
+
+    unsafe {
+        T::cblas_hbmv(
+            CBLAS_LAYOUT::CblasColMajor,
+            CBLAS_UPLO::CblasLower,
+            into_i32(n),
+            into_i32(kl),
+            alpha,
+            ab.as_ptr(),
+            into_i32(kl + 1),
+            x.as_ptr(),
+            into_i32(x.stride(0)),
+            beta,
+            y.as_mut_ptr(),
+            into_i32(y.stride(0)),
+        )
+    }
+}
+
+pub fn tbmv<T, D: Dim, Lx: Layout>(
+    a: &BandMatrix<T>,
+    triangle: Triangle,
+    unit_diag: bool,
+    x: &mut Slice<T, (D,), Lx>,
+) where
+    T: StructuredBlasScalar + ComplexFloat,
+{
+    let (n, kl, ku) = (a.n(), a.kl(), a.ku());
+    assert_eq!(x.len(), n, "x must have length n");
+    let (k, rows) = match triangle {
+        Triangle::Upper => (ku, 0..ku + 1),
+        Triangle::Lower => (kl, ku..ku + kl + 1),
+    };
+    let ab = band_col_major(a, rows);
+
+    unsafe {
+        T::cblas_tbmv(
+            CBLAS_LAYOUT::CblasColMajor,
+            uplo(triangle),
+            CBLAS_TRANSPOSE::CblasNoTrans,
+            diag(unit_diag),
+            into_i32(n),
+            into_i32(k),
+            ab.as_ptr(),
+            into_i32(k + 1),
+            x.as_mut_ptr(),
+            into_i32(x.stride(0)),
+        )
+    }
+}
+
+pub fn hpmv<T, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &PackedMatrix<T>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) where
+    T: StructuredBlasScalar + ComplexFloat,
+{
+    let n = a.n();
+    structured_matvec_dims(n, x, y);
+
+    unsafe {
+        T::cblas_hpmv(
+            CBLAS_LAYOUT::CblasRowMajor,
+            uplo(a.triangle()),
+            into_i32(n),
+            alpha,
+            a.data().as_ptr(),
+            x.as_ptr(),
+            into_i32(x.stride(0)),
+            beta,
+            y.as_mut_ptr(),
+            into_i32(y.stride(0)),
+        )
+    }
+}
+
+pub fn tpmv<T, D: Dim, Lx: Layout>(a: &PackedMatrix<T>, unit_diag: bool, x: &mut Slice<T, (D,), Lx>)
+where
+    T: StructuredBlasScalar + ComplexFloat,
+{
+    let n = a.n();
+    assert_eq!(x.len(), n, "x must have length n");
+
+    unsafe {
+        T::cblas_tpmv(
+            CBLAS_LAYOUT::CblasRowMajor,
+            uplo(a.triangle()),
+            CBLAS_TRANSPOSE::CblasNoTrans,
+            diag(unit_diag),
+            into_i32(n),
+            a.data().as_ptr(),
+            x.as_mut_ptr(),
+            into_i32(x.stride(0)),
+        )
+    }
+}
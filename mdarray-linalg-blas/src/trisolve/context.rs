@@ -0,0 +1,126 @@
+use mdarray::{Dim, Layout, Slice, Tensor};
+use mdarray_linalg::{
+    matmul::{Side, Triangle},
+    trisolve::{Transpose, TriSolve, TriSolveBuilder, TriSolveError, TriSolveOptions},
+};
+
+use super::{
+    scalar::BlasScalar,
+    simple::{trsm, trsv},
+};
+use crate::Blas;
+
+struct BlasTriSolveBuilder<'a, T, La, D>
+where
+    La: Layout,
+    D: Dim,
+{
+    a: &'a Slice<T, (D, D), La>,
+    opts: TriSolveOptions<T>,
+}
+
+impl<'a, T, La, D> TriSolveBuilder<'a, T, La, D> for BlasTriSolveBuilder<'a, T, La, D>
+where
+    T: BlasScalar + 'a,
+    La: Layout,
+    D: Dim,
+{
+    fn side(mut self, side: Side) -> Self {
+        self.opts.side = side;
+        self
+    }
+
+    fn upper(mut self) -> Self {
+        self.opts.triangle = Triangle::Upper;
+        self
+    }
+
+    fn lower(mut self) -> Self {
+        self.opts.triangle = Triangle::Lower;
+        self
+    }
+
+    fn transpose(mut self) -> Self {
+        self.opts.trans = Transpose::Trans;
+        self
+    }
+
+    fn conj_transpose(mut self) -> Self {
+        self.opts.trans = Transpose::ConjTrans;
+        self
+    }
+
+    fn unit_diagonal(mut self) -> Self {
+        self.opts.unit_diag = true;
+        self
+    }
+
+    fn scale(mut self, alpha: T) -> Self {
+        self.opts.alpha = self.opts.alpha * alpha;
+        self
+    }
+
+    fn eval<Lb: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &Slice<T, (D0, D1), Lb>,
+    ) -> Result<Tensor<T, (D0, D1)>, TriSolveError> {
+        let mut x = b.to_tensor();
+        trsm(&self.opts, self.a, &mut x)?;
+        Ok(x)
+    }
+
+    fn write<Lb: Layout, Lx: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &Slice<T, (D0, D1), Lb>,
+        x: &mut Slice<T, (D0, D1), Lx>,
+    ) -> Result<(), TriSolveError> {
+        x.assign(b);
+        trsm(&self.opts, self.a, x)
+    }
+
+    fn in_place<Lb: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<(), TriSolveError> {
+        trsm(&self.opts, self.a, b)
+    }
+
+    fn eval_vec<Lb: Layout, D0: Dim>(
+        self,
+        b: &Slice<T, (D0,), Lb>,
+    ) -> Result<Tensor<T, (D0,)>, TriSolveError> {
+        let mut x = b.to_tensor();
+        trsv(&self.opts, self.a, &mut x)?;
+        Ok(x)
+    }
+
+    fn write_vec<Lb: Layout, Lx: Layout, D0: Dim>(
+        self,
+        b: &Slice<T, (D0,), Lb>,
+        x: &mut Slice<T, (D0,), Lx>,
+    ) -> Result<(), TriSolveError> {
+        x.assign(b);
+        trsv(&self.opts, self.a, x)
+    }
+
+    fn in_place_vec<Lb: Layout, D0: Dim>(
+        self,
+        b: &mut Slice<T, (D0,), Lb>,
+    ) -> Result<(), TriSolveError> {
+        trsv(&self.opts, self.a, b)
+    }
+}
+
+impl<T: BlasScalar> TriSolve<T> for Blas {
+    fn trisolve<'a, La, D>(&self, a: &'a Slice<T, (D, D), La>) -> impl TriSolveBuilder<'a, T, La, D>
+    where
+        T: 'a,
+        La: Layout,
+        D: Dim,
+    {
+        BlasTriSolveBuilder {
+            a,
+            opts: TriSolveOptions::default(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod context;
+pub mod scalar;
+pub mod simple;
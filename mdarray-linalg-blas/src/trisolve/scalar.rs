@@ -0,0 +1,107 @@
+//! Abstracting the BLAS scalar types for triangular solves
+use cblas_sys::{CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+use num_complex::{Complex, ComplexFloat};
+
+#[allow(clippy::too_many_arguments)]
+pub trait BlasScalar: Sized + ComplexFloat {
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_trsm(
+        layout: CBLAS_LAYOUT,
+        side: CBLAS_SIDE,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+    );
+
+    /// # Safety
+    /// Calls must respect BLAS conventions.
+    unsafe fn cblas_trsv(
+        layout: CBLAS_LAYOUT,
+        uplo: CBLAS_UPLO,
+        transa: CBLAS_TRANSPOSE,
+        diag: CBLAS_DIAG,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        x: *mut Self,
+        incx: i32,
+    );
+}
+
+// Complex scalars are passed by pointer, real ones by value
+macro_rules! impl_blas_scalar {
+    ($t:ty, $trsm:ident, $trsv:ident, $alpha:ident => $alpha_arg:expr) => {
+        impl BlasScalar for $t {
+            unsafe fn cblas_trsm(
+                layout: CBLAS_LAYOUT,
+                side: CBLAS_SIDE,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                m: i32,
+                n: i32,
+                $alpha: $t,
+                a: *const $t,
+                lda: i32,
+                b: *mut $t,
+                ldb: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$trsm(
+                        layout,
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        $alpha_arg,
+                        a as *const _,
+                        lda,
+                        b as *mut _,
+                        ldb,
+                    )
+                }
+            }
+
+            unsafe fn cblas_trsv(
+                layout: CBLAS_LAYOUT,
+                uplo: CBLAS_UPLO,
+                transa: CBLAS_TRANSPOSE,
+                diag: CBLAS_DIAG,
+                n: i32,
+                a: *const $t,
+                lda: i32,
+                x: *mut $t,
+                incx: i32,
+            ) {
+                unsafe {
+                    cblas_sys::$trsv(
+                        layout,
+                        uplo,
+                        transa,
+                        diag,
+                        n,
+                        a as *const _,
+                        lda,
+                        x as *mut _,
+                        incx,
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_blas_scalar!(f32, cblas_strsm, cblas_strsv, alpha => alpha);
+impl_blas_scalar!(f64, cblas_dtrsm, cblas_dtrsv, alpha => alpha);
+impl_blas_scalar!(Complex<f32>, cblas_ctrsm, cblas_ctrsv, alpha => &alpha as *const _ as *const _);
+impl_blas_scalar!(Complex<f64>, cblas_ztrsm, cblas_ztrsv, alpha => &alpha as *const _ as *const _);
@@ -0,0 +1,135 @@
+//! Simple function-based interface to BLAS triangular solves
+
+use cblas_sys::{CBLAS_DIAG, CBLAS_LAYOUT, CBLAS_SIDE, CBLAS_TRANSPOSE, CBLAS_UPLO};
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{
+    into_i32,
+    matmul::{Side, Triangle},
+    trisolve::{Transpose, TriSolveError, TriSolveOptions, trisolve_check_diagonal, trisolve_dims},
+};
+
+use super::scalar::BlasScalar;
+
+fn cblas_options<T>(
+    opts: &TriSolveOptions<T>,
+) -> (CBLAS_SIDE, CBLAS_UPLO, CBLAS_TRANSPOSE, CBLAS_DIAG) {
+    let side = match opts.side {
+        Side::Left => CBLAS_SIDE::CblasLeft,
+        Side::Right => CBLAS_SIDE::CblasRight,
+    };
+    let uplo = match opts.triangle {
+        Triangle::Lower => CBLAS_UPLO::CblasLower,
+        Triangle::Upper => CBLAS_UPLO::CblasUpper,
+    };
+    let trans = match opts.trans {
+        Transpose::NoTrans => CBLAS_TRANSPOSE::CblasNoTrans,
+        Transpose::Trans => CBLAS_TRANSPOSE::CblasTrans,
+        Transpose::ConjTrans => CBLAS_TRANSPOSE::CblasConjTrans,
+    };
+    let diag = if opts.unit_diag {
+        CBLAS_DIAG::CblasUnit
+    } else {
+        CBLAS_DIAG::CblasNonUnit
+    };
+    (side, uplo, trans, diag)
+}
+
+/// Solves op(A) X = αB or X op(A) = αB, overwriting B with X
+///
+/// A and B are copied to row-major buffers if their rows are not
+/// contiguous.
+pub fn trsm<T, La, Lb, D, D0, D1>(
+    opts: &TriSolveOptions<T>,
+    a: &Slice<T, (D, D), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> Result<(), TriSolveError>
+where
+    T: BlasScalar,
+    La: Layout,
+    Lb: Layout,
+    D: Dim,
+    D0: Dim,
+    D1: Dim,
+{
+    trisolve_dims(a, b, opts.side);
+    trisolve_check_diagonal(a, opts.unit_diag)?;
+
+    if a.stride(1) != 1 {
+        return trsm(opts, &a.to_tensor(), b);
+    }
+    if b.stride(1) != 1 {
+        let mut b_row_major = b.to_tensor();
+        trsm(opts, a, &mut b_row_major)?;
+        b.assign(&b_row_major);
+        return Ok(());
+    }
+
+    let bsh = *b.shape();
+    let (m, n) = (into_i32(bsh.dim(0)), into_i32(bsh.dim(1)));
+    let (side, uplo, trans, diag) = cblas_options(opts);
+
+    unsafe {
+        T::cblas_trsm(
+            CBLAS_LAYOUT::CblasRowMajor,
+            side,
+            uplo,
+            trans,
+            diag,
+            m,
+            n,
+            opts.alpha,
+            a.as_ptr(),
+            into_i32(a.stride(0)),
+            b.as_mut_ptr(),
+            into_i32(b.stride(0)),
+        )
+    }
+    Ok(())
+}
+
+/// Solves op(A) x = αb, overwriting b with x
+pub fn trsv<T, La, Lx, D, D0>(
+    opts: &TriSolveOptions<T>,
+    a: &Slice<T, (D, D), La>,
+    x: &mut Slice<T, (D0,), Lx>,
+) -> Result<(), TriSolveError>
+where
+    T: BlasScalar,
+    La: Layout,
+    Lx: Layout,
+    D: Dim,
+    D0: Dim,
+{
+    let n = a.shape().dim(0);
+    assert_eq!(n, a.shape().dim(1), "A must be square");
+    assert_eq!(n, x.shape().dim(0), "A and x have incompatible dimensions");
+    trisolve_check_diagonal(a, opts.unit_diag)?;
+
+    if a.stride(1) != 1 {
+        return trsv(opts, &a.to_tensor(), x);
+    }
+
+    let (_, uplo, trans, diag) = cblas_options(opts);
+
+    // trsv has no scaling factor
+    if opts.alpha != T::one() {
+        for i in 0..n {
+            x[[i]] = opts.alpha * x[[i]];
+        }
+    }
+
+    unsafe {
+        T::cblas_trsv(
+            CBLAS_LAYOUT::CblasRowMajor,
+            uplo,
+            trans,
+            diag,
+            into_i32(n),
+            a.as_ptr(),
+            into_i32(a.stride(0)),
+            x.as_mut_ptr(),
+            into_i32(x.stride(0)),
+        )
+    }
+    Ok(())
+}
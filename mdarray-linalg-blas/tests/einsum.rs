@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::einsum::*;
+use mdarray_linalg_blas::Blas;
+
+#[test]
+fn einsum_matmul() {
+    test_einsum_matmul(&Blas);
+}
+
+#[test]
+fn einsum_implicit_output() {
+    test_einsum_implicit_output(&Blas);
+}
+
+#[test]
+fn einsum_trace_diagonal() {
+    test_einsum_trace_diagonal(&Blas);
+}
+
+#[test]
+fn einsum_batch() {
+    test_einsum_batch(&Blas);
+}
+
+#[test]
+fn einsum_single_operand() {
+    test_einsum_single_operand(&Blas);
+}
+
+#[test]
+fn einsum_outer_and_full() {
+    test_einsum_outer_and_full(&Blas);
+}
+
+#[test]
+fn einsum_many_operands() {
+    test_einsum_many_operands(&Blas);
+}
+
+#[test]
+fn einsum_complex() {
+    test_einsum_complex(&Blas);
+}
+
+#[test]
+fn einsum_errors() {
+    test_einsum_errors(&Blas);
+}
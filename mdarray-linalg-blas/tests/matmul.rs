@@ -236,3 +236,34 @@ fn test_gemm() {
         }
     }
 }
+
+#[test]
+fn batched_matmul() {
+    test_batched_matmul_impl(&Blas);
+}
+
+#[test]
+fn batched_matmul_broadcast() {
+    test_batched_matmul_broadcast_impl(&Blas);
+}
+
+#[test]
+fn batched_matmul_write_add_to() {
+    test_batched_matmul_write_add_to_impl(&Blas);
+}
+
+#[test]
+fn batched_matmul_strided() {
+    test_batched_matmul_strided_impl(&Blas);
+}
+
+#[test]
+fn batched_matmul_complex() {
+    test_batched_matmul_complex_impl(&Blas);
+}
+
+#[test]
+#[should_panic]
+fn batched_matmul_mismatch() {
+    test_batched_matmul_mismatch_impl(&Blas);
+}
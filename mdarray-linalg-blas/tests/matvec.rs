@@ -85,3 +85,53 @@ fn argmax_abs() {
 fn argmax_write_real() {
     test_argmax_write_real(Blas)
 }
+
+#[test]
+fn gbmv() {
+    test_gbmv(Blas)
+}
+
+#[test]
+fn gbmv_complex() {
+    test_gbmv_complex(Blas)
+}
+
+#[test]
+fn hbmv() {
+    test_hbmv(Blas)
+}
+
+#[test]
+fn hbmv_complex() {
+    test_hbmv_complex(Blas)
+}
+
+#[test]
+fn tbmv() {
+    test_tbmv(Blas)
+}
+
+#[test]
+fn tbmv_complex() {
+    test_tbmv_complex(Blas)
+}
+
+#[test]
+fn hpmv() {
+    test_hpmv(Blas)
+}
+
+#[test]
+fn hpmv_complex() {
+    test_hpmv_complex(Blas)
+}
+
+#[test]
+fn tpmv() {
+    test_tpmv(Blas)
+}
+
+#[test]
+fn structured_beta_zero() {
+    test_structured_beta_zero(Blas)
+}
@@ -0,0 +1,37 @@
+use mdarray_linalg::testing::ncon::*;
+use mdarray_linalg_blas::Blas;
+
+#[test]
+fn ncon_matrix_chain() {
+    test_ncon_matrix_chain(&Blas);
+}
+
+#[test]
+fn ncon_network() {
+    test_ncon_network(&Blas);
+}
+
+#[test]
+fn ncon_partial_trace() {
+    test_ncon_partial_trace(&Blas);
+}
+
+#[test]
+fn ncon_single_tensor() {
+    test_ncon_single_tensor(&Blas);
+}
+
+#[test]
+fn ncon_complex() {
+    test_ncon_complex(&Blas);
+}
+
+#[test]
+fn ncon_with_path() {
+    test_ncon_with_path(&Blas);
+}
+
+#[test]
+fn ncon_errors() {
+    test_ncon_errors(&Blas);
+}
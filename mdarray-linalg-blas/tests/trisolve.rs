@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::trisolve::*;
+use mdarray_linalg_blas::Blas;
+
+#[test]
+fn trsm_left_lower() {
+    test_trsm_left_lower(&Blas);
+}
+
+#[test]
+fn trsm_left_upper() {
+    test_trsm_left_upper(&Blas);
+}
+
+#[test]
+fn trsm_transpose() {
+    test_trsm_transpose(&Blas);
+}
+
+#[test]
+fn trsm_right() {
+    test_trsm_right(&Blas);
+}
+
+#[test]
+fn trsm_unit_diagonal() {
+    test_trsm_unit_diagonal(&Blas);
+}
+
+#[test]
+fn trsm_scale_write_in_place() {
+    test_trsm_scale_write_in_place(&Blas);
+}
+
+#[test]
+fn trsv() {
+    test_trsv(&Blas);
+}
+
+#[test]
+fn trsm_complex() {
+    test_trsm_complex(&Blas);
+}
+
+#[test]
+fn trsm_singular() {
+    test_trsm_singular(&Blas);
+}
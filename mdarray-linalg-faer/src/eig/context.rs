@@ -13,6 +13,9 @@
 //     - Q is n × n         (orthogonal/unitary eigenvectors)
 //     - Λ is n × n         (diagonal matrix with real eigenvalues)
 //
+// Selected eigenpairs of Hermitian matrices are extracted from the full
+// decomposition, faer having no partial eigensolver.
+//
 // Schur Decomposition:
 //     A = Z * T * Z^H
 // where:
@@ -21,19 +24,14 @@
 
 use faer_traits::ComplexField;
 use mdarray::{Dense, Dim, Layout, Shape, Slice, Tensor};
-use mdarray_linalg::eig::{Eig, EigDecomp, EigError, EigResult, SchurError, SchurResult};
+use mdarray_linalg::eig::{
+    Eig, EigDecomp, EigError, EigRange, EigResult, EighSelectDecomp, EighSelectResult, SchurError,
+    SchurResult, eig_range_check,
+};
 use num_complex::{Complex, ComplexFloat};
 
 use crate::{Faer, into_faer, into_faer_mut};
 
-macro_rules! complex_from_faer {
-    ($val:expr, $t:ty) => {{
-        let re: <$t as ComplexFloat>::Real = unsafe { std::mem::transmute_copy(&($val.re)) };
-        let im: <$t as ComplexFloat>::Real = unsafe { std::mem::transmute_copy(&($val.im)) };
-        Complex::new(re, im)
-    }};
-}
-
 impl<T, D0: Dim, D1: Dim> Eig<T, D0, D1> for Faer
 where
     T: ComplexFloat
@@ -389,6 +387,50 @@ where
         self.eigh(a)
     }
 
+    /// Compute the eigenpairs of a Hermitian matrix selected by index or value range
+    fn eigh_select<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EigRange<<T as ComplexFloat>::Real>,
+    ) -> EighSelectResult<T, D0, D1> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(EigError::NotSquareMatrix);
+        }
+        eig_range_check(&range, n)?;
+
+        let evd = into_faer(a)
+            .self_adjoint_eigen(faer::Side::Lower)
+            .map_err(|_| EigError::BackendDidNotConverge { iterations: 0 })?;
+        let (s, u) = (evd.S(), evd.U());
+
+        // The eigenvalues are sorted in ascending order
+        let selected: Vec<usize> = match range {
+            EigRange::Index(r) => r.collect(),
+            EigRange::Value(lower, upper) => (0..n)
+                .filter(|&i| {
+                    let lambda = s[i].re();
+                    lower < lambda && lambda <= upper
+                })
+                .collect(),
+        };
+        let k = selected.len();
+
+        let eigenvalues = Tensor::from_fn(<(D0,) as Shape>::from_dims(&[k]), |i| {
+            s[selected[i[0]]].re()
+        });
+        let eigenvectors = Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, k]), |i| {
+            u[(i[0], selected[i[1]])]
+        });
+
+        Ok(EighSelectDecomp {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+
     /// Compute Schur decomposition with new allocated matrices
     fn schur<L: Layout>(&self, _a: &mut Slice<T, (D0, D1), L>) -> SchurResult<T, D0, D1> {
         todo!();
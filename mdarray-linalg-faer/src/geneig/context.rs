@@ -0,0 +1,89 @@
+// Generalized eigenvalue problems:
+//     A v = λ B v
+// where:
+//     - A and B are n × n  (input matrices, left untouched)
+//     - λ = α / β          (β = 0 for infinite eigenvalues)
+//     - v are the right eigenvectors
+//
+// General pencils use faer's QZ algorithm. For a Hermitian A and a
+// Hermitian positive definite B, the problem is reduced to a standard
+// Hermitian eigenproblem with the Cholesky factor of B.
+
+use faer_traits::ComplexField;
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::geneig::{
+    GenEig, GenEigDecomp, GenEigError, GenEigResult, GenEighResult, gen_eig_dims,
+};
+use num_complex::ComplexFloat;
+
+use super::simple::gen_eigh_faer;
+use crate::{Faer, into_faer};
+
+impl Faer {
+    fn gen_eig_impl<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+        &self,
+        a: &Slice<T, (D0, D1), La>,
+        b: &Slice<T, (D0, D1), Lb>,
+        vectors: bool,
+    ) -> GenEigResult<T, D0, D1>
+    where
+        T: ComplexFloat + ComplexField + Default + 'static,
+    {
+        let n = gen_eig_dims(a, b)?;
+
+        let gevd = into_faer(a)
+            .generalized_eigen(into_faer(b))
+            .map_err(|_| GenEigError::BackendDidNotConverge { iterations: 0 })?;
+
+        let (s_a, s_b) = (gevd.S_a(), gevd.S_b());
+        let shape1 = <(D0,) as Shape>::from_dims(&[n]);
+        let alpha = Tensor::from_fn(shape1, |i| complex_from_faer!(&s_a[i[0]], T));
+        let beta = Tensor::from_fn(shape1, |i| complex_from_faer!(&s_b[i[0]], T));
+
+        let right_eigenvectors = vectors.then(|| {
+            let u = gevd.U();
+            Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, n]), |i| {
+                complex_from_faer!(&u[(i[0], i[1])], T)
+            })
+        });
+
+        Ok(GenEigDecomp {
+            alpha,
+            beta,
+            right_eigenvectors,
+        })
+    }
+}
+
+impl<T, D0: Dim, D1: Dim> GenEig<T, D0, D1> for Faer
+where
+    T: ComplexFloat
+        + ComplexField
+        + Default
+        + std::convert::From<<T as num_complex::ComplexFloat>::Real>
+        + 'static,
+{
+    fn gen_eig<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEigResult<T, D0, D1> {
+        self.gen_eig_impl(a, b, true)
+    }
+
+    fn gen_eig_values<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEigResult<T, D0, D1> {
+        self.gen_eig_impl(a, b, false)
+    }
+
+    fn gen_eigh<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEighResult<T, D0, D1> {
+        gen_eigh_faer(a, b)
+    }
+}
@@ -0,0 +1,64 @@
+use faer::linalg::{solvers::LltError, triangular_solve};
+use faer_traits::ComplexField;
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::geneig::{GenEigError, GenEighDecomp, GenEighResult, gen_eig_dims};
+use num_complex::ComplexFloat;
+
+use crate::into_faer;
+
+/// Solves the Hermitian-definite problem A v = λ B v.
+///
+/// With the Cholesky factorization B = L Lᴴ, the problem reduces to the
+/// standard Hermitian eigenproblem C w = λ w with C = L⁻¹ A L⁻ᴴ, and the
+/// eigenvectors V = L⁻ᴴ W are B-orthonormal.
+/// Only the lower triangles of A and B are referenced.
+pub fn gen_eigh_faer<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> GenEighResult<T, D0, D1>
+where
+    T: ComplexFloat + ComplexField + Default + 'static,
+{
+    let n = gen_eig_dims(a, b)?;
+    let par = faer::get_global_parallelism();
+
+    let llt =
+        into_faer(b)
+            .llt(faer::Side::Lower)
+            .map_err(
+                |LltError::NonPositivePivot { index }| GenEigError::NotPositiveDefinite {
+                    order: index + 1,
+                },
+            )?;
+    let l = llt.L();
+
+    // X = L⁻¹ A, then C = L⁻¹ Xᴴ since A is Hermitian
+    let a_faer = into_faer(a);
+    let mut x = faer::Mat::from_fn(n, n, |i, j| {
+        if i >= j {
+            a_faer[(i, j)]
+        } else {
+            ComplexFloat::conj(a_faer[(j, i)])
+        }
+    });
+    triangular_solve::solve_lower_triangular_in_place(l, x.as_mut(), par);
+    let mut c = x.adjoint().to_owned();
+    triangular_solve::solve_lower_triangular_in_place(l, c.as_mut(), par);
+
+    let evd = c
+        .self_adjoint_eigen(faer::Side::Lower)
+        .map_err(|_| GenEigError::BackendDidNotConverge { iterations: 0 })?;
+
+    let mut v = evd.U().to_owned();
+    triangular_solve::solve_upper_triangular_in_place(l.adjoint(), v.as_mut(), par);
+
+    let s = evd.S();
+    let eigenvalues = Tensor::from_fn(<(D0,) as Shape>::from_dims(&[n]), |i| s[i[0]].re());
+    let eigenvectors =
+        Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, n]), |i| v[(i[0], i[1])]);
+
+    Ok(GenEighDecomp {
+        eigenvalues,
+        eigenvectors,
+    })
+}
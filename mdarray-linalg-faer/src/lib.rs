@@ -44,7 +44,21 @@
 //! println!("R: {:?}", r);
 //! ```
 
+/// Converts a faer complex scalar into a `num_complex::Complex` with the
+/// real type of `$t`, which both represent as a pair of real numbers
+macro_rules! complex_from_faer {
+    ($val:expr, $t:ty) => {{
+        let re: <$t as num_complex::ComplexFloat>::Real =
+            unsafe { std::mem::transmute_copy(&($val.re)) };
+        let im: <$t as num_complex::ComplexFloat>::Real =
+            unsafe { std::mem::transmute_copy(&($val.im)) };
+        num_complex::Complex::new(re, im)
+    }};
+}
+
 pub mod eig;
+pub mod geneig;
+pub mod lstsq;
 pub mod lu;
 pub mod matmul;
 pub mod qr;
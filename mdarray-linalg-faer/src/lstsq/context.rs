@@ -0,0 +1,73 @@
+// Least-squares solver using Householder QR:
+//     min ‖AX − B‖₂
+// where:
+//     - A is m × n         (coefficient matrix, must have full rank)
+//     - B is m × nrhs      (right-hand side matrix)
+//     - X is n × nrhs      (solution, of minimum norm if m < n)
+//
+// A rank deficient A is reported as `LstsqError::RankDeficient`.
+
+use faer_traits::ComplexField;
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::lstsq::{Lstsq, LstsqError, LstsqResult, LstsqResultType, lstsq_residuals};
+use num_complex::ComplexFloat;
+
+use super::simple::lstsq_faer;
+use crate::Faer;
+
+impl<T, D0: Dim, D1: Dim> Lstsq<T, D0, D1> for Faer
+where
+    T: ComplexFloat
+        + ComplexField
+        + Default
+        + std::convert::From<<T as num_complex::ComplexFloat>::Real>
+        + 'static,
+{
+    /// Solves min ‖AX − B‖ with new allocated solution matrix
+    fn lstsq<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &Slice<T, (D0, D1), Lb>,
+    ) -> LstsqResultType<T, D0, D1> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let bsh = *b.shape();
+        let (mb, nrhs) = (bsh.dim(0), bsh.dim(1));
+
+        if mb != m {
+            return Err(LstsqError::InvalidDimensions);
+        }
+
+        let mut b_work =
+            Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[m.max(n), nrhs]), T::zero());
+        for i in 0..m {
+            for j in 0..nrhs {
+                b_work[[i, j]] = b[[i, j]];
+            }
+        }
+
+        let rank = lstsq_faer(a, &mut b_work)?;
+
+        let x = Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, nrhs]), |i| {
+            b_work[[i[0], i[1]]]
+        });
+        let residuals = lstsq_residuals(a, &x, b);
+
+        Ok(LstsqResult {
+            x,
+            residuals,
+            rank,
+            singular_values: None,
+        })
+    }
+
+    /// Solves min ‖AX − B‖ overwriting B, which must be max(m, n) × nrhs
+    fn lstsq_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<usize, LstsqError> {
+        lstsq_faer(a, b)
+    }
+}
@@ -0,0 +1,88 @@
+use faer::linalg::solvers::SolveLstsq;
+use faer_traits::ComplexField;
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::lstsq::LstsqError;
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast};
+
+use crate::{into_faer, into_faer_mut};
+
+/// Solves min ‖AX − B‖ for a full rank A using column-pivoted Householder QR.
+///
+/// B is max(m, n) × nrhs: on entry its first m rows contain the
+/// right-hand sides, on exit its first n rows contain X.
+///
+/// - m ≥ n: A P = Q R, solved by faer's column-pivoted QR least-squares solver.
+/// - m < n: Aᴴ Pᵀ = Q R, the minimum-norm solution is X = Q R⁻ᴴ P B.
+///
+/// The effective rank is read off the diagonal of the column-pivoted R
+/// factor; a rank deficient A is reported as an error.
+pub fn lstsq_faer<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> Result<usize, LstsqError>
+where
+    T: ComplexFloat + ComplexField + Default + 'static,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    let bsh = *b.shape();
+    let (mb, nrhs) = (bsh.dim(0), bsh.dim(1));
+
+    if mb != m.max(n) {
+        return Err(LstsqError::InvalidDimensions);
+    }
+
+    let a_faer = into_faer(a);
+    let par = faer::get_global_parallelism();
+
+    // The column-pivoted QR of A (m ≥ n) or Aᴴ (m < n) reveals the rank
+    let col_piv_qr = if m >= n {
+        a_faer.col_piv_qr()
+    } else {
+        a_faer.adjoint().col_piv_qr()
+    };
+
+    let k = m.min(n);
+    let r = col_piv_qr.thin_R();
+    let rank = if k == 0 {
+        0
+    } else {
+        let tol = <<T as ComplexFloat>::Real as Float>::epsilon()
+            * <<T as ComplexFloat>::Real as NumCast>::from(m.max(n)).unwrap()
+            * ComplexFloat::abs(r[(0, 0)]);
+        (0..k)
+            .take_while(|&i| ComplexFloat::abs(r[(i, i)]) > tol)
+            .count()
+    };
+
+    if rank < k {
+        return Err(LstsqError::RankDeficient { rank });
+    }
+
+    let mut b_faer = into_faer_mut(b);
+
+    if m >= n {
+        col_piv_qr.solve_lstsq_in_place(b_faer.as_mut());
+    } else {
+        let q = col_piv_qr.compute_thin_Q();
+
+        // Aᴴ Pᵀ = Q R gives P A = Rᴴ Qᴴ: Rᴴ Y = P B, then X = Q Y
+        let mut y = col_piv_qr.P() * b_faer.as_ref().subrows(0, m);
+        faer::linalg::triangular_solve::solve_lower_triangular_in_place(
+            r.adjoint(),
+            y.as_mut(),
+            par,
+        );
+        let x = &q * &y;
+
+        for i in 0..n {
+            for j in 0..nrhs {
+                b_faer[(i, j)] = x[(i, j)];
+            }
+        }
+    }
+
+    Ok(rank)
+}
@@ -5,15 +5,41 @@
 //     - P is m × m        (permutation matrix)
 //     - L is m × m        (lower triangular with ones on diagonal)
 //     - U is m × n         (upper triangular/trapezoidal matrix)
+//
+// `LuFactors` of a square A are computed with faer's `PartialPivLu`, its
+// row permutation being stored as LAPACK-style pivot indices.
+// `CholeskyFactors` of a Hermitian positive definite A use faer's `Llt`,
+// and `LdlFactors` of a Hermitian indefinite A its Bunch–Kaufman `Lblt`.
 
 use dyn_stack::{MemBuffer, MemStack};
+use faer::{
+    Conj,
+    linalg::{
+        cholesky::{
+            lblt::{
+                self,
+                factor::{LbltParams, PivotingStrategy},
+            },
+            llt,
+        },
+        lu::partial_pivoting::solve,
+    },
+};
 use faer_traits::ComplexField;
 use mdarray::{Dim, Layout, Shape, Slice, Tensor};
-use mdarray_linalg::lu::{InvError, InvResult, LU};
+use mdarray_linalg::{
+    lu::{
+        CholeskyFactor, CholeskyFactors, InvError, InvResult, LU, LdlFactor, LdlFactors,
+        LdlPivoting, LuFactor, LuFactors,
+    },
+    matmul::Triangle,
+    solve::SolveError,
+    trisolve::Transpose,
+};
 use num_complex::ComplexFloat;
 
-use super::simple::lu_faer;
-use crate::{Faer, into_faer_mut};
+use super::simple::{ipiv_to_perm, llt_faer, lu_faer, perm_to_ipiv};
+use crate::{Faer, into_faer, into_faer_mut};
 
 impl<T, D0: Dim, D1: Dim> LU<T, D0, D1> for Faer
 where
@@ -199,12 +225,337 @@ where
     }
 
     /// Computes the Cholesky decomposition, returning a lower-triangular matrix
-    fn choleski<L: Layout>(&self, _a: &mut Slice<T, (D0, D1), L>) -> InvResult<T, D0, D1> {
-        todo!("choleski will be implemented later")
+    fn choleski<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> InvResult<T, D0, D1> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        assert_eq!(m, n, "Matrix must be square for Cholesky decomposition");
+
+        let mut l = Tensor::<T, (D0, D1)>::from_elem(ash, T::zero());
+        self.choleski_write(a)?;
+        for i in 0..m {
+            for j in 0..=i {
+                l[[i, j]] = a[[i, j]];
+            }
+        }
+        Ok(l)
     }
 
     /// Computes the Cholesky decomposition in-place, overwriting the input matrix
-    fn choleski_write<L: Layout>(&self, _a: &mut Slice<T, (D0, D1), L>) -> Result<(), InvError> {
-        todo!("choleski_write will be implemented later")
+    fn choleski_write<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<(), InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        assert_eq!(m, n, "Matrix must be square for Cholesky decomposition");
+
+        let square = <(D0, D0) as Shape>::from_dims(&[m, m]);
+        let a_sq = Tensor::<T, (D0, D0)>::from_fn(square, |i| a[[i[0], i[1]]]);
+        let l = llt_faer(&a_sq, Triangle::Lower)
+            .map_err(|lpm| InvError::NotPositiveDefinite { lpm })?;
+
+        for i in 0..m {
+            for j in 0..n {
+                a[[i, j]] = l[[i, j]];
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, D: Dim> LuFactor<T, D> for Faer
+where
+    T: ComplexFloat + ComplexField + Default + 'static,
+{
+    fn lu_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+    ) -> Result<LuFactors<'_, T, D, Self>, InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(InvError::NotSquare {
+                rows: m as i32,
+                cols: n as i32,
+            });
+        }
+
+        let plu = into_faer(a).partial_piv_lu();
+        let (l, u) = (plu.L(), plu.U());
+
+        let lu = Tensor::from_fn(ash, |i| {
+            if i[0] > i[1] {
+                l[(i[0], i[1])]
+            } else {
+                u[(i[0], i[1])]
+            }
+        });
+        let ipiv = perm_to_ipiv(plu.P().arrays().0);
+
+        Ok(LuFactors::new(self, lu, ipiv))
+    }
+
+    fn lu_solve_write<Lb: Layout>(
+        &self,
+        f: &LuFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+        trans: Transpose,
+    ) -> Result<(), SolveError> {
+        let (n, nrhs) = (b.shape().dim(0), b.shape().dim(1));
+
+        if n != f.n() {
+            return Err(SolveError::InvalidDimensions);
+        }
+        if let Some(i) = f.zero_pivot() {
+            return Err(SolveError::SingularMatrix {
+                diagonal: (i + 1) as i32,
+            });
+        }
+
+        let par = faer::get_global_parallelism();
+        let lu = into_faer(&f.lu);
+        let perm = ipiv_to_perm(&f.ipiv);
+        let rhs = into_faer_mut(b);
+        let mut mem = MemBuffer::new(solve::solve_in_place_scratch::<usize, T>(n, nrhs, par));
+        let stack = MemStack::new(&mut mem);
+
+        // The strictly lower part of the packed factors is read as the unit
+        // lower triangular L
+        match trans {
+            Transpose::NoTrans => {
+                solve::solve_in_place_with_conj(lu, lu, perm.as_ref(), Conj::No, rhs, par, stack)
+            }
+            Transpose::Trans => solve::solve_transpose_in_place_with_conj(
+                lu,
+                lu,
+                perm.as_ref(),
+                Conj::No,
+                rhs,
+                par,
+                stack,
+            ),
+            Transpose::ConjTrans => solve::solve_transpose_in_place_with_conj(
+                lu,
+                lu,
+                perm.as_ref(),
+                Conj::Yes,
+                rhs,
+                par,
+                stack,
+            ),
+        }
+
+        Ok(())
+    }
+
+    fn lu_inv(&self, f: &LuFactors<'_, T, D, Self>) -> InvResult<T, D, D> {
+        if let Some(i) = f.zero_pivot() {
+            return Err(InvError::Singular {
+                pivot: (i + 1) as i32,
+            });
+        }
+
+        let n = f.n();
+        let par = faer::get_global_parallelism();
+        let lu = into_faer(&f.lu);
+        let perm = ipiv_to_perm(&f.ipiv);
+
+        let mut inv_mat = Tensor::<T, (D, D)>::from_elem(*f.lu.shape(), T::zero());
+        faer::linalg::lu::partial_pivoting::inverse::inverse(
+            into_faer_mut(&mut inv_mat),
+            lu,
+            lu,
+            perm.as_ref(),
+            par,
+            MemStack::new(&mut MemBuffer::new(
+                faer::linalg::lu::partial_pivoting::inverse::inverse_scratch::<usize, T>(n, par),
+            )),
+        );
+
+        Ok(inv_mat)
+    }
+}
+
+impl<T, D: Dim> CholeskyFactor<T, D> for Faer
+where
+    T: ComplexFloat + ComplexField + Default + 'static,
+{
+    fn cholesky_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        triangle: Triangle,
+    ) -> Result<CholeskyFactors<'_, T, D, Self>, InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(InvError::NotSquare {
+                rows: m as i32,
+                cols: n as i32,
+            });
+        }
+
+        let l = llt_faer(a, triangle).map_err(|lpm| InvError::NotPositiveDefinite { lpm })?;
+        let factor = match triangle {
+            Triangle::Lower => l,
+            Triangle::Upper => Tensor::from_fn(ash, |i| l[[i[1], i[0]]].conj()),
+        };
+
+        Ok(CholeskyFactors::new(self, factor, triangle))
+    }
+
+    fn cholesky_solve_write<Lb: Layout>(
+        &self,
+        f: &CholeskyFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError> {
+        let (n, nrhs) = (b.shape().dim(0), b.shape().dim(1));
+
+        if n != f.n() {
+            return Err(SolveError::InvalidDimensions);
+        }
+
+        // L = Uᴴ when the upper factor is stored
+        let (l, conj) = match f.triangle {
+            Triangle::Lower => (into_faer(&f.factor), Conj::No),
+            Triangle::Upper => (into_faer(&f.factor).transpose(), Conj::Yes),
+        };
+
+        let par = faer::get_global_parallelism();
+        llt::solve::solve_in_place_with_conj(
+            l,
+            conj,
+            into_faer_mut(b),
+            par,
+            MemStack::new(&mut MemBuffer::new(
+                llt::solve::solve_in_place_scratch::<T>(n, nrhs, par),
+            )),
+        );
+
+        Ok(())
+    }
+
+    fn cholesky_inv(&self, f: &CholeskyFactors<'_, T, D, Self>) -> InvResult<T, D, D> {
+        let n = f.n();
+        let l = f.l();
+        let par = faer::get_global_parallelism();
+
+        let mut inv_mat = Tensor::<T, (D, D)>::from_elem(*f.factor.shape(), T::zero());
+        llt::inverse::inverse(
+            into_faer_mut(&mut inv_mat),
+            into_faer(&l),
+            par,
+            MemStack::new(&mut MemBuffer::new(llt::inverse::inverse_scratch::<T>(
+                n, par,
+            ))),
+        );
+
+        // Only the lower triangle of the Hermitian inverse is computed
+        for i in 0..n {
+            for j in 0..i {
+                inv_mat[[j, i]] = inv_mat[[i, j]].conj();
+            }
+        }
+
+        Ok(inv_mat)
+    }
+}
+
+impl<T, D: Dim> LdlFactor<T, D> for Faer
+where
+    T: ComplexFloat + ComplexField + Default + 'static,
+{
+    fn ldl_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        pivoting: LdlPivoting,
+    ) -> Result<LdlFactors<'_, T, D, Self>, InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(InvError::NotSquare {
+                rows: m as i32,
+                cols: n as i32,
+            });
+        }
+
+        let params = LbltParams {
+            pivoting: match pivoting {
+                LdlPivoting::BunchKaufman => PivotingStrategy::Partial,
+                LdlPivoting::Rook => PivotingStrategy::Rook,
+            },
+            ..faer::auto!(T)
+        };
+
+        let par = faer::get_global_parallelism();
+        let mut lb = faer::Mat::<T>::from_fn(n, n, |i, j| a[[i, j]]);
+        let mut subdiag = faer::Col::<T>::zeros(n);
+        let mut perm = vec![0usize; n];
+        let mut perm_inv = vec![0usize; n];
+
+        lblt::factor::cholesky_in_place(
+            lb.as_mut(),
+            subdiag.as_mut().as_diagonal_mut(),
+            &mut perm,
+            &mut perm_inv,
+            par,
+            MemStack::new(&mut MemBuffer::new(
+                lblt::factor::cholesky_in_place_scratch::<usize, T>(n, par, params.into()),
+            )),
+            params.into(),
+        );
+
+        let l = Tensor::from_fn(ash, |i| match i[0].cmp(&i[1]) {
+            std::cmp::Ordering::Greater => lb[(i[0], i[1])],
+            std::cmp::Ordering::Equal => T::one(),
+            std::cmp::Ordering::Less => T::zero(),
+        });
+        let d = (0..n).map(|i| lb[(i, i)]).collect();
+        let e = (0..n).map(|i| subdiag[i]).collect();
+
+        Ok(LdlFactors::new(self, l, d, e, perm))
+    }
+
+    fn ldl_solve_write<Lb: Layout>(
+        &self,
+        f: &LdlFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError> {
+        let (n, nrhs) = (b.shape().dim(0), b.shape().dim(1));
+
+        if n != f.n() {
+            return Err(SolveError::InvalidDimensions);
+        }
+        if let Some(i) = f.singular_block() {
+            return Err(SolveError::SingularMatrix {
+                diagonal: (i + 1) as i32,
+            });
+        }
+
+        let mut perm_inv = vec![0usize; n];
+        for (i, &p) in f.perm.iter().enumerate() {
+            perm_inv[p] = i;
+        }
+        let perm = faer::perm::Perm::new_checked(
+            f.perm.clone().into_boxed_slice(),
+            perm_inv.into_boxed_slice(),
+            n,
+        );
+
+        let par = faer::get_global_parallelism();
+        lblt::solve::solve_in_place_with_conj(
+            into_faer(&f.l),
+            faer::ColRef::from_slice(&f.d).as_diagonal(),
+            faer::ColRef::from_slice(&f.e).as_diagonal(),
+            Conj::No,
+            perm.as_ref(),
+            into_faer_mut(b),
+            par,
+            MemStack::new(&mut MemBuffer::new(lblt::solve::solve_in_place_scratch::<
+                usize,
+                T,
+            >(n, nrhs, par))),
+        );
+
+        Ok(())
     }
 }
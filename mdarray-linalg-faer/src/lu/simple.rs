@@ -1,9 +1,11 @@
 use dyn_stack::{MemBuffer, MemStack};
+use faer::linalg::cholesky::llt::factor::LltError;
 use faer_traits::ComplexField;
-use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::matmul::Triangle;
 use num_complex::ComplexFloat;
 
-use crate::into_faer_mut;
+use crate::{into_faer, into_faer_mut};
 
 pub fn lu_faer<
     T: ComplexFloat + ComplexField + Default + 'static,
@@ -82,3 +84,67 @@ pub fn lu_faer<
         p_faer[(i, perm_idx)] = T::one();
     }
 }
+
+/// LAPACK-style pivot indices (1-based row interchanges) equivalent to the
+/// row permutation P A = L U, `fwd[i]` being the row of A moved to row i
+pub fn perm_to_ipiv(fwd: &[usize]) -> Vec<i32> {
+    let n = fwd.len();
+    let mut rows: Vec<usize> = (0..n).collect();
+    let mut pos: Vec<usize> = (0..n).collect();
+
+    (0..n)
+        .map(|i| {
+            let j = pos[fwd[i]];
+            rows.swap(i, j);
+            pos[rows[i]] = i;
+            pos[rows[j]] = j;
+            (j + 1) as i32
+        })
+        .collect()
+}
+
+/// Row permutation P A = L U from LAPACK-style pivot indices
+pub fn ipiv_to_perm(ipiv: &[i32]) -> faer::perm::Perm<usize> {
+    let n = ipiv.len();
+    let mut fwd: Vec<usize> = (0..n).collect();
+    for (i, &p) in ipiv.iter().enumerate() {
+        fwd.swap(i, (p - 1) as usize);
+    }
+
+    let mut bwd = vec![0usize; n];
+    for (i, &f) in fwd.iter().enumerate() {
+        bwd[f] = i;
+    }
+
+    faer::perm::Perm::new_checked(fwd.into_boxed_slice(), bwd.into_boxed_slice(), n)
+}
+
+/// Lower triangular L with A = L Lᴴ from faer's `Llt`, of which only
+/// `triangle` is referenced, or the 1-based index of the first
+/// non-positive pivot
+pub fn llt_faer<T, D: Dim, L: Layout>(
+    a: &Slice<T, (D, D), L>,
+    triangle: Triangle,
+) -> Result<Tensor<T, (D, D)>, i32>
+where
+    T: ComplexFloat + ComplexField + 'static,
+{
+    let side = match triangle {
+        Triangle::Lower => faer::Side::Lower,
+        Triangle::Upper => faer::Side::Upper,
+    };
+
+    match into_faer(a).llt(side) {
+        Ok(llt) => {
+            let l = llt.L();
+            Ok(Tensor::from_fn(*a.shape(), |i| {
+                if i[0] >= i[1] {
+                    l[(i[0], i[1])]
+                } else {
+                    T::zero()
+                }
+            }))
+        }
+        Err(LltError::NonPositivePivot { index }) => Err(index as i32 + 1),
+    }
+}
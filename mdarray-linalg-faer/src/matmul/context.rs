@@ -4,7 +4,7 @@ use faer::{linalg::matmul::matmul, Accum, Par};
 use faer_traits::ComplexField;
 use mdarray::{Dim, DynRank, Layout, Slice, Tensor};
 use mdarray_linalg::matmul::{
-    Axes, ContractBuilder, MatMul, MatMulBuilder, Side, Triangle, Type, _contract,
+    Axes, ContractBuilder, MatMul, MatMulBuilder, Side, Triangle, Type, _contract, _contract_into,
 };
 use num_complex::ComplexFloat;
 use num_traits::{MulAdd, One, Zero};
@@ -34,6 +34,7 @@ where
     a: &'a Slice<T, DynRank, La>,
     b: &'a Slice<T, DynRank, Lb>,
     axes: Axes,
+    order: Option<Box<[usize]>>,
 }
 
 impl<'a, T, La, Lb, D0, D1, D2> FaerMatMulBuilder<'a, T, La, Lb, D0, D1, D2>
@@ -120,17 +121,15 @@ where
         );
     }
 
-    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, (D0, D2), Lc>, _beta: T) {
-        let mut c_faer = into_faer_mut(c);
-        matmul(
-            &mut c_faer,
-            Accum::Add,
-            into_faer(self.a),
-            into_faer(self.b),
-            self.alpha,
-            self.par,
-        );
-        todo!(); // multiplication by beta not implemented in faer ?
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, (D0, D2), Lc>, beta: T) {
+        // C is not read when β = 0, so that NaN or Inf in C do not leak
+        if beta.is_zero() {
+            return self.write(c);
+        }
+
+        // faer only accumulates into C, which is scaled beforehand
+        c.iter_mut().for_each(|x| *x = beta * *x);
+        self.add_to(c);
     }
 
     fn special(self, _lr: Side, _type_of_matrix: Type, _tr: Triangle) -> Tensor<T, (D0, D2)> {
@@ -149,12 +148,28 @@ where
         self
     }
 
+    fn output_order(mut self, order: impl Into<Box<[usize]>>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
     fn eval(self) -> Tensor<T, DynRank> {
-        _contract(Faer, self.a, self.b, self.axes, self.alpha)
+        _contract(Faer, self.a, self.b, self.axes, self.alpha, self.order.as_deref())
+    }
+
+    fn write<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>) {
+        let order = self.order.as_deref();
+        _contract_into(Faer, self.a, self.b, self.axes, self.alpha, order, None, c);
+    }
+
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>) {
+        let order = self.order.as_deref();
+        _contract_into(Faer, self.a, self.b, self.axes, self.alpha, order, Some(T::one()), c);
     }
 
-    fn write(self, _c: &mut Slice<T>) {
-        todo!()
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>, beta: T) {
+        let order = self.order.as_deref();
+        _contract_into(Faer, self.a, self.b, self.axes, self.alpha, order, Some(beta), c);
     }
 }
 
@@ -198,6 +213,7 @@ where
             a,
             b,
             axes: Axes::All,
+            order: None,
         }
     }
 
@@ -220,6 +236,7 @@ where
             a,
             b,
             axes: Axes::LastFirst { k: (n) },
+            order: None,
         }
     }
 
@@ -244,6 +261,7 @@ where
             a,
             b,
             axes: Axes::Specific(axes_a.into(), axes_b.into()),
+            order: None,
         }
     }
 }
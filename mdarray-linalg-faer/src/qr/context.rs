@@ -5,13 +5,21 @@
 //     - Q is m × m        (orthogonal matrix)
 //     - R is m × n         (upper triangular/trapezoidal matrix)
 //     - For thin QR: Q is m × min(m,n) and R is min(m,n) × n
+//
+// QR with column pivoting:
+//     A * P = Q * R
+// where P is a permutation matrix such that the diagonal of R is
+// non-increasing in magnitude.
 
 use faer_traits::ComplexField;
 use mdarray::{Dim, Layout, Shape, Slice, Tensor};
-use mdarray_linalg::{identity, into_i32, qr::QR};
+use mdarray_linalg::{
+    identity, into_i32,
+    qr::{QR, QRPivot, QRPivotDecomp},
+};
 use num_complex::ComplexFloat;
 
-use super::simple::qr_faer;
+use super::simple::{qr_faer, qr_pivot_faer};
 use crate::Faer;
 
 impl<T, D0: Dim, D1: Dim> QR<T, D0, D1> for Faer
@@ -47,4 +55,64 @@ where
     ) {
         qr_faer::<T, D0, D1, L, Lq, Lr>(a, Some(q), r)
     }
+
+    /// Compute thin QR decomposition with new allocated matrices
+    fn qr_thin<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>) {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let k = m.min(n);
+
+        let mut q_mda = Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[m, k]), T::default());
+        let mut r_mda = Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[k, n]), T::default());
+
+        qr_faer(a, Some(&mut q_mda), &mut r_mda);
+        (q_mda, r_mda)
+    }
+
+    /// Compute thin QR decomposition, overwriting existing matrices
+    fn qr_thin_write<L: Layout, Lq: Layout, Lr: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+    ) {
+        qr_faer::<T, D0, D1, L, Lq, Lr>(a, Some(q), r)
+    }
+}
+
+impl<T, D0: Dim, D1: Dim> QRPivot<T, D0, D1> for Faer
+where
+    T: ComplexFloat
+        + ComplexField
+        + Default
+        + std::convert::From<<T as num_complex::ComplexFloat>::Real>
+        + 'static,
+{
+    /// Compute column-pivoted QR decomposition, overwriting existing matrices
+    fn qr_pivot_write<L: Layout, Lq: Layout, Lr: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        q: &mut Slice<T, (D0, D0), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+        perm: &mut [usize],
+    ) {
+        qr_pivot_faer(a, q, r, perm)
+    }
+
+    /// Compute column-pivoted QR decomposition with new allocated matrices
+    fn qr_pivot<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> QRPivotDecomp<T, D0, D1> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let mut q = Tensor::from_elem(<(D0, D0) as Shape>::from_dims(&[m, m]), T::default());
+        let mut r = Tensor::from_elem(ash, T::default());
+        let mut perm = vec![0; n];
+
+        qr_pivot_faer(a, &mut q, &mut r, &mut perm);
+
+        QRPivotDecomp { q, r, perm }
+    }
 }
@@ -44,26 +44,25 @@ pub fn qr_faer<
         faer::prelude::default(),
     );
 
+    // R is either m × n (full) or min(m, n) × n (thin)
     let mut r_faer = into_faer_mut(r_mda);
-    for i in 0..rank {
-        for j in i..n {
-            r_faer[(i, j)] = qr_mat[(i, j)];
-        }
-        for j in 0..i {
-            r_faer[(i, j)] = T::zero();
-        }
-    }
-    for i in rank..m {
+    for i in 0..r_faer.nrows() {
         for j in 0..n {
-            r_faer[(i, j)] = T::zero();
+            r_faer[(i, j)] = if i < rank && j >= i {
+                qr_mat[(i, j)]
+            } else {
+                T::zero()
+            };
         }
     }
 
     if let Some(q) = q_mda {
+        // Q is either m × m (full) or m × min(m, n) (thin)
         let mut q_faer = into_faer_mut(q);
+        let nq = q_faer.ncols();
         // TODO: check why this is necessary
         for i in 0..m {
-            for j in 0..m {
+            for j in 0..nq {
                 if i == j {
                     q_faer[(i, j)] = T::one();
                 } else {
@@ -82,9 +81,48 @@ pub fn qr_faer<
                         faer::linalg::householder::apply_block_householder_sequence_on_the_left_in_place_scratch::<T>(
                             m,
                             block_size,
-                            m,
+                            nq,
                         )
                     )),
                 );
     }
 }
+
+pub fn qr_pivot_faer<
+    T: ComplexFloat + ComplexField + Default + 'static,
+    D0: Dim,
+    D1: Dim,
+    La: Layout,
+    Lq: Layout,
+    Lr: Layout,
+>(
+    a: &Slice<T, (D0, D1), La>,
+    q_mda: &mut Slice<T, (D0, D0), Lq>,
+    r_mda: &mut Slice<T, (D0, D1), Lr>,
+    perm: &mut [usize],
+) {
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    assert_eq!(perm.len(), n, "perm must have length n");
+
+    let qr = into_faer(a).col_piv_qr();
+
+    let r = qr.R();
+    let mut r_faer = into_faer_mut(r_mda);
+    for i in 0..m {
+        for j in 0..n {
+            r_faer[(i, j)] = if i < r.nrows() && j >= i {
+                r[(i, j)]
+            } else {
+                T::zero()
+            };
+        }
+    }
+
+    let mut q_faer = into_faer_mut(q_mda);
+    q_faer.copy_from(qr.compute_Q());
+
+    // The forward array maps positions in A P to columns of A
+    perm.copy_from_slice(qr.P().arrays().0);
+}
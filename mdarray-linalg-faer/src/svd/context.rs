@@ -6,6 +6,8 @@
 //     - Σ is µ × µ         (diagonal matrix with singular values on the diagonal, µ = min(m,n))
 //     - V^T is n × n      (transpose of right singular vectors, orthogonal)
 //     - s (Σ) contains min(m, n) singular values (non-negative, sorted in descending order)
+//
+// The thin SVD only keeps the first µ columns of U (m × µ) and the first µ rows of V^T (µ × n).
 
 use faer_traits::ComplexField;
 use mdarray::{Dense, Dim, Layout, Shape, Slice, Tensor};
@@ -107,4 +109,50 @@ where
     ) -> Result<(), SVDError> {
         svd_faer::<T, D, L, Ls, Dense, Dense>(a, s, None, None)
     }
+
+    /// Compute thin SVD with new allocated matrices
+    fn svd_thin(&self, a: &mut Slice<T, (D, D), L>) -> Result<SVDDecomp<T, D>, SVDError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let min_mn = m.min(n);
+
+        let s_shape = <(D, D) as Shape>::from_dims(&[min_mn, min_mn]);
+        let u_shape = <(D, D) as Shape>::from_dims(&[m, min_mn]);
+        let vt_shape = <(D, D) as Shape>::from_dims(&[min_mn, n]);
+
+        let mut s_mda = Tensor::from_elem(s_shape, T::default());
+        let mut u_mda = Tensor::from_elem(u_shape, T::default());
+        let mut vt_mda = Tensor::from_elem(vt_shape, T::default());
+
+        match svd_faer(a, &mut s_mda, Some(&mut u_mda), Some(&mut vt_mda)) {
+            Err(_) => Err(SVDError::BackendDidNotConverge {
+                superdiagonals: (0),
+            }),
+            Ok(_) => Ok(SVDDecomp {
+                s: s_mda,
+                u: u_mda,
+                vt: vt_mda,
+            }),
+        }
+    }
+
+    /// Compute thin SVD, overwriting existing matrices
+    fn svd_thin_write<Ls: Layout, Lu: Layout, Lvt: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        s: &mut Slice<T, (D, D), Ls>,
+        u: &mut Slice<T, (D, D), Lu>,
+        vt: &mut Slice<T, (D, D), Lvt>,
+    ) -> Result<(), SVDError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let min_mn = m.min(n);
+
+        assert_eq!(u.shape().dim(0), m, "U must be m × min(m, n)");
+        assert_eq!(u.shape().dim(1), min_mn, "U must be m × min(m, n)");
+        assert_eq!(vt.shape().dim(0), min_mn, "Vᵀ must be min(m, n) × n");
+        assert_eq!(vt.shape().dim(1), n, "Vᵀ must be min(m, n) × n");
+        svd_faer::<T, D, L, Ls, Lu, Lvt>(a, s, Some(u), Some(vt))
+    }
 }
@@ -4,7 +4,7 @@ use mdarray::{Dim, Layout, Shape, Slice};
 use mdarray_linalg::{conjugate_in_place, svd::SVDError};
 use num_complex::ComplexFloat;
 
-use crate::{into_faer, into_faer_diag_mut, into_faer_mut, into_faer_mut_transpose};
+use crate::{into_faer, into_faer_diag_mut, into_faer_mut};
 
 pub fn svd_faer<
     T: 'static + ComplexField + Default + ComplexFloat,
@@ -28,9 +28,16 @@ pub fn svd_faer<
 
     match (u_mda, vt_mda) {
         (Some(x), Some(y)) => {
+            // U is m × m for the full SVD and m × min(m, n) for the thin one
+            let compute = if x.shape().dim(1) == m {
+                faer::linalg::svd::ComputeSvdVectors::Full
+            } else {
+                faer::linalg::svd::ComputeSvdVectors::Thin
+            };
+
             let mut s_faer = into_faer_diag_mut(s_mda);
             let u_faer = into_faer_mut(x);
-            let vt_faer = into_faer_mut_transpose(y);
+            let vt_faer = into_faer_mut(y).transpose_mut();
 
             let ret = faer::linalg::svd::svd(
                 a_faer,
@@ -41,8 +48,8 @@ pub fn svd_faer<
                 MemStack::new(&mut MemBuffer::new(faer::linalg::svd::svd_scratch::<T>(
                     m,
                     n,
-                    faer::linalg::svd::ComputeSvdVectors::Full,
-                    faer::linalg::svd::ComputeSvdVectors::Full,
+                    compute,
+                    compute,
                     par,
                     faer::prelude::default(),
                 ))),
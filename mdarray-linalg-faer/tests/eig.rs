@@ -53,3 +53,23 @@ fn eig_values_non_square() {
 // fn schur_decomp_cplx() {
 //     test_schur_cplx(&Faer);
 // }
+
+#[test]
+fn eigh_select_index() {
+    test_eigh_select_index(&Faer);
+}
+
+#[test]
+fn eigh_select_value() {
+    test_eigh_select_value(&Faer);
+}
+
+#[test]
+fn eigh_select_complex() {
+    test_eigh_select_complex(&Faer);
+}
+
+#[test]
+fn eigh_select_invalid_range() {
+    test_eigh_select_invalid_range(&Faer);
+}
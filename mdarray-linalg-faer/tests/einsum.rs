@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::einsum::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn einsum_matmul() {
+    test_einsum_matmul(&Faer);
+}
+
+#[test]
+fn einsum_implicit_output() {
+    test_einsum_implicit_output(&Faer);
+}
+
+#[test]
+fn einsum_trace_diagonal() {
+    test_einsum_trace_diagonal(&Faer);
+}
+
+#[test]
+fn einsum_batch() {
+    test_einsum_batch(&Faer);
+}
+
+#[test]
+fn einsum_single_operand() {
+    test_einsum_single_operand(&Faer);
+}
+
+#[test]
+fn einsum_outer_and_full() {
+    test_einsum_outer_and_full(&Faer);
+}
+
+#[test]
+fn einsum_many_operands() {
+    test_einsum_many_operands(&Faer);
+}
+
+#[test]
+fn einsum_complex() {
+    test_einsum_complex(&Faer);
+}
+
+#[test]
+fn einsum_errors() {
+    test_einsum_errors(&Faer);
+}
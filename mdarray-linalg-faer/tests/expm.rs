@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::expm::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn expm_diagonal() {
+    test_expm_diagonal(&Faer);
+}
+
+#[test]
+fn expm_nilpotent() {
+    test_expm_nilpotent(&Faer);
+}
+
+#[test]
+fn expm_rotation() {
+    test_expm_rotation(&Faer);
+}
+
+#[test]
+fn expm_inverse() {
+    test_expm_inverse(&Faer);
+}
+
+#[test]
+fn expm_write() {
+    test_expm_write(&Faer);
+}
+
+#[test]
+fn expm_complex() {
+    test_expm_complex(&Faer);
+}
+
+#[test]
+fn expm_frechet_commuting() {
+    test_expm_frechet_commuting(&Faer);
+}
+
+#[test]
+fn expm_frechet_block() {
+    test_expm_frechet_block(&Faer);
+}
+
+#[test]
+fn expm_not_square() {
+    test_expm_not_square(&Faer);
+}
@@ -0,0 +1,42 @@
+use mdarray_linalg::testing::geneig::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn gen_eig_random() {
+    test_gen_eig_random(&Faer);
+}
+
+#[test]
+fn gen_eig_values() {
+    test_gen_eig_values(&Faer);
+}
+
+#[test]
+fn gen_eig_infinite() {
+    test_gen_eig_infinite(&Faer);
+}
+
+#[test]
+fn gen_eig_complex() {
+    test_gen_eig_complex(&Faer);
+}
+
+#[test]
+fn gen_eig_not_square() {
+    test_gen_eig_not_square(&Faer);
+}
+
+#[test]
+fn gen_eigh_random() {
+    test_gen_eigh_random(&Faer);
+}
+
+#[test]
+fn gen_eigh_complex() {
+    test_gen_eigh_complex(&Faer);
+}
+
+#[test]
+fn gen_eigh_not_positive_definite() {
+    test_gen_eigh_not_positive_definite(&Faer);
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::lstsq::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn lstsq_overdetermined() {
+    test_lstsq_overdetermined(&Faer);
+}
+
+#[test]
+fn lstsq_consistent() {
+    test_lstsq_consistent(&Faer);
+}
+
+#[test]
+fn lstsq_underdetermined() {
+    test_lstsq_underdetermined(&Faer);
+}
+
+#[test]
+fn lstsq_write() {
+    test_lstsq_write(&Faer);
+}
+
+#[test]
+fn lstsq_rank_deficient_error() {
+    test_lstsq_rank_deficient_error(&Faer);
+}
+
+#[test]
+fn lstsq_cplx_overdetermined() {
+    test_lstsq_cplx_overdetermined(&Faer);
+}
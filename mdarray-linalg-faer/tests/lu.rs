@@ -47,22 +47,97 @@ fn determinant_dummy() {
     test_determinant_dummy(&Faer);
 }
 
-// #[test]
-// fn cholesky_decomposition() {
-//     test_cholesky_decomposition(&Faer);
-// }
+#[test]
+fn cholesky_decomposition() {
+    test_cholesky_decomposition(&Faer);
+}
 
-// #[test]
-// fn cholesky_write() {
-//     test_cholesky_write(&Faer);
-// }
+#[test]
+fn cholesky_write() {
+    test_cholesky_write(&Faer);
+}
 
-// #[test]
-// fn cholesky_not_positive_definite() {
-//     test_cholesky_not_positive_definite(&Faer);
-// }
+#[test]
+fn cholesky_not_positive_definite() {
+    test_cholesky_not_positive_definite(&Faer);
+}
 
-// #[test]
-// fn cholesky_identity_matrix() {
-//     test_cholesky_identity_matrix(&Faer);
-// }
+#[test]
+fn cholesky_identity_matrix() {
+    test_cholesky_identity_matrix(&Faer);
+}
+
+#[test]
+fn lu_factors_reconstruction() {
+    test_lu_factors_reconstruction(&Faer);
+}
+
+#[test]
+fn lu_factors_solve() {
+    test_lu_factors_solve(&Faer);
+}
+
+#[test]
+fn lu_factors_solve_complex() {
+    test_lu_factors_solve_complex(&Faer);
+}
+
+#[test]
+fn lu_factors_det_inv() {
+    test_lu_factors_det_inv(&Faer);
+}
+
+#[test]
+fn lu_factors_rcond() {
+    test_lu_factors_rcond(&Faer);
+}
+
+#[test]
+fn lu_factors_singular() {
+    test_lu_factors_singular(&Faer);
+}
+
+#[test]
+fn lu_factors_invalid_dimensions() {
+    test_lu_factors_invalid_dimensions(&Faer);
+}
+
+#[test]
+fn cholesky_factors_solve() {
+    test_cholesky_factors_solve(&Faer);
+}
+
+#[test]
+fn cholesky_factors_complex() {
+    test_cholesky_factors_complex(&Faer);
+}
+
+#[test]
+fn cholesky_factors_inv_logdet() {
+    test_cholesky_factors_inv_logdet(&Faer);
+}
+
+#[test]
+fn cholesky_factors_not_positive_definite() {
+    test_cholesky_factors_not_positive_definite(&Faer);
+}
+
+#[test]
+fn ldl_factors_solve() {
+    test_ldl_factors_solve(&Faer);
+}
+
+#[test]
+fn ldl_factors_complex() {
+    test_ldl_factors_complex(&Faer);
+}
+
+#[test]
+fn ldl_factors_inertia() {
+    test_ldl_factors_inertia(&Faer);
+}
+
+#[test]
+fn ldl_factors_singular() {
+    test_ldl_factors_singular(&Faer);
+}
@@ -80,6 +80,18 @@ fn chained_operations() {
     }
 }
 
+#[test]
+fn add_to_scaled_zero_beta() {
+    let a = create_test_matrix_f64([2, 3]).eval();
+    let b = create_test_matrix_f64([3, 2]).eval();
+
+    // C is not read when β = 0
+    let mut c = tensor![[f64::NAN, f64::INFINITY], [0., 0.]];
+    Faer.matmul(&a, &b).add_to_scaled(&mut c, 0.);
+
+    assert_eq!(c, naive_matmul(&a, &b));
+}
+
 #[test]
 fn backend_defaults() {
     let _bd = Faer::default();
@@ -192,3 +204,34 @@ fn special_single_element() {
     assert_eq!(*result.shape(), (1, 1));
     assert_eq!(result[[0, 0]], 10.0);
 }
+
+#[test]
+fn batched_matmul() {
+    test_batched_matmul_impl(&Faer);
+}
+
+#[test]
+fn batched_matmul_broadcast() {
+    test_batched_matmul_broadcast_impl(&Faer);
+}
+
+#[test]
+fn batched_matmul_write_add_to() {
+    test_batched_matmul_write_add_to_impl(&Faer);
+}
+
+#[test]
+fn batched_matmul_strided() {
+    test_batched_matmul_strided_impl(&Faer);
+}
+
+#[test]
+fn batched_matmul_complex() {
+    test_batched_matmul_complex_impl(&Faer);
+}
+
+#[test]
+#[should_panic]
+fn batched_matmul_mismatch() {
+    test_batched_matmul_mismatch_impl(&Faer);
+}
@@ -0,0 +1,37 @@
+use mdarray_linalg::testing::ncon::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn ncon_matrix_chain() {
+    test_ncon_matrix_chain(&Faer);
+}
+
+#[test]
+fn ncon_network() {
+    test_ncon_network(&Faer);
+}
+
+#[test]
+fn ncon_partial_trace() {
+    test_ncon_partial_trace(&Faer);
+}
+
+#[test]
+fn ncon_single_tensor() {
+    test_ncon_single_tensor(&Faer);
+}
+
+#[test]
+fn ncon_complex() {
+    test_ncon_complex(&Faer);
+}
+
+#[test]
+fn ncon_with_path() {
+    test_ncon_with_path(&Faer);
+}
+
+#[test]
+fn ncon_errors() {
+    test_ncon_errors(&Faer);
+}
@@ -0,0 +1,42 @@
+use mdarray_linalg::testing::pinv::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn pinv_square() {
+    test_pinv_square(&Faer);
+}
+
+#[test]
+fn pinv_rectangular() {
+    test_pinv_rectangular(&Faer);
+}
+
+#[test]
+fn pinv_rank_deficient() {
+    test_pinv_rank_deficient(&Faer);
+}
+
+#[test]
+fn pinv_tolerance() {
+    test_pinv_tolerance(&Faer);
+}
+
+#[test]
+fn pinv_complex() {
+    test_pinv_complex(&Faer);
+}
+
+#[test]
+fn pinvh() {
+    test_pinvh(&Faer);
+}
+
+#[test]
+fn pinvh_complex() {
+    test_pinvh_complex(&Faer);
+}
+
+#[test]
+fn pinvh_not_square() {
+    test_pinvh_not_square(&Faer);
+}
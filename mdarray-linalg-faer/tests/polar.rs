@@ -0,0 +1,72 @@
+use mdarray_linalg::testing::polar::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn polar_square() {
+    test_polar_square(&Faer);
+}
+
+#[test]
+fn polar_rectangular() {
+    test_polar_rectangular(&Faer);
+}
+
+#[test]
+fn polar_rank_deficient() {
+    test_polar_rank_deficient(&Faer);
+}
+
+#[test]
+fn polar_known() {
+    test_polar_known(&Faer);
+}
+
+#[test]
+fn polar_complex() {
+    test_polar_complex(&Faer);
+}
+
+#[test]
+fn polar_newton() {
+    test_polar_newton(&Faer);
+}
+
+#[test]
+fn polar_newton_complex() {
+    test_polar_newton_complex(&Faer);
+}
+
+#[test]
+fn polar_newton_not_square() {
+    test_polar_newton_not_square(&Faer);
+}
+
+#[test]
+fn polar_halley() {
+    test_polar_halley(&Faer);
+}
+
+#[test]
+fn polar_halley_complex() {
+    test_polar_halley_complex(&Faer);
+}
+
+#[test]
+fn polar_halley_rank_deficient() {
+    test_polar_halley_rank_deficient(&Faer);
+}
+
+#[test]
+fn polar_halley_ill_conditioned() {
+    test_polar_halley_ill_conditioned(&Faer);
+}
+
+#[test]
+fn polar_iterative_edge_cases() {
+    test_polar_iterative_edge_cases(&Faer);
+}
+
+#[test]
+fn polar_paths_agree() {
+    test_polar_paths_agree(&Faer);
+}
@@ -15,3 +15,68 @@ fn qr_structured_matrix() {
 fn qr_complex_matrix() {
     test_qr_complex_matrix(&Faer);
 }
+
+#[test]
+fn qr_tall_matrix() {
+    test_qr_tall_matrix(&Faer);
+}
+
+#[test]
+fn qr_wide_matrix() {
+    test_qr_wide_matrix(&Faer);
+}
+
+#[test]
+fn qr_thin_tall_matrix() {
+    test_qr_thin_tall_matrix(&Faer);
+}
+
+#[test]
+fn qr_thin_wide_matrix() {
+    test_qr_thin_wide_matrix(&Faer);
+}
+
+#[test]
+fn qr_thin_square_matrix() {
+    test_qr_thin_square_matrix(&Faer);
+}
+
+#[test]
+fn qr_upper_triangular() {
+    test_qr_upper_triangular(&Faer);
+}
+
+#[test]
+fn qr_thin_write() {
+    test_qr_thin_write(&Faer);
+}
+
+#[test]
+fn qr_thin_complex_matrix() {
+    test_qr_thin_complex_matrix(&Faer);
+}
+
+#[test]
+fn qr_pivot_square_matrix() {
+    test_qr_pivot_square_matrix(&Faer);
+}
+
+#[test]
+fn qr_pivot_tall_matrix() {
+    test_qr_pivot_tall_matrix(&Faer);
+}
+
+#[test]
+fn qr_pivot_wide_matrix() {
+    test_qr_pivot_wide_matrix(&Faer);
+}
+
+#[test]
+fn qr_pivot_rank() {
+    test_qr_pivot_rank(&Faer);
+}
+
+#[test]
+fn qr_pivot_complex_matrix() {
+    test_qr_pivot_complex_matrix(&Faer);
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::subspace::*;
+use mdarray_linalg_faer::Faer;
+
+#[test]
+fn rank() {
+    test_rank(&Faer);
+}
+
+#[test]
+fn orth_null_space() {
+    test_orth_null_space(&Faer);
+}
+
+#[test]
+fn orth_null_space_complex() {
+    test_orth_null_space_complex(&Faer);
+}
+
+#[test]
+fn subspace_full_rank() {
+    test_subspace_full_rank(&Faer);
+}
+
+#[test]
+fn subspace_qr() {
+    test_subspace_qr(&Faer);
+}
+
+#[test]
+fn subspace_qr_complex() {
+    test_subspace_qr_complex(&Faer);
+}
@@ -1,3 +1,4 @@
+use mdarray_linalg::Naive;
 use mdarray_linalg::testing::svd::*;
 use mdarray_linalg_faer::Faer;
 
@@ -30,3 +31,54 @@ fn test_backend_svd_cplx_square_matrix() {
 fn test_backend_svd_cplx_random_matrix() {
     test_svd_cplx_random_matrix(&Faer);
 }
+
+#[test]
+fn test_backend_svd_thin_tall_matrix() {
+    test_svd_thin_tall_matrix(&Faer);
+}
+
+#[test]
+fn test_backend_svd_thin_wide_matrix() {
+    test_svd_thin_wide_matrix(&Faer);
+}
+
+#[test]
+fn test_backend_svd_thin_cplx_matrix() {
+    test_svd_thin_cplx_matrix(&Faer);
+}
+
+#[test]
+fn test_backend_svd_thin_write() {
+    test_svd_thin_write(&Faer);
+}
+
+#[test]
+#[should_panic(expected = "min(m, n) × n")]
+fn test_backend_svd_thin_write_invalid_vt() {
+    test_svd_thin_write_invalid_vt(&Faer);
+}
+
+#[test]
+fn test_backend_svd_truncated_max_rank() {
+    test_svd_truncated_max_rank(&Faer);
+}
+
+#[test]
+fn test_backend_svd_truncated_rtol() {
+    test_svd_truncated_rtol(&Faer);
+}
+
+#[test]
+fn test_backend_svd_randomized_low_rank() {
+    test_svd_randomized_low_rank(&Faer);
+}
+
+#[test]
+fn test_backend_svd_randomized_discarded_weight() {
+    test_svd_randomized_discarded_weight(&Faer);
+}
+
+#[test]
+fn test_backend_svd_randomized_with() {
+    test_svd_randomized_with(&Naive, &Faer, &Faer);
+}
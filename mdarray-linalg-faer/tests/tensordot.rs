@@ -0,0 +1,109 @@
+use mdarray_linalg::testing::tensordot::*;
+use mdarray_linalg_faer::Faer;
+
+// --- Basic functionality ---
+
+#[test]
+fn tensordot_all_axes() {
+    tensordot_all_axes_impl(&Faer);
+}
+
+#[test]
+fn tensordot_contract_k_2_should_match_all_axes() {
+    tensordot_contract_k_2_should_match_all_axes_impl(&Faer);
+}
+
+#[test]
+fn tensordot_specific_axes_matrix_multiplication() {
+    tensordot_specific_axes_matrix_multiplication_impl(&Faer);
+}
+
+#[test]
+fn tensordot_specific_empty_axes_should_outer_product() {
+    tensordot_specific_empty_axes_should_outer_product_impl(&Faer);
+}
+
+// --- Edge cases ---
+
+#[test]
+fn tensordot_scalar_inputs_should_multiply() {
+    tensordot_scalar_inputs_should_multiply_impl(&Faer);
+}
+
+#[test]
+fn tensordot_increase_deep() {
+    tensordot_increase_deep_impl(&Faer);
+}
+
+#[test]
+fn tensordot_vector_dot_product() {
+    tensordot_vector_dot_product_impl(&Faer);
+}
+
+#[test]
+fn tensordot_mismatched_dimensions_should_panic() {
+    tensordot_mismatched_dimensions_should_panic_impl(&Faer);
+}
+
+// --- Structural and mathematical properties ---
+
+#[test]
+fn tensordot_outer_should_match_manual_kronecker() {
+    tensordot_outer_should_match_manual_kronecker_impl(&Faer);
+}
+
+// --- Writing into existing tensors ---
+
+#[test]
+fn tensordot_write() {
+    tensordot_write_impl(&Faer);
+}
+
+#[test]
+fn tensordot_write_all_axes() {
+    tensordot_write_all_axes_impl(&Faer);
+}
+
+#[test]
+fn tensordot_add_to() {
+    tensordot_add_to_impl(&Faer);
+}
+
+#[test]
+fn tensordot_add_to_scaled() {
+    tensordot_add_to_scaled_impl(&Faer);
+}
+
+#[test]
+fn tensordot_write_strided() {
+    tensordot_write_strided_impl(&Faer);
+}
+
+#[test]
+fn tensordot_write_shape_mismatch_should_panic() {
+    tensordot_write_shape_mismatch_should_panic_impl(&Faer);
+}
+
+// --- Output axis order ---
+
+#[test]
+fn tensordot_output_order() {
+    tensordot_output_order_impl(&Faer);
+}
+
+#[test]
+fn tensordot_output_order_invalid_should_panic() {
+    tensordot_output_order_invalid_should_panic_impl(&Faer);
+}
+
+// --- Strided operands ---
+
+#[test]
+fn tensordot_transposed_operands() {
+    tensordot_transposed_operands_impl(&Faer);
+}
+
+#[test]
+fn tensordot_strided_operands() {
+    tensordot_strided_operands_impl(&Faer);
+}
@@ -0,0 +1,63 @@
+//! Tridiagonal and banded linear systems (GTSV, PTSV, GBSV, PBSV):
+//!     A X = B
+//! where:
+//!     - A is n × n, tridiagonal or banded (left untouched)
+//!     - B is n × nrhs (overwritten with the solution X)
+//!
+//! GTSV and GBSV use Gaussian elimination with partial pivoting, PTSV an
+//! L D Lᴴ factorization and PBSV a banded Cholesky factorization of the
+//! lower band of A.
+
+use mdarray::{Dim, Layout, Slice};
+use mdarray_linalg::banded::{BandMatrix, BandSolve, BandSolveError, band_dims, tridiagonal_dims};
+use num_complex::ComplexFloat;
+
+use super::{
+    scalar::LapackScalar,
+    simple::{gbsv, gtsv, pbsv, ptsv},
+};
+use crate::Lapack;
+
+impl<T> BandSolve<T> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    fn solve_tridiagonal<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        dl: &[T],
+        d: &[T],
+        du: &[T],
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        tridiagonal_dims(d.len(), &[dl.len(), du.len()], b)?;
+        gtsv(dl, d, du, b)
+    }
+
+    fn solve_tridiagonal_pd<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        d: &[T::Real],
+        e: &[T],
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        tridiagonal_dims(d.len(), &[e.len()], b)?;
+        ptsv(d, e, b)
+    }
+
+    fn solve_band<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        band_dims(a, b)?;
+        gbsv(a, b)
+    }
+
+    fn solve_band_pd<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        band_dims(a, b)?;
+        pbsv(a, b)
+    }
+}
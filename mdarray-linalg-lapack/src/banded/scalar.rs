@@ -0,0 +1,178 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // General tridiagonal solve with partial pivoting (GTSV)
+    unsafe fn lapack_gtsv(
+        n: i32,
+        nrhs: i32,
+        dl: *mut Self,
+        d: *mut Self,
+        du: *mut Self,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+
+    // Symmetric/Hermitian positive definite tridiagonal solve (PTSV)
+    unsafe fn lapack_ptsv(
+        n: i32,
+        nrhs: i32,
+        d: *mut Self::Real,
+        e: *mut Self,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+
+    // General banded solve with partial pivoting (GBSV)
+    unsafe fn lapack_gbsv(
+        n: i32,
+        kl: i32,
+        ku: i32,
+        nrhs: i32,
+        ab: *mut Self,
+        ldab: i32,
+        ipiv: *mut i32,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+
+    // Symmetric/Hermitian positive definite banded solve (PBSV)
+    unsafe fn lapack_pbsv(
+        uplo: i8,
+        n: i32,
+        kd: i32,
+        nrhs: i32,
+        ab: *mut Self,
+        ldab: i32,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_gtsv(
+                n: i32,
+                nrhs: i32,
+                dl: *mut Self,
+                d: *mut Self,
+                du: *mut Self,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gtsv_>](
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            dl as *mut _,
+                            d as *mut _,
+                            du as *mut _,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_ptsv(
+                n: i32,
+                nrhs: i32,
+                d: *mut Self::Real,
+                e: *mut Self,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ptsv_>](
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            d,
+                            e as *mut _,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gbsv(
+                n: i32,
+                kl: i32,
+                ku: i32,
+                nrhs: i32,
+                ab: *mut Self,
+                ldab: i32,
+                ipiv: *mut i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gbsv_>](
+                            &n as *const i32,
+                            &kl as *const i32,
+                            &ku as *const i32,
+                            &nrhs as *const i32,
+                            ab as *mut _,
+                            &ldab as *const i32,
+                            ipiv,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_pbsv(
+                uplo: i8,
+                n: i32,
+                kd: i32,
+                nrhs: i32,
+                ab: *mut Self,
+                ldab: i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix pbsv_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            &kd as *const i32,
+                            &nrhs as *const i32,
+                            ab as *mut _,
+                            &ldab as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar!(f32, s);
+impl_lapack_scalar!(f64, d);
+impl_lapack_scalar!(Complex<f32>, c);
+impl_lapack_scalar!(Complex<f64>, z);
@@ -0,0 +1,198 @@
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::{
+    banded::{BandMatrix, BandSolveError},
+    into_i32, to_col_major,
+};
+use num_complex::ComplexFloat;
+
+use super::scalar::LapackScalar;
+
+/// Runs `solve` on a column-major copy of the n × nrhs matrix B with
+/// leading dimension max(1, n), then copies the solution back into B
+fn solve_col_major<T, D0: Dim, D1: Dim, L: Layout>(
+    b: &mut Slice<T, (D0, D1), L>,
+    solve: impl FnOnce(i32, *mut T, i32) -> i32,
+    positive_definite: bool,
+) -> Result<(), BandSolveError>
+where
+    T: ComplexFloat + Default,
+{
+    let bsh = *b.shape();
+    let (n, nrhs) = (bsh.dim(0), bsh.dim(1));
+
+    let mut b_col_major: Tensor<T, (D0, D1)> = to_col_major(b);
+    let info = solve(into_i32(nrhs), b_col_major.as_mut_ptr(), into_i32(n).max(1));
+
+    match info {
+        0 => {}
+        i if i > 0 && positive_definite => {
+            return Err(BandSolveError::NotPositiveDefinite { order: i as usize });
+        }
+        i if i > 0 => {
+            return Err(BandSolveError::SingularMatrix {
+                diagonal: i as usize,
+            });
+        }
+        i => return Err(BandSolveError::BackendError(i)),
+    }
+
+    for i in 0..n {
+        for j in 0..nrhs {
+            b[[i, j]] = b_col_major[[j, i]];
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves a general tridiagonal system with GTSV
+pub fn gtsv<T, D0: Dim, D1: Dim, L: Layout>(
+    dl: &[T],
+    d: &[T],
+    du: &[T],
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    // GTSV overwrites the diagonals with the factorization
+    let (mut dl, mut d, mut du) = (dl.to_vec(), d.to_vec(), du.to_vec());
+    let n = into_i32(d.len());
+
+    solve_col_major(
+        b,
+        |nrhs, b, ldb| {
+            let mut info = 0;
+            unsafe {
+                T::lapack_gtsv(
+                    n,
+                    nrhs,
+                    dl.as_mut_ptr(),
+                    d.as_mut_ptr(),
+                    du.as_mut_ptr(),
+                    b,
+                    ldb,
+                    &mut info,
+                );
+            }
+            info
+        },
+        false,
+    )
+}
+
+/// Solves a symmetric/Hermitian positive definite tridiagonal system with
+/// PTSV
+pub fn ptsv<T, D0: Dim, D1: Dim, L: Layout>(
+    d: &[T::Real],
+    e: &[T],
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (mut d, mut e) = (d.to_vec(), e.to_vec());
+    let n = into_i32(d.len());
+
+    solve_col_major(
+        b,
+        |nrhs, b, ldb| {
+            let mut info = 0;
+            unsafe {
+                T::lapack_ptsv(n, nrhs, d.as_mut_ptr(), e.as_mut_ptr(), b, ldb, &mut info);
+            }
+            info
+        },
+        true,
+    )
+}
+
+/// Solves a general banded system with GBSV
+pub fn gbsv<T, D0: Dim, D1: Dim, L: Layout>(
+    a: &BandMatrix<T>,
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (n, kl, ku) = (a.n(), a.kl(), a.ku());
+
+    // Column-major band storage with kl additional rows on top for the
+    // fill-in of the LU factorization: A(i, j) is at row kl + ku + i − j
+    let ldab = 2 * kl + ku + 1;
+    let mut ab = vec![T::zero(); ldab * n];
+    let band = a.band();
+    for j in 0..n {
+        for r in 0..kl + ku + 1 {
+            ab[j * ldab + kl + r] = band[[r, j]];
+        }
+    }
+    let mut ipiv = vec![0; n];
+
+    solve_col_major(
+        b,
+        |nrhs, b, ldb| {
+            let mut info = 0;
+            unsafe {
+                T::lapack_gbsv(
+                    into_i32(n),
+                    into_i32(kl),
+                    into_i32(ku),
+                    nrhs,
+                    ab.as_mut_ptr(),
+                    into_i32(ldab),
+                    ipiv.as_mut_ptr(),
+                    b,
+                    ldb,
+                    &mut info,
+                );
+            }
+            info
+        },
+        false,
+    )
+}
+
+/// Solves a symmetric/Hermitian positive definite banded system with
+/// PBSV, referencing the diagonal and the `kl` subdiagonals of A
+pub fn pbsv<T, D0: Dim, D1: Dim, L: Layout>(
+    a: &BandMatrix<T>,
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (n, kd, ku) = (a.n(), a.kl(), a.ku());
+
+    // Column-major lower band storage: A(i, j) is at row i − j
+    let ldab = kd + 1;
+    let mut ab = vec![T::zero(); ldab * n];
+    let band = a.band();
+    for j in 0..n {
+        for r in 0..ldab {
+            ab[j * ldab + r] = band[[ku + r, j]];
+        }
+    }
+
+    solve_col_major(
+        b,
+        |nrhs, b, ldb| {
+            let mut info = 0;
+            unsafe {
+                T::lapack_pbsv(
+                    b'L' as i8,
+                    into_i32(n),
+                    into_i32(kd),
+                    nrhs,
+                    ab.as_mut_ptr(),
+                    into_i32(ldab),
+                    b,
+                    ldb,
+                    &mut info,
+                );
+            }
+            info
+        },
+        true,
+    )
+}
@@ -13,17 +13,24 @@
 //!     - A is n × n Hermitian/symmetric matrix
 //!     - λ are real eigenvalues
 //!     - v are orthonormal eigenvectors
+//!
+//! Selected eigenpairs of Hermitian/symmetric matrices (SYEVR/HEEVR) are
+//! computed with the MRRR algorithm, by index or by value range.
 
 use mdarray::{Dense, Dim, Layout, Shape, Slice, Tensor};
 use mdarray_linalg::{
-    eig::{Eig, EigDecomp, EigError, EigResult, SchurDecomp, SchurError, SchurResult}, transpose_in_place,
+    eig::{
+        Eig, EigDecomp, EigError, EigRange, EigResult, EighSelectDecomp, EighSelectResult,
+        SchurDecomp, SchurError, SchurResult,
+    },
+    transpose_in_place,
 };
 use num_complex::{Complex, ComplexFloat};
 use num_traits::identities::Zero;
 
 use super::{
     scalar::{LapackScalar, NeedsRwork},
-    simple::{gees, gees_complex, geig, geigh},
+    simple::{gees, gees_complex, geig, geigh, syevr},
 };
 use crate::Lapack;
 
@@ -309,6 +316,27 @@ where
         }
     }
 
+    /// Compute the eigenpairs of a Hermitian matrix selected by index or value range
+    fn eigh_select<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EigRange<T::Real>,
+    ) -> EighSelectResult<T, D0, D1> {
+        let n = a.shape().dim(0);
+        let (w, z) = syevr(a, &range)?;
+        let k = w.len();
+
+        let eigenvalues = Tensor::from_fn(<(D0,) as Shape>::from_dims(&[k]), |i| w[i[0]]);
+        let eigenvectors = Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, k]), |i| {
+            z[i[1] * n + i[0]]
+        });
+
+        Ok(EighSelectDecomp {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+
     /// Compute Schur decomposition with new allocated matrices
     fn schur<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> SchurResult<T, D0, D1> {
         let ash = *a.shape();
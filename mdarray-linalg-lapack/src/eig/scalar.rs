@@ -1,10 +1,10 @@
 use std::ffi::c_void;
 
-use num_complex::Complex;
+use num_complex::{Complex, ComplexFloat};
 use paste::paste;
 
 #[allow(clippy::too_many_arguments)]
-pub trait LapackScalar {
+pub trait LapackScalar: ComplexFloat {
     // General eigenvalue decomposition (GEEV)
     unsafe fn lapack_geev(
         jobvl: i8,
@@ -38,6 +38,34 @@ pub trait LapackScalar {
         info: *mut i32,
     );
 
+    // Selected eigenpairs of a Hermitian/symmetric matrix with the MRRR
+    // algorithm (SYEVR/HEEVR)
+    unsafe fn lapack_syevr(
+        jobz: i8,
+        range: i8,
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        vl: Self::Real,
+        vu: Self::Real,
+        il: i32,
+        iu: i32,
+        abstol: Self::Real,
+        m: *mut i32,
+        w: *mut Self::Real,
+        z: *mut Self,
+        ldz: i32,
+        isuppz: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut Self::Real,
+        lrwork: i32,
+        iwork: *mut i32,
+        liwork: i32,
+        info: *mut i32,
+    );
+
     // Schur decomposition (GEES)
     unsafe fn lapack_gees(
         jobvs: i8,
@@ -132,6 +160,61 @@ macro_rules! impl_lapack_scalar_real {
                 }
             }
 
+            #[inline]
+            unsafe fn lapack_syevr(
+                jobz: i8,
+                range: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                vl: Self::Real,
+                vu: Self::Real,
+                il: i32,
+                iu: i32,
+                abstol: Self::Real,
+                m: *mut i32,
+                w: *mut Self::Real,
+                z: *mut Self,
+                ldz: i32,
+                isuppz: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut Self, // unused for real types
+                _lrwork: i32,      // unused for real types
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix syevr_>](
+                            &jobz as *const i8,
+                            &range as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            &vl as *const _,
+                            &vu as *const _,
+                            &il as *const i32,
+                            &iu as *const i32,
+                            &abstol as *const _,
+                            m as *mut i32,
+                            w,
+                            z as *mut _,
+                            &ldz as *const i32,
+                            isuppz as *mut i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork as *mut i32,
+                            &liwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
             #[inline]
             unsafe fn lapack_gees(
                 jobvs: i8,
@@ -270,6 +353,63 @@ macro_rules! impl_lapack_scalar_cplx {
                 }
             }
 
+            #[inline]
+            unsafe fn lapack_syevr(
+                jobz: i8,
+                range: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                vl: Self::Real,
+                vu: Self::Real,
+                il: i32,
+                iu: i32,
+                abstol: Self::Real,
+                m: *mut i32,
+                w: *mut Self::Real,
+                z: *mut Self,
+                ldz: i32,
+                isuppz: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut Self::Real,
+                lrwork: i32,
+                iwork: *mut i32,
+                liwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix heevr_>](
+                            &jobz as *const i8,
+                            &range as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            &vl as *const _,
+                            &vu as *const _,
+                            &il as *const i32,
+                            &iu as *const i32,
+                            &abstol as *const _,
+                            m as *mut i32,
+                            w,
+                            z as *mut lapack_sys_cast!($prefix),
+                            &ldz as *const i32,
+                            isuppz as *mut i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork,
+                            &lrwork as *const i32,
+                            iwork as *mut i32,
+                            &liwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
             #[inline]
             unsafe fn lapack_gees(
                 jobvs: i8,
@@ -2,10 +2,11 @@ use std::{ptr, ptr::null_mut};
 
 use mdarray::{Dim, Layout, Shape, Slice};
 use mdarray_linalg::{
-    eig::{EigError, SchurError},
-    transpose_in_place,
+    eig::{EigError, EigRange, SchurError, eig_range_check},
+    into_i32, transpose_in_place,
 };
 use num_complex::ComplexFloat;
+use num_traits::{Float, ToPrimitive, Zero};
 
 use super::scalar::{LapackScalar, NeedsRwork};
 
@@ -563,3 +564,142 @@ pub fn gees_complex<
 
     Ok(())
 }
+
+/// Computes the eigenpairs of a Hermitian matrix selected by `range`
+/// with the MRRR algorithm. Returns the eigenvalues in ascending order
+/// and the corresponding eigenvectors as a column-major n × k buffer.
+pub fn syevr<T, La: Layout, D0: Dim, D1: Dim>(
+    a: &mut Slice<T, (D0, D1), La>,
+    range: &EigRange<T::Real>,
+) -> Result<(Vec<T::Real>, Vec<T>), EigError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    if m != n {
+        return Err(EigError::NotSquareMatrix);
+    }
+    eig_range_check(range, n)?;
+
+    let zero = T::Real::zero();
+    let (range_char, vl, vu, il, iu, max_found) = match range {
+        EigRange::Index(r) if r.is_empty() => return Ok((Vec::new(), Vec::new())),
+        // LAPACK indices are 1-based and inclusive
+        EigRange::Index(r) => (
+            b'I',
+            zero,
+            zero,
+            into_i32(r.start + 1),
+            into_i32(r.end),
+            r.len(),
+        ),
+        EigRange::Value(lower, upper) => (b'V', *lower, *upper, 0, 0, n),
+    };
+
+    let row_major = a.stride(1) == 1;
+    assert!(
+        row_major || a.stride(0) == 1,
+        "a must be contiguous in one dimension"
+    );
+
+    if row_major {
+        transpose_in_place(a);
+    }
+
+    let ni = into_i32(n);
+    // The safe minimum as absolute tolerance gives the most accurate eigenvalues
+    let abstol = T::Real::min_positive_value();
+
+    let mut found = 0;
+    let mut w = vec![zero; n];
+    let mut z = vec![T::zero(); n * max_found.max(1)];
+    let mut isuppz = vec![0i32; 2 * max_found.max(1)];
+    let mut work = vec![T::default(); 1];
+    let mut rwork = vec![zero; 1];
+    let mut iwork = vec![0i32; 1];
+    let mut info = 0;
+
+    // First call to query optimal workspace sizes
+    unsafe {
+        T::lapack_syevr(
+            b'V' as i8,
+            range_char as i8,
+            b'L' as i8,
+            ni,
+            a.as_mut_ptr(),
+            ni.max(1),
+            vl,
+            vu,
+            il,
+            iu,
+            abstol,
+            &mut found,
+            w.as_mut_ptr(),
+            z.as_mut_ptr(),
+            ni.max(1),
+            isuppz.as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr(),
+            -1,
+            iwork.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+
+    let lwork = work[0]
+        .re()
+        .to_i32()
+        .expect("workspace size must fit into i32");
+    let lrwork = rwork[0].to_i32().unwrap_or(0).max(1);
+    let liwork = iwork[0].max(1);
+    let mut work = vec![T::default(); lwork as usize];
+    let mut rwork = vec![zero; lrwork as usize];
+    let mut iwork = vec![0i32; liwork as usize];
+
+    // Second call with optimal workspace
+    unsafe {
+        T::lapack_syevr(
+            b'V' as i8,
+            range_char as i8,
+            b'L' as i8,
+            ni,
+            a.as_mut_ptr(),
+            ni.max(1),
+            vl,
+            vu,
+            il,
+            iu,
+            abstol,
+            &mut found,
+            w.as_mut_ptr(),
+            z.as_mut_ptr(),
+            ni.max(1),
+            isuppz.as_mut_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr(),
+            lrwork,
+            iwork.as_mut_ptr(),
+            liwork,
+            &mut info,
+        );
+    }
+
+    if info < 0 {
+        panic!(
+            "Invalid argument to EIGH_SELECT: the {}-th parameter had an illegal value.",
+            -info
+        );
+    } else if info > 0 {
+        Err(EigError::BackendDidNotConverge { iterations: info })
+    } else {
+        let found = found as usize;
+        w.truncate(found);
+        z.truncate(n * found);
+        Ok((w, z))
+    }
+}
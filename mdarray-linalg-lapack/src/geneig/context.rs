@@ -0,0 +1,49 @@
+//! Generalized eigenvalue problems (GGEV, SYGV/HEGV):
+//!     A v = λ B v
+//! where:
+//!     - A and B are n × n  (overwritten by the backend)
+//!     - λ = α / β          (β = 0 for infinite eigenvalues)
+//!     - v are the right eigenvectors
+//!
+//! For a symmetric/Hermitian A and a symmetric/Hermitian positive
+//! definite B (SYGV/HEGV), the eigenvalues are real and the eigenvectors
+//! satisfy Vᴴ B V = I. Only the lower triangles of A and B are referenced.
+
+use mdarray::{Dim, Layout, Slice};
+use mdarray_linalg::geneig::{GenEig, GenEigResult, GenEighResult};
+use num_complex::ComplexFloat;
+
+use super::{
+    scalar::LapackScalar,
+    simple::{ggev, sygv},
+};
+use crate::Lapack;
+
+impl<T, D0: Dim, D1: Dim> GenEig<T, D0, D1> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    fn gen_eig<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEigResult<T, D0, D1> {
+        ggev(a, b, true)
+    }
+
+    fn gen_eig_values<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEigResult<T, D0, D1> {
+        ggev(a, b, false)
+    }
+
+    fn gen_eigh<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEighResult<T, D0, D1> {
+        sygv(a, b)
+    }
+}
@@ -0,0 +1,238 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // Generalized eigenvalues and eigenvectors of a general pencil (GGEV).
+    // For real types the eigenvalues are (alphar + i alphai) / beta, for
+    // complex types alpha / beta and `alphai` is not referenced.
+    unsafe fn lapack_ggev(
+        jobvl: i8,
+        jobvr: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        alpha: *mut Self,
+        alphai: *mut Self,
+        beta: *mut Self,
+        vl: *mut Self,
+        ldvl: i32,
+        vr: *mut Self,
+        ldvr: i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut Self::Real,
+        info: *mut i32,
+    );
+
+    // Hermitian-definite generalized eigenproblem (SYGV for real types,
+    // HEGV for complex types)
+    unsafe fn lapack_sygv(
+        itype: i32,
+        jobz: i8,
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        w: *mut Self::Real,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut Self::Real,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar_real {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_ggev(
+                jobvl: i8,
+                jobvr: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alpha: *mut Self,
+                alphai: *mut Self,
+                beta: *mut Self,
+                vl: *mut Self,
+                ldvl: i32,
+                vr: *mut Self,
+                ldvr: i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut Self, // unused
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ggev_>](
+                            &jobvl as *const i8,
+                            &jobvr as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            alpha as *mut _,
+                            alphai as *mut _,
+                            beta as *mut _,
+                            vl as *mut _,
+                            &ldvl as *const i32,
+                            vr as *mut _,
+                            &ldvr as *const i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_sygv(
+                itype: i32,
+                jobz: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                w: *mut Self,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut Self, // unused
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix sygv_>](
+                            &itype as *const i32,
+                            &jobz as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            w as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! lapack_sys_cast {
+    (c) => {
+        lapack_sys::lapack_complex_float
+    };
+    (z) => {
+        lapack_sys::lapack_complex_double
+    };
+}
+
+macro_rules! impl_lapack_scalar_cplx {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_ggev(
+                jobvl: i8,
+                jobvr: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                alpha: *mut Self,
+                _alphai: *mut Self, // unused
+                beta: *mut Self,
+                vl: *mut Self,
+                ldvl: i32,
+                vr: *mut Self,
+                ldvr: i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix ggev_>](
+                            &jobvl as *const i8,
+                            &jobvr as *const i8,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            alpha as *mut lapack_sys_cast!($prefix),
+                            beta as *mut lapack_sys_cast!($prefix),
+                            vl as *mut lapack_sys_cast!($prefix),
+                            &ldvl as *const i32,
+                            vr as *mut lapack_sys_cast!($prefix),
+                            &ldvr as *const i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_sygv(
+                itype: i32,
+                jobz: i8,
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                w: *mut Self::Real,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix hegv_>](
+                            &itype as *const i32,
+                            &jobz as *const i8,
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            w,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar_real!(f32, s);
+impl_lapack_scalar_real!(f64, d);
+impl_lapack_scalar_cplx!(Complex<f32>, c);
+impl_lapack_scalar_cplx!(Complex<f64>, z);
@@ -0,0 +1,227 @@
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::{
+    geneig::{GenEigDecomp, GenEigError, GenEigResult, GenEighDecomp, GenEighResult, gen_eig_dims},
+    into_i32, transpose_in_place,
+};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{ToPrimitive, Zero};
+
+use super::scalar::LapackScalar;
+
+fn lwork_from_query<T: ComplexFloat>(query: T) -> i32 {
+    query
+        .re()
+        .to_i32()
+        .expect("workspace size must fit into i32")
+}
+
+/// Solves the general problem β A v = α B v with the QZ algorithm,
+/// computing the right eigenvectors if `vectors` is set
+pub fn ggev<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &mut Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+    vectors: bool,
+) -> GenEigResult<T, D0, D1>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = gen_eig_dims(a, b)?;
+    let ni = into_i32(n);
+
+    transpose_in_place(a);
+    transpose_in_place(b);
+
+    let jobvr = if vectors { b'V' } else { b'N' };
+    let ldvr = if vectors { ni.max(1) } else { 1 };
+
+    let mut alpha = vec![T::zero(); n];
+    let mut alphai = vec![T::zero(); n];
+    let mut beta = vec![T::zero(); n];
+    let mut vr = vec![T::zero(); if vectors { n * n } else { 1 }];
+    let mut rwork = vec![T::Real::zero(); (8 * n).max(1)];
+    let mut work = vec![T::default(); 1];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_ggev(
+            b'N' as i8,
+            jobvr as i8,
+            ni,
+            a.as_mut_ptr(),
+            ni.max(1),
+            b.as_mut_ptr(),
+            ni.max(1),
+            alpha.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            std::ptr::null_mut(),
+            1,
+            vr.as_mut_ptr(),
+            ldvr,
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    let lwork = lwork_from_query(work[0]);
+    let mut work = vec![T::default(); lwork as usize];
+
+    unsafe {
+        T::lapack_ggev(
+            b'N' as i8,
+            jobvr as i8,
+            ni,
+            a.as_mut_ptr(),
+            ni.max(1),
+            b.as_mut_ptr(),
+            ni.max(1),
+            alpha.as_mut_ptr(),
+            alphai.as_mut_ptr(),
+            beta.as_mut_ptr(),
+            std::ptr::null_mut(),
+            1,
+            vr.as_mut_ptr(),
+            ldvr,
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => {}
+        i if i > 0 => return Err(GenEigError::BackendDidNotConverge { iterations: i }),
+        i => return Err(GenEigError::BackendError(i)),
+    }
+
+    let shape1 = <(D0,) as Shape>::from_dims(&[n]);
+
+    // `alphai` is only set by the real drivers, where it holds the
+    // imaginary parts of the eigenvalues
+    let alpha_c = Tensor::from_fn(shape1, |i| {
+        let j = i[0];
+        Complex::new(alpha[j].re(), alpha[j].im() + alphai[j].re())
+    });
+    let beta_c = Tensor::from_fn(shape1, |i| Complex::new(beta[i[0]].re(), beta[i[0]].im()));
+
+    let right_eigenvectors = vectors.then(|| {
+        let mut v = Tensor::from_elem(
+            <(D0, D1) as Shape>::from_dims(&[n, n]),
+            Complex::new(T::Real::zero(), T::Real::zero()),
+        );
+
+        // VR is column-major. For real types, a complex conjugate pair of
+        // eigenvalues is stored as two columns: Re(v) and Im(v).
+        let mut j = 0;
+        while j < n {
+            if alphai[j].re().is_zero() {
+                for i in 0..n {
+                    let x = vr[j * n + i];
+                    v[[i, j]] = Complex::new(x.re(), x.im());
+                }
+                j += 1;
+            } else {
+                for i in 0..n {
+                    let z = Complex::new(vr[j * n + i].re(), vr[(j + 1) * n + i].re());
+                    v[[i, j]] = z;
+                    v[[i, j + 1]] = z.conj();
+                }
+                j += 2;
+            }
+        }
+        v
+    });
+
+    Ok(GenEigDecomp {
+        alpha: alpha_c,
+        beta: beta_c,
+        right_eigenvectors,
+    })
+}
+
+/// Solves the Hermitian-definite problem A v = λ B v by reduction to a
+/// standard problem with the Cholesky factor of B
+pub fn sygv<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &mut Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> GenEighResult<T, D0, D1>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = gen_eig_dims(a, b)?;
+    let ni = into_i32(n);
+
+    // Column-major copies of A and B, so that 'L' refers to their lower triangles
+    transpose_in_place(a);
+    transpose_in_place(b);
+
+    let mut w = vec![T::Real::zero(); n];
+    let mut rwork = vec![T::Real::zero(); (3 * n).max(1)];
+    let mut work = vec![T::default(); 1];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_sygv(
+            1,
+            b'V' as i8,
+            b'L' as i8,
+            ni,
+            a.as_mut_ptr(),
+            ni.max(1),
+            b.as_mut_ptr(),
+            ni.max(1),
+            w.as_mut_ptr(),
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    let lwork = lwork_from_query(work[0]);
+    let mut work = vec![T::default(); lwork as usize];
+
+    unsafe {
+        T::lapack_sygv(
+            1,
+            b'V' as i8,
+            b'L' as i8,
+            ni,
+            a.as_mut_ptr(),
+            ni.max(1),
+            b.as_mut_ptr(),
+            ni.max(1),
+            w.as_mut_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => {}
+        // The factorization of B could not be completed
+        i if i > ni => {
+            return Err(GenEigError::NotPositiveDefinite {
+                order: (i - ni) as usize,
+            });
+        }
+        i if i > 0 => return Err(GenEigError::BackendDidNotConverge { iterations: i }),
+        i => return Err(GenEigError::BackendError(i)),
+    }
+
+    let eigenvalues = Tensor::from_fn(<(D0,) as Shape>::from_dims(&[n]), |i| w[i[0]]);
+
+    // A holds the column-major eigenvectors
+    let eigenvectors =
+        Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, n]), |i| a[[i[1], i[0]]]);
+
+    Ok(GenEighDecomp {
+        eigenvalues,
+        eigenvectors,
+    })
+}
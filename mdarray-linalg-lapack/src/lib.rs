@@ -46,11 +46,18 @@
 extern crate lapack_sys;
 #[cfg(feature = "lapack-inject-backend")]
 extern crate lapack_inject as lapack_sys;
+pub mod banded;
 pub mod eig;
+pub mod geneig;
+pub mod lstsq;
 pub mod lu;
+pub mod norm;
 pub mod qr;
+pub mod reduction;
 pub mod solve;
 pub mod svd;
+pub mod sylvester;
+pub mod trisolve;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub enum SVDConfig {
@@ -60,17 +67,38 @@ pub enum SVDConfig {
     Jacobi,
 }
 
+/// LAPACK driver used by [`Lstsq`](mdarray_linalg::lstsq::Lstsq)
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum LstsqConfig {
+    /// QR or LQ factorization (`gels`), A must have full rank
+    Gels,
+    /// Divide-and-conquer SVD (`gelsd`), also returns the singular values
+    #[default]
+    Gelsd,
+    /// Complete orthogonal factorization (`gelsy`)
+    Gelsy,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Lapack {
     svd_config: SVDConfig,
-    _qr_config: LapackQRConfig,
+    qr_config: LapackQRConfig,
+    lstsq_config: LstsqConfig,
 }
 
-#[derive(Default, Debug, Clone)]
+/// LAPACK driver used by [`QR`](mdarray_linalg::qr::QR)
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub enum LapackQRConfig {
+    /// Householder QR (`geqrf`)
     #[default]
     Full,
+    /// Householder QR with column pivoting (`geqp3`), used by
+    /// [`QRPivot`](mdarray_linalg::qr::QRPivot). `QR` has no room for
+    /// the permutation and falls back to `geqrf` so that R stays upper
+    /// triangular.
     Pivoting,
+    /// Householder QR (`geqrf`) where `QR::qr` returns the thin factors,
+    /// Q being m × min(m, n) and R min(m, n) × n
     TallSkinny,
 }
 
@@ -78,7 +106,8 @@ impl Lapack {
     pub fn new() -> Self {
         Self {
             svd_config: SVDConfig::default(),
-            _qr_config: LapackQRConfig::default(),
+            qr_config: LapackQRConfig::default(),
+            lstsq_config: LstsqConfig::default(),
         }
     }
 
@@ -87,7 +116,13 @@ impl Lapack {
         self
     }
 
-    pub fn config_qr(self, _config: LapackQRConfig) -> Self {
-        todo!()
+    pub fn config_lstsq(mut self, config: LstsqConfig) -> Self {
+        self.lstsq_config = config;
+        self
+    }
+
+    pub fn config_qr(mut self, config: LapackQRConfig) -> Self {
+        self.qr_config = config;
+        self
     }
 }
@@ -0,0 +1,104 @@
+//! Least-squares solver (GELS, GELSD, GELSY):
+//!     min ‖AX − B‖₂
+//! where:
+//!     - A is m × n (overwritten by its factorization)
+//!     - B is m × nrhs (right-hand side matrix)
+//!     - X is n × nrhs (minimum-norm solution)
+//!
+//! The driver is selected with `Lapack::config_lstsq`:
+//!     - `gels` uses a QR (m ≥ n) or LQ (m < n) factorization and requires A to have full rank,
+//!     - `gelsd` uses a divide-and-conquer SVD and also returns the singular values of A,
+//!     - `gelsy` uses a complete orthogonal factorization based on column-pivoted QR.
+//!
+//! For `gelsd` and `gelsy`, singular values smaller than ε · max(m, n) times
+//! the largest one are treated as zero when determining the effective rank.
+
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::lstsq::{Lstsq, LstsqError, LstsqResult, LstsqResultType, lstsq_residuals};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast};
+
+use super::{
+    scalar::LapackScalar,
+    simple::{gels, gelsd, gelsy},
+};
+use crate::{Lapack, LstsqConfig};
+
+/// Effective rank of A and, for `gelsd`, its singular values
+type DispatchResult<R> = Result<(usize, Option<Vec<R>>), LstsqError>;
+
+impl Lapack {
+    fn lstsq_dispatch<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> DispatchResult<T::Real>
+    where
+        T: ComplexFloat + Default + LapackScalar,
+    {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let rcond = T::Real::epsilon() * <T::Real as NumCast>::from(m.max(n)).unwrap();
+
+        match self.lstsq_config {
+            LstsqConfig::Gels => gels(a, b).map(|()| (m.min(n), None)),
+            LstsqConfig::Gelsd => gelsd(a, b, rcond).map(|(rank, s)| (rank, Some(s))),
+            LstsqConfig::Gelsy => gelsy(a, b, rcond).map(|rank| (rank, None)),
+        }
+    }
+}
+
+impl<T, D0: Dim, D1: Dim> Lstsq<T, D0, D1> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    fn lstsq<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &Slice<T, (D0, D1), Lb>,
+    ) -> LstsqResultType<T, D0, D1> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let bsh = *b.shape();
+        let (mb, nrhs) = (bsh.dim(0), bsh.dim(1));
+
+        if mb != m {
+            return Err(LstsqError::InvalidDimensions);
+        }
+
+        // A is destroyed by LAPACK, keep a copy to compute the residuals
+        let a_copy = a.to_tensor();
+
+        let mut b_work =
+            Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[m.max(n), nrhs]), T::zero());
+        for i in 0..m {
+            for j in 0..nrhs {
+                b_work[[i, j]] = b[[i, j]];
+            }
+        }
+
+        let (rank, singular_values) = self.lstsq_dispatch(a, &mut b_work)?;
+
+        let x = Tensor::from_fn(<(D0, D1) as Shape>::from_dims(&[n, nrhs]), |i| {
+            b_work[[i[0], i[1]]]
+        });
+        let residuals = lstsq_residuals(&a_copy, &x, b);
+
+        Ok(LstsqResult {
+            x,
+            residuals,
+            rank,
+            singular_values,
+        })
+    }
+
+    fn lstsq_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<usize, LstsqError> {
+        self.lstsq_dispatch(a, b).map(|(rank, _)| rank)
+    }
+}
@@ -0,0 +1,310 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // Least squares via QR or LQ factorization, A must have full rank (GELS)
+    unsafe fn lapack_gels(
+        trans: i8,
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    // Minimum-norm least squares via divide-and-conquer SVD (GELSD)
+    unsafe fn lapack_gelsd(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        s: *mut Self::Real,
+        rcond: Self::Real,
+        rank: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut Self::Real,
+        iwork: *mut i32,
+        info: *mut i32,
+    );
+
+    // Minimum-norm least squares via complete orthogonal factorization (GELSY)
+    unsafe fn lapack_gelsy(
+        m: i32,
+        n: i32,
+        nrhs: i32,
+        a: *mut Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        jpvt: *mut i32,
+        rcond: Self::Real,
+        rank: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut Self::Real,
+        info: *mut i32,
+    );
+}
+
+// The lapack-sys binding of `dgels_` exposes the hidden Fortran length of
+// the `trans` argument, which is passed as an optional extra argument.
+macro_rules! impl_lapack_scalar_real {
+    ($t:ty, $prefix:ident $(, $trans_len:expr)?) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_gels(
+                trans: i8,
+                m: i32,
+                n: i32,
+                nrhs: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gels_>](
+                            &trans as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                            $($trans_len,)?
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gelsd(
+                m: i32,
+                n: i32,
+                nrhs: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                s: *mut Self,
+                rcond: Self,
+                rank: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut Self, // unused
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gelsd_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            s as *mut _,
+                            &rcond as *const _,
+                            rank as *mut i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            iwork as *mut i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gelsy(
+                m: i32,
+                n: i32,
+                nrhs: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                jpvt: *mut i32,
+                rcond: Self,
+                rank: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut Self, // unused
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gelsy_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            jpvt as *mut i32,
+                            &rcond as *const _,
+                            rank as *mut i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! lapack_sys_cast {
+    (c) => {
+        lapack_sys::lapack_complex_float
+    };
+    (z) => {
+        lapack_sys::lapack_complex_double
+    };
+}
+
+macro_rules! impl_lapack_scalar_cplx {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_gels(
+                trans: i8,
+                m: i32,
+                n: i32,
+                nrhs: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gels_>](
+                            &trans as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gelsd(
+                m: i32,
+                n: i32,
+                nrhs: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                s: *mut Self::Real,
+                rcond: Self::Real,
+                rank: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut Self::Real,
+                iwork: *mut i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gelsd_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            s,
+                            &rcond as *const _,
+                            rank as *mut i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork,
+                            iwork as *mut i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gelsy(
+                m: i32,
+                n: i32,
+                nrhs: i32,
+                a: *mut Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                jpvt: *mut i32,
+                rcond: Self::Real,
+                rank: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gelsy_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            jpvt as *mut i32,
+                            &rcond as *const _,
+                            rank as *mut i32,
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar_real!(f32, s);
+impl_lapack_scalar_real!(f64, d, 1);
+impl_lapack_scalar_cplx!(Complex<f32>, c);
+impl_lapack_scalar_cplx!(Complex<f64>, z);
@@ -0,0 +1,266 @@
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{into_i32, lstsq::LstsqError, to_col_major, transpose_in_place};
+use num_complex::ComplexFloat;
+use num_traits::{ToPrimitive, Zero};
+
+use super::scalar::LapackScalar;
+
+/// Checks that B is max(m, n) × nrhs and returns (m, n, nrhs)
+fn lstsq_dims<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Result<(i32, i32, i32), LstsqError> {
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    let bsh = *b.shape();
+    let (mb, nrhs) = (bsh.dim(0), bsh.dim(1));
+
+    if mb != m.max(n) {
+        return Err(LstsqError::InvalidDimensions);
+    }
+
+    Ok((into_i32(m), into_i32(n), into_i32(nrhs)))
+}
+
+fn lwork_from_query<T: ComplexFloat>(query: T) -> i32 {
+    query
+        .re()
+        .to_i32()
+        .expect("workspace size must fit into i32")
+}
+
+/// Copies the column-major solution back into the row-major B
+fn copy_back<T: ComplexFloat, D0: Dim, D1: Dim, Lb: Layout>(
+    b: &mut Slice<T, (D0, D1), Lb>,
+    b_col_major: &Slice<T, (D0, D1)>,
+) {
+    let bsh = *b.shape();
+    for i in 0..bsh.dim(0) {
+        for j in 0..bsh.dim(1) {
+            b[[i, j]] = b_col_major[[j, i]];
+        }
+    }
+}
+
+/// Solves a full rank least-squares problem with a QR (m ≥ n) or LQ
+/// (m < n) factorization of A
+pub fn gels<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &mut Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> Result<(), LstsqError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (m, n, nrhs) = lstsq_dims(a, b)?;
+    let ldb = m.max(n);
+
+    transpose_in_place(a);
+    let mut b_col_major = to_col_major(b);
+
+    let mut work = vec![T::default(); 1];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_gels(
+            b'N' as i8,
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            m.max(1),
+            b_col_major.as_mut_ptr(),
+            ldb.max(1),
+            work.as_mut_ptr(),
+            -1,
+            &mut info,
+        );
+    }
+
+    let lwork = lwork_from_query(work[0]);
+    let mut work = vec![T::default(); lwork as usize];
+
+    unsafe {
+        T::lapack_gels(
+            b'N' as i8,
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            m.max(1),
+            b_col_major.as_mut_ptr(),
+            ldb.max(1),
+            work.as_mut_ptr(),
+            lwork,
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => {
+            copy_back(b, &b_col_major);
+            Ok(())
+        }
+        // The i-th diagonal element of the triangular factor is zero:
+        // only the leading i - 1 columns are known to be independent
+        i if i > 0 => Err(LstsqError::RankDeficient {
+            rank: (i - 1) as usize,
+        }),
+        i => Err(LstsqError::BackendError(i)),
+    }
+}
+
+/// Solves a least-squares problem with the SVD of A, returning the
+/// effective rank and the singular values
+pub fn gelsd<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &mut Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+    rcond: T::Real,
+) -> Result<(usize, Vec<T::Real>), LstsqError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (m, n, nrhs) = lstsq_dims(a, b)?;
+    let ldb = m.max(n);
+    let min_mn = m.min(n);
+
+    transpose_in_place(a);
+    let mut b_col_major = to_col_major(b);
+
+    let mut s = vec![T::Real::zero(); min_mn as usize];
+    let mut rank = 0;
+    let mut work = vec![T::default(); 1];
+    let mut rwork = vec![T::Real::zero(); 1];
+    let mut iwork = vec![0i32; 1];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_gelsd(
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            m.max(1),
+            b_col_major.as_mut_ptr(),
+            ldb.max(1),
+            s.as_mut_ptr(),
+            rcond,
+            &mut rank,
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr(),
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    // The query returns the optimal size of work and the minimal sizes
+    // of rwork (complex only) and iwork.
+    let lwork = lwork_from_query(work[0]);
+    let mut work = vec![T::default(); lwork as usize];
+    let lrwork = rwork[0].to_usize().unwrap_or(0).max(1);
+    let mut rwork = vec![T::Real::zero(); lrwork];
+    let mut iwork = vec![0i32; (iwork[0] as usize).max(1)];
+
+    unsafe {
+        T::lapack_gelsd(
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            m.max(1),
+            b_col_major.as_mut_ptr(),
+            ldb.max(1),
+            s.as_mut_ptr(),
+            rcond,
+            &mut rank,
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr(),
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => {
+            copy_back(b, &b_col_major);
+            Ok((rank as usize, s))
+        }
+        i if i > 0 => Err(LstsqError::BackendDidNotConverge { superdiagonals: i }),
+        i => Err(LstsqError::BackendError(i)),
+    }
+}
+
+/// Solves a least-squares problem with a complete orthogonal
+/// factorization of A, returning the effective rank
+pub fn gelsy<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &mut Slice<T, (D0, D1), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+    rcond: T::Real,
+) -> Result<usize, LstsqError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (m, n, nrhs) = lstsq_dims(a, b)?;
+    let ldb = m.max(n);
+
+    transpose_in_place(a);
+    let mut b_col_major = to_col_major(b);
+
+    // All columns are free to be pivoted
+    let mut jpvt = vec![0i32; n as usize];
+    let mut rank = 0;
+    let mut work = vec![T::default(); 1];
+    let mut rwork = vec![T::Real::zero(); 2 * (n as usize).max(1)];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_gelsy(
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            m.max(1),
+            b_col_major.as_mut_ptr(),
+            ldb.max(1),
+            jpvt.as_mut_ptr(),
+            rcond,
+            &mut rank,
+            work.as_mut_ptr(),
+            -1,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    let lwork = lwork_from_query(work[0]);
+    let mut work = vec![T::default(); lwork as usize];
+
+    unsafe {
+        T::lapack_gelsy(
+            m,
+            n,
+            nrhs,
+            a.as_mut_ptr(),
+            m.max(1),
+            b_col_major.as_mut_ptr(),
+            ldb.max(1),
+            jpvt.as_mut_ptr(),
+            rcond,
+            &mut rank,
+            work.as_mut_ptr(),
+            lwork,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => {
+            copy_back(b, &b_col_major);
+            Ok(rank as usize)
+        }
+        i => Err(LstsqError::BackendError(i)),
+    }
+}
@@ -8,17 +8,34 @@
 //! This decomposition is used to solve linear systems, compute matrix determinants, and matrix inversion.
 //! The function `getrf` (LAPACK) computes the LU factorization of a general m-by-n matrix A using partial pivoting.
 //! The matrix L is lower triangular with unit diagonal, and U is upper triangular.
+//!
+//! `LuFactors` keep the packed GETRF output of a square A for repeated
+//! solves (GETRS), inversion (GETRI) and condition estimation (GECON).
+//! `CholeskyFactors` likewise keep the POTRF factor of a Hermitian positive
+//! definite A for POTRS and POTRI, and `LdlFactors` the SYTRF/HETRF
+//! (Bunch–Kaufman) or SYTRF_RK/HETRF_RK (rook) factors of a Hermitian
+//! indefinite A for SYTRS_3/HETRS_3.
 use mdarray::{Dim, Layout, Shape, Slice, Tensor};
 use mdarray_linalg::{
     into_i32, ipiv_to_perm_mat,
-    lu::{InvError, InvResult, LU},
+    lu::{
+        CholeskyFactor, CholeskyFactors, InvError, InvResult, LU, LdlFactor, LdlFactors,
+        LdlPivoting, LuFactor, LuFactors,
+    },
+    matmul::Triangle,
+    norm::{Cond, Norm, NormError},
+    solve::SolveError,
     transpose_in_place,
+    trisolve::Transpose,
 };
 use num_complex::ComplexFloat;
 
 use super::{
-    scalar::{LapackScalar, Workspace},
-    simple::{getrf, getri, potrf},
+    scalar::{LapackScalar, LdlScalar, Workspace},
+    simple::{
+        getrf, getrf_packed, getri, getri_packed, getrs, hetrf, hetrf_rk, hetrs_3, potrf,
+        potrf_factor, potri, potrs,
+    },
 };
 use crate::Lapack;
 
@@ -206,3 +223,173 @@ where
         }
     }
 }
+
+impl<T, D: Dim> LuFactor<T, D> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar + Workspace,
+    T::Real: Into<T>,
+    Lapack: Cond<T>,
+{
+    fn lu_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+    ) -> Result<LuFactors<'_, T, D, Self>, InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(InvError::NotSquare {
+                rows: into_i32(m),
+                cols: into_i32(n),
+            });
+        }
+
+        // A positive info only flags an exactly zero pivot, the
+        // factorization itself is complete
+        match getrf_packed(a) {
+            (lu, ipiv, info) if info >= 0 => Ok(LuFactors::new(self, lu, ipiv)),
+            (_, _, info) => Err(InvError::BackendError(info)),
+        }
+    }
+
+    fn lu_solve_write<Lb: Layout>(
+        &self,
+        f: &LuFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+        trans: Transpose,
+    ) -> Result<(), SolveError> {
+        if b.shape().dim(0) != f.n() {
+            return Err(SolveError::InvalidDimensions);
+        }
+        if let Some(i) = f.zero_pivot() {
+            return Err(SolveError::SingularMatrix {
+                diagonal: into_i32(i + 1),
+            });
+        }
+
+        match getrs(&f.lu, &f.ipiv, b, trans) {
+            0 => Ok(()),
+            i => Err(SolveError::BackendError(i)),
+        }
+    }
+
+    fn lu_inv(&self, f: &LuFactors<'_, T, D, Self>) -> InvResult<T, D, D> {
+        match getri_packed(&f.lu, &f.ipiv) {
+            (inv, 0) => Ok(inv),
+            (_, i) if i > 0 => Err(InvError::Singular { pivot: i }),
+            (_, i) => Err(InvError::BackendError(i)),
+        }
+    }
+
+    fn lu_rcond(
+        &self,
+        f: &LuFactors<'_, T, D, Self>,
+        anorm: T::Real,
+        norm: Norm,
+    ) -> Result<T::Real, NormError> {
+        self.rcond_lu_packed(&f.lu, anorm, norm)
+    }
+}
+
+impl<T, D: Dim> CholeskyFactor<T, D> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    fn cholesky_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        triangle: Triangle,
+    ) -> Result<CholeskyFactors<'_, T, D, Self>, InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(InvError::NotSquare {
+                rows: into_i32(m),
+                cols: into_i32(n),
+            });
+        }
+
+        match potrf_factor(a, triangle) {
+            (factor, 0) => Ok(CholeskyFactors::new(self, factor, triangle)),
+            (_, i) if i > 0 => Err(InvError::NotPositiveDefinite { lpm: i }),
+            (_, i) => Err(InvError::BackendError(i)),
+        }
+    }
+
+    fn cholesky_solve_write<Lb: Layout>(
+        &self,
+        f: &CholeskyFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError> {
+        if b.shape().dim(0) != f.n() {
+            return Err(SolveError::InvalidDimensions);
+        }
+
+        match potrs(&f.factor, f.triangle, b) {
+            0 => Ok(()),
+            i => Err(SolveError::BackendError(i)),
+        }
+    }
+
+    fn cholesky_inv(&self, f: &CholeskyFactors<'_, T, D, Self>) -> InvResult<T, D, D> {
+        match potri(&f.factor, f.triangle) {
+            (inv, 0) => Ok(inv),
+            (_, i) if i > 0 => Err(InvError::Singular { pivot: i }),
+            (_, i) => Err(InvError::BackendError(i)),
+        }
+    }
+}
+
+impl<T, D: Dim> LdlFactor<T, D> for Lapack
+where
+    T: ComplexFloat + Default + LdlScalar + Workspace,
+{
+    fn ldl_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        pivoting: LdlPivoting,
+    ) -> Result<LdlFactors<'_, T, D, Self>, InvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        if m != n {
+            return Err(InvError::NotSquare {
+                rows: into_i32(m),
+                cols: into_i32(n),
+            });
+        }
+
+        let ((l, d, e, perm), info) = match pivoting {
+            LdlPivoting::BunchKaufman => hetrf(a),
+            LdlPivoting::Rook => hetrf_rk(a),
+        };
+
+        // A positive info only flags a singular block of D, the
+        // factorization itself is complete
+        if info < 0 {
+            return Err(InvError::BackendError(info));
+        }
+        Ok(LdlFactors::new(self, l, d, e, perm))
+    }
+
+    fn ldl_solve_write<Lb: Layout>(
+        &self,
+        f: &LdlFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError> {
+        if b.shape().dim(0) != f.n() {
+            return Err(SolveError::InvalidDimensions);
+        }
+        if let Some(i) = f.singular_block() {
+            return Err(SolveError::SingularMatrix {
+                diagonal: into_i32(i + 1),
+            });
+        }
+
+        match hetrs_3(&f.l, &f.d, &f.e, &f.perm, b) {
+            0 => Ok(()),
+            i => Err(SolveError::BackendError(i)),
+        }
+    }
+}
@@ -16,6 +16,31 @@ pub trait LapackScalar {
     );
 
     unsafe fn lapack_potrf(uplo: i8, n: i32, a: *mut Self, lda: i32, info: *mut i32);
+
+    unsafe fn lapack_getrs(
+        trans: i8,
+        n: i32,
+        nrhs: i32,
+        a: *const Self,
+        lda: i32,
+        ipiv: *const i32,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+
+    unsafe fn lapack_potrs(
+        uplo: i8,
+        n: i32,
+        nrhs: i32,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+
+    unsafe fn lapack_potri(uplo: i8, n: i32, a: *mut Self, lda: i32, info: *mut i32);
 }
 
 macro_rules! impl_lapack_scalar {
@@ -83,6 +108,77 @@ macro_rules! impl_lapack_scalar {
                     }
                 }
             }
+
+            #[inline]
+            unsafe fn lapack_getrs(
+                trans: i8,
+                n: i32,
+                nrhs: i32,
+                a: *const Self,
+                lda: i32,
+                ipiv: *const i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix getrs_>](
+                            &trans as *const i8,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            ipiv as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_potrs(
+                uplo: i8,
+                n: i32,
+                nrhs: i32,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix potrs_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_potri(uplo: i8, n: i32, a: *mut Self, lda: i32, info: *mut i32) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix potri_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
         }
     };
 }
@@ -135,3 +231,142 @@ impl_needs_rwork!(f32, f32, no_rwork);
 impl_needs_rwork!(f64, f64, no_rwork);
 impl_needs_rwork!(Complex<f32>, Complex<f32>, f32);
 impl_needs_rwork!(Complex<f64>, Complex<f64>, f64);
+
+/// Symmetric indefinite factorization routines: SYTRF, SYTRF_RK and
+/// SYTRS_3 for real types, their Hermitian HE counterparts for complex ones
+#[allow(clippy::too_many_arguments)]
+pub trait LdlScalar {
+    unsafe fn lapack_ldl_trf(
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        ipiv: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    unsafe fn lapack_ldl_trf_rk(
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        e: *mut Self,
+        ipiv: *mut i32,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    unsafe fn lapack_ldl_trs_3(
+        uplo: i8,
+        n: i32,
+        nrhs: i32,
+        a: *const Self,
+        lda: i32,
+        e: *const Self,
+        ipiv: *const i32,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_ldl_scalar {
+    ($t:ty, $prefix:ident, $kind:ident) => {
+        impl LdlScalar for $t {
+            #[inline]
+            unsafe fn lapack_ldl_trf(
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                ipiv: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $kind trf_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            ipiv as *mut i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_ldl_trf_rk(
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                e: *mut Self,
+                ipiv: *mut i32,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $kind trf_rk_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            e as *mut _,
+                            ipiv as *mut i32,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_ldl_trs_3(
+                uplo: i8,
+                n: i32,
+                nrhs: i32,
+                a: *const Self,
+                lda: i32,
+                e: *const Self,
+                ipiv: *const i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $kind trs_3_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            e as *const _,
+                            ipiv as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_ldl_scalar!(f32, s, sy);
+impl_ldl_scalar!(f64, d, sy);
+impl_ldl_scalar!(Complex<f32>, c, he);
+impl_ldl_scalar!(Complex<f64>, z, he);
@@ -1,8 +1,12 @@
-use mdarray::{DTensor, Dim, Layout, Shape, Slice};
-use mdarray_linalg::{into_i32, transpose_in_place};
+use std::cmp::Ordering;
+
+use mdarray::{DTensor, Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::{
+    into_i32, matmul::Triangle, to_col_major, transpose_in_place, trisolve::Transpose,
+};
 use num_complex::ComplexFloat;
 
-use super::scalar::{LapackScalar, Workspace};
+use super::scalar::{LapackScalar, LdlScalar, Workspace};
 
 pub fn getrf<
     T: ComplexFloat + Default + LapackScalar,
@@ -181,3 +185,482 @@ where
     }
     info
 }
+
+/// Packed LU factors of the square matrix A and the pivot indices from
+/// GETRF, along with its info code. A is left untouched.
+pub fn getrf_packed<T: ComplexFloat + Default + LapackScalar, D: Dim, L: Layout>(
+    a: &Slice<T, (D, D), L>,
+) -> (Tensor<T, (D, D)>, Vec<i32>, i32) {
+    let n = into_i32(a.shape().dim(0));
+
+    let mut lu = to_col_major(a);
+    let mut ipiv = vec![0i32; n as usize];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_getrf(
+            n,
+            n,
+            lu.as_mut_ptr(),
+            n.max(1), // lda
+            ipiv.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    transpose_in_place(&mut lu);
+    (lu, ipiv, info)
+}
+
+/// Overwrites B with op(A)⁻¹ B from the packed LU factors of A
+pub fn getrs<T: ComplexFloat + Default + LapackScalar, D: Dim, Llu: Layout, Lb: Layout>(
+    lu: &Slice<T, (D, D), Llu>,
+    ipiv: &[i32],
+    b: &mut Slice<T, (D, D), Lb>,
+    trans: Transpose,
+) -> i32 {
+    let bsh = *b.shape();
+    let (n, nrhs) = (bsh.dim(0), bsh.dim(1));
+    if n == 0 || nrhs == 0 {
+        return 0;
+    }
+
+    let lu_col_major = to_col_major(lu);
+    let mut b_col_major = to_col_major(b);
+
+    let trans = match trans {
+        Transpose::NoTrans => b'N',
+        Transpose::Trans => b'T',
+        Transpose::ConjTrans => b'C',
+    };
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_getrs(
+            trans as i8,
+            into_i32(n),
+            into_i32(nrhs),
+            lu_col_major.as_ptr(),
+            into_i32(n), // lda
+            ipiv.as_ptr(),
+            b_col_major.as_mut_ptr(),
+            into_i32(n), // ldb
+            &mut info,
+        );
+    }
+
+    for i in 0..n {
+        for j in 0..nrhs {
+            b[[i, j]] = b_col_major[[j, i]];
+        }
+    }
+
+    info
+}
+
+/// Inverse of A from its packed LU factors, along with the GETRI info code
+pub fn getri_packed<T: ComplexFloat + Default + LapackScalar + Workspace, D: Dim, L: Layout>(
+    lu: &Slice<T, (D, D), L>,
+    ipiv: &[i32],
+) -> (Tensor<T, (D, D)>, i32) {
+    let n = into_i32(lu.shape().dim(0));
+
+    let mut inv = to_col_major(lu);
+    if n == 0 {
+        return (inv, 0);
+    }
+
+    let mut info = 0;
+    let mut work_query = T::allocate(1);
+    unsafe {
+        T::lapack_getri(
+            n,
+            inv.as_mut_ptr(),
+            n, // lda
+            ipiv.as_ptr(),
+            work_query.as_mut_ptr() as *mut T,
+            -1,
+            &mut info,
+        );
+    }
+    assert_eq!(
+        info, 0,
+        "LAPACK GETRI workspace query failed with info = {info}"
+    );
+
+    let lwork = T::lwork_from_query(work_query.first().expect("Query buffer is empty"));
+    let mut work = vec![T::zero(); lwork as usize];
+
+    unsafe {
+        T::lapack_getri(
+            n,
+            inv.as_mut_ptr(),
+            n, // lda
+            ipiv.as_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            &mut info,
+        );
+    }
+
+    transpose_in_place(&mut inv);
+    (inv, info)
+}
+
+fn uplo_byte(triangle: Triangle) -> i8 {
+    match triangle {
+        Triangle::Lower => b'L' as i8,
+        Triangle::Upper => b'U' as i8,
+    }
+}
+
+/// Cholesky factor of A from POTRF, the other triangle being zeroed,
+/// along with its info code. A is left untouched.
+pub fn potrf_factor<T: ComplexFloat + Default + LapackScalar, D: Dim, L: Layout>(
+    a: &Slice<T, (D, D), L>,
+    triangle: Triangle,
+) -> (Tensor<T, (D, D)>, i32) {
+    let n = a.shape().dim(0);
+
+    let mut factor = to_col_major(a);
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_potrf(
+            uplo_byte(triangle),
+            into_i32(n),
+            factor.as_mut_ptr(),
+            into_i32(n.max(1)), // lda
+            &mut info,
+        );
+    }
+
+    transpose_in_place(&mut factor);
+    for i in 0..n {
+        for j in 0..n {
+            let outside = match triangle {
+                Triangle::Lower => j > i,
+                Triangle::Upper => j < i,
+            };
+            if outside {
+                factor[[i, j]] = T::zero();
+            }
+        }
+    }
+
+    (factor, info)
+}
+
+/// Overwrites B with A⁻¹ B from the Cholesky factor of A
+pub fn potrs<T: ComplexFloat + Default + LapackScalar, D: Dim, Lf: Layout, Lb: Layout>(
+    factor: &Slice<T, (D, D), Lf>,
+    triangle: Triangle,
+    b: &mut Slice<T, (D, D), Lb>,
+) -> i32 {
+    let bsh = *b.shape();
+    let (n, nrhs) = (bsh.dim(0), bsh.dim(1));
+    if n == 0 || nrhs == 0 {
+        return 0;
+    }
+
+    let factor_col_major = to_col_major(factor);
+    let mut b_col_major = to_col_major(b);
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_potrs(
+            uplo_byte(triangle),
+            into_i32(n),
+            into_i32(nrhs),
+            factor_col_major.as_ptr(),
+            into_i32(n), // lda
+            b_col_major.as_mut_ptr(),
+            into_i32(n), // ldb
+            &mut info,
+        );
+    }
+
+    for i in 0..n {
+        for j in 0..nrhs {
+            b[[i, j]] = b_col_major[[j, i]];
+        }
+    }
+
+    info
+}
+
+/// Inverse of A from its Cholesky factor, along with the POTRI info code
+pub fn potri<T: ComplexFloat + Default + LapackScalar, D: Dim, L: Layout>(
+    factor: &Slice<T, (D, D), L>,
+    triangle: Triangle,
+) -> (Tensor<T, (D, D)>, i32) {
+    let n = factor.shape().dim(0);
+
+    let mut inv = to_col_major(factor);
+    if n == 0 {
+        return (inv, 0);
+    }
+
+    let mut info = 0;
+    unsafe {
+        T::lapack_potri(
+            uplo_byte(triangle),
+            into_i32(n),
+            inv.as_mut_ptr(),
+            into_i32(n), // lda
+            &mut info,
+        );
+    }
+
+    // Only the selected triangle of the Hermitian inverse is computed
+    transpose_in_place(&mut inv);
+    for i in 0..n {
+        for j in 0..i {
+            match triangle {
+                Triangle::Lower => inv[[j, i]] = inv[[i, j]].conj(),
+                Triangle::Upper => inv[[i, j]] = inv[[j, i]].conj(),
+            }
+        }
+    }
+
+    (inv, info)
+}
+
+/// Unit lower L, diagonal d and subdiagonal e of D and permutation of the
+/// factorization P A Pᵀ = L D Lᴴ
+pub type LdlParts<T, D> = (Tensor<T, (D, D)>, Vec<T>, Vec<T>, Vec<usize>);
+
+/// Row permutation resulting from the interchanges of rows k and |ipiv[k]|
+/// (1-based), applied in order
+fn ipiv_to_perm(ipiv: &[i32]) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..ipiv.len()).collect();
+    for (k, &p) in ipiv.iter().enumerate() {
+        perm.swap(k, p.unsigned_abs() as usize - 1);
+    }
+    perm
+}
+
+/// Workspace size of a SYTRF/HETRF or SYTRF_RK/HETRF_RK call, from a query
+fn ldl_lwork<T: ComplexFloat + Workspace>(query: impl FnOnce(*mut T, &mut i32)) -> i32 {
+    let mut work_query = T::allocate(1);
+    let mut info = 0;
+    query(work_query.as_mut_ptr() as *mut T, &mut info);
+    assert_eq!(info, 0, "LAPACK workspace query failed with info = {info}");
+    T::lwork_from_query(work_query.first().expect("Query buffer is empty")).max(1)
+}
+
+/// Bunch–Kaufman factorization of the real symmetric or complex Hermitian
+/// A by SYTRF/HETRF, of which the lower triangle is referenced, along with
+/// its info code. A is left untouched.
+///
+/// LAPACK stores L = P₁ L₁ P₂ L₂ …, each interchange Pₖ only acting on the
+/// trailing rows: applying the later interchanges to the earlier columns
+/// gives the unit lower L of P A Pᵀ = L D Lᴴ, as for GETRF.
+pub fn hetrf<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> (LdlParts<T, D>, i32)
+where
+    T: ComplexFloat + Default + LdlScalar + Workspace,
+{
+    let ash = *a.shape();
+    let n = ash.dim(0);
+    let n_i32 = into_i32(n);
+
+    let mut a_col_major = to_col_major(a);
+    let mut ipiv = vec![0i32; n];
+    let mut info = 0;
+
+    let lwork = ldl_lwork::<T>(|work, info| unsafe {
+        T::lapack_ldl_trf(
+            b'L' as i8,
+            n_i32,
+            a_col_major.as_mut_ptr(),
+            n_i32.max(1),
+            ipiv.as_mut_ptr(),
+            work,
+            -1,
+            info,
+        );
+    });
+    let mut work = vec![T::zero(); lwork as usize];
+
+    unsafe {
+        T::lapack_ldl_trf(
+            b'L' as i8,
+            n_i32,
+            a_col_major.as_mut_ptr(),
+            n_i32.max(1), // lda
+            ipiv.as_mut_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            &mut info,
+        );
+    }
+
+    // Column-major element (i, j) of the factors
+    let f = |i: usize, j: usize| a_col_major[[j, i]];
+
+    let mut l = Tensor::from_fn(ash, |i| if i[0] == i[1] { T::one() } else { T::zero() });
+    let mut d = vec![T::zero(); n];
+    let mut e = vec![T::zero(); n];
+    let mut perm: Vec<usize> = (0..n).collect();
+
+    let mut k = 0;
+    while k < n {
+        // A 2 × 2 block in rows k and k + 1 interchanges row k + 1 only
+        let (row, size) = if ipiv[k] > 0 { (k, 1) } else { (k + 1, 2) };
+        let p = ipiv[k].unsigned_abs() as usize - 1;
+
+        if p != row {
+            perm.swap(row, p);
+            for j in 0..k {
+                let tmp = l[[row, j]];
+                l[[row, j]] = l[[p, j]];
+                l[[p, j]] = tmp;
+            }
+        }
+
+        for c in k..k + size {
+            d[c] = f(c, c);
+            for i in k + size..n {
+                l[[i, c]] = f(i, c);
+            }
+        }
+        if size == 2 {
+            e[k] = f(k + 1, k);
+        }
+        k += size;
+    }
+
+    ((l, d, e, perm), info)
+}
+
+/// Rook pivoting factorization of the real symmetric or complex Hermitian
+/// A by SYTRF_RK/HETRF_RK, of which the lower triangle is referenced,
+/// along with its info code. A is left untouched.
+pub fn hetrf_rk<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> (LdlParts<T, D>, i32)
+where
+    T: ComplexFloat + Default + LdlScalar + Workspace,
+{
+    let ash = *a.shape();
+    let n = ash.dim(0);
+    let n_i32 = into_i32(n);
+
+    let mut a_col_major = to_col_major(a);
+    let mut e = vec![T::zero(); n];
+    let mut ipiv = vec![0i32; n];
+    let mut info = 0;
+
+    let lwork = ldl_lwork::<T>(|work, info| unsafe {
+        T::lapack_ldl_trf_rk(
+            b'L' as i8,
+            n_i32,
+            a_col_major.as_mut_ptr(),
+            n_i32.max(1),
+            e.as_mut_ptr(),
+            ipiv.as_mut_ptr(),
+            work,
+            -1,
+            info,
+        );
+    });
+    let mut work = vec![T::zero(); lwork as usize];
+
+    unsafe {
+        T::lapack_ldl_trf_rk(
+            b'L' as i8,
+            n_i32,
+            a_col_major.as_mut_ptr(),
+            n_i32.max(1), // lda
+            e.as_mut_ptr(),
+            ipiv.as_mut_ptr(),
+            work.as_mut_ptr(),
+            lwork,
+            &mut info,
+        );
+    }
+
+    // The interchanges are already applied to L
+    let l = Tensor::from_fn(ash, |i| match i[0].cmp(&i[1]) {
+        Ordering::Greater => a_col_major[[i[1], i[0]]],
+        Ordering::Equal => T::one(),
+        Ordering::Less => T::zero(),
+    });
+    let d = (0..n).map(|i| a_col_major[[i, i]]).collect();
+
+    ((l, d, e, ipiv_to_perm(&ipiv)), info)
+}
+
+/// Overwrites B with A⁻¹ B from the factors of P A Pᵀ = L D Lᴴ, through
+/// SYTRS_3/HETRS_3
+pub fn hetrs_3<T, D: Dim, Ll: Layout, Lb: Layout>(
+    l: &Slice<T, (D, D), Ll>,
+    d: &[T],
+    e: &[T],
+    perm: &[usize],
+    b: &mut Slice<T, (D, D), Lb>,
+) -> i32
+where
+    T: ComplexFloat + Default + LdlScalar,
+{
+    let bsh = *b.shape();
+    let (n, nrhs) = (bsh.dim(0), bsh.dim(1));
+    if n == 0 || nrhs == 0 {
+        return 0;
+    }
+
+    // Packed factors in the SYTRF_RK format: L below the diagonal, D on it
+    let mut a = vec![T::zero(); n * n];
+    for j in 0..n {
+        a[j * n + j] = d[j];
+        for i in j + 1..n {
+            a[j * n + i] = l[[i, j]];
+        }
+    }
+
+    // Interchanges reproducing the permutation, negative for 2 × 2 blocks
+    let mut ipiv = vec![0i32; n];
+    let mut rows: Vec<usize> = (0..n).collect();
+    let mut pos: Vec<usize> = (0..n).collect();
+    for k in 0..n {
+        let j = pos[perm[k]];
+        rows.swap(k, j);
+        pos[rows[k]] = k;
+        pos[rows[j]] = j;
+        ipiv[k] = into_i32(j + 1);
+    }
+    let mut k = 0;
+    while k < n {
+        if k + 1 < n && e[k] != T::zero() {
+            ipiv[k] = -ipiv[k];
+            ipiv[k + 1] = -ipiv[k + 1];
+            k += 2;
+        } else {
+            k += 1;
+        }
+    }
+
+    let mut b_col_major = to_col_major(b);
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_ldl_trs_3(
+            b'L' as i8,
+            into_i32(n),
+            into_i32(nrhs),
+            a.as_ptr(),
+            into_i32(n), // lda
+            e.as_ptr(),
+            ipiv.as_ptr(),
+            b_col_major.as_mut_ptr(),
+            into_i32(n), // ldb
+            &mut info,
+        );
+    }
+
+    for i in 0..n {
+        for j in 0..nrhs {
+            b[[i, j]] = b_col_major[[j, i]];
+        }
+    }
+
+    info
+}
@@ -0,0 +1,237 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // One, infinity, Frobenius or max-abs norm of a general matrix (LANGE)
+    unsafe fn lapack_lange(
+        norm: i8,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        work: *mut Self::Real,
+    ) -> Self::Real;
+
+    // Reciprocal condition number estimate from an LU factorization (GECON).
+    // Real types use `iwork`, complex types `rwork`.
+    unsafe fn lapack_gecon(
+        norm: i8,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        anorm: Self::Real,
+        rcond: *mut Self::Real,
+        work: *mut Self,
+        iwork: *mut i32,
+        rwork: *mut Self::Real,
+        info: *mut i32,
+    );
+
+    // Reciprocal condition number estimate from a Cholesky factorization (POCON).
+    // Real types use `iwork`, complex types `rwork`.
+    unsafe fn lapack_pocon(
+        uplo: i8,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        anorm: Self::Real,
+        rcond: *mut Self::Real,
+        work: *mut Self,
+        iwork: *mut i32,
+        rwork: *mut Self::Real,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar_real {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_lange(
+                norm: i8,
+                m: i32,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                work: *mut Self::Real,
+            ) -> Self::Real {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix lange_>](
+                            &norm as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            work,
+                        )
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gecon(
+                norm: i8,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                anorm: Self::Real,
+                rcond: *mut Self::Real,
+                work: *mut Self,
+                iwork: *mut i32,
+                _rwork: *mut Self::Real, // unused
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gecon_>](
+                            &norm as *const i8,
+                            &n as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            &anorm as *const _,
+                            rcond as *mut _,
+                            work as *mut _,
+                            iwork as *mut i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_pocon(
+                uplo: i8,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                anorm: Self::Real,
+                rcond: *mut Self::Real,
+                work: *mut Self,
+                iwork: *mut i32,
+                _rwork: *mut Self::Real, // unused
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix pocon_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            &anorm as *const _,
+                            rcond as *mut _,
+                            work as *mut _,
+                            iwork as *mut i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! lapack_sys_cast {
+    (c) => {
+        lapack_sys::lapack_complex_float
+    };
+    (z) => {
+        lapack_sys::lapack_complex_double
+    };
+}
+
+macro_rules! impl_lapack_scalar_cplx {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_lange(
+                norm: i8,
+                m: i32,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                work: *mut Self::Real,
+            ) -> Self::Real {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix lange_>](
+                            &norm as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *const lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            work,
+                        )
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gecon(
+                norm: i8,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                anorm: Self::Real,
+                rcond: *mut Self::Real,
+                work: *mut Self,
+                _iwork: *mut i32, // unused
+                rwork: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gecon_>](
+                            &norm as *const i8,
+                            &n as *const i32,
+                            a as *const lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            &anorm as *const _,
+                            rcond as *mut _,
+                            work as *mut lapack_sys_cast!($prefix),
+                            rwork,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_pocon(
+                uplo: i8,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                anorm: Self::Real,
+                rcond: *mut Self::Real,
+                work: *mut Self,
+                _iwork: *mut i32, // unused
+                rwork: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix pocon_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *const lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            &anorm as *const _,
+                            rcond as *mut _,
+                            work as *mut lapack_sys_cast!($prefix),
+                            rwork,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar_real!(f32, s);
+impl_lapack_scalar_real!(f64, d);
+impl_lapack_scalar_cplx!(Complex<f32>, c);
+impl_lapack_scalar_cplx!(Complex<f64>, z);
@@ -0,0 +1,158 @@
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{
+    into_i32,
+    norm::{Norm, NormError, rcond_dims, rcond_lu_dims},
+    to_col_major,
+};
+use num_complex::ComplexFloat;
+use num_traits::Zero;
+
+use super::scalar::LapackScalar;
+
+/// One, infinity, Frobenius or max-abs norm of a general matrix
+pub fn lange<T, D0: Dim, D1: Dim, L: Layout>(a: &Slice<T, (D0, D1), L>, norm: Norm) -> T::Real
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    if m == 0 || n == 0 {
+        return T::Real::zero();
+    }
+
+    let norm = match norm {
+        Norm::One => b'1',
+        Norm::Inf => b'I',
+        Norm::Frobenius => b'F',
+        Norm::MaxAbs => b'M',
+        Norm::Spectral | Norm::Nuclear => unreachable!("computed from the singular values"),
+    };
+
+    let a_col_major = to_col_major(a);
+    let mut work = vec![T::Real::zero(); m];
+
+    unsafe {
+        T::lapack_lange(
+            norm as i8,
+            into_i32(m),
+            into_i32(n),
+            a_col_major.as_ptr(),
+            into_i32(m),
+            work.as_mut_ptr(),
+        )
+    }
+}
+
+/// Estimates the reciprocal condition number from the factors of P A = L U
+pub fn gecon<T, D: Dim, Ll: Layout, Lu: Layout>(
+    l: &Slice<T, (D, D), Ll>,
+    u: &Slice<T, (D, D), Lu>,
+    anorm: T::Real,
+    norm: Norm,
+) -> Result<T::Real, NormError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = rcond_lu_dims(l, u)?;
+
+    // Packed column-major LU: the unit diagonal of L is implicit
+    let mut lu = vec![T::default(); n * n];
+    for j in 0..n {
+        for i in 0..n {
+            lu[j * n + i] = if i > j { l[[i, j]] } else { u[[i, j]] };
+        }
+    }
+
+    gecon_col_major(n, &lu, anorm, norm)
+}
+
+/// Estimates the reciprocal condition number from L and U packed in a
+/// single matrix, as computed by `getrf`
+pub fn gecon_packed<T, D: Dim, L: Layout>(
+    lu: &Slice<T, (D, D), L>,
+    anorm: T::Real,
+    norm: Norm,
+) -> Result<T::Real, NormError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = rcond_dims(lu)?;
+    gecon_col_major(n, to_col_major(lu).flatten().as_ref(), anorm, norm)
+}
+
+/// GECON on the n × n packed LU factors stored in column-major order
+fn gecon_col_major<T>(n: usize, lu: &[T], anorm: T::Real, norm: Norm) -> Result<T::Real, NormError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let norm = match norm {
+        Norm::One => b'1',
+        Norm::Inf => b'I',
+        other => return Err(NormError::UnsupportedNorm(other)),
+    };
+
+    let mut rcond = T::Real::zero();
+    let mut work = vec![T::default(); 4 * n.max(1)];
+    let mut iwork = vec![0i32; n.max(1)];
+    let mut rwork = vec![T::Real::zero(); 2 * n.max(1)];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_gecon(
+            norm as i8,
+            into_i32(n),
+            lu.as_ptr(),
+            into_i32(n.max(1)),
+            anorm,
+            &mut rcond,
+            work.as_mut_ptr(),
+            iwork.as_mut_ptr(),
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => Ok(rcond),
+        i => Err(NormError::BackendError(i)),
+    }
+}
+
+/// Estimates the reciprocal condition number from the lower Cholesky
+/// factor of A = L Lᴴ
+pub fn pocon<T, D: Dim, L: Layout>(
+    l: &Slice<T, (D, D), L>,
+    anorm: T::Real,
+) -> Result<T::Real, NormError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = rcond_dims(l)?;
+    let l_col_major = to_col_major(l);
+
+    let mut rcond = T::Real::zero();
+    let mut work = vec![T::default(); 3 * n.max(1)];
+    let mut iwork = vec![0i32; n.max(1)];
+    let mut rwork = vec![T::Real::zero(); n.max(1)];
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_pocon(
+            b'L' as i8,
+            into_i32(n),
+            l_col_major.as_ptr(),
+            into_i32(n.max(1)),
+            anorm,
+            &mut rcond,
+            work.as_mut_ptr(),
+            iwork.as_mut_ptr(),
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    match info {
+        0 => Ok(rcond),
+        i => Err(NormError::BackendError(i)),
+    }
+}
@@ -7,16 +7,22 @@
 //! This decomposition is used to solve linear equations, least squares problems, and eigenvalue problems.
 //! The function `geqrf` (LAPACK) computes the QR factorization of a general m-by-n matrix A using a blocking algorithm.
 //! The matrix Q is orthogonal, and R is upper triangular.
+//! The thin (economy) factorization keeps Q as m × min(m, n) and R as min(m, n) × n.
+//!
+//! QR with column pivoting:
+//!     A * P = Q * R
+//! where P is a permutation matrix chosen by `geqp3` so that the diagonal
+//! of R is non-increasing in magnitude, which reveals the numerical rank of A.
 
-use mdarray::{Dim, Layout, Slice, Tensor};
-use mdarray_linalg::qr::QR;
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::qr::{QR, QRPivot, QRPivotDecomp};
 use num_complex::ComplexFloat;
 
 use super::{
     scalar::{LapackScalar, NeedsRwork},
-    simple::geqrf,
+    simple::{geqp3, geqrf},
 };
-use crate::Lapack;
+use crate::{Lapack, LapackQRConfig};
 
 impl<T, D0: Dim, D1: Dim> QR<T, D0, D1> for Lapack
 where
@@ -32,17 +38,77 @@ where
         geqrf(a, q, r)
     }
 
+    /// With `LapackQRConfig::TallSkinny`, returns the thin factors as `qr_thin`
     fn qr<L: Layout>(
         &self,
         a: &mut Slice<T, (D0, D1), L>,
     ) -> (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>) {
+        if self.qr_config == LapackQRConfig::TallSkinny {
+            return self.qr_thin(a);
+        }
+
         let ash = *a.shape();
+        let m = ash.dim(0);
 
-        let mut q = Tensor::from_elem(ash, T::default());
+        let mut q = Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[m, m]), T::default());
         let mut r = Tensor::from_elem(ash, T::default());
 
         geqrf(a, &mut q, &mut r);
 
         (q, r)
     }
+
+    fn qr_thin_write<L: Layout, Lq: Layout, Lr: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+    ) {
+        geqrf(a, q, r)
+    }
+
+    fn qr_thin<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>) {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let k = m.min(n);
+
+        let mut q = Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[m, k]), T::default());
+        let mut r = Tensor::from_elem(<(D0, D1) as Shape>::from_dims(&[k, n]), T::default());
+
+        geqrf(a, &mut q, &mut r);
+
+        (q, r)
+    }
+}
+
+impl<T, D0: Dim, D1: Dim> QRPivot<T, D0, D1> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork,
+    T::Real: Into<T>,
+{
+    fn qr_pivot_write<L: Layout, Lq: Layout, Lr: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        q: &mut Slice<T, (D0, D0), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+        perm: &mut [usize],
+    ) {
+        geqp3(a, q, r, perm)
+    }
+
+    fn qr_pivot<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> QRPivotDecomp<T, D0, D1> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let mut q = Tensor::from_elem(<(D0, D0) as Shape>::from_dims(&[m, m]), T::default());
+        let mut r = Tensor::from_elem(ash, T::default());
+        let mut perm = vec![0; n];
+
+        geqp3(a, &mut q, &mut r, &mut perm);
+
+        QRPivotDecomp { q, r, perm }
+    }
 }
@@ -1,8 +1,8 @@
-use num_complex::Complex;
+use num_complex::{Complex, ComplexFloat};
 use paste::paste;
 
 #[allow(clippy::too_many_arguments)]
-pub trait LapackScalar {
+pub trait LapackScalar: ComplexFloat {
     unsafe fn lapack_geqrf(
         m: i32,
         n: i32,
@@ -15,6 +15,7 @@ pub trait LapackScalar {
 
     unsafe fn lapack_orgqr(
         m: i32,
+        n: i32,
         min_mn: i32,
         a: *mut Self,
         tau: *mut Self,
@@ -22,6 +23,18 @@ pub trait LapackScalar {
         lwork: i32,
         info: *mut i32,
     );
+
+    unsafe fn lapack_geqp3(
+        m: i32,
+        n: i32,
+        a: *mut Self,
+        jpvt: *mut i32,
+        tau: *mut Self,
+        work: *mut Self,
+        lwork: i32,
+        rwork: *mut Self::Real,
+        info: *mut i32,
+    );
 }
 
 macro_rules! impl_lapack_real {
@@ -54,6 +67,7 @@ macro_rules! impl_lapack_real {
             }
             unsafe fn lapack_orgqr(
                 m: i32,
+                n: i32,
                 min_mn: i32,
                 a: *mut Self,
                 tau: *mut Self,
@@ -65,7 +79,7 @@ macro_rules! impl_lapack_real {
                     paste! {
                                 lapack_sys::[<$prefix $suffix gqr_>](
                                     &m as *const i32,
-                    &m as *const i32,
+                    &n as *const i32,
                     &min_mn as *const i32,
                     a as *mut _,
                     &m as *const i32,
@@ -77,6 +91,35 @@ macro_rules! impl_lapack_real {
                                 }
                 }
             }
+
+            #[inline]
+            unsafe fn lapack_geqp3(
+                m: i32,
+                n: i32,
+                a: *mut Self,
+                jpvt: *mut i32,
+                tau: *mut Self,
+                work: *mut Self,
+                lwork: i32,
+                _rwork: *mut Self, // unused
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix geqp3_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *mut _,
+                            &m as *const i32,
+                            jpvt as *mut i32,
+                            tau as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
         }
     };
 }
@@ -120,6 +163,7 @@ macro_rules! impl_lapack_cplx {
             }
             unsafe fn lapack_orgqr(
                 m: i32,
+                n: i32,
                 min_mn: i32,
                 a: *mut Self,
                 tau: *mut Self,
@@ -131,7 +175,7 @@ macro_rules! impl_lapack_cplx {
                     paste! {
                                 lapack_sys::[<$prefix $suffix gqr_>](
                                     &m as *const i32,
-                    &m as *const i32,
+                    &n as *const i32,
                     &min_mn as *const i32,
                     a as *mut lapack_sys_cast!($prefix),
                     &m as *const i32,
@@ -143,6 +187,36 @@ macro_rules! impl_lapack_cplx {
                                 }
                 }
             }
+
+            #[inline]
+            unsafe fn lapack_geqp3(
+                m: i32,
+                n: i32,
+                a: *mut Self,
+                jpvt: *mut i32,
+                tau: *mut Self,
+                work: *mut Self,
+                lwork: i32,
+                rwork: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix geqp3_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *mut lapack_sys_cast!($prefix),
+                            &m as *const i32,
+                            jpvt as *mut i32,
+                            tau as *mut lapack_sys_cast!($prefix),
+                            work as *mut lapack_sys_cast!($prefix),
+                            &lwork as *const i32,
+                            rwork,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
         }
     };
 }
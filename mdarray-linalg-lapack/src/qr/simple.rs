@@ -1,9 +1,13 @@
 use mdarray::{Dim, Layout, Shape, Slice};
 use mdarray_linalg::{into_i32, transpose_in_place};
 use num_complex::ComplexFloat;
+use num_traits::Zero;
 
 use super::scalar::{LapackScalar, NeedsRwork};
 
+/// QR decomposition A = Q R
+/// The shapes of Q and R select between the full (Q is m × m, R is m × n)
+/// and the thin (Q is m × k, R is k × n, with k = min(m, n)) factorization.
 pub fn geqrf<
     La: Layout,
     Lq: Layout,
@@ -19,22 +23,24 @@ pub fn geqrf<
     T::Real: Into<T>,
 {
     let ash = *a.shape();
-    let (m, n) = (into_i32(ash.dim(0)), into_i32(ash.dim(1)));
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let min_mn = m.min(n);
 
     let qsh = *q.shape();
-    let (mq, nq) = (into_i32(qsh.dim(0)), into_i32(qsh.dim(1)));
-
-    let rsh = *q.shape();
-    let (mr, nr) = (into_i32(rsh.dim(0)), into_i32(rsh.dim(1)));
+    let (mq, nq) = (qsh.dim(0), qsh.dim(1));
 
-    let min_mn = m.min(n);
+    let rsh = *r.shape();
+    let (mr, nr) = (rsh.dim(0), rsh.dim(1));
 
-    assert_eq!(mq, nq, "Q must be square (m × m)");
-    assert_eq!(mr, min_mn, "R must have min(m,n) rows");
+    assert_eq!(mq, m, "Q must have m rows");
+    assert!(
+        (nq == m && mr == m) || (nq == min_mn && mr == min_mn),
+        "Q and R must be either m × m and m × n, or m × min(m, n) and min(m, n) × n"
+    );
     assert_eq!(nr, n, "R must have n columns");
 
     // Allocate tau (Householder scalars)
-    let mut tau = vec![T::default(); min_mn as usize];
+    let mut tau = vec![T::default(); min_mn];
 
     let mut work = T::allocate(1);
     let lwork = -1;
@@ -46,8 +52,8 @@ pub fn geqrf<
     // Query optimal workspace size
     unsafe {
         T::lapack_geqrf(
-            m,
-            n,
+            into_i32(m),
+            into_i32(n),
             a.as_mut_ptr(),
             tau.as_mut_ptr(),
             work.as_mut_ptr() as *mut _,
@@ -62,8 +68,8 @@ pub fn geqrf<
     // Actual computation
     unsafe {
         T::lapack_geqrf(
-            m,
-            n,
+            into_i32(m),
+            into_i32(n),
             a.as_mut_ptr(),
             tau.as_mut_ptr(),
             work.as_mut_ptr() as *mut _,
@@ -72,24 +78,61 @@ pub fn geqrf<
         );
     }
 
-    for i in 0_usize..(min_mn as usize) {
-        for j in i..(n as usize) {
-            r[[i, j]] = a[[j, i]];
+    copy_r(a, r);
+    build_q(a, &mut tau, q);
+}
+
+/// Copies the upper triangle of the column-major factorization stored in
+/// A into the row-major R, whose rows beyond min(m, n) are zero
+fn copy_r<T: ComplexFloat, D0: Dim, D1: Dim, La: Layout, Lr: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    r: &mut Slice<T, (D0, D1), Lr>,
+) {
+    let m = a.shape().dim(0);
+    let rsh = *r.shape();
+    for i in 0..rsh.dim(0) {
+        for j in 0..rsh.dim(1) {
+            r[[i, j]] = if j >= i { a[j * m + i] } else { T::zero() };
         }
     }
+}
+
+/// Forms the first `q.dim(1)` columns of Q from the Householder reflectors
+/// stored in the column-major A with orgqr/ungqr
+fn build_q<
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork,
+    D0: Dim,
+    D1: Dim,
+    D2: Dim,
+    La: Layout,
+    Lq: Layout,
+>(
+    a: &Slice<T, (D0, D1), La>,
+    tau: &mut [T],
+    q: &mut Slice<T, (D0, D2), Lq>,
+) {
+    let m = a.shape().dim(0);
+    let nq = q.shape().dim(1);
+    let k = tau.len();
+
+    // The full Q needs an m × m buffer when A has fewer than m columns
+    let mut q_col_major = vec![T::default(); m * nq];
+    for (idx, x) in q_col_major.iter_mut().take(m * k).enumerate() {
+        *x = a[idx];
+    }
 
     let mut work = T::allocate(1);
-    let lwork = -1;
+    let mut info = 0;
 
-    // Construct Q explicitly using orgqr/ungqr
     unsafe {
         T::lapack_orgqr(
-            m,
-            min_mn,
-            a.as_mut_ptr() as *mut _,
-            tau.as_mut_ptr() as *mut _,
+            into_i32(m),
+            into_i32(nq),
+            into_i32(k),
+            q_col_major.as_mut_ptr(),
+            tau.as_mut_ptr(),
             work.as_mut_ptr() as *mut _,
-            lwork,
+            -1,
             &mut info,
         );
     }
@@ -99,19 +142,111 @@ pub fn geqrf<
 
     unsafe {
         T::lapack_orgqr(
-            m,
-            min_mn,
-            a.as_mut_ptr() as *mut _,
-            tau.as_mut_ptr() as *mut _,
+            into_i32(m),
+            into_i32(nq),
+            into_i32(k),
+            q_col_major.as_mut_ptr(),
+            tau.as_mut_ptr(),
             work.as_mut_ptr() as *mut _,
             lwork,
             &mut info,
         );
     }
 
-    for i in 0_usize..(m as usize) {
-        for j in 0_usize..(m as usize) {
-            q[[i, j]] = a[[j, i]];
+    for i in 0..m {
+        for j in 0..nq {
+            q[[i, j]] = q_col_major[j * m + i];
         }
     }
 }
+
+/// Column-pivoted QR decomposition A P = Q R, with Q m × m, R m × n and
+/// `perm[j]` the column of A moved to position j
+pub fn geqp3<
+    La: Layout,
+    Lq: Layout,
+    Lr: Layout,
+    D0: Dim,
+    D1: Dim,
+    T: ComplexFloat + Default + LapackScalar + NeedsRwork,
+>(
+    a: &mut Slice<T, (D0, D1), La>,
+    q: &mut Slice<T, (D0, D0), Lq>,
+    r: &mut Slice<T, (D0, D1), Lr>,
+    perm: &mut [usize],
+) where
+    T::Real: Into<T>,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let min_mn = m.min(n);
+
+    let qsh = *q.shape();
+    assert_eq!(qsh.dim(0), m, "Q must have m rows");
+    assert_eq!(qsh.dim(1), m, "Q must be square (m × m)");
+
+    let rsh = *r.shape();
+    assert_eq!(rsh.dim(0), m, "R must have m rows");
+    assert_eq!(rsh.dim(1), n, "R must have n columns");
+
+    assert_eq!(perm.len(), n, "perm must have length n");
+
+    // All columns are free to be pivoted
+    let mut jpvt = vec![0i32; n];
+    let mut tau = vec![T::default(); min_mn];
+    let mut rwork = vec![T::Real::zero(); 2 * n.max(1)];
+
+    let mut work = T::allocate(1);
+    let mut info = 0;
+
+    // Lapack works with column-major
+    transpose_in_place(a);
+
+    // Query optimal workspace size
+    unsafe {
+        T::lapack_geqp3(
+            into_i32(m),
+            into_i32(n),
+            a.as_mut_ptr(),
+            jpvt.as_mut_ptr(),
+            tau.as_mut_ptr(),
+            work.as_mut_ptr() as *mut _,
+            -1,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    let lwork = T::lwork_from_query(work.first().expect("Query buffer is empty"));
+    let mut work = T::allocate(lwork);
+
+    unsafe {
+        T::lapack_geqp3(
+            into_i32(m),
+            into_i32(n),
+            a.as_mut_ptr(),
+            jpvt.as_mut_ptr(),
+            tau.as_mut_ptr(),
+            work.as_mut_ptr() as *mut _,
+            lwork,
+            rwork.as_mut_ptr(),
+            &mut info,
+        );
+    }
+
+    assert_eq!(
+        info, 0,
+        "Invalid argument to geqp3: the {}-th parameter had an illegal value.",
+        -info
+    );
+
+    // A now holds R in its upper triangle, column-major
+    copy_r(a, r);
+
+    // LAPACK pivots are 1-based
+    for (p, &jp) in perm.iter_mut().zip(jpvt.iter()) {
+        *p = (jp - 1) as usize;
+    }
+
+    build_q(a, &mut tau, q);
+}
@@ -0,0 +1,39 @@
+//! Orthogonal reductions to condensed forms:
+//!     - Hessenberg (GEHRD, ORGHR/UNGHR):   A = Q H Qᴴ
+//!     - tridiagonal (SYTRD/HETRD, ORGTR/UNGTR): A = Q T Qᴴ
+//!     - bidiagonal (GEBRD, ORGBR/UNGBR):   A = Q B Pᴴ
+//! where:
+//!     - A is n × n, or m × n for the bidiagonal reduction (left untouched)
+//!     - Q and P are unitary, and only their leading k = min(m, n)
+//!       columns are formed for the bidiagonal reduction
+//!     - T and B are real, B being upper bidiagonal if m ≥ n and lower
+//!       bidiagonal otherwise
+//!
+//! The tridiagonal reduction only references the lower triangle of A.
+
+use mdarray::{Dim, Layout, Slice};
+use mdarray_linalg::reduction::{BidiagonalResult, HessenbergResult, Reduction, TridiagonalResult};
+use num_complex::ComplexFloat;
+
+use super::{
+    scalar::LapackScalar,
+    simple::{gebrd, gehrd, sytrd},
+};
+use crate::Lapack;
+
+impl<T, D: Dim> Reduction<T, D> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    fn hessenberg<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> HessenbergResult<T, D> {
+        gehrd(a)
+    }
+
+    fn tridiagonal<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> TridiagonalResult<T, D> {
+        sytrd(a)
+    }
+
+    fn bidiagonal<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> BidiagonalResult<T, D> {
+        gebrd(a)
+    }
+}
@@ -0,0 +1,280 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // Reduction to upper Hessenberg form (GEHRD)
+    unsafe fn lapack_gehrd(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: *mut Self,
+        lda: i32,
+        tau: *mut Self,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    // Unitary factor of the Hessenberg reduction (ORGHR/UNGHR)
+    unsafe fn lapack_orghr(
+        n: i32,
+        ilo: i32,
+        ihi: i32,
+        a: *mut Self,
+        lda: i32,
+        tau: *const Self,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    // Reduction of a symmetric/Hermitian matrix to real tridiagonal form
+    // (SYTRD/HETRD)
+    unsafe fn lapack_sytrd(
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        d: *mut Self::Real,
+        e: *mut Self::Real,
+        tau: *mut Self,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    // Unitary factor of the tridiagonal reduction (ORGTR/UNGTR)
+    unsafe fn lapack_orgtr(
+        uplo: i8,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        tau: *const Self,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    // Reduction to real bidiagonal form (GEBRD)
+    unsafe fn lapack_gebrd(
+        m: i32,
+        n: i32,
+        a: *mut Self,
+        lda: i32,
+        d: *mut Self::Real,
+        e: *mut Self::Real,
+        tauq: *mut Self,
+        taup: *mut Self,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+
+    // Unitary factors Q or Pᴴ of the bidiagonal reduction (ORGBR/UNGBR)
+    unsafe fn lapack_orgbr(
+        vect: i8,
+        m: i32,
+        n: i32,
+        k: i32,
+        a: *mut Self,
+        lda: i32,
+        tau: *const Self,
+        work: *mut Self,
+        lwork: i32,
+        info: *mut i32,
+    );
+}
+
+// `$sy` and `$or` name the symmetric and orthogonal routines: `sy`/`or` for
+// real types, `he`/`un` for complex types
+macro_rules! impl_lapack_scalar {
+    ($t:ty, $prefix:ident, $sy:ident, $or:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_gehrd(
+                n: i32,
+                ilo: i32,
+                ihi: i32,
+                a: *mut Self,
+                lda: i32,
+                tau: *mut Self,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gehrd_>](
+                            &n as *const i32,
+                            &ilo as *const i32,
+                            &ihi as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            tau as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_orghr(
+                n: i32,
+                ilo: i32,
+                ihi: i32,
+                a: *mut Self,
+                lda: i32,
+                tau: *const Self,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $or ghr_>](
+                            &n as *const i32,
+                            &ilo as *const i32,
+                            &ihi as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            tau as *const _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_sytrd(
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                d: *mut Self::Real,
+                e: *mut Self::Real,
+                tau: *mut Self,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $sy trd_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            d,
+                            e,
+                            tau as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_orgtr(
+                uplo: i8,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                tau: *const Self,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $or gtr_>](
+                            &uplo as *const i8,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            tau as *const _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_gebrd(
+                m: i32,
+                n: i32,
+                a: *mut Self,
+                lda: i32,
+                d: *mut Self::Real,
+                e: *mut Self::Real,
+                tauq: *mut Self,
+                taup: *mut Self,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix gebrd_>](
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            d,
+                            e,
+                            tauq as *mut _,
+                            taup as *mut _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+
+            #[inline]
+            unsafe fn lapack_orgbr(
+                vect: i8,
+                m: i32,
+                n: i32,
+                k: i32,
+                a: *mut Self,
+                lda: i32,
+                tau: *const Self,
+                work: *mut Self,
+                lwork: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix $or gbr_>](
+                            &vect as *const i8,
+                            &m as *const i32,
+                            &n as *const i32,
+                            &k as *const i32,
+                            a as *mut _,
+                            &lda as *const i32,
+                            tau as *const _,
+                            work as *mut _,
+                            &lwork as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar!(f32, s, sy, or);
+impl_lapack_scalar!(f64, d, sy, or);
+impl_lapack_scalar!(Complex<f32>, c, he, un);
+impl_lapack_scalar!(Complex<f64>, z, he, un);
@@ -0,0 +1,245 @@
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use mdarray_linalg::{
+    into_i32,
+    reduction::{
+        BidiagonalDecomp, BidiagonalResult, HessenbergDecomp, HessenbergResult, ReductionError,
+        TridiagonalDecomp, TridiagonalResult, reduction_dims,
+    },
+    to_col_major,
+};
+use num_complex::ComplexFloat;
+use num_traits::{ToPrimitive, Zero};
+
+use super::scalar::LapackScalar;
+
+fn lwork_from_query<T: ComplexFloat>(query: T) -> i32 {
+    query
+        .re()
+        .to_i32()
+        .expect("workspace size must fit into i32")
+}
+
+fn check_info(info: i32) -> Result<(), ReductionError> {
+    match info {
+        0 => Ok(()),
+        i => Err(ReductionError::BackendError(i)),
+    }
+}
+
+/// Runs a LAPACK routine twice, first as a workspace query with
+/// `lwork = -1`, then with a workspace of the returned size
+fn with_workspace<T: ComplexFloat + Default>(
+    mut routine: impl FnMut(*mut T, i32, &mut i32),
+) -> Result<(), ReductionError> {
+    let mut work = vec![T::default(); 1];
+    let mut info = 0;
+    routine(work.as_mut_ptr(), -1, &mut info);
+    check_info(info)?;
+
+    let lwork = lwork_from_query(work[0]).max(1);
+    let mut work = vec![T::default(); lwork as usize];
+    routine(work.as_mut_ptr(), lwork, &mut info);
+    check_info(info)
+}
+
+/// Copies the `rows` × `cols` leading block of a column-major matrix as
+/// returned by `to_col_major`, i.e. stored transposed
+fn from_col_major<T: ComplexFloat, D: Dim>(
+    a: &Tensor<T, (D, D)>,
+    rows: usize,
+    cols: usize,
+) -> Tensor<T, (D, D)> {
+    Tensor::from_fn(<(D, D) as Shape>::from_dims(&[rows, cols]), |i| {
+        a[[i[1], i[0]]]
+    })
+}
+
+/// Reduces A to upper Hessenberg form with GEHRD and forms Q with ORGHR
+pub fn gehrd<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> HessenbergResult<T, D>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = reduction_dims(a)?;
+    let ni = into_i32(n);
+    let lda = ni.max(1);
+
+    let mut h = to_col_major(a);
+    let mut tau = vec![T::zero(); n.max(2) - 1];
+
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_gehrd(
+            ni,
+            1,
+            ni,
+            h.as_mut_ptr(),
+            lda,
+            tau.as_mut_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    // The reflectors are stored below the first subdiagonal of H
+    let mut q = h.clone();
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_orghr(
+            ni,
+            1,
+            ni,
+            q.as_mut_ptr(),
+            lda,
+            tau.as_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    let h = Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+        if i[0] <= i[1] + 1 {
+            h[[i[1], i[0]]]
+        } else {
+            T::zero()
+        }
+    });
+
+    Ok(HessenbergDecomp {
+        h,
+        q: from_col_major(&q, n, n),
+    })
+}
+
+/// Reduces the Hermitian A to real tridiagonal form with SYTRD/HETRD and
+/// forms Q with ORGTR/UNGTR, referencing the lower triangle of A
+pub fn sytrd<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> TridiagonalResult<T, D>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let n = reduction_dims(a)?;
+    let ni = into_i32(n);
+    let lda = ni.max(1);
+
+    // The column-major copy keeps 'L' referring to the lower triangle of A
+    let mut q = to_col_major(a);
+    let mut d = vec![T::Real::zero(); n];
+    let mut e = vec![T::Real::zero(); n.max(2) - 1];
+    let mut tau = vec![T::zero(); n.max(2) - 1];
+
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_sytrd(
+            b'L' as i8,
+            ni,
+            q.as_mut_ptr(),
+            lda,
+            d.as_mut_ptr(),
+            e.as_mut_ptr(),
+            tau.as_mut_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_orgtr(
+            b'L' as i8,
+            ni,
+            q.as_mut_ptr(),
+            lda,
+            tau.as_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    e.truncate(n.saturating_sub(1));
+
+    Ok(TridiagonalDecomp {
+        d,
+        e,
+        q: from_col_major(&q, n, n),
+    })
+}
+
+/// Reduces A to real bidiagonal form with GEBRD and forms the thin
+/// factors Q and Pᴴ with ORGBR/UNGBR
+pub fn gebrd<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> BidiagonalResult<T, D>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let k = m.min(n);
+    let (mi, ni, ki) = (into_i32(m), into_i32(n), into_i32(k));
+    let lda = mi.max(1);
+
+    let mut q = to_col_major(a);
+    let mut d = vec![T::Real::zero(); k.max(1)];
+    let mut e = vec![T::Real::zero(); k.max(1)];
+    let mut tauq = vec![T::zero(); k.max(1)];
+    let mut taup = vec![T::zero(); k.max(1)];
+
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_gebrd(
+            mi,
+            ni,
+            q.as_mut_ptr(),
+            lda,
+            d.as_mut_ptr(),
+            e.as_mut_ptr(),
+            tauq.as_mut_ptr(),
+            taup.as_mut_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    // Both sets of reflectors are stored in the reduced matrix
+    let mut pt = q.clone();
+
+    // Q is m × k, built from the n reflectors of the columns
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_orgbr(
+            b'Q' as i8,
+            mi,
+            ki,
+            ni,
+            q.as_mut_ptr(),
+            lda,
+            tauq.as_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    // Pᴴ is k × n, built from the m reflectors of the rows
+    with_workspace(|work, lwork, info| unsafe {
+        T::lapack_orgbr(
+            b'P' as i8,
+            ki,
+            ni,
+            mi,
+            pt.as_mut_ptr(),
+            lda,
+            taup.as_ptr(),
+            work,
+            lwork,
+            info,
+        );
+    })?;
+
+    d.truncate(k);
+    e.truncate(k.saturating_sub(1));
+
+    Ok(BidiagonalDecomp {
+        d,
+        e,
+        upper: m >= n,
+        q: from_col_major(&q, m, k),
+        pt: from_col_major(&pt, k, n),
+    })
+}
@@ -6,6 +6,8 @@
 //!     - Σ is µ × µ         (diagonal matrix with singular values on the diagonal, µ = min(m,n))
 //!     - V^T is n × n       (transpose of right singular vectors, orthogonal)
 //!     - s (Σ) contains min(m, n) singular values (non-negative, sorted in descending order) in the first row
+//!
+//! The thin SVD only keeps the first µ columns of U (m × µ) and the first µ rows of V^T (µ × n).
 
 use mdarray::{Dense, Dim, Layout, Shape, Slice, Tensor};
 use mdarray_linalg::svd::{SVD, SVDDecomp, SVDError};
@@ -80,4 +82,35 @@ where
     ) -> Result<(), SVDError> {
         gsvd::<T, D, L, Ls, Dense, Dense>(a, s, None, None, self.svd_config)
     }
+
+    // Computes thin SVD with new allocated matrices
+    fn svd_thin(&self, a: &mut Slice<T, (D, D), L>) -> Result<SVDDecomp<T, D>, SVDError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let min_mn = m.min(n);
+
+        let s_shape = <(D, D) as Shape>::from_dims(&[min_mn, min_mn]);
+        let u_shape = <(D, D) as Shape>::from_dims(&[m, min_mn]);
+        let vt_shape = <(D, D) as Shape>::from_dims(&[min_mn, n]);
+
+        let mut s = Tensor::from_elem(s_shape, T::default());
+        let mut u = Tensor::from_elem(u_shape, T::default());
+        let mut vt = Tensor::from_elem(vt_shape, T::default());
+
+        match gsvd(a, &mut s, Some(&mut u), Some(&mut vt), self.svd_config) {
+            Ok(_) => Ok(SVDDecomp { s, u, vt }),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Computes thin SVD, overwriting existing matrices
+    fn svd_thin_write<Ls: Layout, Lu: Layout, Lvt: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        s: &mut Slice<T, (D, D), Ls>,
+        u: &mut Slice<T, (D, D), Lu>,
+        vt: &mut Slice<T, (D, D), Lvt>,
+    ) -> Result<(), SVDError> {
+        gsvd(a, s, Some(u), Some(vt), self.svd_config)
+    }
 }
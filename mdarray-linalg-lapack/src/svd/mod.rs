@@ -2,6 +2,8 @@ mod context;
 mod scalar;
 mod simple;
 
+pub(crate) use scalar::{LapackScalar, NeedsRwork};
+
 // #[derive(Default, Debug, Clone, Copy, PartialEq)]
 // pub enum SVDConfig {
 //     #[default]
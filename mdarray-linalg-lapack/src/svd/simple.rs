@@ -48,19 +48,23 @@ where
 
             let vtsh = y.shape();
             let (mvt, nvt) = (vtsh.dim(0), vtsh.dim(1));
-            assert_eq!(mu, nu, "U must be square (m × m)");
-            assert_eq!(mvt, nvt, "VT must be square (n × n)");
             assert_eq!(ns, ms, "s must be square (min(m,n),min(m,n))");
             assert_eq!(
                 ms, min_mn,
                 "s must have min(m, n) rows (number of singular values)"
             );
-            assert_eq!(mu, m, "U must have the same number of rows as A: U(m, m)");
-            assert_eq!(
-                nvt, n,
-                "VT must have the same number of columns as A: VT(n, n)"
-            );
-            'A'
+            assert_eq!(mu, m, "U must have the same number of rows as A");
+            assert_eq!(nvt, n, "VT must have the same number of columns as A");
+
+            // The shapes of U and VT select between full (U is m × m, VT
+            // is n × n) and thin (U is m × k, VT is k × n) SVD.
+            if nu == m && mvt == n {
+                'A'
+            } else {
+                assert_eq!(nu, min_mn, "U must be either m × m or m × min(m, n)");
+                assert_eq!(mvt, min_mn, "VT must be either n × n or min(m, n) × n");
+                'S'
+            }
         }
         (None, None) => 'N',
         _ => return Err(SVDError::InconsistentUV),
@@ -135,10 +139,7 @@ where
                 superdiagonals: (info),
             })
         } else {
-            if job == 'A' {
-                transpose_in_place(u.unwrap());
-                transpose_in_place(vt.unwrap());
-            }
+            restore_row_major(job, u, vt);
             Ok(())
         }
     } else if info > 0 {
@@ -146,14 +147,49 @@ where
             superdiagonals: (info),
         })
     } else {
-        if job == 'A' {
+        restore_row_major(job, u, vt);
+        Ok(())
+    }
+}
+
+/// LAPACK writes U and VT in column-major order.  Full factors are
+/// square and can be transposed in place, thin factors are not and
+/// need to be reshuffled.
+fn restore_row_major<T: ComplexFloat + Default, D: Dim, Lu: Layout, Lvt: Layout>(
+    job: char,
+    u: Option<&mut Slice<T, (D, D), Lu>>,
+    vt: Option<&mut Slice<T, (D, D), Lvt>>,
+) {
+    match job {
+        'A' => {
             transpose_in_place(u.unwrap());
             transpose_in_place(vt.unwrap());
         }
-        Ok(())
+        'S' => {
+            col_major_to_row_major(u.unwrap());
+            col_major_to_row_major(vt.unwrap());
+        }
+        _ => {}
     }
 }
 
+fn col_major_to_row_major<T: ComplexFloat + Default, D: Dim, L: Layout>(
+    x: &mut Slice<T, (D, D), L>,
+) {
+    let (m, n) = (x.shape().dim(0), x.shape().dim(1));
+    let col_major = x.to_tensor();
+    for i in 0..m {
+        for j in 0..n {
+            x[i * n + j] = col_major[j * m + i];
+        }
+    }
+}
+
+/// Leading dimension of VT: full VT is n × n, thin VT is min(m, n) × n.
+fn ldvt(job: char, m: i32, n: i32) -> i32 {
+    if job == 'A' { n } else { m.min(n) }
+}
+
 fn call_gesdd<T: ComplexFloat + Default + LapackScalar + NeedsRwork, D0: Dim, D1: Dim, La: Layout>(
     a: &mut Slice<T, (D0, D1), La>,
     m: i32,
@@ -195,7 +231,7 @@ where
             u_ptr.unwrap() as *mut _,
             m,
             vt_ptr.unwrap() as *mut _,
-            m.min(n), // n,
+            ldvt(job, m, n),
             work.as_mut_ptr() as *mut _,
             lwork,
             rwork.as_mut_ptr() as *mut _,
@@ -220,7 +256,7 @@ where
             u_ptr.unwrap() as *mut _,
             m,
             vt_ptr.unwrap() as *mut _,
-            m.min(n), // n,
+            ldvt(job, m, n),
             work.as_mut_ptr() as *mut _,
             lwork as i32,
             rwork.as_mut_ptr() as *mut _,
@@ -273,7 +309,7 @@ where
             u_ptr.unwrap_or(null_mut()) as *mut _,
             m,
             vt_ptr.unwrap_or(null_mut()) as *mut _,
-            m.min(n), // n,
+            ldvt(job, m, n),
             work.as_mut_ptr() as *mut _,
             lwork,
             rwork.as_mut_ptr() as *mut _,
@@ -297,7 +333,7 @@ where
             u_ptr.unwrap_or(null_mut()) as *mut _,
             m,
             vt_ptr.unwrap_or(null_mut()) as *mut _,
-            m.min(n), // n,
+            ldvt(job, m, n),
             work.as_mut_ptr() as *mut _,
             lwork,
             rwork.as_mut_ptr() as *mut _,
@@ -0,0 +1,33 @@
+//! Sylvester equation with (quasi-)triangular coefficients (TRSYL):
+//!     A X + X op(B) = C
+//! where:
+//!     - A is m × m and B is n × n, both upper triangular or, for real
+//!       types, upper quasi-triangular in Schur canonical form
+//!     - op(B) is B or Bᴴ
+//!     - C is m × n and is overwritten with X
+//!
+//! This is the triangular stage of the Bartels–Stewart algorithm behind
+//! the Sylvester, Lyapunov and Stein solvers.
+
+use mdarray::{Dim, Layout, Slice};
+use mdarray_linalg::sylvester::{SylvesterError, SylvesterTriangular, sylvester_dims};
+use num_complex::ComplexFloat;
+
+use super::{scalar::LapackScalar, simple::trsyl};
+use crate::Lapack;
+
+impl<T> SylvesterTriangular<T> for Lapack
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    fn sylvester_triangular<D: Dim, La: Layout, Lb: Layout, Lc: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        b: &Slice<T, (D, D), Lb>,
+        adjoint_b: bool,
+        c: &mut Slice<T, (D, D), Lc>,
+    ) -> Result<(), SylvesterError> {
+        sylvester_dims(a, b, c)?;
+        trsyl(a, b, adjoint_b, c)
+    }
+}
@@ -0,0 +1,70 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // Sylvester equation with (quasi-)triangular coefficients (TRSYL)
+    unsafe fn lapack_trsyl(
+        trana: i8,
+        tranb: i8,
+        isgn: i32,
+        m: i32,
+        n: i32,
+        a: *const Self,
+        lda: i32,
+        b: *const Self,
+        ldb: i32,
+        c: *mut Self,
+        ldc: i32,
+        scale: *mut Self::Real,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_trsyl(
+                trana: i8,
+                tranb: i8,
+                isgn: i32,
+                m: i32,
+                n: i32,
+                a: *const Self,
+                lda: i32,
+                b: *const Self,
+                ldb: i32,
+                c: *mut Self,
+                ldc: i32,
+                scale: *mut Self::Real,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trsyl_>](
+                            &trana as *const i8,
+                            &tranb as *const i8,
+                            &isgn as *const i32,
+                            &m as *const i32,
+                            &n as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            b as *const _,
+                            &ldb as *const i32,
+                            c as *mut _,
+                            &ldc as *const i32,
+                            scale,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar!(f32, s);
+impl_lapack_scalar!(f64, d);
+impl_lapack_scalar!(Complex<f32>, c);
+impl_lapack_scalar!(Complex<f64>, z);
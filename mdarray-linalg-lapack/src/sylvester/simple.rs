@@ -0,0 +1,65 @@
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{into_i32, sylvester::SylvesterError, to_col_major};
+use num_complex::ComplexFloat;
+use num_traits::{NumCast, One, Zero};
+
+use super::scalar::LapackScalar;
+
+/// Solves A X + X op(B) = C with TRSYL, C being overwritten with X
+pub fn trsyl<T, D: Dim, La: Layout, Lb: Layout, Lc: Layout>(
+    a: &Slice<T, (D, D), La>,
+    b: &Slice<T, (D, D), Lb>,
+    adjoint_b: bool,
+    c: &mut Slice<T, (D, D), Lc>,
+) -> Result<(), SylvesterError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+{
+    let (m, n) = (a.shape().dim(0), b.shape().dim(0));
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+
+    let a_col = to_col_major(a);
+    let b_col = to_col_major(b);
+    let mut x = to_col_major(c);
+    let (mi, ni) = (into_i32(m), into_i32(n));
+
+    // For real types, 'C' is the same as 'T'
+    let tranb = if adjoint_b { b'C' } else { b'N' };
+    let mut scale = T::Real::zero();
+    let mut info = 0;
+    unsafe {
+        T::lapack_trsyl(
+            b'N' as i8,
+            tranb as i8,
+            1,
+            mi,
+            ni,
+            a_col.as_ptr(),
+            mi,
+            b_col.as_ptr(),
+            ni,
+            x.as_mut_ptr(),
+            mi,
+            &mut scale,
+            &mut info,
+        );
+    }
+    match info {
+        0 => {}
+        // A and −op(B) have close eigenvalues, perturbed values were used
+        1 => return Err(SylvesterError::Singular),
+        i => return Err(SylvesterError::BackendError(i)),
+    }
+
+    // The solution is returned scaled by 0 < scale ≤ 1 to avoid overflow
+    let inv_scale =
+        <T as NumCast>::from(T::Real::one() / scale).expect("real values can be cast to T");
+    for i in 0..m {
+        for j in 0..n {
+            c[[i, j]] = x[[j, i]] * inv_scale;
+        }
+    }
+    Ok(())
+}
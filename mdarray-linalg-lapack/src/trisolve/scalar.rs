@@ -0,0 +1,107 @@
+use num_complex::{Complex, ComplexFloat};
+use paste::paste;
+
+#[allow(clippy::too_many_arguments)]
+pub trait LapackScalar: ComplexFloat {
+    // Triangular solve with multiple right-hand sides (TRTRS)
+    unsafe fn lapack_trtrs(
+        uplo: i8,
+        trans: i8,
+        diag: i8,
+        n: i32,
+        nrhs: i32,
+        a: *const Self,
+        lda: i32,
+        b: *mut Self,
+        ldb: i32,
+        info: *mut i32,
+    );
+}
+
+macro_rules! impl_lapack_scalar_real {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_trtrs(
+                uplo: i8,
+                trans: i8,
+                diag: i8,
+                n: i32,
+                nrhs: i32,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trtrs_>](
+                            &uplo as *const i8,
+                            &trans as *const i8,
+                            &diag as *const i8,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *const _,
+                            &lda as *const i32,
+                            b as *mut _,
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! lapack_sys_cast {
+    (c) => {
+        lapack_sys::lapack_complex_float
+    };
+    (z) => {
+        lapack_sys::lapack_complex_double
+    };
+}
+
+macro_rules! impl_lapack_scalar_cplx {
+    ($t:ty, $prefix:ident) => {
+        impl LapackScalar for $t {
+            #[inline]
+            unsafe fn lapack_trtrs(
+                uplo: i8,
+                trans: i8,
+                diag: i8,
+                n: i32,
+                nrhs: i32,
+                a: *const Self,
+                lda: i32,
+                b: *mut Self,
+                ldb: i32,
+                info: *mut i32,
+            ) {
+                unsafe {
+                    paste! {
+                        lapack_sys::[<$prefix trtrs_>](
+                            &uplo as *const i8,
+                            &trans as *const i8,
+                            &diag as *const i8,
+                            &n as *const i32,
+                            &nrhs as *const i32,
+                            a as *const lapack_sys_cast!($prefix),
+                            &lda as *const i32,
+                            b as *mut lapack_sys_cast!($prefix),
+                            &ldb as *const i32,
+                            info as *mut i32,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_lapack_scalar_real!(f32, s);
+impl_lapack_scalar_real!(f64, d);
+impl_lapack_scalar_cplx!(Complex<f32>, c);
+impl_lapack_scalar_cplx!(Complex<f64>, z);
@@ -0,0 +1,160 @@
+use mdarray::{Dim, Layout, Shape, Slice};
+use mdarray_linalg::{
+    into_i32,
+    matmul::{Side, Triangle},
+    to_col_major,
+    trisolve::{Transpose, TriSolveError, TriSolveOptions, trisolve_dims},
+};
+use num_complex::ComplexFloat;
+
+use super::scalar::LapackScalar;
+
+/// Solves op(A) R = R' in place, with A n × n and R n × nrhs both in
+/// column-major order.  R is left untouched when A is singular.
+fn trtrs_col_major<T, D, La>(
+    opts: &TriSolveOptions<T>,
+    trans: Transpose,
+    a: &Slice<T, (D, D), La>,
+    r: &mut [T],
+    nrhs: usize,
+) -> Result<(), TriSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+    D: Dim,
+    La: Layout,
+{
+    let n = a.shape().dim(0);
+    if n == 0 || nrhs == 0 {
+        return Ok(());
+    }
+
+    let a_col_major = to_col_major(a);
+
+    let uplo = match opts.triangle {
+        Triangle::Lower => b'L',
+        Triangle::Upper => b'U',
+    };
+    let trans = match trans {
+        Transpose::NoTrans => b'N',
+        Transpose::Trans => b'T',
+        Transpose::ConjTrans => b'C',
+    };
+    let diag = if opts.unit_diag { b'U' } else { b'N' };
+    let mut info = 0;
+
+    unsafe {
+        T::lapack_trtrs(
+            uplo as i8,
+            trans as i8,
+            diag as i8,
+            into_i32(n),
+            into_i32(nrhs),
+            a_col_major.as_ptr(),
+            into_i32(n),
+            r.as_mut_ptr(),
+            into_i32(n),
+            &mut info,
+        );
+    }
+
+    assert!(
+        info >= 0,
+        "Invalid argument to trtrs: the {}-th parameter had an illegal value.",
+        -info
+    );
+    if info > 0 {
+        return Err(TriSolveError::SingularMatrix {
+            diagonal: (info - 1) as usize,
+        });
+    }
+    Ok(())
+}
+
+/// Solves op(A) X = αB or X op(A) = αB, overwriting B with X
+///
+/// LAPACK only solves on the left, X op(A) = αB is solved as
+/// op(A)ᵀ Xᵀ = αBᵀ, or as A Xᴴ = ᾱBᴴ when op(A) = Aᴴ.
+pub fn trtrs<T, La, Lb, D, D0, D1>(
+    opts: &TriSolveOptions<T>,
+    a: &Slice<T, (D, D), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> Result<(), TriSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+    La: Layout,
+    Lb: Layout,
+    D: Dim,
+    D0: Dim,
+    D1: Dim,
+{
+    trisolve_dims(a, b, opts.side);
+
+    let bsh = *b.shape();
+    let (m, n) = (bsh.dim(0), bsh.dim(1));
+
+    match opts.side {
+        Side::Left => {
+            let mut r = vec![T::default(); m * n];
+            for i in 0..m {
+                for j in 0..n {
+                    r[j * m + i] = opts.alpha * b[[i, j]];
+                }
+            }
+            trtrs_col_major(opts, opts.trans, a, &mut r, n)?;
+            for i in 0..m {
+                for j in 0..n {
+                    b[[i, j]] = r[j * m + i];
+                }
+            }
+        }
+        Side::Right => {
+            // Bᵀ in column-major order is B in row-major order
+            let conj = opts.trans == Transpose::ConjTrans;
+            let trans = match opts.trans {
+                Transpose::NoTrans => Transpose::Trans,
+                Transpose::Trans | Transpose::ConjTrans => Transpose::NoTrans,
+            };
+
+            let mut r = vec![T::default(); m * n];
+            for i in 0..m {
+                for j in 0..n {
+                    let x = opts.alpha * b[[i, j]];
+                    r[i * n + j] = if conj { x.conj() } else { x };
+                }
+            }
+            trtrs_col_major(opts, trans, a, &mut r, m)?;
+            for i in 0..m {
+                for j in 0..n {
+                    let x = r[i * n + j];
+                    b[[i, j]] = if conj { x.conj() } else { x };
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Solves op(A) x = αb, overwriting b with x
+pub fn trtrs_vec<T, La, Lb, D, D0>(
+    opts: &TriSolveOptions<T>,
+    a: &Slice<T, (D, D), La>,
+    b: &mut Slice<T, (D0,), Lb>,
+) -> Result<(), TriSolveError>
+where
+    T: ComplexFloat + Default + LapackScalar,
+    La: Layout,
+    Lb: Layout,
+    D: Dim,
+    D0: Dim,
+{
+    let n = a.shape().dim(0);
+    assert_eq!(n, a.shape().dim(1), "A must be square");
+    assert_eq!(n, b.shape().dim(0), "A and b have incompatible dimensions");
+
+    let mut r: Vec<T> = (0..n).map(|i| opts.alpha * b[[i]]).collect();
+    trtrs_col_major(opts, opts.trans, a, &mut r, 1)?;
+    for (i, x) in r.into_iter().enumerate() {
+        b[[i]] = x;
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::banded::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn solve_tridiagonal() {
+    test_solve_tridiagonal(&Lapack::default());
+}
+
+#[test]
+fn solve_tridiagonal_complex() {
+    test_solve_tridiagonal_complex(&Lapack::default());
+}
+
+#[test]
+fn solve_tridiagonal_pd() {
+    test_solve_tridiagonal_pd(&Lapack::default());
+}
+
+#[test]
+fn solve_band() {
+    test_solve_band(&Lapack::default());
+}
+
+#[test]
+fn solve_band_complex() {
+    test_solve_band_complex(&Lapack::default());
+}
+
+#[test]
+fn solve_band_pd() {
+    test_solve_band_pd(&Lapack::default());
+}
+
+#[test]
+fn solve_band_pd_complex() {
+    test_solve_band_pd_complex(&Lapack::default());
+}
+
+#[test]
+fn band_singular() {
+    test_band_singular(&Lapack::default());
+}
+
+#[test]
+fn band_invalid_dimensions() {
+    test_band_invalid_dimensions(&Lapack::default());
+}
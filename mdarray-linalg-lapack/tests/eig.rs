@@ -53,3 +53,23 @@ fn schur_decomp() {
 fn schur_decomp_cplx() {
     test_schur_cplx(&Lapack::default());
 }
+
+#[test]
+fn eigh_select_index() {
+    test_eigh_select_index(&Lapack::default());
+}
+
+#[test]
+fn eigh_select_value() {
+    test_eigh_select_value(&Lapack::default());
+}
+
+#[test]
+fn eigh_select_complex() {
+    test_eigh_select_complex(&Lapack::default());
+}
+
+#[test]
+fn eigh_select_invalid_range() {
+    test_eigh_select_invalid_range(&Lapack::default());
+}
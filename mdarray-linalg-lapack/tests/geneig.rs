@@ -0,0 +1,42 @@
+use mdarray_linalg::testing::geneig::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn gen_eig_random() {
+    test_gen_eig_random(&Lapack::default());
+}
+
+#[test]
+fn gen_eig_values() {
+    test_gen_eig_values(&Lapack::default());
+}
+
+#[test]
+fn gen_eig_infinite() {
+    test_gen_eig_infinite(&Lapack::default());
+}
+
+#[test]
+fn gen_eig_complex() {
+    test_gen_eig_complex(&Lapack::default());
+}
+
+#[test]
+fn gen_eig_not_square() {
+    test_gen_eig_not_square(&Lapack::default());
+}
+
+#[test]
+fn gen_eigh_random() {
+    test_gen_eigh_random(&Lapack::default());
+}
+
+#[test]
+fn gen_eigh_complex() {
+    test_gen_eigh_complex(&Lapack::default());
+}
+
+#[test]
+fn gen_eigh_not_positive_definite() {
+    test_gen_eigh_not_positive_definite(&Lapack::default());
+}
@@ -0,0 +1,43 @@
+use mdarray_linalg::testing::lstsq::*;
+use mdarray_linalg_lapack::{Lapack, LstsqConfig};
+
+#[test]
+fn lstsq_overdetermined() {
+    test_lstsq_overdetermined(&Lapack::default());
+    test_lstsq_overdetermined(&Lapack::default().config_lstsq(LstsqConfig::Gels));
+    test_lstsq_overdetermined(&Lapack::default().config_lstsq(LstsqConfig::Gelsy));
+}
+
+#[test]
+fn lstsq_consistent() {
+    test_lstsq_consistent(&Lapack::default());
+}
+
+#[test]
+fn lstsq_underdetermined() {
+    test_lstsq_underdetermined(&Lapack::default());
+    test_lstsq_underdetermined(&Lapack::default().config_lstsq(LstsqConfig::Gels));
+    test_lstsq_underdetermined(&Lapack::default().config_lstsq(LstsqConfig::Gelsy));
+}
+
+#[test]
+fn lstsq_write() {
+    test_lstsq_write(&Lapack::default());
+}
+
+#[test]
+fn lstsq_rank_deficient() {
+    test_lstsq_rank_deficient(&Lapack::default());
+    test_lstsq_rank_deficient(&Lapack::default().config_lstsq(LstsqConfig::Gelsy));
+}
+
+#[test]
+fn lstsq_singular_values() {
+    test_lstsq_singular_values(&Lapack::default());
+}
+
+#[test]
+fn lstsq_cplx_overdetermined() {
+    test_lstsq_cplx_overdetermined(&Lapack::default());
+    test_lstsq_cplx_overdetermined(&Lapack::default().config_lstsq(LstsqConfig::Gelsy));
+}
@@ -66,3 +66,78 @@ fn cholesky_not_positive_definite() {
 fn cholesky_identity_matrix() {
     test_cholesky_identity_matrix(&Lapack::default());
 }
+
+#[test]
+fn lu_factors_reconstruction() {
+    test_lu_factors_reconstruction(&Lapack::default());
+}
+
+#[test]
+fn lu_factors_solve() {
+    test_lu_factors_solve(&Lapack::default());
+}
+
+#[test]
+fn lu_factors_solve_complex() {
+    test_lu_factors_solve_complex(&Lapack::default());
+}
+
+#[test]
+fn lu_factors_det_inv() {
+    test_lu_factors_det_inv(&Lapack::default());
+}
+
+#[test]
+fn lu_factors_rcond() {
+    test_lu_factors_rcond(&Lapack::default());
+}
+
+#[test]
+fn lu_factors_singular() {
+    test_lu_factors_singular(&Lapack::default());
+}
+
+#[test]
+fn lu_factors_invalid_dimensions() {
+    test_lu_factors_invalid_dimensions(&Lapack::default());
+}
+
+#[test]
+fn cholesky_factors_solve() {
+    test_cholesky_factors_solve(&Lapack::default());
+}
+
+#[test]
+fn cholesky_factors_complex() {
+    test_cholesky_factors_complex(&Lapack::default());
+}
+
+#[test]
+fn cholesky_factors_inv_logdet() {
+    test_cholesky_factors_inv_logdet(&Lapack::default());
+}
+
+#[test]
+fn cholesky_factors_not_positive_definite() {
+    test_cholesky_factors_not_positive_definite(&Lapack::default());
+}
+
+#[test]
+fn ldl_factors_solve() {
+    test_ldl_factors_solve(&Lapack::default());
+}
+
+#[test]
+fn ldl_factors_complex() {
+    test_ldl_factors_complex(&Lapack::default());
+}
+
+#[test]
+fn ldl_factors_inertia() {
+    test_ldl_factors_inertia(&Lapack::default());
+}
+
+#[test]
+fn ldl_factors_singular() {
+    test_ldl_factors_singular(&Lapack::default());
+}
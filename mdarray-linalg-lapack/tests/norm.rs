@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::norm::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn matrix_norms() {
+    test_matrix_norms(&Lapack::default());
+}
+
+#[test]
+fn matrix_norms_rectangular() {
+    test_matrix_norms_rectangular(&Lapack::default());
+}
+
+#[test]
+fn matrix_norms_complex() {
+    test_matrix_norms_complex(&Lapack::default());
+}
+
+#[test]
+fn rcond_lu() {
+    test_rcond_lu(&Lapack::default());
+}
+
+#[test]
+fn rcond_lu_complex() {
+    test_rcond_lu_complex(&Lapack::default());
+}
+
+#[test]
+fn rcond_lu_ill_conditioned() {
+    test_rcond_lu_ill_conditioned(&Lapack::default());
+}
+
+#[test]
+fn rcond_lu_unsupported_norm() {
+    test_rcond_lu_unsupported_norm(&Lapack::default());
+}
+
+#[test]
+fn rcond_cholesky() {
+    test_rcond_cholesky(&Lapack::default());
+}
+
+#[test]
+fn rcond_cholesky_complex() {
+    test_rcond_cholesky_complex(&Lapack::default());
+}
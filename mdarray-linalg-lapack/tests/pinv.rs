@@ -0,0 +1,42 @@
+use mdarray_linalg::testing::pinv::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn pinv_square() {
+    test_pinv_square(&Lapack::default());
+}
+
+#[test]
+fn pinv_rectangular() {
+    test_pinv_rectangular(&Lapack::default());
+}
+
+#[test]
+fn pinv_rank_deficient() {
+    test_pinv_rank_deficient(&Lapack::default());
+}
+
+#[test]
+fn pinv_tolerance() {
+    test_pinv_tolerance(&Lapack::default());
+}
+
+#[test]
+fn pinv_complex() {
+    test_pinv_complex(&Lapack::default());
+}
+
+#[test]
+fn pinvh() {
+    test_pinvh(&Lapack::default());
+}
+
+#[test]
+fn pinvh_complex() {
+    test_pinvh_complex(&Lapack::default());
+}
+
+#[test]
+fn pinvh_not_square() {
+    test_pinvh_not_square(&Lapack::default());
+}
@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::polar::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn polar_square() {
+    test_polar_square(&Lapack::default());
+}
+
+#[test]
+fn polar_rectangular() {
+    test_polar_rectangular(&Lapack::default());
+}
+
+#[test]
+fn polar_rank_deficient() {
+    test_polar_rank_deficient(&Lapack::default());
+}
+
+#[test]
+fn polar_known() {
+    test_polar_known(&Lapack::default());
+}
+
+#[test]
+fn polar_complex() {
+    test_polar_complex(&Lapack::default());
+}
@@ -1,5 +1,6 @@
-use mdarray_linalg::testing::qr::*;
-use mdarray_linalg_lapack::Lapack;
+use mdarray_linalg::prelude::*;
+use mdarray_linalg::testing::{common::random_matrix, qr::*};
+use mdarray_linalg_lapack::{Lapack, LapackQRConfig};
 
 #[test]
 fn qr_random_matrix() {
@@ -15,3 +16,91 @@ fn qr_structured_matrix() {
 fn qr_complex_matrix() {
     test_qr_complex_matrix(&Lapack::default());
 }
+
+#[test]
+fn qr_tall_matrix() {
+    test_qr_tall_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_wide_matrix() {
+    test_qr_wide_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_thin_tall_matrix() {
+    test_qr_thin_tall_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_thin_wide_matrix() {
+    test_qr_thin_wide_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_thin_square_matrix() {
+    test_qr_thin_square_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_thin_write() {
+    test_qr_thin_write(&Lapack::default());
+}
+
+#[test]
+fn qr_thin_complex_matrix() {
+    test_qr_thin_complex_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_pivot_square_matrix() {
+    test_qr_pivot_square_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_pivot_tall_matrix() {
+    test_qr_pivot_tall_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_pivot_wide_matrix() {
+    test_qr_pivot_wide_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_pivot_rank() {
+    test_qr_pivot_rank(&Lapack::default());
+}
+
+#[test]
+fn qr_pivot_complex_matrix() {
+    test_qr_pivot_complex_matrix(&Lapack::default());
+}
+
+#[test]
+fn qr_pivoting_config_random_matrix() {
+    test_qr_random_matrix(&Lapack::default().config_qr(LapackQRConfig::Pivoting));
+}
+
+#[test]
+fn qr_upper_triangular_every_config() {
+    for config in [
+        LapackQRConfig::Full,
+        LapackQRConfig::Pivoting,
+        LapackQRConfig::TallSkinny,
+    ] {
+        test_qr_upper_triangular(&Lapack::default().config_qr(config));
+    }
+}
+
+#[test]
+fn qr_tall_skinny_config() {
+    test_qr_thin_tall_matrix(&Lapack::default().config_qr(LapackQRConfig::TallSkinny));
+
+    let a = random_matrix(10, 4);
+    let (q, r) = Lapack::default()
+        .config_qr(LapackQRConfig::TallSkinny)
+        .qr(&mut a.clone());
+    assert_eq!(*q.shape(), (10, 4));
+    assert_eq!(*r.shape(), (4, 4));
+}
@@ -0,0 +1,37 @@
+use mdarray_linalg::testing::reduction::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn hessenberg() {
+    test_hessenberg(&Lapack::default());
+}
+
+#[test]
+fn hessenberg_complex() {
+    test_hessenberg_complex(&Lapack::default());
+}
+
+#[test]
+fn tridiagonal() {
+    test_tridiagonal(&Lapack::default());
+}
+
+#[test]
+fn tridiagonal_complex() {
+    test_tridiagonal_complex(&Lapack::default());
+}
+
+#[test]
+fn bidiagonal() {
+    test_bidiagonal(&Lapack::default());
+}
+
+#[test]
+fn bidiagonal_complex() {
+    test_bidiagonal_complex(&Lapack::default());
+}
+
+#[test]
+fn reduction_not_square() {
+    test_reduction_not_square(&Lapack::default());
+}
@@ -0,0 +1,32 @@
+use mdarray_linalg::testing::subspace::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn rank() {
+    test_rank(&Lapack::default());
+}
+
+#[test]
+fn orth_null_space() {
+    test_orth_null_space(&Lapack::default());
+}
+
+#[test]
+fn orth_null_space_complex() {
+    test_orth_null_space_complex(&Lapack::default());
+}
+
+#[test]
+fn subspace_full_rank() {
+    test_subspace_full_rank(&Lapack::default());
+}
+
+#[test]
+fn subspace_qr() {
+    test_subspace_qr(&Lapack::default());
+}
+
+#[test]
+fn subspace_qr_complex() {
+    test_subspace_qr_complex(&Lapack::default());
+}
@@ -1,3 +1,4 @@
+use mdarray_linalg::Naive;
 use mdarray_linalg::testing::svd::*;
 use mdarray_linalg_lapack::{Lapack, SVDConfig};
 
@@ -25,3 +26,44 @@ fn test_backend_svd_random_matrix() {
 fn test_backend_svd_cplx_square_matrix() {
     test_svd_cplx_square_matrix(&Lapack::default());
 }
+
+#[test]
+fn test_backend_svd_thin_tall_matrix() {
+    test_svd_thin_tall_matrix(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_thin_wide_matrix() {
+    test_svd_thin_wide_matrix(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_thin_cplx_matrix() {
+    test_svd_thin_cplx_matrix(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_thin_write() {
+    test_svd_thin_write(&Lapack::default());
+}
+
+#[test]
+#[should_panic(expected = "min(m, n) × n")]
+fn test_backend_svd_thin_write_invalid_vt() {
+    test_svd_thin_write_invalid_vt(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_truncated_max_rank() {
+    test_svd_truncated_max_rank(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_truncated_rtol() {
+    test_svd_truncated_rtol(&Lapack::default());
+}
+
+#[test]
+fn test_backend_svd_randomized_with() {
+    test_svd_randomized_with(&Naive, &Lapack::default(), &Lapack::default());
+}
@@ -0,0 +1,63 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::sylvester::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn sylvester_triangular() {
+    test_sylvester_triangular(&Lapack::default());
+}
+
+#[test]
+fn sylvester_triangular_complex() {
+    test_sylvester_triangular_complex(&Lapack::default());
+}
+
+#[test]
+fn sylvester_triangular_singular() {
+    test_sylvester_triangular_singular(&Lapack::default());
+}
+
+#[test]
+fn sylvester() {
+    test_sylvester(&Lapack::default(), &Lapack::default(), &Naive);
+}
+
+#[test]
+fn sylvester_complex() {
+    test_sylvester_complex(&Lapack::default(), &Lapack::default(), &Naive);
+}
+
+#[test]
+fn lyapunov() {
+    test_lyapunov(&Lapack::default(), &Lapack::default(), &Naive);
+}
+
+#[test]
+fn lyapunov_complex() {
+    test_lyapunov_complex(&Lapack::default(), &Lapack::default(), &Naive);
+}
+
+#[test]
+fn stein() {
+    test_stein(&Lapack::default(), &Naive);
+}
+
+#[test]
+fn stein_complex() {
+    test_stein_complex(&Lapack::default(), &Naive);
+}
+
+#[test]
+fn sylvester_equations() {
+    test_sylvester_equations(&Lapack::default());
+}
+
+#[test]
+fn sylvester_equations_complex() {
+    test_sylvester_equations_complex(&Lapack::default());
+}
+
+#[test]
+fn sylvester_invalid_dimensions() {
+    test_sylvester_invalid_dimensions(&Lapack::default(), &Lapack::default(), &Naive);
+}
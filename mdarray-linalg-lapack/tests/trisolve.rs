@@ -0,0 +1,47 @@
+use mdarray_linalg::testing::trisolve::*;
+use mdarray_linalg_lapack::Lapack;
+
+#[test]
+fn trsm_left_lower() {
+    test_trsm_left_lower(&Lapack::default());
+}
+
+#[test]
+fn trsm_left_upper() {
+    test_trsm_left_upper(&Lapack::default());
+}
+
+#[test]
+fn trsm_transpose() {
+    test_trsm_transpose(&Lapack::default());
+}
+
+#[test]
+fn trsm_right() {
+    test_trsm_right(&Lapack::default());
+}
+
+#[test]
+fn trsm_unit_diagonal() {
+    test_trsm_unit_diagonal(&Lapack::default());
+}
+
+#[test]
+fn trsm_scale_write_in_place() {
+    test_trsm_scale_write_in_place(&Lapack::default());
+}
+
+#[test]
+fn trsv() {
+    test_trsv(&Lapack::default());
+}
+
+#[test]
+fn trsm_complex() {
+    test_trsm_complex(&Lapack::default());
+}
+
+#[test]
+fn trsm_singular() {
+    test_trsm_singular(&Lapack::default());
+}
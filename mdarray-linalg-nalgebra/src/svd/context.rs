@@ -6,6 +6,8 @@
 //     - Σ is µ × µ         (diagonal matrix with singular values on the diagonal, µ = min(m,n))
 //     - V^T is n × n      (transpose of right singular vectors, orthogonal)
 //     - s (Σ) contains min(m, n) singular values (non-negative, sorted in descending order)
+//
+// The thin SVD only keeps the first µ columns of U (m × µ) and the first µ rows of V^T (µ × n).
 use std::fmt::Debug;
 
 use mdarray::{Dim, Layout, Shape, Slice, Tensor};
@@ -144,6 +146,76 @@ where
         Ok(())
     }
 
+    /// Compute thin SVD with new allocated matrices
+    fn svd_thin(&self, a: &mut Slice<T, (D, D), L>) -> SVDResult<T, D> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let min_mn = m.min(n);
+
+        let s_shape = <(D, D) as Shape>::from_dims(&[min_mn, min_mn]);
+        let u_shape = <(D, D) as Shape>::from_dims(&[m, min_mn]);
+        let vt_shape = <(D, D) as Shape>::from_dims(&[min_mn, n]);
+
+        let mut s_mda = Tensor::<T, (D, D)>::from_elem(s_shape, T::default());
+        let mut u_mda = Tensor::<T, (D, D)>::from_elem(u_shape, T::default());
+        let mut vt_mda = Tensor::<T, (D, D)>::from_elem(vt_shape, T::default());
+
+        self.svd_thin_write(a, &mut s_mda, &mut u_mda, &mut vt_mda)?;
+
+        Ok(SVDDecomp {
+            s: s_mda,
+            u: u_mda,
+            vt: vt_mda,
+        })
+    }
+
+    /// Compute thin SVD, overwriting existing matrices
+    fn svd_thin_write<Ls: Layout, Lu: Layout, Lvt: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        s_mda: &mut Slice<T, (D, D), Ls>,
+        u_mda: &mut Slice<T, (D, D), Lu>,
+        vt_mda: &mut Slice<T, (D, D), Lvt>,
+    ) -> Result<(), SVDError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+
+        let min_mn = m.min(n);
+
+        assert_eq!(u_mda.shape().dim(0), m, "U must be m × min(m, n)");
+        assert_eq!(u_mda.shape().dim(1), min_mn, "U must be m × min(m, n)");
+        assert_eq!(vt_mda.shape().dim(0), min_mn, "Vᵀ must be min(m, n) × n");
+        assert_eq!(vt_mda.shape().dim(1), n, "Vᵀ must be min(m, n) × n");
+
+        let a_nalgebra = nalgebra::DMatrix::<T>::from_fn(m, n, |i, j| a[[i, j]]);
+
+        // nalgebra already returns the thin factors: U is m × µ and V^T is µ × n
+        let svd_result = a_nalgebra.svd(true, true);
+
+        let singular_values = svd_result.singular_values;
+        let u = svd_result.u.ok_or(SVDError::BackendError(-1))?;
+        let v_t = svd_result.v_t.ok_or(SVDError::BackendError(-1))?;
+
+        for i in 0..min_mn {
+            s_mda[[0, i]] = T::from_real(singular_values[i]);
+        }
+
+        for i in 0..m {
+            for j in 0..min_mn {
+                u_mda[[i, j]] = u[(i, j)];
+            }
+        }
+
+        for i in 0..min_mn {
+            for j in 0..n {
+                vt_mda[[i, j]] = v_t[(i, j)];
+            }
+        }
+
+        Ok(())
+    }
+
     /// Compute only singular values, overwriting existing matrix
     fn svd_write_s<Ls: Layout>(
         &self,
@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::pinv::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn pinv_square() {
+    test_pinv_square(&Nalgebra);
+}
+
+#[test]
+fn pinv_rectangular() {
+    test_pinv_rectangular(&Nalgebra);
+}
+
+#[test]
+fn pinv_rank_deficient() {
+    test_pinv_rank_deficient(&Nalgebra);
+}
+
+#[test]
+fn pinv_tolerance() {
+    test_pinv_tolerance(&Nalgebra);
+}
+
+#[test]
+fn pinv_complex() {
+    test_pinv_complex(&Nalgebra);
+}
@@ -0,0 +1,27 @@
+use mdarray_linalg::testing::polar::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn polar_square() {
+    test_polar_square(&Nalgebra);
+}
+
+#[test]
+fn polar_rectangular() {
+    test_polar_rectangular(&Nalgebra);
+}
+
+#[test]
+fn polar_rank_deficient() {
+    test_polar_rank_deficient(&Nalgebra);
+}
+
+#[test]
+fn polar_known() {
+    test_polar_known(&Nalgebra);
+}
+
+#[test]
+fn polar_complex() {
+    test_polar_complex(&Nalgebra);
+}
@@ -0,0 +1,22 @@
+use mdarray_linalg::testing::subspace::*;
+use mdarray_linalg_nalgebra::Nalgebra;
+
+#[test]
+fn rank() {
+    test_rank(&Nalgebra);
+}
+
+#[test]
+fn orth_null_space() {
+    test_orth_null_space(&Nalgebra);
+}
+
+#[test]
+fn orth_null_space_complex() {
+    test_orth_null_space_complex(&Nalgebra);
+}
+
+#[test]
+fn subspace_full_rank() {
+    test_subspace_full_rank(&Nalgebra);
+}
@@ -30,3 +30,39 @@ fn test_backend_svd_cplx_square_matrix() {
 fn test_backend_svd_cplx_random_matrix() {
     test_svd_cplx_random_matrix(&Nalgebra);
 }
+
+#[test]
+fn test_backend_svd_thin_tall_matrix() {
+    test_svd_thin_tall_matrix(&Nalgebra);
+}
+
+#[test]
+fn test_backend_svd_thin_wide_matrix() {
+    test_svd_thin_wide_matrix(&Nalgebra);
+}
+
+#[test]
+fn test_backend_svd_thin_cplx_matrix() {
+    test_svd_thin_cplx_matrix(&Nalgebra);
+}
+
+#[test]
+fn test_backend_svd_thin_write() {
+    test_svd_thin_write(&Nalgebra);
+}
+
+#[test]
+#[should_panic(expected = "min(m, n) × n")]
+fn test_backend_svd_thin_write_invalid_vt() {
+    test_svd_thin_write_invalid_vt(&Nalgebra);
+}
+
+#[test]
+fn test_backend_svd_truncated_max_rank() {
+    test_svd_truncated_max_rank(&Nalgebra);
+}
+
+#[test]
+fn test_backend_svd_truncated_rtol() {
+    test_svd_truncated_rtol(&Nalgebra);
+}
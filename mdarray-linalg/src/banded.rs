@@ -0,0 +1,228 @@
+//! Tridiagonal and banded linear systems A X = B
+//!
+//! A general tridiagonal matrix is given by its subdiagonal `dl`,
+//! diagonal `d` and superdiagonal `du`. A Hermitian positive definite
+//! tridiagonal matrix is given by its real diagonal `d` and its
+//! subdiagonal `e`.
+//!
+//! Banded matrices are held in a [`BandMatrix`], which only stores the
+//! diagonals within the band. Solving costs O(n (kl + ku)²) instead of
+//! the O(n³) of a dense [`Solve`](crate::solve::Solve).
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::Naive;
+//!use mdarray_linalg::prelude::*;
+//!
+//!// 1D Poisson problem: tridiag(-1, 2, -1) x = b
+//!let mut b = tensor![[1.], [0.], [1.0_f64]];
+//!Naive
+//!    .solve_tridiagonal(&[-1., -1.], &[2., 2., 2.], &[-1., -1.], &mut b)
+//!    .unwrap();
+//!assert!((b[[1, 0]] - 1.).abs() < 1e-12);
+//!```
+use mdarray::{DTensor, Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+use thiserror::Error;
+
+/// Error types related to tridiagonal and banded solvers
+#[derive(Debug, Error)]
+pub enum BandSolveError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Matrix is singular: U({diagonal},{diagonal}) is exactly zero")]
+    SingularMatrix { diagonal: usize },
+
+    #[error("Matrix is not positive definite: the leading minor of order {order} is not positive")]
+    NotPositiveDefinite { order: usize },
+
+    #[error("Invalid matrix dimensions")]
+    InvalidDimensions,
+}
+
+/// n × n matrix with `kl` subdiagonals and `ku` superdiagonals in band
+/// storage
+///
+/// The band is a (kl + ku + 1) × n matrix holding A(i, j) at
+/// `[ku + i − j, j]`: its row `ku` is the diagonal, the rows above the
+/// superdiagonals and the rows below the subdiagonals. This is the layout
+/// of LAPACK's band storage.
+///
+/// ```rust
+/// use mdarray::tensor;
+/// use mdarray_linalg::banded::BandMatrix;
+///
+/// let a = tensor![[4., 1., 0.], [2., 4., 1.], [0., 2., 4.]];
+/// let band = BandMatrix::from_dense(&a, 1, 1);
+/// assert_eq!(band.get(1, 0), 2.);
+/// assert_eq!(band.get(2, 0), 0.);
+/// assert_eq!(band.to_dense(), a);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BandMatrix<T> {
+    kl: usize,
+    ku: usize,
+    band: DTensor<T, 2>,
+}
+
+impl<T: ComplexFloat> BandMatrix<T> {
+    /// Creates an n × n zero matrix with `kl` subdiagonals and `ku`
+    /// superdiagonals
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Self {
+        Self {
+            kl,
+            ku,
+            band: DTensor::<T, 2>::from_elem([kl + ku + 1, n], T::zero()),
+        }
+    }
+
+    /// Wraps a (kl + ku + 1) × n matrix already in band storage
+    pub fn from_band(band: DTensor<T, 2>, kl: usize, ku: usize) -> Self {
+        assert_eq!(
+            band.shape().0,
+            kl + ku + 1,
+            "band storage must have kl + ku + 1 rows"
+        );
+        Self { kl, ku, band }
+    }
+
+    /// Copies the band of the square matrix A, the elements outside of it
+    /// being ignored
+    pub fn from_dense<D0: Dim, D1: Dim, L: Layout>(
+        a: &Slice<T, (D0, D1), L>,
+        kl: usize,
+        ku: usize,
+    ) -> Self {
+        let ash = *a.shape();
+        let n = ash.dim(0);
+        assert_eq!(n, ash.dim(1), "Matrix must be square");
+
+        let mut band = Self::zeros(n, kl, ku);
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..(j + kl + 1).min(n) {
+                band.band[[ku + i - j, j]] = a[[i, j]];
+            }
+        }
+        band
+    }
+
+    /// Order n of the matrix
+    pub fn n(&self) -> usize {
+        self.band.shape().1
+    }
+
+    /// Number of subdiagonals
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+
+    /// Number of superdiagonals
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+
+    /// The (kl + ku + 1) × n band storage
+    pub fn band(&self) -> &DTensor<T, 2> {
+        &self.band
+    }
+
+    /// Mutable access to the band storage
+    pub fn band_mut(&mut self) -> &mut DTensor<T, 2> {
+        &mut self.band
+    }
+
+    /// Whether (i, j) lies within the band
+    pub fn in_band(&self, i: usize, j: usize) -> bool {
+        i < self.n() && j < self.n() && i <= j + self.kl && j <= i + self.ku
+    }
+
+    /// Returns A(i, j), zero outside of the band
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if self.in_band(i, j) {
+            self.band[[self.ku + i - j, j]]
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Sets A(i, j), which must lie within the band
+    pub fn set(&mut self, i: usize, j: usize, value: T) {
+        assert!(self.in_band(i, j), "({i}, {j}) is outside of the band");
+        self.band[[self.ku + i - j, j]] = value;
+    }
+
+    /// Returns the dense n × n matrix
+    pub fn to_dense(&self) -> DTensor<T, 2> {
+        let n = self.n();
+        DTensor::<T, 2>::from_fn([n, n], |i| self.get(i[0], i[1]))
+    }
+}
+
+/// Solvers for tridiagonal and banded systems A X = B
+///
+/// The right-hand sides are the columns of the n × nrhs matrix B, which is
+/// overwritten with the solution X. A is left untouched.
+pub trait BandSolve<T: ComplexFloat> {
+    /// Solves a general tridiagonal system, A having subdiagonal `dl`,
+    /// diagonal `d` and superdiagonal `du`.
+    /// The Naive backend eliminates without pivoting and returns
+    /// `SingularMatrix` on a zero pivot, even when A is not singular.
+    fn solve_tridiagonal<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        dl: &[T],
+        d: &[T],
+        du: &[T],
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError>;
+
+    /// Solves a Hermitian positive definite tridiagonal system, A having
+    /// real diagonal `d` and subdiagonal `e`
+    fn solve_tridiagonal_pd<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        d: &[T::Real],
+        e: &[T],
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError>;
+
+    /// Solves a general banded system
+    fn solve_band<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError>;
+
+    /// Solves a Hermitian positive definite banded system. Only the
+    /// diagonal and the `kl` subdiagonals of A are referenced.
+    fn solve_band_pd<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError>;
+}
+
+/// Checks that the off-diagonals of a tridiagonal matrix of order n
+/// have length n − 1 and that the right-hand side has n rows
+pub fn tridiagonal_dims<T, D0: Dim, D1: Dim, L: Layout>(
+    n: usize,
+    off_lens: &[usize],
+    b: &Slice<T, (D0, D1), L>,
+) -> Result<usize, BandSolveError> {
+    if b.shape().dim(0) != n || off_lens.iter().any(|&len| len != n.saturating_sub(1)) {
+        return Err(BandSolveError::InvalidDimensions);
+    }
+    Ok(n)
+}
+
+/// Checks that the right-hand side has as many rows as the banded A,
+/// returning n
+pub fn band_dims<T: ComplexFloat, D0: Dim, D1: Dim, L: Layout>(
+    a: &BandMatrix<T>,
+    b: &Slice<T, (D0, D1), L>,
+) -> Result<usize, BandSolveError> {
+    let n = a.n();
+    if b.shape().dim(0) != n {
+        return Err(BandSolveError::InvalidDimensions);
+    }
+    Ok(n)
+}
@@ -1,4 +1,6 @@
 //! Eigenvalue, eigenvector, and Schur decomposition utilities for general and Hermitian matrices
+use std::ops::Range;
+
 use mdarray::{Dense, Dim, Layout, Slice, Tensor};
 use num_complex::{Complex, ComplexFloat};
 use thiserror::Error;
@@ -14,6 +16,9 @@ pub enum EigError {
 
     #[error("Matrix must be square for eigenvalue decomposition")]
     NotSquareMatrix,
+
+    #[error("Invalid eigenvalue range")]
+    InvalidRange,
 }
 
 /// Holds the results of an eigenvalue decomposition, including
@@ -28,6 +33,27 @@ pub struct EigDecomp<T: ComplexFloat, D0: Dim, D1: Dim> {
 /// `EigDecomp` or an `EigError`
 pub type EigResult<T, D0, D1> = Result<EigDecomp<T, D0, D1>, EigError>;
 
+/// Selects the eigenpairs computed by [`Eig::eigh_select`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum EigRange<R> {
+    /// Eigenpairs with indices in the range, the smallest eigenvalue having index 0
+    Index(Range<usize>),
+    /// Eigenpairs whose eigenvalue lies in the half-open interval (lower, upper]
+    Value(R, R),
+}
+
+/// Holds the eigenpairs of a Hermitian matrix selected by an [`EigRange`]
+pub struct EighSelectDecomp<T: ComplexFloat, D0: Dim, D1: Dim> {
+    /// Selected eigenvalues in ascending order
+    pub eigenvalues: Tensor<T::Real, (D0,)>,
+    /// Corresponding orthonormal eigenvectors as columns (n × k)
+    pub eigenvectors: Tensor<T, (D0, D1)>,
+}
+
+/// Result type for selected Hermitian eigenpairs, returning either an
+/// `EighSelectDecomp` or an `EigError`
+pub type EighSelectResult<T, D0, D1> = Result<EighSelectDecomp<T, D0, D1>, EigError>;
+
 /// Error types related to Schur decomposition
 #[derive(Debug, Error)]
 pub enum SchurError {
@@ -87,6 +113,15 @@ pub trait Eig<T: ComplexFloat, D0: Dim, D1: Dim> {
     /// Compute eigenvalues and eigenvectors of a symmetric matrix (input should be real)
     fn eigs<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> EigResult<T, D0, D1>;
 
+    /// Compute only the eigenpairs of a Hermitian matrix selected by `range`
+    /// Only the lower triangle of A is referenced. An empty selection is
+    /// not an error and returns no eigenpairs.
+    fn eigh_select<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        range: EigRange<T::Real>,
+    ) -> EighSelectResult<T, D0, D1>;
+
     /// Compute Schur decomposition with new allocated matrices
     fn schur<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> SchurResult<T, D0, D1>;
 
@@ -109,3 +144,16 @@ pub trait Eig<T: ComplexFloat, D0: Dim, D1: Dim> {
         z: &mut Slice<T, (D0, D1), Dense>,
     ) -> Result<(), SchurError>;
 }
+
+/// Checks an [`EigRange`] against the size n of the matrix
+pub fn eig_range_check<R: PartialOrd>(range: &EigRange<R>, n: usize) -> Result<(), EigError> {
+    let valid = match range {
+        EigRange::Index(r) => r.start <= r.end && r.end <= n,
+        EigRange::Value(lower, upper) => lower < upper,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(EigError::InvalidRange)
+    }
+}
@@ -0,0 +1,359 @@
+//! Einstein summation from a subscript string
+//!
+//! `bd.einsum("abc,cbd->ad", &[&x, &y])` labels each axis of each operand
+//! with a letter and sums over the labels missing from the output, which
+//! avoids handling raw axis lists as in [`MatMul::contract`]:
+//! - a label shared by two operands and absent from the output is
+//!   contracted,
+//! - a label shared by two operands and present in the output is a batch
+//!   (Hadamard) index,
+//! - a label repeated within an operand takes its diagonal, as in `ii->i`,
+//!   or its trace, as in `ii->`,
+//! - the output labels may appear in any order, permuting the result.
+//!
+//! Without `->`, the output holds the labels appearing exactly once, in
+//! alphabetical order, as in NumPy.
+//!
+//! The operands are contracted pairwise from left to right, each pair
+//! through [`MatMul::contract`], or [`BatchedMatMul`] when it shares batch
+//! indices, so that every [`MatMul`] backend can evaluate it.
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::Naive;
+//!
+//!let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+//!let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+//!
+//!let c = Naive.einsum("ij,jk->ki", &[&a, &b]).unwrap();
+//!assert_eq!(c, tensor![[19., 43.], [22., 50.]].into_dyn());
+//!
+//!let trace = Naive.einsum("ii->", &[&a]).unwrap();
+//!assert_eq!(trace[[]], 5.);
+//!```
+use mdarray::{DynRank, Layout, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::MulAdd;
+use thiserror::Error;
+
+use crate::matmul::{BatchedMatMul, BatchedMatMulBuilder, ContractBuilder, MatMul};
+
+/// Error types related to Einstein summation
+#[derive(Debug, Error)]
+pub enum EinsumError {
+    #[error("Invalid subscripts: {0}")]
+    InvalidSubscripts(String),
+
+    #[error("Subscripts describe {expected} operands, got {got}")]
+    OperandCount { expected: usize, got: usize },
+
+    #[error("Operand {operand} has rank {rank} but {labels} subscripts")]
+    RankMismatch {
+        operand: usize,
+        rank: usize,
+        labels: usize,
+    },
+
+    #[error("Inconsistent dimensions for index '{label}': {first} ≠ {second}")]
+    DimensionMismatch {
+        label: char,
+        first: usize,
+        second: usize,
+    },
+
+    #[error("Output index '{0}' does not appear in any operand")]
+    UnknownOutputIndex(char),
+
+    #[error("Output index '{0}' is repeated")]
+    RepeatedOutputIndex(char),
+}
+
+/// Einstein summation over tensors of dynamic rank
+///
+/// Implemented for every backend providing [`MatMul`].
+pub trait Einsum<T> {
+    /// Evaluates the summation described by `subscripts`, such as
+    /// `"abc,cbd->ad"`, over the given operands
+    fn einsum<L: Layout>(
+        &self,
+        subscripts: &str,
+        operands: &[&Slice<T, DynRank, L>],
+    ) -> Result<Tensor<T, DynRank>, EinsumError>;
+}
+
+impl<T, B> Einsum<T> for B
+where
+    T: ComplexFloat + MulAdd<Output = T> + Send + Sync,
+    B: MatMul<T> + Sync,
+{
+    fn einsum<L: Layout>(
+        &self,
+        subscripts: &str,
+        operands: &[&Slice<T, DynRank, L>],
+    ) -> Result<Tensor<T, DynRank>, EinsumError> {
+        let (inputs, output) = parse_subscripts(subscripts)?;
+        check_operands(&inputs, operands)?;
+
+        // Labels still needed after the operands up to i are contracted
+        let needed_after = |i: usize| -> Vec<char> {
+            let mut labels = output.clone();
+            inputs[i + 1..].iter().for_each(|l| labels.extend(l));
+            labels
+        };
+
+        let mut keep = needed_after(0);
+        let mut current = reduce(&inputs[0], operands[0], &keep);
+        for (i, labels) in inputs.iter().enumerate().skip(1) {
+            let needed = needed_after(i);
+            keep = needed.iter().chain(&current.labels).copied().collect();
+            let other = reduce(labels, operands[i], &keep);
+            keep = needed.iter().chain(&other.labels).copied().collect();
+            current = reduce_owned(current, &keep);
+            current = contract_pair(self, current, other, &needed);
+        }
+
+        let current = reduce_owned(current, &output);
+        let perm: Vec<usize> = output
+            .iter()
+            .map(|c| current.labels.iter().position(|l| l == c).unwrap())
+            .collect();
+        Ok(current.tensor.permute(perm).to_tensor())
+    }
+}
+
+/// Tensor whose axes carry distinct labels
+pub(crate) struct Labeled<T, I = char> {
+    pub(crate) labels: Vec<I>,
+    pub(crate) tensor: Tensor<T, DynRank>,
+}
+
+/// Contracts the labels shared by `a` and `b` that are not `needed`,
+/// returning a tensor labeled by the shared needed ones, then the other ones
+/// of `a` and of `b`
+fn contract_pair<T, B>(bd: &B, a: Labeled<T>, b: Labeled<T>, needed: &[char]) -> Labeled<T>
+where
+    T: ComplexFloat + MulAdd<Output = T> + Send + Sync,
+    B: MatMul<T> + Sync,
+{
+    let shared = |l: &char| b.labels.contains(l);
+    let (batch, summed): (Vec<char>, Vec<char>) = a
+        .labels
+        .iter()
+        .filter(|l| shared(l))
+        .partition(|l| needed.contains(l));
+    let free_a: Vec<char> = a.labels.iter().filter(|l| !shared(l)).copied().collect();
+    let free_b: Vec<char> = b
+        .labels
+        .iter()
+        .filter(|l| !a.labels.contains(l))
+        .copied()
+        .collect();
+
+    let axes = |t: &Labeled<T>, labels: &[char]| -> Vec<usize> {
+        labels
+            .iter()
+            .map(|c| t.labels.iter().position(|l| l == c).unwrap())
+            .collect()
+    };
+    let dims = |t: &Labeled<T>, labels: &[char]| -> Vec<usize> {
+        axes(t, labels)
+            .into_iter()
+            .map(|ax| t.tensor.dim(ax))
+            .collect()
+    };
+
+    let mut out_dims = dims(&a, &batch);
+    out_dims.extend(dims(&a, &free_a));
+    out_dims.extend(dims(&b, &free_b));
+
+    let tensor = if batch.is_empty() {
+        let c = bd.contract(&a.tensor, &b.tensor, axes(&a, &summed), axes(&b, &summed));
+        // Contracting all axes leaves a 1 × 1 matrix
+        c.eval().into_shape(&out_dims[..])
+    } else {
+        let size = |dims: Vec<usize>| dims.iter().product::<usize>();
+        let (nb, m, k, n) = (
+            size(dims(&a, &batch)),
+            size(dims(&a, &free_a)),
+            size(dims(&a, &summed)),
+            size(dims(&b, &free_b)),
+        );
+
+        let order_a: Vec<usize> = [&batch, &free_a, &summed]
+            .into_iter()
+            .flat_map(|labels| axes(&a, labels))
+            .collect();
+        let order_b: Vec<usize> = [&batch, &summed, &free_b]
+            .into_iter()
+            .flat_map(|labels| axes(&b, labels))
+            .collect();
+        let a3 = a.tensor.permute(order_a).to_tensor().into_shape([nb, m, k]);
+        let b3 = b.tensor.permute(order_b).to_tensor().into_shape([nb, k, n]);
+
+        bd.batched_matmul(&a3, &b3).eval().into_shape(&out_dims[..])
+    };
+
+    Labeled {
+        labels: [batch, free_a, free_b].concat(),
+        tensor,
+    }
+}
+
+/// Sums `t`, with axes labeled by `labels`, over the labels not in `keep`,
+/// taking the diagonal of repeated labels. The result is labeled by the
+/// distinct kept labels, in order of first appearance.
+pub(crate) fn reduce<T: ComplexFloat, L: Layout, I: Copy + PartialEq>(
+    labels: &[I],
+    t: &Slice<T, DynRank, L>,
+    keep: &[I],
+) -> Labeled<T, I> {
+    let mut unique: Vec<I> = Vec::new();
+    for &l in labels {
+        if !unique.contains(&l) {
+            unique.push(l);
+        }
+    }
+    let (kept, summed): (Vec<I>, Vec<I>) = unique.iter().partition(|l| keep.contains(l));
+
+    if kept.len() == labels.len() {
+        return Labeled {
+            labels: kept,
+            tensor: t.to_tensor(),
+        };
+    }
+
+    let dim = |c: &I| t.dim(labels.iter().position(|l| l == c).unwrap());
+    let kept_dims: Vec<usize> = kept.iter().map(dim).collect();
+    let summed_dims: Vec<usize> = summed.iter().map(dim).collect();
+    let summed_count = summed_dims.iter().product::<usize>();
+
+    // Position in `kept` of the label of each axis, if it is kept
+    let kept_pos: Vec<Option<usize>> = labels
+        .iter()
+        .map(|l| kept.iter().position(|k| k == l))
+        .collect();
+    let mut index = vec![0; labels.len()];
+    let tensor = Tensor::from_fn(&kept_dims[..], |i| {
+        for (ax, p) in kept_pos.iter().enumerate() {
+            if let Some(p) = *p {
+                index[ax] = i[p];
+            }
+        }
+        let mut acc = T::zero();
+        for mut flat in 0..summed_count {
+            for (s, &d) in summed.iter().zip(&summed_dims).rev() {
+                let value = flat % d;
+                flat /= d;
+                for (ax, l) in labels.iter().enumerate() {
+                    if l == s {
+                        index[ax] = value;
+                    }
+                }
+            }
+            acc = acc + t[&index[..]];
+        }
+        acc
+    });
+
+    Labeled {
+        labels: kept,
+        tensor,
+    }
+}
+
+/// Same as [`reduce`] for an owned labeled tensor, returned as is when
+/// all of its labels are kept
+pub(crate) fn reduce_owned<T: ComplexFloat, I: Copy + PartialEq>(
+    t: Labeled<T, I>,
+    keep: &[I],
+) -> Labeled<T, I> {
+    if t.labels.iter().all(|l| keep.contains(l)) {
+        return t;
+    }
+    reduce(&t.labels, &t.tensor, keep)
+}
+
+/// Splits subscripts such as `"ij,jk->ik"` into the labels of each operand
+/// and of the output
+pub fn parse_subscripts(subscripts: &str) -> Result<(Vec<Vec<char>>, Vec<char>), EinsumError> {
+    let subscripts: String = subscripts.chars().filter(|c| !c.is_whitespace()).collect();
+    let invalid = |msg: &str| EinsumError::InvalidSubscripts(format!("{msg} in '{subscripts}'"));
+
+    let (lhs, rhs) = match subscripts.split_once("->") {
+        Some((lhs, rhs)) => (lhs, Some(rhs)),
+        None => (subscripts.as_str(), None),
+    };
+    let labels = |s: &str| -> Result<Vec<char>, EinsumError> {
+        match s.chars().find(|c| !c.is_ascii_alphabetic()) {
+            Some(c) => Err(invalid(&format!("unexpected character '{c}'"))),
+            None => Ok(s.chars().collect()),
+        }
+    };
+
+    let inputs = lhs.split(',').map(labels).collect::<Result<Vec<_>, _>>()?;
+    let output = match rhs {
+        Some(rhs) => labels(rhs)?,
+        None => {
+            let all: Vec<char> = inputs.concat();
+            let mut once: Vec<char> = all
+                .iter()
+                .filter(|c| all.iter().filter(|d| d == c).count() == 1)
+                .copied()
+                .collect();
+            once.sort_unstable();
+            once
+        }
+    };
+
+    for (i, c) in output.iter().enumerate() {
+        if output[..i].contains(c) {
+            return Err(EinsumError::RepeatedOutputIndex(*c));
+        }
+        if !inputs.iter().any(|l| l.contains(c)) {
+            return Err(EinsumError::UnknownOutputIndex(*c));
+        }
+    }
+
+    Ok((inputs, output))
+}
+
+/// Checks the number and ranks of the operands and that each label has a
+/// single dimension
+fn check_operands<T, L: Layout>(
+    inputs: &[Vec<char>],
+    operands: &[&Slice<T, DynRank, L>],
+) -> Result<(), EinsumError> {
+    if inputs.len() != operands.len() {
+        return Err(EinsumError::OperandCount {
+            expected: inputs.len(),
+            got: operands.len(),
+        });
+    }
+
+    let mut dims: Vec<(char, usize)> = Vec::new();
+    for (operand, (labels, t)) in inputs.iter().zip(operands).enumerate() {
+        if labels.len() != t.rank() {
+            return Err(EinsumError::RankMismatch {
+                operand,
+                rank: t.rank(),
+                labels: labels.len(),
+            });
+        }
+        for (ax, &label) in labels.iter().enumerate() {
+            let dim = t.dim(ax);
+            match dims.iter().find(|(l, _)| *l == label) {
+                Some(&(_, first)) if first != dim => {
+                    return Err(EinsumError::DimensionMismatch {
+                        label,
+                        first,
+                        second: dim,
+                    });
+                }
+                Some(_) => {}
+                None => dims.push((label, dim)),
+            }
+        }
+    }
+    Ok(())
+}
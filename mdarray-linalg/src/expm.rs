@@ -0,0 +1,365 @@
+//! Matrix exponential exp(A) and its Fréchet derivative
+//!
+//! exp(A) is computed by scaling and squaring with a diagonal Padé
+//! approximant (Higham, 2005): the degree m ∈ {3, 5, 7, 9, 13} and the
+//! number of squarings s are chosen from ‖A‖₁ so that r_m(2⁻ˢA)^(2ˢ) is
+//! accurate to unit roundoff.  The Fréchet derivative
+//! L(A, E) = d/dt exp(A + tE)|ₜ₌₀ is obtained by differentiating every
+//! step of the algorithm (Al-Mohy & Higham, 2009).
+//!
+//! Only [`MatMul`] and [`Solve`] are used, so every backend providing
+//! both gets [`Expm`].
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::{Float, MulAdd, NumCast, ToPrimitive, Zero};
+use thiserror::Error;
+
+use crate::{
+    matmul::{MatMul, MatMulBuilder},
+    solve::{Solve, SolveError},
+};
+
+/// Error types related to the matrix exponential
+#[derive(Debug, Error)]
+pub enum ExpmError {
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+
+    #[error("A and E must have the same shape")]
+    InvalidDimensions,
+
+    /// The denominator of the Padé approximant could not be inverted
+    #[error("Padé denominator solve failed: {0}")]
+    Solve(#[from] SolveError),
+}
+
+/// Result type for [`Expm::expm_frechet`], holding (exp(A), L(A, E))
+pub type ExpmFrechetResult<T, D> = Result<(Tensor<T, (D, D)>, Tensor<T, (D, D)>), ExpmError>;
+
+/// exp(A) and, when a direction E is given, L(A, E)
+type ExpmImplResult<T, D> = Result<(Tensor<T, (D, D)>, Option<Tensor<T, (D, D)>>), ExpmError>;
+
+/// Term cᵢ Xᵢ of a linear combination
+type Term<'a, T, D> = (T, &'a Tensor<T, (D, D)>);
+
+/// Matrix exponential by scaling and squaring
+///
+/// Implemented for every backend providing [`MatMul`] and [`Solve`].
+pub trait Expm<T: ComplexFloat, D: Dim> {
+    /// Computes exp(A) with new allocated matrix
+    fn expm<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> Result<Tensor<T, (D, D)>, ExpmError>;
+
+    /// Computes exp(A) overwriting `e`
+    fn expm_write<La: Layout, Le: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        e: &mut Slice<T, (D, D), Le>,
+    ) -> Result<(), ExpmError>;
+
+    /// Computes exp(A) and the Fréchet derivative L(A, E) of the
+    /// exponential at A in the direction E, returned as (exp(A), L(A, E))
+    fn expm_frechet<La: Layout, Le: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        e: &Slice<T, (D, D), Le>,
+    ) -> ExpmFrechetResult<T, D>;
+}
+
+impl<T, D, B> Expm<T, D> for B
+where
+    T: ComplexFloat + MulAdd<Output = T> + Default,
+    D: Dim,
+    B: MatMul<T> + Solve<T, D, D>,
+{
+    fn expm<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> Result<Tensor<T, (D, D)>, ExpmError> {
+        square_dim(a)?;
+        expm_impl(self, a, None).map(|(r, _)| r)
+    }
+
+    fn expm_write<La: Layout, Le: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        e: &mut Slice<T, (D, D), Le>,
+    ) -> Result<(), ExpmError> {
+        let n = square_dim(a)?;
+        if *e.shape() != *a.shape() {
+            return Err(ExpmError::InvalidDimensions);
+        }
+
+        let (r, _) = expm_impl(self, a, None)?;
+        for i in 0..n {
+            for j in 0..n {
+                e[[i, j]] = r[[i, j]];
+            }
+        }
+        Ok(())
+    }
+
+    fn expm_frechet<La: Layout, Le: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        e: &Slice<T, (D, D), Le>,
+    ) -> ExpmFrechetResult<T, D> {
+        square_dim(a)?;
+        if *e.shape() != *a.shape() {
+            return Err(ExpmError::InvalidDimensions);
+        }
+
+        let e = e.to_tensor();
+        expm_impl(self, a, Some(&e)).map(|(r, l)| (r, l.unwrap()))
+    }
+}
+
+/// Coefficients b₀, …, b_m of the numerator p_m(x) = Σ bⱼ xʲ of the
+/// [m/m] Padé approximant of eˣ, the denominator being p_m(−x)
+fn pade_coefficients(m: usize) -> &'static [f64] {
+    match m {
+        3 => &[120., 60., 12., 1.],
+        5 => &[30240., 15120., 3360., 420., 30., 1.],
+        7 => &[
+            17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+        ],
+        9 => &[
+            17643225600.,
+            8821612800.,
+            2075673600.,
+            302702400.,
+            30270240.,
+            2162160.,
+            110880.,
+            3960.,
+            90.,
+            1.,
+        ],
+        13 => &[
+            64764752532480000.,
+            32382376266240000.,
+            7771770303897600.,
+            1187353796428800.,
+            129060195264000.,
+            10559470521600.,
+            670442572800.,
+            33522128640.,
+            1323241920.,
+            40840800.,
+            960960.,
+            16380.,
+            182.,
+            1.,
+        ],
+        _ => unreachable!("no Padé approximant of degree {m}"),
+    }
+}
+
+/// Largest ‖A‖₁ for which the Padé approximant of each degree is accurate
+/// to unit roundoff without scaling (Higham, 2005, Table 2.3 and 2.2).
+/// The last degree is the one used together with scaling.
+fn pade_thresholds<R: Float>() -> &'static [(usize, f64)] {
+    if R::epsilon() <= <R as NumCast>::from(f64::EPSILON).unwrap() {
+        &[
+            (3, 1.495585217958292e-2),
+            (5, 2.53939833006323e-1),
+            (7, 9.504178996162932e-1),
+            (9, 2.097847961257068e0),
+            (13, 5.371920351148152e0),
+        ]
+    } else {
+        &[
+            (3, 4.258730016922831e-1),
+            (5, 1.880152677804762e0),
+            (7, 3.92572478313866e0),
+        ]
+    }
+}
+
+fn square_dim<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> Result<usize, ExpmError> {
+    let ash = *a.shape();
+    let n = ash.dim(0);
+    if n != ash.dim(1) {
+        return Err(ExpmError::NotSquareMatrix);
+    }
+    Ok(n)
+}
+
+/// Maximum absolute column sum ‖A‖₁
+fn norm1<T: ComplexFloat, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> T::Real {
+    let n = a.shape().dim(0);
+    (0..n)
+        .map(|j| {
+            (0..n)
+                .map(|i| a[[i, j]].abs())
+                .fold(T::Real::zero(), |acc, x| acc + x)
+        })
+        .fold(T::Real::zero(), |acc, x| acc.max(x))
+}
+
+/// Σ cᵢ Xᵢ + c I
+fn combine<T: ComplexFloat, D: Dim>(
+    n: usize,
+    terms: &[Term<'_, T, D>],
+    identity: T,
+) -> Tensor<T, (D, D)> {
+    Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+        let diag = if i[0] == i[1] { identity } else { T::zero() };
+        terms
+            .iter()
+            .fold(diag, |acc, (c, x)| acc + *c * x[[i[0], i[1]]])
+    })
+}
+
+fn matmul<T, D, B>(bd: &B, a: &Tensor<T, (D, D)>, b: &Tensor<T, (D, D)>) -> Tensor<T, (D, D)>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    B: MatMul<T>,
+{
+    bd.matmul(a, b).eval()
+}
+
+/// Scaling and squaring, differentiated along E if it is given
+fn expm_impl<T, D, L, B>(
+    bd: &B,
+    a: &Slice<T, (D, D), L>,
+    e: Option<&Tensor<T, (D, D)>>,
+) -> ExpmImplResult<T, D>
+where
+    T: ComplexFloat + MulAdd<Output = T> + Default,
+    D: Dim,
+    L: Layout,
+    B: MatMul<T> + Solve<T, D, D>,
+{
+    let n = a.shape().dim(0);
+    let real = |x: f64| <T::Real as NumCast>::from(x).unwrap();
+    let norm = norm1(a);
+
+    // Smallest degree that needs no scaling, otherwise the largest one
+    // with s squarings bringing ‖2⁻ˢA‖₁ below its threshold
+    let thresholds = pade_thresholds::<T::Real>();
+    let (m, s) = match thresholds.iter().find(|&&(_, theta)| norm <= real(theta)) {
+        Some(&(m, _)) => (m, 0),
+        None => {
+            let &(m, theta) = thresholds.last().unwrap();
+            let s = Float::ceil(Float::log2(norm / real(theta)))
+                .to_i32()
+                .unwrap_or(0)
+                .max(0);
+            (m, s)
+        }
+    };
+
+    let scale = T::from(Float::powi(real(2.0), -s)).unwrap();
+    let a = Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+        a[[i[0], i[1]]] * scale
+    });
+    let e = e.map(|e| Tensor::from_fn(*e.shape(), |i| e[[i[0], i[1]]] * scale));
+
+    let b: Vec<T> = pade_coefficients(m)
+        .iter()
+        .map(|&x| T::from(real(x)).unwrap())
+        .collect();
+
+    // Even powers A^{2k} and their derivatives M_{2k} along E
+    let mut powers = vec![matmul(bd, &a, &a)];
+    let mut derivs = Vec::new();
+    if let Some(e) = &e {
+        let mut m2 = matmul(bd, &a, e);
+        bd.matmul(e, &a).add_to(&mut m2);
+        derivs.push(m2);
+    }
+    let needed = if m == 13 { 3 } else { m / 2 };
+    while powers.len() < needed {
+        // A^{2(i+j)} = A^{2i} A^{2j}, with i = 1 or i = j
+        let k = powers.len();
+        let (i, j) = if k % 2 == 1 {
+            (k / 2, k / 2)
+        } else {
+            (0, k - 1)
+        };
+        powers.push(matmul(bd, &powers[i], &powers[j]));
+        if e.is_some() {
+            let mut d = matmul(bd, &powers[i], &derivs[j]);
+            bd.matmul(&derivs[i], &powers[j]).add_to(&mut d);
+            derivs.push(d);
+        }
+    }
+
+    let (u, v, derivatives) = if m == 13 {
+        let (a2, a4, a6) = (&powers[0], &powers[1], &powers[2]);
+        let w1 = combine(n, &[(b[13], a6), (b[11], a4), (b[9], a2)], T::zero());
+        let w2 = combine(n, &[(b[7], a6), (b[5], a4), (b[3], a2)], b[1]);
+        let z1 = combine(n, &[(b[12], a6), (b[10], a4), (b[8], a2)], T::zero());
+        let z2 = combine(n, &[(b[6], a6), (b[4], a4), (b[2], a2)], b[0]);
+
+        let mut w = w2.clone();
+        bd.matmul(a6, &w1).add_to(&mut w);
+        let u = matmul(bd, &a, &w);
+        let mut v = z2.clone();
+        bd.matmul(a6, &z1).add_to(&mut v);
+
+        let derivatives = e.as_ref().map(|e| {
+            let (m2, m4, m6) = (&derivs[0], &derivs[1], &derivs[2]);
+            let lw1 = combine(n, &[(b[13], m6), (b[11], m4), (b[9], m2)], T::zero());
+            let lw2 = combine(n, &[(b[7], m6), (b[5], m4), (b[3], m2)], T::zero());
+            let lz1 = combine(n, &[(b[12], m6), (b[10], m4), (b[8], m2)], T::zero());
+            let lz2 = combine(n, &[(b[6], m6), (b[4], m4), (b[2], m2)], T::zero());
+
+            let mut lw = lw2;
+            bd.matmul(a6, &lw1).add_to(&mut lw);
+            bd.matmul(m6, &w1).add_to(&mut lw);
+            let mut lu = matmul(bd, &a, &lw);
+            bd.matmul(e, &w).add_to(&mut lu);
+            let mut lv = lz2;
+            bd.matmul(a6, &lz1).add_to(&mut lv);
+            bd.matmul(m6, &z1).add_to(&mut lv);
+            (lu, lv)
+        });
+        (u, v, derivatives)
+    } else {
+        // U = A Σ_{j odd} bⱼ A^{j−1} and V = Σ_{j even} bⱼ Aʲ
+        let odd: Vec<_> = (1..=m / 2)
+            .map(|k| (b[2 * k + 1], &powers[k - 1]))
+            .collect();
+        let even: Vec<_> = (1..=m / 2).map(|k| (b[2 * k], &powers[k - 1])).collect();
+        let w = combine(n, &odd, b[1]);
+        let u = matmul(bd, &a, &w);
+        let v = combine(n, &even, b[0]);
+
+        let derivatives = e.as_ref().map(|e| {
+            let odd: Vec<_> = (1..=m / 2)
+                .map(|k| (b[2 * k + 1], &derivs[k - 1]))
+                .collect();
+            let even: Vec<_> = (1..=m / 2).map(|k| (b[2 * k], &derivs[k - 1])).collect();
+            let mut lu = matmul(bd, &a, &combine(n, &odd, T::zero()));
+            bd.matmul(e, &w).add_to(&mut lu);
+            (lu, combine(n, &even, T::zero()))
+        });
+        (u, v, derivatives)
+    };
+
+    // r_m(A) = (V − U)⁻¹ (V + U)
+    let q = combine(n, &[(T::one(), &v), (-T::one(), &u)], T::zero());
+    let p = combine(n, &[(T::one(), &v), (T::one(), &u)], T::zero());
+    let mut r = bd.solve(&mut q.clone(), &p)?.x;
+
+    // L = (V − U)⁻¹ (Lu + Lv + (Lu − Lv) r_m(A))
+    let mut l = match derivatives {
+        Some((lu, lv)) => {
+            let mut rhs = combine(n, &[(T::one(), &lu), (T::one(), &lv)], T::zero());
+            let diff = combine(n, &[(T::one(), &lu), (-T::one(), &lv)], T::zero());
+            bd.matmul(&diff, &r).add_to(&mut rhs);
+            Some(bd.solve(&mut q.clone(), &rhs)?.x)
+        }
+        None => None,
+    };
+
+    for _ in 0..s {
+        if let Some(l) = &mut l {
+            let mut next = matmul(bd, &r, l);
+            bd.matmul(l, &r).add_to(&mut next);
+            *l = next;
+        }
+        r = matmul(bd, &r, &r);
+    }
+
+    Ok((r, l))
+}
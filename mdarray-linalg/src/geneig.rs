@@ -0,0 +1,124 @@
+//! Generalized eigenvalue problems A v = λ B v
+//!
+//! For a general pencil (A, B) the eigenvalues are returned as pairs
+//! (α, β) with λ = α / β.  A singular B gives eigenvalues at infinity
+//! (β = 0) which are represented exactly, and a singular pencil
+//! (α = β = 0) can be detected by the caller.
+//!
+//! When A is Hermitian and B is Hermitian positive definite, the
+//! eigenvalues are real and the eigenvectors are normalized so that
+//! Vᴴ B V = I.
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::{Complex, ComplexFloat};
+use num_traits::{Float, Zero};
+use thiserror::Error;
+
+/// Error types related to generalized eigenvalue problems
+#[derive(Debug, Error)]
+pub enum GenEigError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Backend failed to converge in {iterations} iterations")]
+    BackendDidNotConverge { iterations: i32 },
+
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+
+    #[error("A and B must have the same shape")]
+    InvalidDimensions,
+
+    /// B is not positive definite, as required by the Hermitian-definite solver
+    #[error("B is not positive definite: the leading minor of order {order} is not positive")]
+    NotPositiveDefinite { order: usize },
+}
+
+/// Complex eigenvectors of a general pencil, stored as columns
+pub type GenEigVectors<T, D0, D1> = Tensor<Complex<<T as ComplexFloat>::Real>, (D0, D1)>;
+
+/// Holds the results of a general generalized eigendecomposition
+pub struct GenEigDecomp<T: ComplexFloat, D0: Dim, D1: Dim> {
+    /// Numerators α of the eigenvalues λ = α / β
+    pub alpha: Tensor<Complex<T::Real>, (D0,)>,
+    /// Denominators β of the eigenvalues λ = α / β, zero for infinite eigenvalues
+    pub beta: Tensor<Complex<T::Real>, (D0,)>,
+    /// Right eigenvectors as columns, satisfying β A v = α B v
+    pub right_eigenvectors: Option<GenEigVectors<T, D0, D1>>,
+}
+
+impl<T: ComplexFloat, D0: Dim, D1: Dim> GenEigDecomp<T, D0, D1> {
+    /// Returns the eigenvalues λ = α / β, with a positive infinite real
+    /// part where β = 0
+    pub fn eigenvalues(&self) -> Tensor<Complex<T::Real>, (D0,)> {
+        Tensor::from_fn(*self.alpha.shape(), |i| {
+            let (alpha, beta) = (self.alpha[i[0]], self.beta[i[0]]);
+            if beta.is_zero() {
+                Complex::new(T::Real::infinity(), T::Real::zero())
+            } else {
+                alpha / beta
+            }
+        })
+    }
+}
+
+/// Holds the results of a Hermitian-definite generalized eigendecomposition
+pub struct GenEighDecomp<T: ComplexFloat, D0: Dim, D1: Dim> {
+    /// Real eigenvalues in ascending order
+    pub eigenvalues: Tensor<T::Real, (D0,)>,
+    /// B-orthonormal eigenvectors as columns: Vᴴ B V = I
+    pub eigenvectors: Tensor<T, (D0, D1)>,
+}
+
+/// Result type for general generalized eigendecompositions
+pub type GenEigResult<T, D0, D1> = Result<GenEigDecomp<T, D0, D1>, GenEigError>;
+
+/// Result type for Hermitian-definite generalized eigendecompositions
+pub type GenEighResult<T, D0, D1> = Result<GenEighDecomp<T, D0, D1>, GenEigError>;
+
+/// Generalized eigenvalue decomposition of a matrix pencil (A, B)
+pub trait GenEig<T: ComplexFloat, D0: Dim, D1: Dim> {
+    /// Computes the eigenvalues as (α, β) pairs and the right eigenvectors
+    /// of a general pencil. A and B are overwritten by the backend.
+    fn gen_eig<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEigResult<T, D0, D1>;
+
+    /// Computes only the eigenvalues as (α, β) pairs of a general pencil.
+    /// A and B are overwritten by the backend.
+    fn gen_eig_values<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEigResult<T, D0, D1>;
+
+    /// Computes the eigenvalues and B-orthonormal eigenvectors of a
+    /// Hermitian A and a Hermitian positive definite B.
+    /// Only the lower triangles of A and B are referenced; both are
+    /// overwritten by the backend.
+    fn gen_eigh<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> GenEighResult<T, D0, D1>;
+}
+
+/// Checks that A and B are square and of the same size, returning n
+pub fn gen_eig_dims<T, D0: Dim, D1: Dim, La: Layout, Lb: Layout>(
+    a: &Slice<T, (D0, D1), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Result<usize, GenEigError> {
+    let ash = *a.shape();
+    let bsh = *b.shape();
+    let n = ash.dim(0);
+
+    if n != ash.dim(1) || bsh.dim(0) != bsh.dim(1) {
+        return Err(GenEigError::NotSquareMatrix);
+    }
+    if bsh.dim(0) != n {
+        return Err(GenEigError::InvalidDimensions);
+    }
+
+    Ok(n)
+}
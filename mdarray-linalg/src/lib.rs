@@ -20,19 +20,40 @@
 //! |---------------------------------------------------|:----:|:------:|:-----:|:----:|:--------:|
 //! | **▶︎ Basic vector/matrix operations**              ||||||
 //! | [Matrix/vector multiplications](crate::matvec::MatVec) | ✅ | ⬜ | ✅ | 🔧 | 🔧  |
+//! | [Banded and packed mat-vec](crate::matvec::StructuredMatVec) | ✅ | ⬜ | ✅ | 🔧 | 🔧  |
 //! | [Operations on vectors](crate::matvec::VecOps)     | ✅ | ⬜ | 🔧 | 🔧 | 🔧  |
 //! | [Matrix multiplication](crate::matmul::MatMul)     | ✅ | ⬜ | ✅ | ✅ | 🔧  |
+//! | [Batched matrix multiplication](crate::matmul::BatchedMatMul) | ✅ | ⬜ | ✅ | ✅ | 🔧  |
 //! | [Argmax](crate::matvec::Argmax)                    | ✅ | ⬜ | ✅ | ⬜ | ⬜  |
+//! | [Triangular solve](crate::trisolve::TriSolve)      | ✅ | ✅ | ✅ | 🔧 | 🔧  |
 //! | **▶︎ Linear algebra**                              |||||
 //! | [Eigen decomposition](crate::eig::Eig)             | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
+//! | [Generalized eigenproblem](crate::geneig::GenEig)  | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
 //! | [SVD decomposition](crate::svd::SVD)               | ⬜ | ✅ | ⬜ | ✅ | ✅  |
+//! | [Truncated SVD](crate::svd::TruncatedSVD)          | ⬜ | ✅ | ⬜ | ✅ | ✅  |
+//! | [Randomized SVD](crate::svd::RandomizedSVD)        | ⬜ | ⬜ | ⬜ | ✅ | ⬜  |
+//! | [Pseudo-inverse](crate::pinv::Pinv)                | ⬜ | ✅ | ⬜ | ✅ | ✅  |
+//! | [Polar decomposition](crate::polar::Polar)         | ⬜ | ✅ | ⬜ | ✅ | ✅  |
 //! | [LU decomposition](crate::lu::LU)                  | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
+//! | [Reusable LU factors](crate::lu::LuFactors)         | ⬜ | ✅ | ⬜ | ✅ | ⬜  |
 //! | [Solve and inverse](crate::solve::Solve)           | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
+//! | [Tridiagonal and banded solve](crate::banded::BandSolve) | ⬜ | ✅ | ✅ | 🔧 | 🔧  |
+//! | [Least squares](crate::lstsq::Lstsq)               | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
 //! | [QR decomposition](crate::qr::QR)                  | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
-//! | [Cholesky decomposition](crate::lu::LU)| ⬜ | ✅ | ⬜ | 🔧 |🔧 |
+//! | [Column-pivoted QR](crate::qr::QRPivot)            | ⬜ | ✅ | ⬜ | ✅ | 🔧  |
+//! | [Cholesky decomposition](crate::lu::LU)| ⬜ | ✅ | ⬜ | ✅ |🔧 |
+//! | [Reusable Cholesky factors](crate::lu::CholeskyFactors) | ⬜ | ✅ | ⬜ | ✅ | ⬜  |
+//! | [Symmetric indefinite LDLᵀ](crate::lu::LdlFactors)  | ⬜ | ✅ | ⬜ | ✅ | ⬜  |
 //! | [Schur decomposition](crate::eig::Eig)         | ⬜ | ✅ | ⬜ | 🔧 | 🔧  |
+//! | [Hessenberg, tridiagonal, bidiagonal](crate::reduction::Reduction) | ⬜ | ✅ | ⬜ | 🔧 | 🔧  |
+//! | [Sylvester, Lyapunov and Stein equations](crate::sylvester::Sylvester) | ⬜ | ✅ | ⬜ | 🔧 | 🔧  |
 //! | **▶︎ Advanced**                                   ||||||
 //! | [Tensor contraction](crate::matmul::MatMul)        | ✅ | ⬜ | ✅ | ✅ | 🔧  |
+//! | [Einstein summation](crate::einsum::Einsum)        | ✅ | ⬜ | ✅ | ✅ | 🔧  |
+//! | [Tensor network contraction](crate::ncon::Ncon)    | ✅ | ⬜ | ✅ | ✅ | 🔧  |
+//! | [Matrix exponential](crate::expm::Expm)            | ⬜ | ⬜ | ⬜ | ✅ | 🔧  |
+//! | [Matrix norms](crate::norm::MatrixNorm)            | ⬜ | ✅ | ✅ | 🔧 | 🔧  |
+//! | [Condition number estimate](crate::norm::Cond)     | ⬜ | ✅ | ✅ | 🔧 | 🔧  |
 //!
 //! ✅ = implemented
 //! 🔧 = not implemented yet / partially implemented
@@ -115,13 +136,27 @@
 
 pub mod prelude;
 
+pub mod banded;
 pub mod eig;
+pub mod einsum;
+pub mod expm;
+pub mod geneig;
+pub mod lstsq;
 pub mod lu;
 pub mod matmul;
 pub mod matvec;
+pub mod ncon;
+pub mod norm;
+pub mod packed;
+pub mod pinv;
+pub mod polar;
 pub mod qr;
+pub mod reduction;
 pub mod solve;
+pub mod subspace;
 pub mod svd;
+pub mod sylvester;
+pub mod trisolve;
 
 pub mod utils;
 pub use utils::*;
@@ -0,0 +1,97 @@
+//! Least-squares solutions of linear systems AX ≈ B
+//!
+//! For an m × n matrix A and an m × nrhs matrix B, the solution X is the
+//! n × nrhs matrix minimizing ‖AX − B‖₂.  If the system is
+//! underdetermined (m < n) or A is rank deficient, the solution of
+//! minimum norm ‖X‖₂ is returned, provided the backend supports it.
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::Zero;
+use thiserror::Error;
+
+/// Error types related to least-squares solving
+#[derive(Debug, Error)]
+pub enum LstsqError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Backend failed to converge: {superdiagonals} superdiagonals did not converge to zero")]
+    BackendDidNotConverge { superdiagonals: i32 },
+
+    /// The selected algorithm requires A to have full rank
+    #[error("Matrix is rank deficient: effective rank is {rank}")]
+    RankDeficient { rank: usize },
+
+    #[error("Invalid matrix dimensions")]
+    InvalidDimensions,
+}
+
+/// Holds the results of a least-squares solve
+pub struct LstsqResult<T: ComplexFloat, D0: Dim, D1: Dim> {
+    /// Solution X (n × nrhs)
+    pub x: Tensor<T, (D0, D1)>,
+    /// Squared residual norm ‖bⱼ − A xⱼ‖² of each column of B
+    pub residuals: Vec<T::Real>,
+    /// Effective rank of A
+    pub rank: usize,
+    /// Singular values of A in decreasing order, if computed by the backend
+    pub singular_values: Option<Vec<T::Real>>,
+}
+
+/// Result type for least-squares solving, returning either an
+/// `LstsqResult` or an `LstsqError`
+pub type LstsqResultType<T, D0, D1> = Result<LstsqResult<T, D0, D1>, LstsqError>;
+
+/// Least-squares solver for overdetermined and underdetermined systems
+pub trait Lstsq<T: ComplexFloat, D0: Dim, D1: Dim> {
+    /// Solves min ‖AX − B‖ with new allocated solution matrix
+    /// A (m × n) is overwritten by the backend, B (m × nrhs) is left untouched.
+    fn lstsq<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &Slice<T, (D0, D1), Lb>,
+    ) -> LstsqResultType<T, D0, D1>;
+
+    /// Solves min ‖AX − B‖ overwriting existing matrices
+    /// B must be max(m, n) × nrhs: on entry its first m rows contain the
+    /// right-hand sides, on exit its first n rows contain the solution X.
+    /// A is overwritten by the backend.
+    /// Returns the effective rank of A.
+    fn lstsq_write<La: Layout, Lb: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), La>,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<usize, LstsqError>;
+}
+
+/// Squared residual norms ‖bⱼ − A xⱼ‖² of each column, used by the
+/// backends to fill [`LstsqResult::residuals`]
+pub fn lstsq_residuals<T, D0, D1, La, Lx, Lb>(
+    a: &Slice<T, (D0, D1), La>,
+    x: &Slice<T, (D0, D1), Lx>,
+    b: &Slice<T, (D0, D1), Lb>,
+) -> Vec<T::Real>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    La: Layout,
+    Lx: Layout,
+    Lb: Layout,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let nrhs = b.shape().dim(1);
+
+    (0..nrhs)
+        .map(|j| {
+            (0..m)
+                .map(|i| {
+                    let ax = (0..n).fold(T::zero(), |acc, k| acc + a[[i, k]] * x[[k, j]]);
+                    let r = (b[[i, j]] - ax).abs();
+                    r * r
+                })
+                .fold(T::Real::zero(), |acc, r2| acc + r2)
+        })
+        .collect()
+}
@@ -1,7 +1,19 @@
 //! LU, Cholesky, matrix inversion, and determinant computation utilities
-use mdarray::{Dim, Layout, Slice, Tensor};
+use std::cmp::Ordering;
+
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::{Float, One, Zero};
 use thiserror::Error;
 
+use crate::{
+    Naive,
+    matmul::Triangle,
+    norm::{Cond, Norm, NormError},
+    solve::SolveError,
+    trisolve::Transpose,
+};
+
 /// Error types related to matrix inversion
 #[derive(Debug, Error)]
 pub enum InvError {
@@ -62,3 +74,485 @@ pub trait LU<T, D0: Dim, D1: Dim> {
     /// Computes the Cholesky decomposition in-place, overwriting the input matrix
     fn choleski_write<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> Result<(), InvError>;
 }
+
+/// LU factorization with partial pivoting of a square matrix, kept for
+/// repeated solves with the same A
+///
+/// A = P L U is stored packed as returned by `getrf`: L strictly below
+/// the diagonal, its unit diagonal being implicit, and U on and above
+/// it. Obtained from [`LuFactor::lu_factor`].
+///
+///```rust
+///use mdarray::tensor;
+///use mdarray_linalg::lu::LuFactor;
+///use mdarray_linalg_faer::Faer;
+///
+///let a = tensor![[4., 3.], [6., 3.]];
+///let lu = Faer.lu_factor(&a).unwrap();
+///assert!((lu.det() + 6.0_f64).abs() < 1e-12);
+///
+///// Factor once, solve many right-hand sides
+///for b in [tensor![[10.], [12.]], tensor![[1.], [0.]]] {
+///    let x = lu.solve(&b).unwrap();
+///    assert!((4. * x[[0, 0]] + 3. * x[[1, 0]] - b[[0, 0]]).abs() < 1e-12_f64);
+///}
+///```
+pub struct LuFactors<'a, T: ComplexFloat, D: Dim, B> {
+    /// L and U packed in a single n × n matrix
+    pub lu: Tensor<T, (D, D)>,
+    /// Row interchanges in LAPACK's 1-based convention: row i was
+    /// interchanged with row `ipiv[i]`, see [`ipiv_to_perm_mat`](crate::ipiv_to_perm_mat)
+    pub ipiv: Vec<i32>,
+    backend: &'a B,
+}
+
+impl<'a, T: ComplexFloat, D: Dim, B> LuFactors<'a, T, D, B> {
+    /// Wraps the packed factors and pivots computed by `backend`
+    pub fn new(backend: &'a B, lu: Tensor<T, (D, D)>, ipiv: Vec<i32>) -> Self {
+        let lush = *lu.shape();
+        assert_eq!(lush.dim(0), lush.dim(1), "Packed LU factors must be square");
+        assert_eq!(
+            ipiv.len(),
+            lush.dim(0),
+            "One pivot index per row is expected"
+        );
+        Self { lu, ipiv, backend }
+    }
+
+    /// Order n of the factored matrix
+    pub fn n(&self) -> usize {
+        self.ipiv.len()
+    }
+
+    /// Unit lower triangular factor L
+    pub fn l(&self) -> Tensor<T, (D, D)> {
+        Tensor::from_fn(*self.lu.shape(), |i| match i[0].cmp(&i[1]) {
+            Ordering::Greater => self.lu[[i[0], i[1]]],
+            Ordering::Equal => T::one(),
+            Ordering::Less => T::zero(),
+        })
+    }
+
+    /// Upper triangular factor U
+    pub fn u(&self) -> Tensor<T, (D, D)> {
+        Tensor::from_fn(*self.lu.shape(), |i| {
+            if i[0] <= i[1] {
+                self.lu[[i[0], i[1]]]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Determinant of A, the product of the diagonal of U with the sign
+    /// of the row permutation
+    pub fn det(&self) -> T {
+        (0..self.n()).fold(T::one(), |acc, i| {
+            let d = acc * self.lu[[i, i]];
+            if self.ipiv[i] - 1 != i as i32 { -d } else { d }
+        })
+    }
+
+    /// Index of the first zero on the diagonal of U, if any
+    pub fn zero_pivot(&self) -> Option<usize> {
+        (0..self.n()).find(|&i| self.lu[[i, i]] == T::zero())
+    }
+}
+
+impl<'a, T: ComplexFloat, D: Dim, B: LuFactor<T, D>> LuFactors<'a, T, D, B> {
+    /// Solves A X = B with new allocated solution matrix
+    pub fn solve<Lb: Layout>(
+        &self,
+        b: &Slice<T, (D, D), Lb>,
+    ) -> Result<Tensor<T, (D, D)>, SolveError> {
+        let mut x = Tensor::from_fn(*b.shape(), |i| b[[i[0], i[1]]]);
+        self.solve_write(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A X = B, overwriting B with the solution X
+    pub fn solve_write<Lb: Layout>(&self, b: &mut Slice<T, (D, D), Lb>) -> Result<(), SolveError> {
+        self.backend.lu_solve_write(self, b, Transpose::NoTrans)
+    }
+
+    /// Solves Aᵀ X = B with new allocated solution matrix
+    pub fn solve_transpose<Lb: Layout>(
+        &self,
+        b: &Slice<T, (D, D), Lb>,
+    ) -> Result<Tensor<T, (D, D)>, SolveError> {
+        let mut x = Tensor::from_fn(*b.shape(), |i| b[[i[0], i[1]]]);
+        self.solve_transpose_write(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves Aᵀ X = B, overwriting B with the solution X
+    pub fn solve_transpose_write<Lb: Layout>(
+        &self,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError> {
+        self.backend.lu_solve_write(self, b, Transpose::Trans)
+    }
+
+    /// Computes A⁻¹ from the factors
+    pub fn inv(&self) -> InvResult<T, D, D> {
+        self.backend.lu_inv(self)
+    }
+
+    /// Estimates the reciprocal condition number 1 / (‖A‖ ‖A⁻¹‖) in the
+    /// 1- or ∞-norm, `anorm` being the corresponding norm of A
+    pub fn rcond(&self, anorm: T::Real, norm: Norm) -> Result<T::Real, NormError> {
+        self.backend.lu_rcond(self, anorm, norm)
+    }
+}
+
+/// LU factorization kept for repeated solves, see [`LuFactors`]
+pub trait LuFactor<T: ComplexFloat, D: Dim>: Sized {
+    /// Computes the LU factorization of the square matrix A, which is left
+    /// untouched. A singular A is factored successfully: its zero pivot
+    /// is only reported when solving or inverting.
+    fn lu_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+    ) -> Result<LuFactors<'_, T, D, Self>, InvError>;
+
+    /// Overwrites B with op(A)⁻¹ B, op being selected by `trans`
+    fn lu_solve_write<Lb: Layout>(
+        &self,
+        f: &LuFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+        trans: Transpose,
+    ) -> Result<(), SolveError>;
+
+    /// Computes A⁻¹ from its factors
+    fn lu_inv(&self, f: &LuFactors<'_, T, D, Self>) -> InvResult<T, D, D>;
+
+    /// Estimates the reciprocal condition number of A from its factors.
+    /// Defaults to the estimator of the [`Naive`] backend.
+    fn lu_rcond(
+        &self,
+        f: &LuFactors<'_, T, D, Self>,
+        anorm: T::Real,
+        norm: Norm,
+    ) -> Result<T::Real, NormError> {
+        Naive.rcond_lu_packed(&f.lu, anorm, norm)
+    }
+}
+
+/// Cholesky factorization of a Hermitian positive definite matrix, kept
+/// for repeated solves with the same A
+///
+/// Depending on `triangle`, `factor` holds either the lower triangular L
+/// with A = L Lᴴ or the upper triangular U with A = Uᴴ U, its other
+/// triangle being zero. Obtained from [`CholeskyFactor::cholesky_factor`].
+///
+///```rust
+///use mdarray::tensor;
+///use mdarray_linalg::lu::CholeskyFactor;
+///use mdarray_linalg::matmul::Triangle;
+///use mdarray_linalg_faer::Faer;
+///
+///let a = tensor![[4., 2.], [2., 5.]];
+///let chol = Faer.cholesky_factor(&a, Triangle::Lower).unwrap();
+///assert!((chol.logdet() - 16.0_f64.ln()).abs() < 1e-12);
+///
+///let x = chol.solve(&tensor![[6.], [7.]]).unwrap();
+///assert!((x[[0, 0]] - 1.0_f64).abs() < 1e-12 && (x[[1, 0]] - 1.0_f64).abs() < 1e-12);
+///```
+pub struct CholeskyFactors<'a, T: ComplexFloat, D: Dim, B> {
+    /// Triangular factor L or U
+    pub factor: Tensor<T, (D, D)>,
+    /// Whether `factor` is the lower L or the upper U
+    pub triangle: Triangle,
+    backend: &'a B,
+}
+
+impl<'a, T: ComplexFloat, D: Dim, B> CholeskyFactors<'a, T, D, B> {
+    /// Wraps the triangular factor computed by `backend`
+    pub fn new(backend: &'a B, factor: Tensor<T, (D, D)>, triangle: Triangle) -> Self {
+        let fsh = *factor.shape();
+        assert_eq!(fsh.dim(0), fsh.dim(1), "Cholesky factor must be square");
+        Self {
+            factor,
+            triangle,
+            backend,
+        }
+    }
+
+    /// Order n of the factored matrix
+    pub fn n(&self) -> usize {
+        self.factor.shape().dim(0)
+    }
+
+    /// Lower triangular factor L, conjugate-transposing U if needed
+    pub fn l(&self) -> Tensor<T, (D, D)> {
+        match self.triangle {
+            Triangle::Lower => self.factor.clone(),
+            Triangle::Upper => {
+                Tensor::from_fn(*self.factor.shape(), |i| self.factor[[i[1], i[0]]].conj())
+            }
+        }
+    }
+
+    /// Logarithm of the determinant of A, 2 Σᵢ ln(Lᵢᵢ), which does not
+    /// overflow nor underflow for large matrices
+    pub fn logdet(&self) -> T::Real {
+        let two = T::Real::one() + T::Real::one();
+        (0..self.n()).fold(T::Real::zero(), |acc, i| {
+            acc + two * Float::ln(self.factor[[i, i]].re())
+        })
+    }
+}
+
+impl<'a, T: ComplexFloat, D: Dim, B: CholeskyFactor<T, D>> CholeskyFactors<'a, T, D, B> {
+    /// Solves A X = B with new allocated solution matrix
+    pub fn solve<Lb: Layout>(
+        &self,
+        b: &Slice<T, (D, D), Lb>,
+    ) -> Result<Tensor<T, (D, D)>, SolveError> {
+        let mut x = Tensor::from_fn(*b.shape(), |i| b[[i[0], i[1]]]);
+        self.solve_write(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A X = B, overwriting B with the solution X
+    pub fn solve_write<Lb: Layout>(&self, b: &mut Slice<T, (D, D), Lb>) -> Result<(), SolveError> {
+        self.backend.cholesky_solve_write(self, b)
+    }
+
+    /// Computes A⁻¹ from the factor
+    pub fn inv(&self) -> InvResult<T, D, D> {
+        self.backend.cholesky_inv(self)
+    }
+}
+
+/// Cholesky factorization kept for repeated solves, see [`CholeskyFactors`]
+pub trait CholeskyFactor<T: ComplexFloat, D: Dim>: Sized {
+    /// Computes the Cholesky factorization of the Hermitian positive
+    /// definite matrix A, of which only `triangle` is referenced. A is
+    /// left untouched.
+    fn cholesky_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        triangle: Triangle,
+    ) -> Result<CholeskyFactors<'_, T, D, Self>, InvError>;
+
+    /// Overwrites B with A⁻¹ B
+    fn cholesky_solve_write<Lb: Layout>(
+        &self,
+        f: &CholeskyFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError>;
+
+    /// Computes A⁻¹ from its factor
+    fn cholesky_inv(&self, f: &CholeskyFactors<'_, T, D, Self>) -> InvResult<T, D, D>;
+}
+
+/// Pivoting strategy of the LDLᵀ factorization
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum LdlPivoting {
+    /// Partial pivoting of Bunch and Kaufman, searching the current column
+    #[default]
+    BunchKaufman,
+    /// Rook pivoting (bounded Bunch–Kaufman), with bounded entries in L
+    Rook,
+}
+
+/// Number of positive, negative and zero eigenvalues of a Hermitian
+/// matrix
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Inertia {
+    pub positive: usize,
+    pub negative: usize,
+    pub zero: usize,
+}
+
+/// Symmetric indefinite factorization P A Pᵀ = L D Lᴴ of a real symmetric
+/// or complex Hermitian matrix, kept for repeated solves with the same A
+///
+/// L is unit lower triangular and D is Hermitian block diagonal with 1 × 1
+/// and 2 × 2 blocks. Row i of P A is row `perm[i]` of A. Obtained from
+/// [`LdlFactor::ldl_factor`].
+///
+///```rust
+///use mdarray::tensor;
+///use mdarray_linalg::lu::{Inertia, LdlFactor, LdlPivoting};
+///use mdarray_linalg_faer::Faer;
+///
+///// Saddle point matrix of a KKT system
+///let a = tensor![[2., 0., 1.], [0., 2., 1.], [1., 1., 0.]];
+///let ldl = Faer.ldl_factor(&a, LdlPivoting::BunchKaufman).unwrap();
+///
+///let inertia = ldl.inertia();
+///assert_eq!((inertia.positive, inertia.negative, inertia.zero), (2, 1, 0));
+///
+///let x = ldl.solve(&tensor![[3.], [3.], [2.]]).unwrap();
+///assert!((x[[2, 0]] - 1.0_f64).abs() < 1e-12);
+///```
+pub struct LdlFactors<'a, T: ComplexFloat, D: Dim, B> {
+    /// Unit lower triangular factor L
+    pub l: Tensor<T, (D, D)>,
+    /// Diagonal of D
+    pub d: Vec<T>,
+    /// Subdiagonal of D: `e[k]` = D(k + 1, k) is nonzero only for a 2 × 2
+    /// block in rows k and k + 1
+    pub e: Vec<T>,
+    /// Symmetric row and column permutation
+    pub perm: Vec<usize>,
+    backend: &'a B,
+}
+
+impl<'a, T: ComplexFloat, D: Dim, B> LdlFactors<'a, T, D, B> {
+    /// Wraps the factors computed by `backend`
+    pub fn new(
+        backend: &'a B,
+        l: Tensor<T, (D, D)>,
+        d: Vec<T>,
+        e: Vec<T>,
+        perm: Vec<usize>,
+    ) -> Self {
+        let n = d.len();
+        assert_eq!(
+            (l.shape().dim(0), l.shape().dim(1)),
+            (n, n),
+            "L must be n × n"
+        );
+        assert_eq!(e.len(), n, "The subdiagonal of D must have n entries");
+        assert_eq!(perm.len(), n, "The permutation must have n entries");
+        Self {
+            l,
+            d,
+            e,
+            perm,
+            backend,
+        }
+    }
+
+    /// Order n of the factored matrix
+    pub fn n(&self) -> usize {
+        self.d.len()
+    }
+
+    /// Block diagonal factor D as a dense matrix
+    pub fn d_matrix(&self) -> Tensor<T, (D, D)> {
+        Tensor::from_fn(*self.l.shape(), |i| {
+            let (r, c) = (i[0], i[1]);
+            if r == c {
+                self.d[r]
+            } else if r == c + 1 {
+                self.e[c]
+            } else if c == r + 1 {
+                self.e[r].conj()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Diagonal blocks of D as (first row, size) pairs
+    fn blocks(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let n = self.n();
+        let mut k = 0;
+        std::iter::from_fn(move || {
+            (k < n).then(|| {
+                let size = if k + 1 < n && self.e[k] != T::zero() {
+                    2
+                } else {
+                    1
+                };
+                k += size;
+                (k - size, size)
+            })
+        })
+    }
+
+    /// Inertia of A, equal to that of D by Sylvester's law of inertia.
+    /// Only exactly zero eigenvalues of D are counted as zero.
+    pub fn inertia(&self) -> Inertia {
+        let mut inertia = Inertia::default();
+        let mut count = |x: T::Real| match x.partial_cmp(&T::Real::zero()) {
+            Some(Ordering::Greater) => inertia.positive += 1,
+            Some(Ordering::Less) => inertia.negative += 1,
+            _ => inertia.zero += 1,
+        };
+
+        for (k, size) in self.blocks() {
+            if size == 1 {
+                count(self.d[k].re());
+                continue;
+            }
+
+            // The eigenvalues of a Hermitian 2 × 2 block have the signs of
+            // its determinant and trace
+            let (a, c) = (self.d[k].re(), self.d[k + 1].re());
+            let b = self.e[k].abs();
+            let det = a * c - b * b;
+            let trace = a + c;
+            match det.partial_cmp(&T::Real::zero()) {
+                Some(Ordering::Less) => {
+                    count(T::Real::one());
+                    count(-T::Real::one());
+                }
+                Some(Ordering::Greater) => {
+                    count(trace);
+                    count(trace);
+                }
+                _ => {
+                    count(T::Real::zero());
+                    count(trace);
+                }
+            }
+        }
+
+        inertia
+    }
+
+    /// Index of the first row of a singular block of D, if any
+    pub fn singular_block(&self) -> Option<usize> {
+        self.blocks().find_map(|(k, size)| {
+            let det = if size == 1 {
+                self.d[k]
+            } else {
+                self.d[k] * self.d[k + 1] - self.e[k] * self.e[k].conj()
+            };
+            (det == T::zero()).then_some(k)
+        })
+    }
+}
+
+impl<'a, T: ComplexFloat, D: Dim, B: LdlFactor<T, D>> LdlFactors<'a, T, D, B> {
+    /// Solves A X = B with new allocated solution matrix
+    pub fn solve<Lb: Layout>(
+        &self,
+        b: &Slice<T, (D, D), Lb>,
+    ) -> Result<Tensor<T, (D, D)>, SolveError> {
+        let mut x = Tensor::from_fn(*b.shape(), |i| b[[i[0], i[1]]]);
+        self.solve_write(&mut x)?;
+        Ok(x)
+    }
+
+    /// Solves A X = B, overwriting B with the solution X
+    pub fn solve_write<Lb: Layout>(&self, b: &mut Slice<T, (D, D), Lb>) -> Result<(), SolveError> {
+        self.backend.ldl_solve_write(self, b)
+    }
+}
+
+/// Symmetric indefinite LDLᵀ (LDLᴴ for complex Hermitian matrices)
+/// factorization kept for repeated solves, see [`LdlFactors`]
+pub trait LdlFactor<T: ComplexFloat, D: Dim>: Sized {
+    /// Computes the factorization of the real symmetric or complex
+    /// Hermitian matrix A, of which only the lower triangle is referenced.
+    /// A is left untouched. A singular A is factored successfully: its
+    /// singular block of D is only reported when solving.
+    fn ldl_factor<L: Layout>(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        pivoting: LdlPivoting,
+    ) -> Result<LdlFactors<'_, T, D, Self>, InvError>;
+
+    /// Overwrites B with A⁻¹ B
+    fn ldl_solve_write<Lb: Layout>(
+        &self,
+        f: &LdlFactors<'_, T, D, Self>,
+        b: &mut Slice<T, (D, D), Lb>,
+    ) -> Result<(), SolveError>;
+}
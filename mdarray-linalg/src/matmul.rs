@@ -19,17 +19,22 @@
 //!    .eval();
 //!assert_eq!(result_specific, expected_matmul);
 //!```
-use mdarray::{Dim, DynRank, Layout, Slice, Tensor};
+use std::cmp::Reverse;
+use std::thread;
+
+use mdarray::{Dim, DynRank, Layout, Shape, Slice, Strided, StridedMapping, Tensor, View, ViewMut};
 use num_complex::ComplexFloat;
 use num_traits::{MulAdd, One, Zero};
 
 /// Specifies whether the left or right matrix has the special property
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Side {
     Left,
     Right,
 }
 
 /// Identifies the structural type of a matrix (Hermitian, symmetric, or triangular)
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Type {
     Sym,
     Her,
@@ -37,6 +42,7 @@ pub enum Type {
 }
 
 /// Specifies whether a matrix is lower or upper triangular
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Triangle {
     Upper,
     Lower,
@@ -161,11 +167,329 @@ where
     /// Multiplies the result by a scalar factor.
     fn scale(self, factor: T) -> Self;
 
+    /// Permutes the axes of the result, whose axis `i` becomes axis
+    /// `order[i]` of the default result (the free axes of the first tensor
+    /// followed by those of the second one).
+    fn output_order(self, order: impl Into<Box<[usize]>>) -> Self;
+
     /// Returns a new owned tensor containing the result.
     fn eval(self) -> Tensor<T, DynRank>;
 
     /// Overwrites the provided tensor with the result.
-    fn write(self, c: &mut Slice<T>);
+    fn write<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>);
+
+    /// Adds the result to the provided tensor.
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>);
+
+    /// Adds the result to the provided tensor after scaling the tensor by
+    /// `beta` (i.e. C := beta * C + result).
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>, beta: T);
+}
+
+/// Batched matrix multiplication over leading tensor axes
+///
+/// The last two axes of each operand are the matrix axes and the leading
+/// ones index the batch: for a (batch, m, k) tensor A and a (batch, k, n)
+/// tensor B, `C[i] = A[i] B[i]` is (batch, m, n). The batch axes broadcast
+/// as in NumPy: they are aligned from the right, a missing axis or one of
+/// length 1 being repeated along the other operand.
+///
+/// Implemented for every backend providing [`MatMul`], whose kernel is
+/// called on each pair of matrices without copying them.
+///
+///```rust
+///use mdarray::DTensor;
+///use mdarray_linalg::prelude::*;
+///use mdarray_linalg::Naive;
+///
+///// 100 products of 2 × 3 and 3 × 2 matrices, the same B for all of them
+///let a = DTensor::<f64, 3>::from_fn([100, 2, 3], |i| (i[0] + i[1] * i[2]) as f64);
+///let b = DTensor::<f64, 2>::from_fn([3, 2], |i| (i[0] + i[1]) as f64);
+///
+///let c = Naive.batched_matmul(&a, &b).parallelize().eval();
+///assert_eq!(c.dims(), &[100, 2, 2]);
+///assert_eq!(c[[7, 1, 0]], 7. * 3. + 1. + 2. * 2.);
+///```
+pub trait BatchedMatMul<T> {
+    fn batched_matmul<'a, Sa, La, Sb, Lb>(
+        &'a self,
+        a: &'a Slice<T, Sa, La>,
+        b: &'a Slice<T, Sb, Lb>,
+    ) -> impl BatchedMatMulBuilder<'a, T>
+    where
+        T: 'a,
+        Sa: Shape,
+        La: Layout,
+        Sb: Shape,
+        Lb: Layout;
+}
+
+/// Builder interface for configuring batched matrix multiplications
+pub trait BatchedMatMulBuilder<'a, T: 'a> {
+    /// Distributes the batch over all available threads.
+    ///
+    /// Each thread calls the kernel of the backend on its share of the
+    /// batch, which assumes a single-threaded kernel: with a backend that
+    /// is itself multithreaded, such as Faer or a threaded BLAS, the
+    /// threads oversubscribe the cores.
+    fn parallelize(self) -> Self;
+
+    /// Multiplies the result by a scalar factor.
+    fn scale(self, factor: T) -> Self;
+
+    /// Returns a new owned tensor containing the result, of shape the
+    /// broadcast batch axes followed by (m, n).
+    fn eval(self) -> Tensor<T, DynRank>;
+
+    /// Overwrites the provided tensor with the result.
+    fn write<Sc: Shape, Lc: Layout>(self, c: &mut Slice<T, Sc, Lc>);
+
+    /// Adds the result to the provided tensor.
+    fn add_to<Sc: Shape, Lc: Layout>(self, c: &mut Slice<T, Sc, Lc>);
+}
+
+type Matrix<'a, T> = View<'a, T, (usize, usize), Strided>;
+type MatrixMut<'a, T> = ViewMut<'a, T, (usize, usize), Strided>;
+
+/// One product of a batch: the operands A and B and the output C
+type Job<'a, T> = ((Matrix<'a, T>, Matrix<'a, T>), MatrixMut<'a, T>);
+
+struct Batched<'a, B, T, Sa, La, Sb, Lb>
+where
+    Sa: Shape,
+    La: Layout,
+    Sb: Shape,
+    Lb: Layout,
+{
+    bd: &'a B,
+    alpha: T,
+    a: &'a Slice<T, Sa, La>,
+    b: &'a Slice<T, Sb, Lb>,
+    parallel: bool,
+}
+
+impl<T, B> BatchedMatMul<T> for B
+where
+    T: ComplexFloat + MulAdd<Output = T> + Send + Sync,
+    B: MatMul<T> + Sync,
+{
+    fn batched_matmul<'a, Sa, La, Sb, Lb>(
+        &'a self,
+        a: &'a Slice<T, Sa, La>,
+        b: &'a Slice<T, Sb, Lb>,
+    ) -> impl BatchedMatMulBuilder<'a, T>
+    where
+        T: 'a,
+        Sa: Shape,
+        La: Layout,
+        Sb: Shape,
+        Lb: Layout,
+    {
+        Batched {
+            bd: self,
+            alpha: T::one(),
+            a,
+            b,
+            parallel: false,
+        }
+    }
+}
+
+impl<'a, B, T, Sa, La, Sb, Lb> Batched<'a, B, T, Sa, La, Sb, Lb>
+where
+    T: ComplexFloat + MulAdd<Output = T> + Send + Sync,
+    B: MatMul<T> + Sync,
+    Sa: Shape,
+    La: Layout,
+    Sb: Shape,
+    Lb: Layout,
+{
+    fn run<Sc: Shape, Lc: Layout>(self, c: &mut Slice<T, Sc, Lc>, add: bool) {
+        let dims = batched_matmul_dims(
+            &self.a.shape().with_dims(<[usize]>::to_vec),
+            &self.b.shape().with_dims(<[usize]>::to_vec),
+        );
+        let c_dims = c.shape().with_dims(<[usize]>::to_vec);
+        assert_eq!(
+            c_dims, dims,
+            "Output shape mismatch: expected {:?}, got {:?}",
+            dims, c_dims
+        );
+
+        let batch = &dims[..dims.len() - 2];
+        let mut jobs: Vec<_> = batch_matrices(self.a, batch)
+            .into_iter()
+            .zip(batch_matrices(self.b, batch))
+            .zip(batch_matrices_mut(c, batch))
+            .collect();
+
+        let (bd, alpha) = (self.bd, self.alpha);
+        let run_chunk = |chunk: &mut [Job<'_, T>]| {
+            for ((a, b), c) in chunk {
+                let product = bd.matmul(a, b).scale(alpha);
+                if add {
+                    product.add_to(c);
+                } else {
+                    product.write(c);
+                }
+            }
+        };
+
+        let threads = if self.parallel {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            1
+        };
+        if threads <= 1 || jobs.len() <= 1 {
+            run_chunk(&mut jobs);
+        } else {
+            let chunk_size = jobs.len().div_ceil(threads);
+            thread::scope(|s| {
+                for chunk in jobs.chunks_mut(chunk_size) {
+                    s.spawn(|| run_chunk(chunk));
+                }
+            });
+        }
+    }
+}
+
+impl<'a, B, T, Sa, La, Sb, Lb> BatchedMatMulBuilder<'a, T> for Batched<'a, B, T, Sa, La, Sb, Lb>
+where
+    T: ComplexFloat + MulAdd<Output = T> + Send + Sync,
+    B: MatMul<T> + Sync,
+    Sa: Shape,
+    La: Layout,
+    Sb: Shape,
+    Lb: Layout,
+{
+    fn parallelize(mut self) -> Self {
+        self.parallel = true;
+        self
+    }
+
+    fn scale(mut self, factor: T) -> Self {
+        self.alpha = self.alpha * factor;
+        self
+    }
+
+    fn eval(self) -> Tensor<T, DynRank> {
+        let dims = batched_matmul_dims(
+            &self.a.shape().with_dims(<[usize]>::to_vec),
+            &self.b.shape().with_dims(<[usize]>::to_vec),
+        );
+        let mut c = Tensor::from_elem(DynRank::from_dims(&dims), T::zero());
+        self.run(&mut c, false);
+        c
+    }
+
+    fn write<Sc: Shape, Lc: Layout>(self, c: &mut Slice<T, Sc, Lc>) {
+        self.run(c, false);
+    }
+
+    fn add_to<Sc: Shape, Lc: Layout>(self, c: &mut Slice<T, Sc, Lc>) {
+        self.run(c, true);
+    }
+}
+
+/// Returns the shape of the batched product of tensors with dimensions
+/// `a` and `b`: the broadcast batch axes followed by (m, n)
+///
+/// # Panics
+///
+/// Panics if an operand has rank below 2, if the inner dimensions differ
+/// or if the batch axes cannot be broadcast.
+pub fn batched_matmul_dims(a: &[usize], b: &[usize]) -> Vec<usize> {
+    assert!(
+        a.len() >= 2 && b.len() >= 2,
+        "Batched operands must have rank at least 2, got {} and {}",
+        a.len(),
+        b.len()
+    );
+    let (ra, rb) = (a.len() - 2, b.len() - 2);
+    assert_eq!(
+        a[ra + 1],
+        b[rb],
+        "Dimension mismatch: A has {} columns ≠ B has {} rows",
+        a[ra + 1],
+        b[rb]
+    );
+
+    let rank = ra.max(rb);
+    let mut dims: Vec<usize> = (0..rank)
+        .map(|i| {
+            let da = (i + ra).checked_sub(rank).map_or(1, |j| a[j]);
+            let db = (i + rb).checked_sub(rank).map_or(1, |j| b[j]);
+            assert!(
+                da == db || da == 1 || db == 1,
+                "Batch axes cannot be broadcast: {:?} vs {:?}",
+                &a[..ra],
+                &b[..rb]
+            );
+            if da == 1 { db } else { da }
+        })
+        .collect();
+    dims.extend([a[ra], b[rb + 1]]);
+    dims
+}
+
+/// Offsets of the matrices of a tensor with dimensions `dims` and strides
+/// `strides` for each index of the broadcast batch axes `batch`, in
+/// row-major order
+fn batch_offsets(dims: &[usize], strides: &[isize], batch: &[usize]) -> Vec<isize> {
+    let skip = batch.len() - (dims.len() - 2);
+    let count = batch.iter().product::<usize>();
+    (0..count)
+        .map(|mut flat| {
+            let mut offset = 0;
+            for (i, &d) in batch.iter().enumerate().rev() {
+                let index = flat % d;
+                flat /= d;
+                if i >= skip && dims[i - skip] != 1 {
+                    offset += index as isize * strides[i - skip];
+                }
+            }
+            offset
+        })
+        .collect()
+}
+
+/// Views of the matrices of `a` for each index of the broadcast batch axes
+/// `batch`, the broadcast ones being repeated
+fn batch_matrices<'s, T, S: Shape, L: Layout>(
+    a: &'s Slice<T, S, L>,
+    batch: &[usize],
+) -> Vec<Matrix<'s, T>> {
+    let a = a.remap::<DynRank, Strided>();
+    let (dims, strides) = (a.dims(), a.strides());
+    let r = dims.len() - 2;
+    let mapping = StridedMapping::new((dims[r], dims[r + 1]), &strides[r..]);
+
+    // SAFETY: the offsets address matrices within `a`, borrowed for 's
+    batch_offsets(dims, strides, batch)
+        .into_iter()
+        .map(|offset| unsafe { View::new_unchecked(a.as_ptr().wrapping_offset(offset), mapping) })
+        .collect()
+}
+
+/// Mutable views of the matrices of `c`, whose leading axes must be exactly
+/// `batch`
+fn batch_matrices_mut<'s, T, S: Shape, L: Layout>(
+    c: &'s mut Slice<T, S, L>,
+    batch: &[usize],
+) -> Vec<MatrixMut<'s, T>> {
+    let mut c = c.remap_mut::<DynRank, Strided>();
+    let (dims, strides) = (c.dims().to_vec(), c.strides().to_vec());
+    assert_eq!(&dims[..dims.len() - 2], batch);
+    let r = dims.len() - 2;
+    let mapping = StridedMapping::new((dims[r], dims[r + 1]), &strides[r..]);
+    let ptr = c.as_mut_ptr();
+
+    // SAFETY: `c` is not broadcast, so that the matrices are disjoint parts
+    // of the tensor borrowed mutably for 's
+    batch_offsets(&dims, &strides, batch)
+        .into_iter()
+        .map(|offset| unsafe { ViewMut::new_unchecked(ptr.wrapping_offset(offset), mapping) })
+        .collect()
 }
 
 pub enum Axes {
@@ -174,14 +498,147 @@ pub enum Axes {
     Specific(Box<[usize]>, Box<[usize]>),
 }
 
-/// Helper for implementing contraction through matrix multiplication
+/// Helper for implementing `ContractBuilder::{write, add_to,
+/// add_to_scaled}`: C := beta * C + alpha * contract(A, B), the axes of the
+/// result being permuted by `order`. C is not read when `beta` is `None`.
+///
+/// The product is written by the backend straight into C when C, with its
+/// axes taken in the unpermuted order, can be viewed as a matrix, and
+/// computed into a temporary otherwise.
+#[allow(clippy::too_many_arguments)]
+pub fn _contract_into<T, La, Lb, Lc>(
+    bd: impl MatMul<T>,
+    a: &Slice<T, DynRank, La>,
+    b: &Slice<T, DynRank, Lb>,
+    axes: Axes,
+    alpha: T,
+    order: Option<&[usize]>,
+    beta: Option<T>,
+    c: &mut Slice<T, DynRank, Lc>,
+) where
+    T: Zero + ComplexFloat + MulAdd<Output = T>,
+    La: Layout,
+    Lb: Layout,
+    Lc: Layout,
+{
+    // C is not read when β = 0, so that NaN or Inf in C do not leak
+    let beta = beta.filter(|beta| !beta.is_zero());
+
+    // Checks the shape of C, returning the order of the result axes
+    let check = |dims: &ContractDims, c: &Slice<T, DynRank, Lc>| -> Vec<usize> {
+        let rank = dims.dims.len();
+        let order = order.map_or_else(|| (0..rank).collect(), |order| order.to_vec());
+        check_order(&order, rank);
+        let expected: Vec<usize> = order.iter().map(|&ax| dims.dims[ax]).collect();
+        assert_eq!(
+            expected,
+            c.dims(),
+            "Output shape mismatch: expected {:?}, got {:?}",
+            expected,
+            c.dims()
+        );
+        order
+    };
+
+    let (dims, done) = with_matrices(a, b, axes, |a_mat, b_mat, dims| {
+        let order = check(dims, c);
+        let product = bd.matmul(&a_mat, &b_mat).scale(alpha);
+
+        // Axis of C holding each axis of the unpermuted result
+        let mut inverse = vec![0; order.len()];
+        order
+            .iter()
+            .enumerate()
+            .for_each(|(i, &ax)| inverse[ax] = i);
+        let (rows, cols) = inverse.split_at(dims.rows);
+
+        match (matricize_mut(c, rows, cols), beta) {
+            (Some(mut c_mat), None) => product.write(&mut c_mat),
+            (Some(mut c_mat), Some(beta)) => product.add_to_scaled(&mut c_mat, beta),
+            (None, beta) => {
+                let ab = product.eval().into_shape(&dims.dims[..]);
+                let ab = ab.permute(order);
+                match beta {
+                    None => c.assign(&ab),
+                    Some(beta) => c
+                        .iter_mut()
+                        .zip(ab.iter())
+                        .for_each(|(x, &y)| *x = beta * *x + y),
+                }
+            }
+        }
+    });
+
+    // An empty operand gives a zero product
+    if done.is_none() {
+        check(&dims, c);
+        c.iter_mut()
+            .for_each(|x| *x = beta.map_or(T::zero(), |beta| beta * *x));
+    }
+}
+
+/// Helper for implementing contraction through matrix multiplication, the
+/// axes of the result being permuted by `order`
+///
+/// Operands whose layout allows it are passed to the backend as strided
+/// matrices, the others being permuted into contiguous copies.
 pub fn _contract<T: Zero + ComplexFloat + MulAdd<Output = T>, La: Layout, Lb: Layout>(
     bd: impl MatMul<T>,
     a: &Slice<T, DynRank, La>,
     b: &Slice<T, DynRank, Lb>,
     axes: Axes,
     alpha: T,
+    order: Option<&[usize]>,
 ) -> Tensor<T, DynRank> {
+    let (dims, ab) = with_matrices(a, b, axes, |a_mat, b_mat, _| {
+        bd.matmul(&a_mat, &b_mat).scale(alpha).eval()
+    });
+    let ab = match ab {
+        Some(ab) => ab.into_shape(&dims.dims[..]),
+        None => Tensor::from_elem(&dims.dims[..], T::zero()),
+    };
+    let Some(order) = order else {
+        return ab;
+    };
+
+    check_order(order, ab.rank());
+    ab.permute(order).to_tensor()
+}
+
+/// Checks that `order` is a permutation of the `rank` result axes
+fn check_order(order: &[usize], rank: usize) {
+    let mut sorted = order.to_vec();
+    sorted.sort_unstable();
+    assert!(
+        sorted.iter().copied().eq(0..rank),
+        "Output order {order:?} is not a permutation of the {rank} result axes"
+    );
+}
+
+/// Dimensions of the result of a contraction, whose first `rows` axes index
+/// the rows of the matrix product and the others its columns. Contracting
+/// all axes leaves a 1 × 1 matrix.
+struct ContractDims {
+    dims: Vec<usize>,
+    rows: usize,
+}
+
+/// Views A and B as the matrices whose product is their contraction and
+/// passes them to `f`, along with the dimensions of the result
+///
+/// Operands that cannot be viewed as matrices are permuted into copies.
+/// `f` is not called when an operand is empty, the result being zero.
+fn with_matrices<T, La, Lb, R>(
+    a: &Slice<T, DynRank, La>,
+    b: &Slice<T, DynRank, Lb>,
+    axes: Axes,
+    f: impl FnOnce(Matrix<'_, T>, Matrix<'_, T>, &ContractDims) -> R,
+) -> (ContractDims, Option<R>)
+where
+    T: Clone,
+    La: Layout,
+    Lb: Layout,
+{
     let rank_a = a.rank();
     let rank_b = b.rank();
 
@@ -214,57 +671,133 @@ pub fn _contract<T: Zero + ComplexFloat + MulAdd<Output = T>, La: Layout, Lb: La
         );
     });
 
-    let compute_keep_axes = |rank: usize, axes: &[usize]| -> Vec<usize> {
-        (0..rank).filter(|k| !axes.contains(k)).collect()
+    let keep_a: Vec<usize> = (0..rank_a).filter(|k| !axes_a.contains(k)).collect();
+    let keep_b: Vec<usize> = (0..rank_b).filter(|k| !axes_b.contains(k)).collect();
+    let out_dims: Vec<usize> = keep_a
+        .iter()
+        .map(|&ax| shape_a[ax])
+        .chain(keep_b.iter().map(|&ax| shape_b[ax]))
+        .collect();
+
+    let dims = if out_dims.is_empty() {
+        ContractDims {
+            dims: vec![1, 1],
+            rows: 1,
+        }
+    } else {
+        ContractDims {
+            dims: out_dims,
+            rows: keep_a.len(),
+        }
+    };
+    if shape_a.contains(&0) || shape_b.contains(&0) {
+        return (dims, None);
+    }
+
+    // The contracted axes may be taken in any common order, so also try
+    // those following the memory layout of either operand
+    let pairs: Vec<(usize, usize)> = axes_a.iter().copied().zip(axes_b).collect();
+    let mut by_a = pairs.clone();
+    by_a.sort_by_key(|&(ax, _)| Reverse(a.stride(ax)));
+    let mut by_b = pairs.clone();
+    by_b.sort_by_key(|&(_, ax)| Reverse(b.stride(ax)));
+
+    let copies = |pairs: &Vec<(usize, usize)>| {
+        let (axes_a, axes_b): (Vec<usize>, Vec<usize>) = pairs.iter().copied().unzip();
+        matricize(a, &keep_a, &axes_a).is_none() as usize
+            + matricize(b, &axes_b, &keep_b).is_none() as usize
+    };
+    let pairs = [pairs, by_a, by_b].into_iter().min_by_key(copies).unwrap();
+    let (axes_a, axes_b): (Vec<usize>, Vec<usize>) = pairs.into_iter().unzip();
+
+    // Operands that cannot be viewed as matrices are permuted into copies
+    let (copy_a, copy_b);
+    let a_mat = match matricize(a, &keep_a, &axes_a) {
+        Some(a_mat) => a_mat,
+        None => {
+            copy_a = a.permute([&keep_a[..], &axes_a].concat()).to_tensor();
+            let rows: Vec<usize> = (0..keep_a.len()).collect();
+            let cols: Vec<usize> = (keep_a.len()..rank_a).collect();
+            matricize(&copy_a, &rows, &cols).unwrap()
+        }
     };
-    let keep_axes_a = compute_keep_axes(rank_a, &axes_a);
-    let keep_axes_b = compute_keep_axes(rank_b, &axes_b);
-    let compute_keep_shape = |axes: &[usize], shape: &[usize]| -> Vec<usize> {
-        axes.iter().map(|&ax| shape[ax]).collect()
+    let b_mat = match matricize(b, &axes_b, &keep_b) {
+        Some(b_mat) => b_mat,
+        None => {
+            copy_b = b.permute([&axes_b[..], &keep_b].concat()).to_tensor();
+            let rows: Vec<usize> = (0..axes_b.len()).collect();
+            let cols: Vec<usize> = (axes_b.len()..rank_b).collect();
+            matricize(&copy_b, &rows, &cols).unwrap()
+        }
     };
 
-    let mut keep_shape_a = compute_keep_shape(&keep_axes_a, &shape_a);
-    let keep_shape_b = compute_keep_shape(&keep_axes_b, &shape_b);
-
-    let compute_size =
-        |axes: &[usize], shape: &[usize]| -> usize { axes.iter().map(|&k| shape[k]).product() };
-
-    let contract_size_a = compute_size(&axes_a, &shape_a);
-    let contract_size_b = compute_size(&axes_b, &shape_b);
-    let keep_size_a = compute_size(&keep_axes_a, &shape_a);
-    let keep_size_b = compute_size(&keep_axes_b, &shape_b);
-
-    let order_a: Vec<usize> = keep_axes_a.iter().chain(axes_a.iter()).copied().collect();
-    let order_b: Vec<usize> = axes_b.iter().chain(keep_axes_b.iter()).copied().collect();
-
-    let trans_a = a.permute(order_a).to_tensor();
-    let trans_b = b.permute(order_b).to_tensor();
-
-    let a_resh = trans_a.reshape([keep_size_a, contract_size_a]);
-    let b_resh = trans_b.reshape([contract_size_b, keep_size_b]);
-
-    let ab_resh = bd.matmul(&a_resh, &b_resh).scale(alpha).eval();
-
-    if keep_shape_a.is_empty() && keep_shape_b.is_empty() {
-        ab_resh.to_owned().into_dyn()
-    } else if keep_shape_a.is_empty() {
-        // Only B has non-contracted indices, reshape to keep_shape_b
-        ab_resh
-            .view(0, ..)
-            .reshape(keep_shape_b)
-            .to_owned()
-            .into_dyn()
-            .into()
-    } else if keep_shape_b.is_empty() {
-        // Only A has non-contracted indices, reshape to keep_shape_a
-        ab_resh
-            .view(.., 0)
-            .reshape(keep_shape_a)
-            .to_owned()
-            .into_dyn()
-            .into()
-    } else {
-        keep_shape_a.extend(keep_shape_b);
-        ab_resh.reshape(keep_shape_a).to_owned().into_dyn().into()
-    }
+    let result = f(a_mat, b_mat, &dims);
+    (dims, Some(result))
+}
+
+/// View of `t` as the matrix whose rows are indexed by the axes `rows` and
+/// columns by the axes `cols`, each group in row-major order.
+///
+/// Returns `None` when this needs a copy: the axes of a group must be
+/// nested in memory, and as required by GEMM, one of the two groups must
+/// be contiguous while the other one does not overlap with it. A transposed
+/// matrix is thus viewed with unit row stride, letting the backend pick the
+/// transpose flag.
+fn matricize<'s, T, L: Layout>(
+    t: &'s Slice<T, DynRank, L>,
+    rows: &[usize],
+    cols: &[usize],
+) -> Option<Matrix<'s, T>> {
+    let t = t.remap::<DynRank, Strided>();
+    let mapping = matrix_mapping(t.dims(), t.strides(), rows, cols)?;
+
+    // SAFETY: the mapping addresses the elements of `t`, borrowed for 's
+    Some(unsafe { View::new_unchecked(t.as_ptr(), mapping) })
+}
+
+/// Mutable counterpart of [`matricize`]
+fn matricize_mut<'s, T, L: Layout>(
+    t: &'s mut Slice<T, DynRank, L>,
+    rows: &[usize],
+    cols: &[usize],
+) -> Option<MatrixMut<'s, T>> {
+    let mut t = t.remap_mut::<DynRank, Strided>();
+    let mapping = matrix_mapping(t.dims(), t.strides(), rows, cols)?;
+
+    // SAFETY: the mapping addresses distinct elements of `t`, borrowed
+    // mutably for 's
+    Some(unsafe { ViewMut::new_unchecked(t.as_mut_ptr(), mapping) })
+}
+
+/// Strided mapping of the matrix view of [`matricize`]
+fn matrix_mapping(
+    dims: &[usize],
+    strides: &[isize],
+    rows: &[usize],
+    cols: &[usize],
+) -> Option<StridedMapping<(usize, usize)>> {
+    // Size and innermost stride of a group, which is None for a single element
+    let merge = |group: &[usize]| -> Option<(usize, Option<isize>)> {
+        let (mut size, mut inner, mut next) = (1, None, None);
+        for &ax in group.iter().rev().filter(|&&ax| dims[ax] != 1) {
+            if next.is_some_and(|next| next != strides[ax]) {
+                return None;
+            }
+            inner.get_or_insert(strides[ax]);
+            next = Some(strides[ax] * dims[ax] as isize);
+            size *= dims[ax];
+        }
+        Some((size, inner))
+    };
+    let (m, row_stride) = merge(rows)?;
+    let (n, col_stride) = merge(cols)?;
+
+    let strides = match (row_stride, col_stride) {
+        (row_stride, None | Some(1)) if row_stride.unwrap_or(n as isize) >= n as isize => {
+            [row_stride.unwrap_or(n as isize), 1]
+        }
+        (None | Some(1), Some(col_stride)) if col_stride >= m as isize => [1, col_stride],
+        _ => return None,
+    };
+    Some(StridedMapping::new((m, n), &strides))
 }
@@ -183,10 +183,42 @@
 //! // |1+2i| + |2+3i| = (|1|+|2|) + (|2|+|3|) = 8
 //! assert_eq!(norm, 8.0);
 //! ```
+//! # Banded and Packed Matrices
+//!
+//! ```rust
+//! use mdarray::tensor;
+//! use mdarray_linalg::prelude::*;
+//! use mdarray_linalg::Naive;
+//! use mdarray_linalg::banded::BandMatrix;
+//! use mdarray_linalg::matmul::Triangle;
+//! use mdarray_linalg::packed::PackedMatrix;
+//!
+//! let a = tensor![[2., 1., 0.],
+//!                 [1., 2., 1.],
+//!                 [0., 1., 2.]];
+//! let x = tensor![1., 1., 1.];
+//!
+//! // General banded product: y := α·A·x + β·y
+//! let mut y = tensor![1., 1., 1.];
+//! Naive.gbmv(1., &BandMatrix::from_dense(&a, 1, 1), &x, 1., &mut y);
+//! assert_eq!(y, tensor![4., 5., 4.]);
+//!
+//! // Symmetric product, only the lower triangle being stored
+//! let mut y = tensor![0., 0., 0.];
+//! Naive.hpmv(2., &PackedMatrix::from_dense(&a, Triangle::Lower), &x, 0., &mut y);
+//! assert_eq!(y, tensor![6., 8., 6.]);
+//!
+//! // Triangular product in place: x := A·x
+//! let mut x = tensor![1., 1., 1.];
+//! Naive.tbmv(&BandMatrix::from_dense(&a, 0, 1), Triangle::Upper, false, &mut x);
+//! assert_eq!(x, tensor![3., 3., 2.]);
+//! ```
 use mdarray::{Dim, Layout, Shape, Slice, Tensor};
 use num_complex::ComplexFloat;
 
+use crate::banded::BandMatrix;
 use crate::matmul::{Triangle, Type};
+use crate::packed::PackedMatrix;
 
 /// Matrix-vector multiplication and transformations
 pub trait MatVec<T, D0: Dim, D1: Dim> {
@@ -262,6 +294,75 @@ pub trait VecOps<T: ComplexFloat, D1: Dim> {
         T: ComplexFloat;
 }
 
+/// Matrix-vector products with banded and packed matrices
+///
+/// Banded matrices are held in a [`BandMatrix`] and packed triangles in a
+/// [`PackedMatrix`]. For real types, the Hermitian products are the
+/// symmetric ones (`sbmv`, `spmv`). The vectors must have length n,
+/// otherwise these functions panic.
+pub trait StructuredMatVec<T: ComplexFloat> {
+    /// `y := α·A·x + β·y` for a general banded A
+    fn gbmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    );
+
+    /// `y := α·A·x + β·y` for a Hermitian banded A, of which only the
+    /// diagonal and the `kl` subdiagonals are referenced
+    fn hbmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    );
+
+    /// `x := A·x` for a triangular banded A, made of the diagonal and the
+    /// `ku` superdiagonals (upper) or the `kl` subdiagonals (lower). With
+    /// `unit_diag`, the diagonal is taken as one and not referenced.
+    fn tbmv<D: Dim, Lx: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        triangle: Triangle,
+        unit_diag: bool,
+        x: &mut Slice<T, (D,), Lx>,
+    );
+
+    /// `y := α·A·x + β·y` for a Hermitian A of which one triangle is packed
+    fn hpmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &PackedMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    );
+
+    /// `x := A·x` for a packed triangular A. With `unit_diag`, the diagonal
+    /// is taken as one and not referenced.
+    fn tpmv<D: Dim, Lx: Layout>(
+        &self,
+        a: &PackedMatrix<T>,
+        unit_diag: bool,
+        x: &mut Slice<T, (D,), Lx>,
+    );
+}
+
+/// Checks that x and y have the length n of the structured matrix
+pub fn structured_matvec_dims<T, D: Dim, Lx: Layout, Ly: Layout>(
+    n: usize,
+    x: &Slice<T, (D,), Lx>,
+    y: &Slice<T, (D,), Ly>,
+) {
+    assert_eq!(x.shape().dim(0), n, "x must have length n");
+    assert_eq!(y.shape().dim(0), n, "y must have length n");
+}
+
 /// Argmax for tensors, unlike other traits: it requires `T: PartialOrd` and works on tensor of any rank.
 pub trait Argmax<T: ComplexFloat + std::cmp::PartialOrd> {
     fn argmax_write<Lx: Layout, S: Shape>(
@@ -0,0 +1,49 @@
+use mdarray::{Dim, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use super::simple::{naive_gbsv, naive_gtsv, naive_pbsv, naive_ptsv};
+use crate::{
+    Naive,
+    banded::{BandMatrix, BandSolve, BandSolveError, band_dims, tridiagonal_dims},
+};
+
+impl<T: ComplexFloat> BandSolve<T> for Naive {
+    fn solve_tridiagonal<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        dl: &[T],
+        d: &[T],
+        du: &[T],
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        tridiagonal_dims(d.len(), &[dl.len(), du.len()], b)?;
+        naive_gtsv(dl, d, du, b)
+    }
+
+    fn solve_tridiagonal_pd<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        d: &[T::Real],
+        e: &[T],
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        tridiagonal_dims(d.len(), &[e.len()], b)?;
+        naive_ptsv(d, e, b)
+    }
+
+    fn solve_band<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        band_dims(a, b)?;
+        naive_gbsv(a, b)
+    }
+
+    fn solve_band_pd<D0: Dim, D1: Dim, L: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        b: &mut Slice<T, (D0, D1), L>,
+    ) -> Result<(), BandSolveError> {
+        band_dims(a, b)?;
+        naive_pbsv(a, b)
+    }
+}
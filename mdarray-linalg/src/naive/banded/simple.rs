@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+
+use mdarray::{Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, Zero};
+
+use crate::banded::{BandMatrix, BandSolveError};
+
+fn real_to<T: ComplexFloat>(x: T::Real) -> T {
+    <T as NumCast>::from(x).expect("real values can be cast to T")
+}
+
+/// Thomas algorithm: Gaussian elimination without pivoting on a
+/// tridiagonal matrix, each column of `b` being overwritten with the
+/// solution
+///
+/// Without pivoting, a zero pivot is reported as
+/// [`BandSolveError::SingularMatrix`] even when the matrix is not singular,
+/// for instance for [[0, 1], [1, 0]]. Such systems are solved by
+/// [`naive_gbsv`] with kl = ku = 1.
+pub fn naive_gtsv<T: ComplexFloat, D0: Dim, D1: Dim, L: Layout>(
+    dl: &[T],
+    d: &[T],
+    du: &[T],
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError> {
+    let n = d.len();
+    let nrhs = b.shape().dim(1);
+    if n == 0 {
+        return Ok(());
+    }
+
+    // Diagonal of U, the superdiagonal of U being `du`
+    let mut u = vec![T::zero(); n];
+    let mut l = vec![T::zero(); n.saturating_sub(1)];
+    u[0] = d[0];
+    for i in 1..n {
+        if u[i - 1].is_zero() {
+            return Err(BandSolveError::SingularMatrix { diagonal: i });
+        }
+        l[i - 1] = dl[i - 1] / u[i - 1];
+        u[i] = d[i] - l[i - 1] * du[i - 1];
+    }
+    if u[n - 1].is_zero() {
+        return Err(BandSolveError::SingularMatrix { diagonal: n });
+    }
+
+    for c in 0..nrhs {
+        for i in 1..n {
+            b[[i, c]] = b[[i, c]] - l[i - 1] * b[[i - 1, c]];
+        }
+        b[[n - 1, c]] = b[[n - 1, c]] / u[n - 1];
+        for i in (0..n - 1).rev() {
+            b[[i, c]] = (b[[i, c]] - du[i] * b[[i + 1, c]]) / u[i];
+        }
+    }
+
+    Ok(())
+}
+
+/// L D Lᴴ factorization of a Hermitian positive definite tridiagonal
+/// matrix followed by the solve, each column of `b` being overwritten
+/// with the solution
+pub fn naive_ptsv<T: ComplexFloat, D0: Dim, D1: Dim, L: Layout>(
+    d: &[T::Real],
+    e: &[T],
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError> {
+    let n = d.len();
+    let nrhs = b.shape().dim(1);
+
+    // D and the subdiagonal of the unit lower bidiagonal L
+    let mut dk = vec![T::Real::zero(); n];
+    let mut l = vec![T::zero(); n.saturating_sub(1)];
+    for k in 0..n {
+        dk[k] = d[k];
+        if k > 0 {
+            dk[k] = dk[k] - (e[k - 1] * e[k - 1].conj()).re() / dk[k - 1];
+        }
+        if dk[k] <= T::Real::zero() || Float::is_nan(dk[k]) {
+            return Err(BandSolveError::NotPositiveDefinite { order: k + 1 });
+        }
+        if k + 1 < n {
+            l[k] = e[k] / real_to::<T>(dk[k]);
+        }
+    }
+
+    for c in 0..nrhs {
+        for i in 1..n {
+            b[[i, c]] = b[[i, c]] - l[i - 1] * b[[i - 1, c]];
+        }
+        for i in 0..n {
+            b[[i, c]] = b[[i, c]] / real_to::<T>(dk[i]);
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            b[[i, c]] = b[[i, c]] - l[i].conj() * b[[i + 1, c]];
+        }
+    }
+
+    Ok(())
+}
+
+/// Banded LU factorization with partial pivoting followed by the solve,
+/// each column of `b` being overwritten with the solution
+pub fn naive_gbsv<T: ComplexFloat, D0: Dim, D1: Dim, L: Layout>(
+    a: &BandMatrix<T>,
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError> {
+    let (n, kl) = (a.n(), a.kl());
+    let nrhs = b.shape().dim(1);
+
+    // Row interchanges widen the upper band of U to kl + ku
+    let ku = kl + a.ku();
+    let mut w = BandMatrix::zeros(n, kl, ku);
+    for j in 0..n {
+        for i in j.saturating_sub(a.ku())..(j + kl + 1).min(n) {
+            w.set(i, j, a.get(i, j));
+        }
+    }
+
+    for k in 0..n {
+        let last = (k + kl).min(n - 1);
+        let cols = k..(k + ku + 1).min(n);
+
+        let p = (k..=last)
+            .max_by(|&i, &j| {
+                w.get(i, k)
+                    .abs()
+                    .partial_cmp(&w.get(j, k).abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap_or(k);
+        if w.get(p, k).is_zero() {
+            return Err(BandSolveError::SingularMatrix { diagonal: k + 1 });
+        }
+        if p != k {
+            for j in cols.clone() {
+                let (x, y) = (w.get(k, j), w.get(p, j));
+                w.set(k, j, y);
+                w.set(p, j, x);
+            }
+            for c in 0..nrhs {
+                let x = b[[k, c]];
+                b[[k, c]] = b[[p, c]];
+                b[[p, c]] = x;
+            }
+        }
+
+        let pivot = w.get(k, k);
+        for i in k + 1..=last {
+            let l = w.get(i, k) / pivot;
+            if l.is_zero() {
+                continue;
+            }
+            for j in cols.clone().skip(1) {
+                w.set(i, j, w.get(i, j) - l * w.get(k, j));
+            }
+            for c in 0..nrhs {
+                b[[i, c]] = b[[i, c]] - l * b[[k, c]];
+            }
+        }
+    }
+
+    for c in 0..nrhs {
+        for i in (0..n).rev() {
+            let mut x = b[[i, c]];
+            for j in i + 1..(i + ku + 1).min(n) {
+                x = x - w.get(i, j) * b[[j, c]];
+            }
+            b[[i, c]] = x / w.get(i, i);
+        }
+    }
+
+    Ok(())
+}
+
+/// Banded Cholesky factorization A = L Lᴴ followed by the solve, each
+/// column of `b` being overwritten with the solution. Only the diagonal
+/// and the `kl` subdiagonals of A are referenced.
+pub fn naive_pbsv<T: ComplexFloat, D0: Dim, D1: Dim, L: Layout>(
+    a: &BandMatrix<T>,
+    b: &mut Slice<T, (D0, D1), L>,
+) -> Result<(), BandSolveError> {
+    let (n, kd) = (a.n(), a.kl());
+    let nrhs = b.shape().dim(1);
+
+    let mut l = BandMatrix::<T>::zeros(n, kd, 0);
+    for j in 0..n {
+        let first = j.saturating_sub(kd);
+
+        let mut s = a.get(j, j).re();
+        for k in first..j {
+            s = s - (l.get(j, k) * l.get(j, k).conj()).re();
+        }
+        if s <= T::Real::zero() || Float::is_nan(s) {
+            return Err(BandSolveError::NotPositiveDefinite { order: j + 1 });
+        }
+        let ljj = real_to::<T>(Float::sqrt(s));
+        l.set(j, j, ljj);
+
+        for i in j + 1..(j + kd + 1).min(n) {
+            let mut x = a.get(i, j);
+            for k in i.saturating_sub(kd)..j {
+                x = x - l.get(i, k) * l.get(j, k).conj();
+            }
+            l.set(i, j, x / ljj);
+        }
+    }
+
+    for c in 0..nrhs {
+        for i in 0..n {
+            let mut x = b[[i, c]];
+            for k in i.saturating_sub(kd)..i {
+                x = x - l.get(i, k) * b[[k, c]];
+            }
+            b[[i, c]] = x / l.get(i, i);
+        }
+        for i in (0..n).rev() {
+            let mut x = b[[i, c]];
+            for k in i + 1..(i + kd + 1).min(n) {
+                x = x - l.get(k, i).conj() * b[[k, c]];
+            }
+            b[[i, c]] = x / l.get(i, i).conj();
+        }
+    }
+
+    Ok(())
+}
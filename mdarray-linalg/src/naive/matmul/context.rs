@@ -5,7 +5,10 @@ use num_traits::{MulAdd, One, Zero};
 use super::simple::naive_matmul;
 use crate::{
     Naive,
-    matmul::{_contract, Axes, ContractBuilder, MatMul, MatMulBuilder, Side, Triangle, Type},
+    matmul::{
+        _contract, _contract_into, Axes, ContractBuilder, MatMul, MatMulBuilder, Side, Triangle,
+        Type,
+    },
 };
 
 struct NaiveMatMulBuilder<'a, T, La, Lb, D0, D1, D2>
@@ -30,6 +33,7 @@ where
     a: &'a Slice<T, DynRank, La>,
     b: &'a Slice<T, DynRank, Lb>,
     axes: Axes,
+    order: Option<Box<[usize]>>,
 }
 
 impl<'a, T, La, Lb, D0, D1, D2> MatMulBuilder<'a, T, La, Lb, D0, D1, D2>
@@ -112,12 +116,53 @@ where
         self
     }
 
+    fn output_order(mut self, order: impl Into<Box<[usize]>>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
     fn eval(self) -> Tensor<T> {
-        _contract(Naive, self.a, self.b, self.axes, self.alpha)
+        _contract(
+            Naive,
+            self.a,
+            self.b,
+            self.axes,
+            self.alpha,
+            self.order.as_deref(),
+        )
     }
 
-    fn write(self, _c: &mut Slice<T>) {
-        todo!()
+    fn write<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>) {
+        let order = self.order.as_deref();
+        _contract_into(Naive, self.a, self.b, self.axes, self.alpha, order, None, c);
+    }
+
+    fn add_to<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>) {
+        let order = self.order.as_deref();
+        _contract_into(
+            Naive,
+            self.a,
+            self.b,
+            self.axes,
+            self.alpha,
+            order,
+            Some(T::one()),
+            c,
+        );
+    }
+
+    fn add_to_scaled<Lc: Layout>(self, c: &mut Slice<T, DynRank, Lc>, beta: T) {
+        let order = self.order.as_deref();
+        _contract_into(
+            Naive,
+            self.a,
+            self.b,
+            self.axes,
+            self.alpha,
+            order,
+            Some(beta),
+            c,
+        );
     }
 }
 
@@ -160,6 +205,7 @@ where
             a,
             b,
             axes: Axes::All,
+            order: None,
         }
     }
 
@@ -182,6 +228,7 @@ where
             a,
             b,
             axes: Axes::LastFirst { k: (n) },
+            order: None,
         }
     }
 
@@ -206,6 +253,7 @@ where
             a,
             b,
             axes: Axes::Specific(axes_a.into(), axes_b.into()),
+            order: None,
         }
     }
 }
@@ -3,7 +3,8 @@ use num_complex::ComplexFloat;
 use num_traits::MulAdd;
 
 /// Textbook implementation of matrix multiplication, useful for
-/// debugging and simple tests without relying on a external backend
+/// debugging and simple tests without relying on a external backend.
+/// As in BLAS, C is not read when `beta` is zero.
 pub fn naive_matmul<
     T: ComplexFloat + MulAdd<Output = T>,
     La: Layout,
@@ -34,7 +35,11 @@ pub fn naive_matmul<
 
     for i in 0..d0 {
         for j in 0..d2 {
-            c[[i, j]] = beta * c[[i, j]];
+            c[[i, j]] = if beta.is_zero() {
+                T::zero()
+            } else {
+                beta * c[[i, j]]
+            };
         }
     }
 
@@ -4,11 +4,13 @@ use mdarray::{Dim, Layout, Shape, Slice, Tensor};
 use num_complex::ComplexFloat;
 use num_traits::Zero;
 
-use super::simple::naive_outer;
+use super::simple::{naive_gbmv, naive_hbmv, naive_hpmv, naive_outer, naive_tbmv, naive_tpmv};
 use crate::{
     Naive,
+    banded::BandMatrix,
     matmul::{Triangle, Type},
-    matvec::{Argmax, MatVec, MatVecBuilder, Outer, OuterBuilder, VecOps},
+    matvec::{Argmax, MatVec, MatVecBuilder, Outer, OuterBuilder, StructuredMatVec, VecOps},
+    packed::PackedMatrix,
     utils::unravel_index,
 };
 
@@ -393,3 +395,57 @@ where
         naive_outer(a, self.x, self.y, self.alpha, Some(ty), Some(tr));
     }
 }
+
+impl<T: ComplexFloat> StructuredMatVec<T> for Naive {
+    fn gbmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    ) {
+        naive_gbmv(alpha, a, x, beta, y);
+    }
+
+    fn hbmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    ) {
+        naive_hbmv(alpha, a, x, beta, y);
+    }
+
+    fn tbmv<D: Dim, Lx: Layout>(
+        &self,
+        a: &BandMatrix<T>,
+        triangle: Triangle,
+        unit_diag: bool,
+        x: &mut Slice<T, (D,), Lx>,
+    ) {
+        naive_tbmv(a, triangle, unit_diag, x);
+    }
+
+    fn hpmv<D: Dim, Lx: Layout, Ly: Layout>(
+        &self,
+        alpha: T,
+        a: &PackedMatrix<T>,
+        x: &Slice<T, (D,), Lx>,
+        beta: T,
+        y: &mut Slice<T, (D,), Ly>,
+    ) {
+        naive_hpmv(alpha, a, x, beta, y);
+    }
+
+    fn tpmv<D: Dim, Lx: Layout>(
+        &self,
+        a: &PackedMatrix<T>,
+        unit_diag: bool,
+        x: &mut Slice<T, (D,), Lx>,
+    ) {
+        naive_tpmv(a, unit_diag, x);
+    }
+}
@@ -1,7 +1,17 @@
+use std::ops::Range;
+
 use mdarray::{Dim, Layout, Shape, Slice};
 use num_complex::ComplexFloat;
+use num_traits::NumCast;
 
+use crate::banded::BandMatrix;
 use crate::matmul::{Triangle, Type};
+use crate::matvec::structured_matvec_dims;
+use crate::packed::PackedMatrix;
+
+fn real_to<T: ComplexFloat>(x: T::Real) -> T {
+    <T as NumCast>::from(x).expect("real values can be cast to T")
+}
 
 /// Performs naively A + α·x·yᵀ (or α·x·xᵀ or x·x†)
 pub fn naive_outer<T: ComplexFloat, La: Layout, Lx: Layout, Ly: Layout, D0, D1>(
@@ -78,3 +88,147 @@ pub fn naive_outer<T: ComplexFloat, La: Layout, Lx: Layout, Ly: Layout, D0, D1>(
         }
     }
 }
+
+/// y := α·A·x + β·y, A(i, j) being given by `a` and row i of A being
+/// nonzero over `cols(i)` only. As in BLAS, y is not read when β = 0.
+fn naive_structured_mv<T: ComplexFloat, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: impl Fn(usize, usize) -> T,
+    cols: impl Fn(usize) -> Range<usize>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) {
+    for i in 0..y.shape().dim(0) {
+        let ax = cols(i).fold(T::zero(), |acc, j| acc + a(i, j) * x[[j]]);
+        let by = if beta.is_zero() {
+            T::zero()
+        } else {
+            beta * y[[i]]
+        };
+        y[[i]] = alpha * ax + by;
+    }
+}
+
+/// x := A·x for a triangular A with `k` off-diagonals, A(i, j) being
+/// given by `a`
+fn naive_triangular_mv<T: ComplexFloat, D: Dim, Lx: Layout>(
+    a: impl Fn(usize, usize) -> T,
+    triangle: Triangle,
+    k: usize,
+    unit_diag: bool,
+    x: &mut Slice<T, (D,), Lx>,
+) {
+    let n = x.shape().dim(0);
+    // Row i only reads entries of x that are still to be overwritten
+    let row = |x: &Slice<T, (D,), Lx>, i: usize, cols: Range<usize>| {
+        let diag = if unit_diag { x[[i]] } else { a(i, i) * x[[i]] };
+        cols.fold(diag, |acc, j| acc + a(i, j) * x[[j]])
+    };
+    match triangle {
+        Triangle::Upper => {
+            for i in 0..n {
+                x[[i]] = row(x, i, i + 1..(i + k + 1).min(n));
+            }
+        }
+        Triangle::Lower => {
+            for i in (0..n).rev() {
+                x[[i]] = row(x, i, i.saturating_sub(k)..i);
+            }
+        }
+    }
+}
+
+/// Performs naively y := α·A·x + β·y for a general banded A
+pub fn naive_gbmv<T: ComplexFloat, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &BandMatrix<T>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) {
+    let (n, kl, ku) = (a.n(), a.kl(), a.ku());
+    structured_matvec_dims(n, x, y);
+    naive_structured_mv(
+        alpha,
+        |i, j| a.get(i, j),
+        |i| i.saturating_sub(kl)..(i + ku + 1).min(n),
+        x,
+        beta,
+        y,
+    );
+}
+
+/// Performs naively y := α·A·x + β·y for a Hermitian banded A given by
+/// its diagonal and `kl` subdiagonals
+pub fn naive_hbmv<T: ComplexFloat, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &BandMatrix<T>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) {
+    let (n, kd) = (a.n(), a.kl());
+    structured_matvec_dims(n, x, y);
+    let hermitian = |i: usize, j: usize| match i.cmp(&j) {
+        std::cmp::Ordering::Greater => a.get(i, j),
+        std::cmp::Ordering::Less => a.get(j, i).conj(),
+        std::cmp::Ordering::Equal => real_to(a.get(i, i).re()),
+    };
+    naive_structured_mv(
+        alpha,
+        hermitian,
+        |i| i.saturating_sub(kd)..(i + kd + 1).min(n),
+        x,
+        beta,
+        y,
+    );
+}
+
+/// Performs naively x := A·x for a triangular banded A
+pub fn naive_tbmv<T: ComplexFloat, D: Dim, Lx: Layout>(
+    a: &BandMatrix<T>,
+    triangle: Triangle,
+    unit_diag: bool,
+    x: &mut Slice<T, (D,), Lx>,
+) {
+    assert_eq!(x.shape().dim(0), a.n(), "x must have length n");
+    let k = match triangle {
+        Triangle::Upper => a.ku(),
+        Triangle::Lower => a.kl(),
+    };
+    naive_triangular_mv(|i, j| a.get(i, j), triangle, k, unit_diag, x);
+}
+
+/// Performs naively y := α·A·x + β·y for a Hermitian A in packed storage
+pub fn naive_hpmv<T: ComplexFloat, D: Dim, Lx: Layout, Ly: Layout>(
+    alpha: T,
+    a: &PackedMatrix<T>,
+    x: &Slice<T, (D,), Lx>,
+    beta: T,
+    y: &mut Slice<T, (D,), Ly>,
+) {
+    let n = a.n();
+    structured_matvec_dims(n, x, y);
+    let hermitian = |i: usize, j: usize| {
+        if i == j {
+            real_to(a.get(i, i).re())
+        } else if a.in_triangle(i, j) {
+            a.get(i, j)
+        } else {
+            a.get(j, i).conj()
+        }
+    };
+    naive_structured_mv(alpha, hermitian, |_| 0..n, x, beta, y);
+}
+
+/// Performs naively x := A·x for a packed triangular A
+pub fn naive_tpmv<T: ComplexFloat, D: Dim, Lx: Layout>(
+    a: &PackedMatrix<T>,
+    unit_diag: bool,
+    x: &mut Slice<T, (D,), Lx>,
+) {
+    let n = a.n();
+    assert_eq!(x.shape().dim(0), n, "x must have length n");
+    naive_triangular_mv(|i, j| a.get(i, j), a.triangle(), n, unit_diag, x);
+}
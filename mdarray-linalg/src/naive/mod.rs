@@ -1,6 +1,10 @@
+pub mod banded;
 pub mod matmul;
 pub mod matvec;
+pub mod norm;
 pub mod qr;
+pub mod sylvester;
+pub mod trisolve;
 
 /// Simple backend, mostly for demonstratration purposes
 #[derive(Default)]
@@ -0,0 +1,118 @@
+use mdarray::{Dim, Layout, Slice};
+use num_complex::ComplexFloat;
+use num_traits::{One, Zero};
+
+use super::simple::{naive_norm, naive_norm1_estimate};
+use crate::{
+    Naive,
+    matmul::Triangle,
+    naive::trisolve::naive_trsv,
+    norm::{
+        Cond, MatrixNorm, Norm, NormError, has_zero_diagonal, rcond_dims, rcond_from_norms,
+        rcond_lu_dims,
+    },
+    trisolve::{Transpose, TriSolveOptions},
+};
+
+fn tri_options<T: ComplexFloat>(
+    triangle: Triangle,
+    trans: Transpose,
+    unit_diag: bool,
+) -> TriSolveOptions<T> {
+    TriSolveOptions {
+        triangle,
+        trans,
+        unit_diag,
+        ..Default::default()
+    }
+}
+
+impl<T: ComplexFloat> MatrixNorm<T> for Naive {
+    fn matrix_norm<L: Layout, D0: Dim, D1: Dim>(
+        &self,
+        a: &Slice<T, (D0, D1), L>,
+        norm: Norm,
+    ) -> Result<T::Real, NormError> {
+        Ok(naive_norm(a, norm))
+    }
+}
+
+impl<T: ComplexFloat> Cond<T> for Naive {
+    fn rcond_lu<Ll: Layout, Lu: Layout, D: Dim>(
+        &self,
+        l: &Slice<T, (D, D), Ll>,
+        u: &Slice<T, (D, D), Lu>,
+        anorm: T::Real,
+        norm: Norm,
+    ) -> Result<T::Real, NormError> {
+        // ‖A⁻¹‖∞ = ‖A⁻ᴴ‖₁, so the ∞-norm estimate swaps both products
+        let inf = match norm {
+            Norm::One => false,
+            Norm::Inf => true,
+            other => return Err(NormError::UnsupportedNorm(other)),
+        };
+        let n = rcond_lu_dims(l, u)?;
+        if n == 0 {
+            return Ok(T::Real::one());
+        }
+        if has_zero_diagonal(u) {
+            return Ok(T::Real::zero());
+        }
+
+        // A⁻¹ = U⁻¹ L⁻¹ P and A⁻ᴴ = L⁻ᴴ U⁻ᴴ P, the permutation leaves the
+        // norms unchanged
+        let ainvnm = naive_norm1_estimate(n, |x, adjoint| {
+            if adjoint != inf {
+                let opts_u = tri_options(Triangle::Upper, Transpose::ConjTrans, false);
+                let opts_l = tri_options(Triangle::Lower, Transpose::ConjTrans, true);
+                naive_trsv(&opts_u, u, x)
+                    .and_then(|()| naive_trsv(&opts_l, l, x))
+                    .expect("the diagonal of U is nonzero");
+            } else {
+                let opts_l = tri_options(Triangle::Lower, Transpose::NoTrans, true);
+                let opts_u = tri_options(Triangle::Upper, Transpose::NoTrans, false);
+                naive_trsv(&opts_l, l, x)
+                    .and_then(|()| naive_trsv(&opts_u, u, x))
+                    .expect("the diagonal of U is nonzero");
+            }
+        });
+
+        Ok(rcond_from_norms(anorm, ainvnm))
+    }
+
+    fn rcond_lu_packed<L: Layout, D: Dim>(
+        &self,
+        lu: &Slice<T, (D, D), L>,
+        anorm: T::Real,
+        norm: Norm,
+    ) -> Result<T::Real, NormError> {
+        // Only the strict lower triangle of L and the upper triangle of U
+        // are referenced
+        self.rcond_lu(lu, lu, anorm, norm)
+    }
+
+    fn rcond_cholesky<L: Layout, D: Dim>(
+        &self,
+        l: &Slice<T, (D, D), L>,
+        anorm: T::Real,
+    ) -> Result<T::Real, NormError> {
+        let n = rcond_dims(l)?;
+        if n == 0 {
+            return Ok(T::Real::one());
+        }
+        if has_zero_diagonal(l) {
+            return Ok(T::Real::zero());
+        }
+
+        // A⁻¹ = L⁻ᴴ L⁻¹ is Hermitian
+        let ainvnm = naive_norm1_estimate(n, |x, _| {
+            let opts = tri_options(Triangle::Lower, Transpose::NoTrans, false);
+            let opts_h = tri_options(Triangle::Lower, Transpose::ConjTrans, false);
+            naive_trsv(&opts, l, x)
+                .and_then(|()| naive_trsv(&opts_h, l, x))
+                .expect("the diagonal of L is nonzero");
+        });
+
+        Ok(rcond_from_norms(anorm, ainvnm))
+    }
+}
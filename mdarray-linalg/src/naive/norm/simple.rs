@@ -0,0 +1,205 @@
+use mdarray::{DTensor, Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::norm::Norm;
+
+/// Maximum number of sweeps of the one-sided Jacobi method
+const MAX_SWEEPS: usize = 60;
+
+/// Maximum number of power-like iterations of the 1-norm estimator
+const MAX_ESTIMATOR_ITERATIONS: usize = 5;
+
+fn cast<T: ComplexFloat>(x: T::Real) -> T {
+    <T as NumCast>::from(x).expect("real values can be cast to T")
+}
+
+/// Computes a matrix norm by direct summation, or from the singular values
+pub fn naive_norm<T, D0, D1, L>(a: &Slice<T, (D0, D1), L>, norm: Norm) -> T::Real
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let zero = T::Real::zero();
+
+    match norm {
+        Norm::One => (0..n)
+            .map(|j| (0..m).fold(zero, |acc, i| acc + a[[i, j]].abs()))
+            .fold(zero, Float::max),
+        Norm::Inf => (0..m)
+            .map(|i| (0..n).fold(zero, |acc, j| acc + a[[i, j]].abs()))
+            .fold(zero, Float::max),
+        Norm::Frobenius => Float::sqrt(a.iter().fold(zero, |acc, x| acc + x.abs() * x.abs())),
+        Norm::MaxAbs => a.iter().fold(zero, |acc, x| acc.max(x.abs())),
+        Norm::Spectral => naive_singular_values(a).first().copied().unwrap_or(zero),
+        Norm::Nuclear => naive_singular_values(a)
+            .into_iter()
+            .fold(zero, |acc, s| acc + s),
+    }
+}
+
+/// Singular values of A in descending order, computed with the one-sided
+/// Jacobi method: plane rotations are applied to pairs of columns until
+/// they are all mutually orthogonal, the singular values being then the
+/// column norms.
+pub fn naive_singular_values<T, D0, D1, L>(a: &Slice<T, (D0, D1), L>) -> Vec<T::Real>
+where
+    T: ComplexFloat,
+    D0: Dim,
+    D1: Dim,
+    L: Layout,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+
+    // Work on the shortest side: Aᴴ has the same singular values as A
+    let mut cols: Vec<Vec<T>> = if m >= n {
+        (0..n)
+            .map(|j| (0..m).map(|i| a[[i, j]]).collect())
+            .collect()
+    } else {
+        (0..m)
+            .map(|i| (0..n).map(|j| a[[i, j]].conj()).collect())
+            .collect()
+    };
+    let k = cols.len();
+
+    let sq_norm = |c: &[T]| {
+        c.iter()
+            .fold(T::Real::zero(), |acc, x| acc + x.abs() * x.abs())
+    };
+    let eps = T::Real::epsilon();
+    let two = T::Real::one() + T::Real::one();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..k {
+            for q in p + 1..k {
+                let alpha = sq_norm(&cols[p]);
+                let beta = sq_norm(&cols[q]);
+                let gamma = cols[p]
+                    .iter()
+                    .zip(&cols[q])
+                    .fold(T::zero(), |acc, (&x, &y)| acc + x.conj() * y);
+                let g = gamma.abs();
+
+                if g.is_zero() || g <= eps * Float::sqrt(alpha * beta) {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotation zeroing the (real) inner product of c_p and
+                // c_q e^{-iφ}, with e^{iφ} = γ / |γ|
+                let zeta = (beta - alpha) / (two * g);
+                let t =
+                    zeta.signum() / (Float::abs(zeta) + Float::sqrt(T::Real::one() + zeta * zeta));
+                let c = T::Real::one() / Float::sqrt(T::Real::one() + t * t);
+                let (c, s) = (cast::<T>(c), cast::<T>(c * t));
+                let phase = (gamma / cast::<T>(g)).conj();
+
+                for i in 0..cols[p].len() {
+                    let xp = cols[p][i];
+                    let xq = cols[q][i] * phase;
+                    cols[p][i] = c * xp - s * xq;
+                    cols[q][i] = s * xp + c * xq;
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut s: Vec<T::Real> = cols.iter().map(|c| Float::sqrt(sq_norm(c))).collect();
+    s.sort_by(|x, y| y.partial_cmp(x).unwrap_or(std::cmp::Ordering::Equal));
+    s
+}
+
+/// Estimates ‖B‖₁ for an n × n matrix B only known through the products
+/// `apply(x, false)`: x ← B x and `apply(x, true)`: x ← Bᴴ x, with
+/// Hager's method as refined by Higham (the algorithm behind LAPACK's
+/// `lacn2`).
+pub fn naive_norm1_estimate<T>(n: usize, mut apply: impl FnMut(&mut DTensor<T, 1>, bool)) -> T::Real
+where
+    T: ComplexFloat,
+{
+    if n == 0 {
+        return T::Real::zero();
+    }
+
+    let norm1 = |x: &DTensor<T, 1>| x.iter().fold(T::Real::zero(), |acc, y| acc + y.abs());
+    let n_real = <T::Real as NumCast>::from(n).expect("n can be cast to a real value");
+
+    let mut x = Tensor::from_elem((n,), cast::<T>(T::Real::one() / n_real));
+    let mut est = T::Real::zero();
+
+    for iter in 0..MAX_ESTIMATOR_ITERATIONS {
+        let mut y = x.clone();
+        apply(&mut y, false);
+        let est_new = norm1(&y);
+        if iter > 0 && est_new <= est {
+            break;
+        }
+        est = est_new;
+
+        // z = Bᴴ sign(y) is a subgradient of ‖B x‖₁ at x
+        let mut z = Tensor::from_fn((n,), |i| {
+            let yi = y[i[0]];
+            if yi.is_zero() {
+                T::one()
+            } else {
+                yi / cast::<T>(yi.abs())
+            }
+        });
+        apply(&mut z, true);
+
+        let (j, z_max) = z
+            .iter()
+            .enumerate()
+            .fold((0, T::Real::zero()), |(j, zj), (i, zi)| {
+                if zi.abs() > zj {
+                    (i, zi.abs())
+                } else {
+                    (j, zj)
+                }
+            });
+        let ztx = z
+            .iter()
+            .zip(x.iter())
+            .fold(T::zero(), |acc, (&zi, &xi)| acc + zi.conj() * xi)
+            .re();
+
+        // No vertex of the unit ball gives a larger value
+        if iter > 0 && z_max <= ztx {
+            break;
+        }
+
+        x = Tensor::from_fn((n,), |i| if i[0] == j { T::one() } else { T::zero() });
+    }
+
+    // Alternative estimate guarding against the worst cases of the
+    // iteration above
+    if n > 1 {
+        let denom = <T::Real as NumCast>::from(n - 1).expect("n can be cast to a real value");
+        let mut x = Tensor::from_fn((n,), |i| {
+            let v = T::Real::one() + <T::Real as NumCast>::from(i[0]).unwrap() / denom;
+            if i[0] % 2 == 0 {
+                cast::<T>(v)
+            } else {
+                cast::<T>(-v)
+            }
+        });
+        apply(&mut x, false);
+        let three = <T::Real as NumCast>::from(3).unwrap();
+        let alt = (T::Real::one() + T::Real::one()) * norm1(&x) / (three * n_real);
+        est = est.max(alt);
+    }
+
+    est
+}
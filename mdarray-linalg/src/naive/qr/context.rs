@@ -1,4 +1,4 @@
-use mdarray::{Dim, Layout, Slice, Tensor};
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
 use num_complex::ComplexFloat;
 use num_traits::{MulAdd, One, Zero};
 
@@ -23,8 +23,39 @@ where
         &self,
         a: &mut Slice<T, (D0, D1), L>,
     ) -> (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>) {
-        let mut q = Tensor::<T, (D0, D1)>::from_elem(*a.shape(), T::zero());
-        let mut r = Tensor::<T, (D0, D1)>::from_elem(*a.shape(), T::zero());
+        let ash = *a.shape();
+        let m = ash.dim(0);
+
+        let mut q =
+            Tensor::<T, (D0, D1)>::from_elem(<(D0, D1) as Shape>::from_dims(&[m, m]), T::zero());
+        let mut r = Tensor::<T, (D0, D1)>::from_elem(ash, T::zero());
+
+        naive_qr(a, &mut q, &mut r);
+
+        (q, r)
+    }
+
+    fn qr_thin_write<L: Layout, Lq: Layout, Lr: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+    ) {
+        naive_qr(a, q, r);
+    }
+
+    fn qr_thin<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+    ) -> (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>) {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let k = m.min(n);
+
+        let mut q =
+            Tensor::<T, (D0, D1)>::from_elem(<(D0, D1) as Shape>::from_dims(&[m, k]), T::zero());
+        let mut r =
+            Tensor::<T, (D0, D1)>::from_elem(<(D0, D1) as Shape>::from_dims(&[k, n]), T::zero());
 
         naive_qr(a, &mut q, &mut r);
 
@@ -4,6 +4,10 @@ use num_traits::{MulAdd, One, Zero};
 
 /// Textbook implementation of QR decomposition using Gram-Schmidt process
 /// Useful for debugging and simple tests without relying on external backend
+///
+/// The shapes of Q and R select between the full (Q is m × m, R is m × n)
+/// and the thin (Q is m × k, R is k × n, with k = min(m, n)) factorization.
+/// A must have full rank.
 pub fn naive_qr<T, D0: Dim, D1: Dim, L, Lq, Lr>(
     a: &mut Slice<T, (D0, D1), L>,
     q: &mut Slice<T, (D0, D1), Lq>,
@@ -17,20 +21,26 @@ pub fn naive_qr<T, D0: Dim, D1: Dim, L, Lq, Lr>(
     let (m, n) = *a.shape();
     let m_size = m.size();
     let n_size = n.size();
+    let k = m_size.min(n_size);
+
+    let nq = q.shape().1.size();
+    let mr = r.shape().0.size();
 
     assert_eq!(q.shape().0.size(), m_size);
-    assert_eq!(q.shape().1.size(), n_size);
-    assert_eq!(r.shape().0.size(), n_size);
+    assert!(
+        (nq == m_size && mr == m_size) || (nq == k && mr == k),
+        "Q and R must be either m × m and m × n, or m × min(m, n) and min(m, n) × n"
+    );
     assert_eq!(r.shape().1.size(), n_size);
 
-    for i in 0..n_size {
+    for i in 0..mr {
         for j in 0..n_size {
             r[[i, j]] = T::zero();
         }
     }
 
     // Modified Gram-Schmidt process
-    for j in 0..n_size {
+    for j in 0..k {
         // Copy column j of A to column j of Q
         for i in 0..m_size {
             q[[i, j]] = a[[i, j]];
@@ -67,4 +77,60 @@ pub fn naive_qr<T, D0: Dim, D1: Dim, L, Lq, Lr>(
         }
         // }
     }
+
+    // Wide matrix: the remaining columns of A are already spanned by Q
+    for j in k..n_size {
+        for i in 0..k {
+            let mut dot = T::zero();
+            for l in 0..m_size {
+                dot = q[[l, i]].conj().mul_add(a[[l, j]], dot);
+            }
+            r[[i, j]] = dot;
+        }
+    }
+
+    // Full Q of a tall matrix: complete the basis with the canonical
+    // vector having the largest component orthogonal to the current one
+    for j in k..nq {
+        let mut best = (T::zero().abs(), 0);
+        for e in 0..m_size {
+            let mut norm_sq = T::zero();
+            for i in 0..m_size {
+                let mut x = if i == e { T::one() } else { T::zero() };
+                for c in 0..j {
+                    x = x - q[[e, c]].conj() * q[[i, c]];
+                }
+                norm_sq = x.conj().mul_add(x, norm_sq);
+            }
+            if norm_sq.abs() > best.0 {
+                best = (norm_sq.abs(), e);
+            }
+        }
+
+        for i in 0..m_size {
+            q[[i, j]] = if i == best.1 { T::one() } else { T::zero() };
+        }
+
+        // Orthogonalize twice for numerical stability
+        for _ in 0..2 {
+            for c in 0..j {
+                let mut dot = T::zero();
+                for l in 0..m_size {
+                    dot = q[[l, c]].conj().mul_add(q[[l, j]], dot);
+                }
+                for l in 0..m_size {
+                    q[[l, j]] = q[[l, j]] - dot * q[[l, c]];
+                }
+            }
+        }
+
+        let mut norm_sq = T::zero();
+        for l in 0..m_size {
+            norm_sq = q[[l, j]].conj().mul_add(q[[l, j]], norm_sq);
+        }
+        let inv_norm = T::one() / norm_sq.sqrt();
+        for l in 0..m_size {
+            q[[l, j]] = q[[l, j]] * inv_norm;
+        }
+    }
 }
@@ -0,0 +1,21 @@
+use mdarray::{Dim, Layout, Slice};
+use num_complex::ComplexFloat;
+
+use super::simple::naive_trsyl;
+use crate::{
+    Naive,
+    sylvester::{SylvesterError, SylvesterTriangular, sylvester_dims},
+};
+
+impl<T: ComplexFloat> SylvesterTriangular<T> for Naive {
+    fn sylvester_triangular<D: Dim, La: Layout, Lb: Layout, Lc: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        b: &Slice<T, (D, D), Lb>,
+        adjoint_b: bool,
+        c: &mut Slice<T, (D, D), Lc>,
+    ) -> Result<(), SylvesterError> {
+        sylvester_dims(a, b, c)?;
+        naive_trsyl(a, b, adjoint_b, c)
+    }
+}
@@ -0,0 +1,69 @@
+use mdarray::{Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+
+use crate::sylvester::{SylvesterError, schur_blocks, solve_block};
+
+/// Bartels–Stewart back substitution for A X + X op(B) = C, A and B being
+/// in Schur form. C is overwritten with X block by block: from the last
+/// block row up and, from the first block column on for op(B) = B and
+/// from the last one back for op(B) = Bᴴ.
+pub fn naive_trsyl<T: ComplexFloat, D: Dim, La: Layout, Lb: Layout, Lc: Layout>(
+    a: &Slice<T, (D, D), La>,
+    b: &Slice<T, (D, D), Lb>,
+    adjoint_b: bool,
+    c: &mut Slice<T, (D, D), Lc>,
+) -> Result<(), SylvesterError> {
+    let (m, n) = (a.shape().dim(0), b.shape().dim(0));
+    let op_b = |i: usize, j: usize| {
+        if adjoint_b {
+            b[[j, i]].conj()
+        } else {
+            b[[i, j]]
+        }
+    };
+
+    let row_blocks = schur_blocks(a);
+    let mut col_blocks = schur_blocks(b);
+    if adjoint_b {
+        col_blocks.reverse();
+    }
+
+    for l in &col_blocks {
+        // Block columns of X already solved
+        let solved = if adjoint_b { l.end..n } else { 0..l.start };
+        let q = l.len();
+
+        for k in row_blocks.iter().rev() {
+            let p = k.len();
+            let mut mat = vec![T::zero(); p * q * p * q];
+            let mut r = vec![T::zero(); p * q];
+
+            for (u, i) in k.clone().enumerate() {
+                for (v, j) in l.clone().enumerate() {
+                    let known = (k.end..m).fold(T::zero(), |acc, ip| acc + a[[i, ip]] * c[[ip, j]]);
+                    let known = solved
+                        .clone()
+                        .fold(known, |acc, jp| acc + c[[i, jp]] * op_b(jp, j));
+                    r[u * q + v] = c[[i, j]] - known;
+
+                    let row = (u * q + v) * p * q;
+                    for (up, ip) in k.clone().enumerate() {
+                        mat[row + up * q + v] = mat[row + up * q + v] + a[[i, ip]];
+                    }
+                    for (vp, jp) in l.clone().enumerate() {
+                        mat[row + u * q + vp] = mat[row + u * q + vp] + op_b(jp, j);
+                    }
+                }
+            }
+
+            solve_block(&mut mat, &mut r, p * q)?;
+            for (u, i) in k.clone().enumerate() {
+                for (v, j) in l.clone().enumerate() {
+                    c[[i, j]] = r[u * q + v];
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
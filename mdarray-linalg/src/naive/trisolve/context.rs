@@ -0,0 +1,124 @@
+use mdarray::{Dim, Layout, Slice, Tensor};
+use num_complex::ComplexFloat;
+
+use super::simple::{naive_trsm, naive_trsv};
+use crate::{
+    Naive,
+    matmul::{Side, Triangle},
+    trisolve::{Transpose, TriSolve, TriSolveBuilder, TriSolveError, TriSolveOptions},
+};
+
+struct NaiveTriSolveBuilder<'a, T, La, D>
+where
+    La: Layout,
+    D: Dim,
+{
+    a: &'a Slice<T, (D, D), La>,
+    opts: TriSolveOptions<T>,
+}
+
+impl<'a, T, La, D> TriSolveBuilder<'a, T, La, D> for NaiveTriSolveBuilder<'a, T, La, D>
+where
+    T: ComplexFloat + 'a,
+    La: Layout,
+    D: Dim,
+{
+    fn side(mut self, side: Side) -> Self {
+        self.opts.side = side;
+        self
+    }
+
+    fn upper(mut self) -> Self {
+        self.opts.triangle = Triangle::Upper;
+        self
+    }
+
+    fn lower(mut self) -> Self {
+        self.opts.triangle = Triangle::Lower;
+        self
+    }
+
+    fn transpose(mut self) -> Self {
+        self.opts.trans = Transpose::Trans;
+        self
+    }
+
+    fn conj_transpose(mut self) -> Self {
+        self.opts.trans = Transpose::ConjTrans;
+        self
+    }
+
+    fn unit_diagonal(mut self) -> Self {
+        self.opts.unit_diag = true;
+        self
+    }
+
+    fn scale(mut self, alpha: T) -> Self {
+        self.opts.alpha = self.opts.alpha * alpha;
+        self
+    }
+
+    fn eval<Lb: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &Slice<T, (D0, D1), Lb>,
+    ) -> Result<Tensor<T, (D0, D1)>, TriSolveError> {
+        let mut x = b.to_tensor();
+        naive_trsm(&self.opts, self.a, &mut x)?;
+        Ok(x)
+    }
+
+    fn write<Lb: Layout, Lx: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &Slice<T, (D0, D1), Lb>,
+        x: &mut Slice<T, (D0, D1), Lx>,
+    ) -> Result<(), TriSolveError> {
+        x.assign(b);
+        naive_trsm(&self.opts, self.a, x)
+    }
+
+    fn in_place<Lb: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<(), TriSolveError> {
+        naive_trsm(&self.opts, self.a, b)
+    }
+
+    fn eval_vec<Lb: Layout, D0: Dim>(
+        self,
+        b: &Slice<T, (D0,), Lb>,
+    ) -> Result<Tensor<T, (D0,)>, TriSolveError> {
+        let mut x = b.to_tensor();
+        naive_trsv(&self.opts, self.a, &mut x)?;
+        Ok(x)
+    }
+
+    fn write_vec<Lb: Layout, Lx: Layout, D0: Dim>(
+        self,
+        b: &Slice<T, (D0,), Lb>,
+        x: &mut Slice<T, (D0,), Lx>,
+    ) -> Result<(), TriSolveError> {
+        x.assign(b);
+        naive_trsv(&self.opts, self.a, x)
+    }
+
+    fn in_place_vec<Lb: Layout, D0: Dim>(
+        self,
+        b: &mut Slice<T, (D0,), Lb>,
+    ) -> Result<(), TriSolveError> {
+        naive_trsv(&self.opts, self.a, b)
+    }
+}
+
+impl<T: ComplexFloat> TriSolve<T> for Naive {
+    fn trisolve<'a, La, D>(&self, a: &'a Slice<T, (D, D), La>) -> impl TriSolveBuilder<'a, T, La, D>
+    where
+        T: 'a,
+        La: Layout,
+        D: Dim,
+    {
+        NaiveTriSolveBuilder {
+            a,
+            opts: TriSolveOptions::default(),
+        }
+    }
+}
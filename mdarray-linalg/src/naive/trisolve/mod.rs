@@ -0,0 +1,4 @@
+mod context;
+mod simple;
+
+pub(crate) use simple::naive_trsv;
@@ -0,0 +1,141 @@
+use mdarray::{Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+
+use crate::{
+    matmul::{Side, Triangle},
+    trisolve::{Transpose, TriSolveError, TriSolveOptions, trisolve_check_diagonal, trisolve_dims},
+};
+
+/// Element (i, j) of op(A)
+fn op_element<T: ComplexFloat, D: Dim, La: Layout>(
+    a: &Slice<T, (D, D), La>,
+    trans: Transpose,
+    i: usize,
+    j: usize,
+) -> T {
+    match trans {
+        Transpose::NoTrans => a[[i, j]],
+        Transpose::Trans => a[[j, i]],
+        Transpose::ConjTrans => a[[j, i]].conj(),
+    }
+}
+
+/// Whether op(A) is lower triangular
+fn op_is_lower(triangle: Triangle, trans: Transpose) -> bool {
+    (triangle == Triangle::Lower) == (trans == Transpose::NoTrans)
+}
+
+/// Forward (lower) or back (upper) substitution M x = b, with x
+/// holding b on entry
+fn substitute<T: ComplexFloat>(
+    lower: bool,
+    unit_diag: bool,
+    m: impl Fn(usize, usize) -> T,
+    x: &mut [T],
+) {
+    let n = x.len();
+    let mut solve_row = |i: usize, known: std::ops::Range<usize>| {
+        let mut xi = x[i];
+        for j in known {
+            xi = xi - m(i, j) * x[j];
+        }
+        x[i] = if unit_diag { xi } else { xi / m(i, i) };
+    };
+
+    if lower {
+        for i in 0..n {
+            solve_row(i, 0..i);
+        }
+    } else {
+        for i in (0..n).rev() {
+            solve_row(i, i + 1..n);
+        }
+    }
+}
+
+/// Textbook triangular solve with multiple right-hand sides, overwriting B
+pub fn naive_trsm<T, D, D0, D1, La, Lb>(
+    opts: &TriSolveOptions<T>,
+    a: &Slice<T, (D, D), La>,
+    b: &mut Slice<T, (D0, D1), Lb>,
+) -> Result<(), TriSolveError>
+where
+    T: ComplexFloat,
+    D: Dim,
+    D0: Dim,
+    D1: Dim,
+    La: Layout,
+    Lb: Layout,
+{
+    trisolve_dims(a, b, opts.side);
+    trisolve_check_diagonal(a, opts.unit_diag)?;
+
+    let bsh = *b.shape();
+    let (m, n) = (bsh.dim(0), bsh.dim(1));
+    let lower = op_is_lower(opts.triangle, opts.trans);
+
+    match opts.side {
+        // Each column of X solves op(A) x = αb
+        Side::Left => {
+            for col in 0..n {
+                let mut x: Vec<T> = (0..m).map(|i| opts.alpha * b[[i, col]]).collect();
+                substitute(
+                    lower,
+                    opts.unit_diag,
+                    |i, j| op_element(a, opts.trans, i, j),
+                    &mut x,
+                );
+                for (i, xi) in x.into_iter().enumerate() {
+                    b[[i, col]] = xi;
+                }
+            }
+        }
+        // Each row of X solves op(A)ᵀ x = αb
+        Side::Right => {
+            for row in 0..m {
+                let mut x: Vec<T> = (0..n).map(|j| opts.alpha * b[[row, j]]).collect();
+                substitute(
+                    !lower,
+                    opts.unit_diag,
+                    |i, j| op_element(a, opts.trans, j, i),
+                    &mut x,
+                );
+                for (j, xj) in x.into_iter().enumerate() {
+                    b[[row, j]] = xj;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Textbook triangular solve op(A) x = αb, overwriting b
+pub fn naive_trsv<T, D, D0, La, Lb>(
+    opts: &TriSolveOptions<T>,
+    a: &Slice<T, (D, D), La>,
+    b: &mut Slice<T, (D0,), Lb>,
+) -> Result<(), TriSolveError>
+where
+    T: ComplexFloat,
+    D: Dim,
+    D0: Dim,
+    La: Layout,
+    Lb: Layout,
+{
+    let n = a.shape().dim(0);
+    assert_eq!(n, a.shape().dim(1), "A must be square");
+    assert_eq!(n, b.shape().dim(0), "A and b have incompatible dimensions");
+    trisolve_check_diagonal(a, opts.unit_diag)?;
+
+    let mut x: Vec<T> = (0..n).map(|i| opts.alpha * b[[i]]).collect();
+    substitute(
+        op_is_lower(opts.triangle, opts.trans),
+        opts.unit_diag,
+        |i, j| op_element(a, opts.trans, i, j),
+        &mut x,
+    );
+    for (i, xi) in x.into_iter().enumerate() {
+        b[[i]] = xi;
+    }
+    Ok(())
+}
@@ -0,0 +1,580 @@
+//! Contraction of tensor networks in an optimized pairwise order
+//!
+//! A network is described in the `ncon` convention: each axis of each
+//! tensor carries a nonzero integer label,
+//! - a positive label appears exactly twice and is contracted, either
+//!   between two tensors or, within a single tensor, as a partial trace,
+//! - a negative label appears exactly once and is an open axis, the axes of
+//!   the result being ordered as `-1, -2, …`.
+//!
+//! Einsum subscripts such as `"ij,jk,kl->il"` can be translated to this
+//! convention with [`ncon_labels`].
+//!
+//! The cost of contracting a network depends mostly on the order in which
+//! the tensors are contracted pairwise. [`ncon_path`] searches for a good
+//! order, reporting its estimated FLOPs and peak memory, either greedily or
+//! exhaustively by dynamic programming over subsets of tensors for small
+//! networks. [`Ncon`] then evaluates the network along such a path, each
+//! pair through [`MatMul::contract`].
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::ncon::{PathStrategy, ncon_path};
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::Naive;
+//!
+//!let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+//!let x = tensor![[1.], [1.]].into_dyn();
+//!
+//!// Matrix chain a · a · x
+//!let labels: [&[i32]; 3] = [&[-1, 1], &[1, 2], &[2, -2]];
+//!let path = ncon_path(&labels, &[a.dims(), a.dims(), x.dims()], PathStrategy::Optimal).unwrap();
+//!assert_eq!(path.steps, [(1, 2), (0, 1)]);
+//!
+//!let c = Naive.ncon_with_path(&[&a, &a, &x], &labels, &path).unwrap();
+//!assert_eq!(c, tensor![[17.], [37.]].into_dyn());
+//!```
+use mdarray::{DynRank, Layout, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::MulAdd;
+use thiserror::Error;
+
+use crate::einsum::{EinsumError, Labeled, parse_subscripts, reduce};
+use crate::matmul::{ContractBuilder, MatMul};
+
+/// Largest network accepted by [`PathStrategy::Optimal`]
+pub const OPTIMAL_MAX_TENSORS: usize = 16;
+
+/// Largest network for which [`PathStrategy::Auto`] searches exhaustively
+const AUTO_OPTIMAL_TENSORS: usize = 10;
+
+/// Error types related to tensor network contraction
+#[derive(Debug, Error)]
+pub enum NconError {
+    #[error("No tensors to contract")]
+    Empty,
+
+    #[error("Got {tensors} tensors but {labels} label lists")]
+    LabelCount { tensors: usize, labels: usize },
+
+    #[error("Tensor {tensor} has rank {rank} but {labels} labels")]
+    RankMismatch {
+        tensor: usize,
+        rank: usize,
+        labels: usize,
+    },
+
+    #[error("Label 0 is not allowed")]
+    ZeroLabel,
+
+    #[error("Inconsistent dimensions for label {label}: {first} ≠ {second}")]
+    DimensionMismatch {
+        label: i32,
+        first: usize,
+        second: usize,
+    },
+
+    #[error("Label {label} appears {count} times, expected {expected}")]
+    LabelMultiplicity {
+        label: i32,
+        count: usize,
+        expected: usize,
+    },
+
+    #[error("Open label {0} is missing, open labels must be -1, -2, …")]
+    MissingOpenLabel(i32),
+
+    #[error("Index '{0}' must appear twice if summed and once if in the output")]
+    UnsupportedIndex(char),
+
+    #[error("Exhaustive search is limited to {max} tensors, got {count}")]
+    TooManyTensors { count: usize, max: usize },
+
+    #[error("Invalid contraction path: {0}")]
+    InvalidPath(String),
+
+    #[error(transparent)]
+    Einsum(#[from] EinsumError),
+}
+
+pub type NconResult<T> = Result<Tensor<T, DynRank>, NconError>;
+
+/// Algorithm searching for the pairwise contraction order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathStrategy {
+    /// Repeatedly contracts the pair of tensors that reduces the total size
+    /// the most, in time polynomial in the number of tensors
+    Greedy,
+    /// Minimizes the FLOPs over all orders, in time O(3ⁿ) for n tensors
+    Optimal,
+    /// Optimal for networks of up to 10 tensors, greedy otherwise
+    #[default]
+    Auto,
+}
+
+/// Pairwise contraction order of a network, with its estimated cost
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractionPath {
+    /// Positions of the two tensors contracted at each step in the list of
+    /// remaining tensors, which initially holds the inputs. Both are removed
+    /// and their contraction is appended.
+    pub steps: Vec<(usize, usize)>,
+    /// Floating-point operations, counting a multiply-add as two
+    pub flops: f64,
+    /// Largest number of elements held at once by the inputs and
+    /// intermediate tensors
+    pub peak_memory: usize,
+}
+
+/// Contraction of tensor networks in the `ncon` convention
+///
+/// Implemented for every backend providing [`MatMul`].
+pub trait Ncon<T> {
+    /// Contracts the network in the order found by [`PathStrategy::Auto`]
+    fn ncon<L: Layout>(
+        &self,
+        tensors: &[&Slice<T, DynRank, L>],
+        labels: &[&[i32]],
+    ) -> NconResult<T>;
+
+    /// Contracts the network along `path`, as returned by [`ncon_path`]
+    fn ncon_with_path<L: Layout>(
+        &self,
+        tensors: &[&Slice<T, DynRank, L>],
+        labels: &[&[i32]],
+        path: &ContractionPath,
+    ) -> NconResult<T>;
+}
+
+impl<T, B> Ncon<T> for B
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    B: MatMul<T>,
+{
+    fn ncon<L: Layout>(
+        &self,
+        tensors: &[&Slice<T, DynRank, L>],
+        labels: &[&[i32]],
+    ) -> NconResult<T> {
+        let dims: Vec<&[usize]> = tensors.iter().map(|t| t.dims()).collect();
+        let path = ncon_path(labels, &dims, PathStrategy::Auto)?;
+        self.ncon_with_path(tensors, labels, &path)
+    }
+
+    fn ncon_with_path<L: Layout>(
+        &self,
+        tensors: &[&Slice<T, DynRank, L>],
+        labels: &[&[i32]],
+        path: &ContractionPath,
+    ) -> NconResult<T> {
+        let dims: Vec<&[usize]> = tensors.iter().map(|t| t.dims()).collect();
+        let network = Network::new(labels, &dims)?;
+        network.cost(&path.steps)?;
+
+        // Partial traces are taken first
+        let mut remaining: Vec<Labeled<T, i32>> = tensors
+            .iter()
+            .zip(labels)
+            .zip(&network.tensors)
+            .map(|((t, labels), keep)| reduce(labels, *t, keep))
+            .collect();
+
+        for &(i, j) in &path.steps {
+            let b = remaining.remove(j);
+            let a = remaining.remove(i);
+            remaining.push(contract_pair(self, a, b));
+        }
+
+        let result = remaining.pop().unwrap();
+        let perm: Vec<usize> = (1..=network.open as i32)
+            .map(|k| result.labels.iter().position(|&l| l == -k).unwrap())
+            .collect();
+        Ok(result.tensor.permute(perm).to_tensor())
+    }
+}
+
+/// Contracts the labels shared by `a` and `b`, returning a tensor labeled by
+/// the other ones of `a`, then of `b`
+fn contract_pair<T, B>(bd: &B, a: Labeled<T, i32>, b: Labeled<T, i32>) -> Labeled<T, i32>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    B: MatMul<T>,
+{
+    let mut axes_a = Vec::new();
+    let mut axes_b = Vec::new();
+    for (ax, l) in a.labels.iter().enumerate() {
+        if let Some(bx) = b.labels.iter().position(|m| m == l) {
+            axes_a.push(ax);
+            axes_b.push(bx);
+        }
+    }
+
+    let free = |t: &Labeled<T, i32>, axes: &[usize]| -> Vec<(i32, usize)> {
+        (0..t.labels.len())
+            .filter(|ax| !axes.contains(ax))
+            .map(|ax| (t.labels[ax], t.tensor.dim(ax)))
+            .collect()
+    };
+    let (labels, out_dims): (Vec<i32>, Vec<usize>) = free(&a, &axes_a)
+        .into_iter()
+        .chain(free(&b, &axes_b))
+        .unzip();
+
+    // Contracting all axes leaves a 1 × 1 matrix
+    let c = bd.contract(&a.tensor, &b.tensor, axes_a, axes_b);
+    Labeled {
+        labels,
+        tensor: c.eval().into_shape(&out_dims[..]),
+    }
+}
+
+/// Finds a pairwise contraction order for the network whose tensors have
+/// the given labels and dimensions
+pub fn ncon_path(
+    labels: &[&[i32]],
+    dims: &[&[usize]],
+    strategy: PathStrategy,
+) -> Result<ContractionPath, NconError> {
+    let network = Network::new(labels, dims)?;
+    let n = network.tensors.len();
+
+    let steps = match strategy {
+        PathStrategy::Optimal if n > OPTIMAL_MAX_TENSORS => {
+            return Err(NconError::TooManyTensors {
+                count: n,
+                max: OPTIMAL_MAX_TENSORS,
+            });
+        }
+        PathStrategy::Optimal => network.optimal_path(),
+        PathStrategy::Auto if n <= AUTO_OPTIMAL_TENSORS => network.optimal_path(),
+        PathStrategy::Greedy | PathStrategy::Auto => network.greedy_path(),
+    };
+
+    let (flops, peak_memory) = network.cost(&steps)?;
+    Ok(ContractionPath {
+        steps,
+        flops,
+        peak_memory,
+    })
+}
+
+/// Translates einsum subscripts such as `"ij,jk->ik"` to `ncon` labels
+///
+/// The output indices become `-1, -2, …` in order, and the summed ones
+/// `1, 2, …` in order of first appearance. Each summed index must appear
+/// exactly twice and each output index exactly once.
+pub fn ncon_labels(subscripts: &str) -> Result<Vec<Vec<i32>>, NconError> {
+    let (inputs, output) = parse_subscripts(subscripts)?;
+    let all: Vec<char> = inputs.concat();
+
+    let mut summed: Vec<char> = Vec::new();
+    for &c in &all {
+        let count = all.iter().filter(|&&d| d == c).count();
+        match output.iter().position(|&o| o == c) {
+            Some(_) if count != 1 => return Err(NconError::UnsupportedIndex(c)),
+            None if count != 2 => return Err(NconError::UnsupportedIndex(c)),
+            None if !summed.contains(&c) => summed.push(c),
+            _ => {}
+        }
+    }
+
+    let label = |c: &char| match output.iter().position(|o| o == c) {
+        Some(k) => -(k as i32 + 1),
+        None => summed.iter().position(|s| s == c).unwrap() as i32 + 1,
+    };
+    Ok(inputs
+        .iter()
+        .map(|input| input.iter().map(label).collect())
+        .collect())
+}
+
+/// Validated network, each tensor being described by the labels left after
+/// its partial traces
+struct Network {
+    tensors: Vec<Vec<i32>>,
+    sizes: Vec<f64>,
+    dims: Vec<(i32, usize)>,
+    open: usize,
+}
+
+/// Pair of remaining tensors `(i, j)` considered by the greedy search. The
+/// cost compares whether the pair shares no label, then the growth in size
+/// and then the flops of its contraction.
+struct Candidate {
+    cost: (bool, f64, f64),
+    pair: (usize, usize),
+}
+
+impl Network {
+    fn new(labels: &[&[i32]], dims: &[&[usize]]) -> Result<Self, NconError> {
+        if labels.len() != dims.len() {
+            return Err(NconError::LabelCount {
+                tensors: dims.len(),
+                labels: labels.len(),
+            });
+        }
+        if labels.is_empty() {
+            return Err(NconError::Empty);
+        }
+
+        let mut label_dims: Vec<(i32, usize)> = Vec::new();
+        let mut counts: Vec<(i32, usize)> = Vec::new();
+        for (tensor, (labels, dims)) in labels.iter().zip(dims).enumerate() {
+            if labels.len() != dims.len() {
+                return Err(NconError::RankMismatch {
+                    tensor,
+                    rank: dims.len(),
+                    labels: labels.len(),
+                });
+            }
+            for (&label, &dim) in labels.iter().zip(*dims) {
+                if label == 0 {
+                    return Err(NconError::ZeroLabel);
+                }
+                match label_dims.iter().find(|(l, _)| *l == label) {
+                    Some(&(_, first)) if first != dim => {
+                        return Err(NconError::DimensionMismatch {
+                            label,
+                            first,
+                            second: dim,
+                        });
+                    }
+                    Some(_) => {}
+                    None => label_dims.push((label, dim)),
+                }
+                match counts.iter_mut().find(|(l, _)| *l == label) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((label, 1)),
+                }
+            }
+        }
+
+        for &(label, count) in &counts {
+            let expected = if label > 0 { 2 } else { 1 };
+            if count != expected {
+                return Err(NconError::LabelMultiplicity {
+                    label,
+                    count,
+                    expected,
+                });
+            }
+        }
+        let open = counts.iter().filter(|(l, _)| *l < 0).count();
+        if let Some(k) = (1..=open as i32).find(|k| !counts.iter().any(|(l, _)| *l == -k)) {
+            return Err(NconError::MissingOpenLabel(-k));
+        }
+
+        let tensors = labels
+            .iter()
+            .map(|labels| {
+                labels
+                    .iter()
+                    .filter(|l| labels.iter().filter(|m| m == l).count() == 1)
+                    .copied()
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            tensors,
+            sizes: dims
+                .iter()
+                .map(|d| d.iter().product::<usize>() as f64)
+                .collect(),
+            dims: label_dims,
+            open,
+        })
+    }
+
+    fn size(&self, labels: &[i32]) -> f64 {
+        labels
+            .iter()
+            .map(|l| self.dims.iter().find(|(m, _)| m == l).unwrap().1 as f64)
+            .product()
+    }
+
+    /// Labels of the contraction of `a` and `b`, and its cost in FLOPs
+    fn contract(&self, a: &[i32], b: &[i32]) -> (Vec<i32>, f64) {
+        let labels: Vec<i32> = a
+            .iter()
+            .filter(|l| !b.contains(l))
+            .chain(b.iter().filter(|l| !a.contains(l)))
+            .copied()
+            .collect();
+        let shared: Vec<i32> = a.iter().filter(|l| b.contains(l)).copied().collect();
+        let multiply_adds = self.size(&labels) * self.size(&shared);
+        let flops = if shared.is_empty() {
+            multiply_adds
+        } else {
+            2. * multiply_adds
+        };
+        (labels, flops)
+    }
+
+    /// Checks `steps` and returns its FLOPs and peak memory
+    fn cost(&self, steps: &[(usize, usize)]) -> Result<(f64, usize), NconError> {
+        let n = self.tensors.len();
+        if steps.len() + 1 != n {
+            return Err(NconError::InvalidPath(format!(
+                "{} steps for {n} tensors",
+                steps.len()
+            )));
+        }
+
+        // Inputs with partial traces are held along with their trace
+        let mut remaining: Vec<(Vec<i32>, f64)> = self
+            .tensors
+            .iter()
+            .zip(&self.sizes)
+            .map(|(labels, &size)| {
+                let traced = self.size(labels);
+                (
+                    labels.clone(),
+                    if traced < size { size + traced } else { size },
+                )
+            })
+            .collect();
+        let mut memory: f64 = remaining.iter().map(|(_, size)| size).sum();
+        let mut peak = memory;
+        let mut flops = 0.;
+        for &(i, j) in steps {
+            if i >= j || j >= remaining.len() {
+                return Err(NconError::InvalidPath(format!(
+                    "step ({i}, {j}) with {} tensors left",
+                    remaining.len()
+                )));
+            }
+            let (b, size_b) = remaining.remove(j);
+            let (a, size_a) = remaining.remove(i);
+            let (labels, step_flops) = self.contract(&a, &b);
+            let size = self.size(&labels);
+
+            flops += step_flops;
+            memory += size;
+            peak = peak.max(memory);
+            memory -= size_a + size_b;
+            remaining.push((labels, size));
+        }
+        Ok((flops, peak as usize))
+    }
+
+    /// Contracts first the pairs sharing labels, choosing each time the one
+    /// whose result is smallest relative to its operands
+    fn greedy_path(&self) -> Vec<(usize, usize)> {
+        let mut remaining = self.tensors.clone();
+        let mut steps = Vec::new();
+
+        while remaining.len() > 1 {
+            let mut best: Option<Candidate> = None;
+            for j in 1..remaining.len() {
+                for i in 0..j {
+                    let (a, b) = (&remaining[i], &remaining[j]);
+                    let (labels, flops) = self.contract(a, b);
+                    let disconnected = !a.iter().any(|l| b.contains(l));
+                    let growth = self.size(&labels) - self.size(a) - self.size(b);
+                    let cost = (disconnected, growth, flops);
+                    if best.as_ref().is_none_or(|best| cost < best.cost) {
+                        best = Some(Candidate { cost, pair: (i, j) });
+                    }
+                }
+            }
+
+            let (i, j) = best.unwrap().pair;
+            let b = remaining.remove(j);
+            let a = remaining.remove(i);
+            remaining.push(self.contract(&a, &b).0);
+            steps.push((i, j));
+        }
+        steps
+    }
+
+    /// Minimizes the FLOPs by dynamic programming over the subsets of tensors
+    fn optimal_path(&self) -> Vec<(usize, usize)> {
+        let n = self.tensors.len();
+        let full = (1usize << n) - 1;
+
+        // Tensors carrying each label, open labels also reaching outside
+        let edges: Vec<(usize, f64)> = self
+            .dims
+            .iter()
+            .map(|&(label, dim)| {
+                let mut mask = (0..n)
+                    .filter(|&t| self.tensors[t].contains(&label))
+                    .fold(0, |mask, t| mask | 1 << t);
+                if label < 0 {
+                    mask |= 1 << n;
+                }
+                (mask, dim as f64)
+            })
+            .filter(|&(mask, _)| mask != 0)
+            .collect();
+        let outgoing = |set: usize, mask: usize| mask & set != 0 && mask & !set != 0;
+
+        // Cost of the best contraction of each subset and its split
+        let mut best: Vec<(f64, usize)> = vec![(f64::INFINITY, 0); full + 1];
+        for t in 0..n {
+            best[1 << t] = (0., 0);
+        }
+        for set in 1..=full {
+            if set.count_ones() < 2 {
+                continue;
+            }
+            let low = set & set.wrapping_neg();
+            let rest = set ^ low;
+
+            // Subsets of `set` containing its lowest tensor
+            let mut sub = rest;
+            loop {
+                let a = sub | low;
+                if a != set {
+                    let b = set ^ a;
+                    let mut size = 1.;
+                    let mut shared = false;
+                    for &(mask, dim) in &edges {
+                        let (in_a, in_b) = (outgoing(a, mask), outgoing(b, mask));
+                        if in_a || in_b {
+                            size *= dim;
+                        }
+                        shared |= in_a && in_b && !outgoing(set, mask);
+                    }
+                    let flops = if shared { 2. * size } else { size };
+                    let cost = best[a].0 + best[b].0 + flops;
+                    if cost < best[set].0 {
+                        best[set] = (cost, a);
+                    }
+                }
+                if sub == 0 {
+                    break;
+                }
+                sub = (sub - 1) & rest;
+            }
+        }
+
+        // Unfold the splits into steps on the list of remaining tensors,
+        // which holds subsets
+        fn unfold(set: usize, best: &[(f64, usize)], order: &mut Vec<(usize, usize)>) {
+            if set.count_ones() > 1 {
+                let a = best[set].1;
+                unfold(a, best, order);
+                unfold(set ^ a, best, order);
+                order.push((a, set ^ a));
+            }
+        }
+        let mut order = Vec::new();
+        unfold(full, &best, &mut order);
+
+        let mut remaining: Vec<usize> = (0..n).map(|t| 1 << t).collect();
+        order
+            .into_iter()
+            .map(|(a, b)| {
+                let i = remaining.iter().position(|&s| s == a).unwrap();
+                let j = remaining.iter().position(|&s| s == b).unwrap();
+                let (i, j) = (i.min(j), i.max(j));
+                remaining.remove(j);
+                remaining.remove(i);
+                remaining.push(a | b);
+                (i, j)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,170 @@
+//! Matrix norms and condition number estimation
+//!
+//! [`MatrixNorm`] computes the usual matrix norms of any m × n matrix.
+//! [`Cond`] estimates the reciprocal condition number
+//! rcond(A) = 1 / (‖A‖ ‖A⁻¹‖) from an existing LU or Cholesky
+//! factorization of A, without forming A⁻¹. The norm ‖A‖ of the original
+//! matrix must be provided by the caller, typically with
+//! [`MatrixNorm::matrix_norm`] before the factorization overwrites A.
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::Naive;
+//!use mdarray_linalg::norm::Norm;
+//!
+//!let a = tensor![[1., -2.], [3., 4.]];
+//!assert_eq!(Naive.matrix_norm(&a, Norm::One).unwrap(), 6.);
+//!assert_eq!(Naive.matrix_norm(&a, Norm::Inf).unwrap(), 7.);
+//!assert_eq!(Naive.matrix_norm(&a, Norm::MaxAbs).unwrap(), 4.);
+//!
+//!// A = L U with L unit lower triangular
+//!let l = tensor![[1., 0.], [3., 1.]];
+//!let u = tensor![[1., -2.], [0., 10.]];
+//!let rcond = Naive.rcond_lu(&l, &u, 6., Norm::One).unwrap();
+//!// ‖A⁻¹‖₁ = 0.7
+//!assert!((rcond - 1. / 4.2_f64).abs() < 1e-12);
+//!```
+use std::cmp::Ordering;
+
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::Float;
+use thiserror::Error;
+
+use crate::svd::SVDError;
+
+/// Matrix norms supported by [`MatrixNorm`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Norm {
+    /// Maximum absolute column sum
+    One,
+    /// Maximum absolute row sum
+    Inf,
+    /// Square root of the sum of the squared absolute values
+    Frobenius,
+    /// Largest absolute value of the entries (not a consistent matrix norm)
+    MaxAbs,
+    /// Largest singular value
+    Spectral,
+    /// Sum of the singular values
+    Nuclear,
+}
+
+/// Error types related to matrix norms and condition numbers
+#[derive(Debug, Error)]
+pub enum NormError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+
+    #[error("L and U must have the same shape")]
+    InvalidDimensions,
+
+    /// Condition numbers are only estimated in the 1- and ∞-norms
+    #[error("Unsupported norm for condition number estimation: {0:?}")]
+    UnsupportedNorm(Norm),
+
+    #[error(transparent)]
+    SVD(#[from] SVDError),
+}
+
+/// Matrix norms of a general m × n matrix
+pub trait MatrixNorm<T: ComplexFloat> {
+    /// Computes the requested norm of A. The spectral and nuclear norms
+    /// require the singular values of A, which may fail to converge.
+    fn matrix_norm<L: Layout, D0: Dim, D1: Dim>(
+        &self,
+        a: &Slice<T, (D0, D1), L>,
+        norm: Norm,
+    ) -> Result<T::Real, NormError>;
+}
+
+/// Reciprocal condition number estimation from a factorization
+pub trait Cond<T: ComplexFloat> {
+    /// Estimates 1 / (‖A‖ ‖A⁻¹‖) in the 1- or ∞-norm from the factors of
+    /// P A = L U, where L is unit lower triangular and U upper triangular,
+    /// as returned by [`LU::lu`](crate::lu::LU::lu). The permutation does
+    /// not change ‖A⁻¹‖ and is not needed. `anorm` is ‖A‖ in the same norm.
+    /// Returns 0 when U has a zero on its diagonal.
+    fn rcond_lu<Ll: Layout, Lu: Layout, D: Dim>(
+        &self,
+        l: &Slice<T, (D, D), Ll>,
+        u: &Slice<T, (D, D), Lu>,
+        anorm: T::Real,
+        norm: Norm,
+    ) -> Result<T::Real, NormError>;
+
+    /// Same as [`Cond::rcond_lu`], with L and U packed in a single n × n
+    /// matrix as held by [`LuFactors`](crate::lu::LuFactors), the unit
+    /// diagonal of L being implicit
+    fn rcond_lu_packed<L: Layout, D: Dim>(
+        &self,
+        lu: &Slice<T, (D, D), L>,
+        anorm: T::Real,
+        norm: Norm,
+    ) -> Result<T::Real, NormError> {
+        let l = Tensor::<T, (D, D)>::from_fn(*lu.shape(), |i| match i[0].cmp(&i[1]) {
+            Ordering::Greater => lu[[i[0], i[1]]],
+            Ordering::Equal => T::one(),
+            Ordering::Less => T::zero(),
+        });
+        let u = Tensor::<T, (D, D)>::from_fn(*lu.shape(), |i| {
+            if i[0] <= i[1] {
+                lu[[i[0], i[1]]]
+            } else {
+                T::zero()
+            }
+        });
+        self.rcond_lu(&l, &u, anorm, norm)
+    }
+
+    /// Estimates 1 / (‖A‖₁ ‖A⁻¹‖₁) from the lower triangular Cholesky
+    /// factor of A = L Lᴴ, as returned by
+    /// [`LU::choleski`](crate::lu::LU::choleski). Only the lower triangle
+    /// of L is referenced. `anorm` is ‖A‖₁, equal to ‖A‖∞ for Hermitian A.
+    fn rcond_cholesky<L: Layout, D: Dim>(
+        &self,
+        l: &Slice<T, (D, D), L>,
+        anorm: T::Real,
+    ) -> Result<T::Real, NormError>;
+}
+
+/// Checks that L and U are square and of the same size, returning n
+pub fn rcond_lu_dims<T, D: Dim, Ll: Layout, Lu: Layout>(
+    l: &Slice<T, (D, D), Ll>,
+    u: &Slice<T, (D, D), Lu>,
+) -> Result<usize, NormError> {
+    let n = rcond_dims(l)?;
+    if rcond_dims(u)? != n {
+        return Err(NormError::InvalidDimensions);
+    }
+    Ok(n)
+}
+
+/// Checks that a triangular factor is square, returning n
+pub fn rcond_dims<T, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> Result<usize, NormError> {
+    let ash = *a.shape();
+    let n = ash.dim(0);
+    if n != ash.dim(1) {
+        return Err(NormError::NotSquareMatrix);
+    }
+    Ok(n)
+}
+
+/// Whether a factorization is exactly singular, in which case the
+/// reciprocal condition number is zero
+pub fn has_zero_diagonal<T: ComplexFloat, D: Dim, L: Layout>(a: &Slice<T, (D, D), L>) -> bool {
+    (0..a.shape().dim(0)).any(|i| a[[i, i]].is_zero())
+}
+
+/// Combines ‖A‖ and an estimate of ‖A⁻¹‖ into the reciprocal condition number
+pub fn rcond_from_norms<R: Float>(anorm: R, ainvnm: R) -> R {
+    if anorm.is_zero() || ainvnm.is_zero() {
+        R::zero()
+    } else {
+        (R::one() / ainvnm) / anorm
+    }
+}
@@ -0,0 +1,138 @@
+//! Packed storage of triangular, symmetric and Hermitian matrices
+//!
+//! A [`PackedMatrix`] stores one triangle of an n × n matrix
+//! contiguously, row by row, in n (n + 1) / 2 elements instead of n².
+//! This is the row-major packed layout of CBLAS.
+//!
+//! ```rust
+//! use mdarray::tensor;
+//! use mdarray_linalg::matmul::Triangle;
+//! use mdarray_linalg::packed::PackedMatrix;
+//!
+//! let a = tensor![[1., 2., 3.], [0., 4., 5.], [0., 0., 6.]];
+//! let p = PackedMatrix::from_dense(&a, Triangle::Upper);
+//! assert_eq!(p.data(), &[1., 2., 3., 4., 5., 6.]);
+//! assert_eq!(p.to_dense(), a);
+//! ```
+use mdarray::{DTensor, Dim, Layout, Shape, Slice};
+use num_complex::ComplexFloat;
+
+use crate::matmul::Triangle;
+
+/// n × n matrix of which only the upper or lower triangle is stored, in
+/// packed row-major order
+///
+/// For the upper triangle, row i holds A(i, i..n); for the lower
+/// triangle, row i holds A(i, 0..=i).
+#[derive(Clone, Debug)]
+pub struct PackedMatrix<T> {
+    n: usize,
+    triangle: Triangle,
+    data: Vec<T>,
+}
+
+impl<T: ComplexFloat> PackedMatrix<T> {
+    /// Creates an n × n zero matrix storing the given triangle
+    pub fn zeros(n: usize, triangle: Triangle) -> Self {
+        Self {
+            n,
+            triangle,
+            data: vec![T::zero(); n * (n + 1) / 2],
+        }
+    }
+
+    /// Wraps n (n + 1) / 2 elements already in packed storage
+    pub fn from_packed(data: Vec<T>, n: usize, triangle: Triangle) -> Self {
+        assert_eq!(
+            data.len(),
+            n * (n + 1) / 2,
+            "packed storage must hold n (n + 1) / 2 elements"
+        );
+        Self { n, triangle, data }
+    }
+
+    /// Copies the given triangle of the square matrix A, the other one
+    /// being ignored
+    pub fn from_dense<D0: Dim, D1: Dim, L: Layout>(
+        a: &Slice<T, (D0, D1), L>,
+        triangle: Triangle,
+    ) -> Self {
+        let ash = *a.shape();
+        let n = ash.dim(0);
+        assert_eq!(n, ash.dim(1), "Matrix must be square");
+
+        let data = match triangle {
+            Triangle::Upper => (0..n)
+                .flat_map(|i| (i..n).map(move |j| (i, j)))
+                .map(|(i, j)| a[[i, j]])
+                .collect(),
+            Triangle::Lower => (0..n)
+                .flat_map(|i| (0..=i).map(move |j| (i, j)))
+                .map(|(i, j)| a[[i, j]])
+                .collect(),
+        };
+        Self { n, triangle, data }
+    }
+
+    /// Order n of the matrix
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The stored triangle
+    pub fn triangle(&self) -> Triangle {
+        self.triangle
+    }
+
+    /// The n (n + 1) / 2 packed elements
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Mutable access to the packed elements
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Whether (i, j) lies within the stored triangle
+    pub fn in_triangle(&self, i: usize, j: usize) -> bool {
+        i < self.n
+            && j < self.n
+            && match self.triangle {
+                Triangle::Upper => i <= j,
+                Triangle::Lower => j <= i,
+            }
+    }
+
+    /// Position of A(i, j) in the packed storage
+    fn offset(&self, i: usize, j: usize) -> usize {
+        match self.triangle {
+            Triangle::Upper => i * self.n - i * (i + 1) / 2 + j,
+            Triangle::Lower => i * (i + 1) / 2 + j,
+        }
+    }
+
+    /// Returns A(i, j), zero outside of the stored triangle
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if self.in_triangle(i, j) {
+            self.data[self.offset(i, j)]
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Sets A(i, j), which must lie within the stored triangle
+    pub fn set(&mut self, i: usize, j: usize, value: T) {
+        assert!(
+            self.in_triangle(i, j),
+            "({i}, {j}) is outside of the stored triangle"
+        );
+        let k = self.offset(i, j);
+        self.data[k] = value;
+    }
+
+    /// Returns the dense n × n triangular matrix
+    pub fn to_dense(&self) -> DTensor<T, 2> {
+        DTensor::<T, 2>::from_fn([self.n, self.n], |i| self.get(i[0], i[1]))
+    }
+}
@@ -0,0 +1,261 @@
+//! Moore–Penrose pseudo-inverse
+//!
+//! For an m × n matrix A with thin SVD A = U Σ Vᴴ, the pseudo-inverse is
+//! the n × m matrix A⁺ = V Σ⁺ Uᴴ, where Σ⁺ inverts the singular values
+//! above a cutoff and zeroes the others. The cutoff is
+//! max(atol, rtol · σ₀), σ₀ being the largest singular value.
+//!
+//! For a Hermitian A, [`PinvHermitian`] uses the eigendecomposition
+//! A = V Λ Vᴴ instead, which is cheaper than an SVD, and the cutoff
+//! applies to the absolute values of the eigenvalues.
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::pinv::PinvTolerance;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg_faer::Faer;
+//!
+//!// Rank one: A = u vᵀ with u = (1, 2) and v = (1, 1, 1)
+//!let mut a = tensor![[1., 1., 1.], [2., 2., 2.]];
+//!let p = Faer.pinv(&mut a, PinvTolerance::default()).unwrap();
+//!
+//!// A⁺ = v uᵀ / (‖u‖² ‖v‖²)
+//!assert_eq!(*p.shape(), (3, 2));
+//!assert!((p[[0, 1]] - 2. / 15.0_f64).abs() < 1e-12);
+//!```
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::{Float, NumCast, Zero};
+use thiserror::Error;
+
+use crate::{
+    eig::{Eig, EigError, EigRange},
+    svd::{SVD, SVDError},
+};
+
+/// Error types related to the pseudo-inverse
+#[derive(Debug, Error)]
+pub enum PinvError {
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+
+    #[error(transparent)]
+    SVD(#[from] SVDError),
+
+    #[error(transparent)]
+    Eig(#[from] EigError),
+}
+
+/// Cutoff below which singular values are treated as zero by [`Pinv`]
+///
+/// Singular values σᵢ ≤ max(atol, rtol · σ₀) are discarded. When neither
+/// tolerance is set, rtol = max(m, n) · ε. When only `atol` is set, no
+/// relative cutoff is applied.
+///
+/// ```rust
+/// use mdarray_linalg::pinv::PinvTolerance;
+///
+/// let tol = PinvTolerance::default().rtol(1e-10).atol(1e-14);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PinvTolerance<R> {
+    pub rtol: Option<R>,
+    pub atol: Option<R>,
+}
+
+impl<R: Float> PinvTolerance<R> {
+    /// Discard singular values smaller than or equal to `rtol` times the largest one
+    pub fn rtol(self, rtol: R) -> Self {
+        Self {
+            rtol: Some(rtol),
+            ..self
+        }
+    }
+
+    /// Discard singular values smaller than or equal to `atol`
+    pub fn atol(self, atol: R) -> Self {
+        Self {
+            atol: Some(atol),
+            ..self
+        }
+    }
+
+    /// Absolute cutoff for an m × n matrix whose largest singular value is `smax`
+    pub fn cutoff(&self, m: usize, n: usize, smax: R) -> R {
+        let rtol = match (self.rtol, self.atol) {
+            (Some(rtol), _) => rtol,
+            (None, Some(_)) => R::zero(),
+            (None, None) => {
+                R::epsilon() * <R as NumCast>::from(m.max(n)).expect("size can be cast to R")
+            }
+        };
+        self.atol.unwrap_or(R::zero()).max(rtol * smax)
+    }
+}
+
+/// Result type for the pseudo-inverse
+pub type PinvResult<T, D> = Result<Tensor<T, (D, D)>, PinvError>;
+
+/// Moore–Penrose pseudo-inverse of a general matrix
+///
+/// Implemented for every backend providing [`SVD`].
+pub trait Pinv<T: ComplexFloat, D: Dim, L: Layout> {
+    /// Computes the n × m pseudo-inverse of the m × n matrix A, which is
+    /// overwritten by the backend
+    fn pinv(&self, a: &mut Slice<T, (D, D), L>, tol: PinvTolerance<T::Real>) -> PinvResult<T, D>;
+
+    /// Computes the pseudo-inverse of A into the n × m matrix `p`, A being
+    /// overwritten by the backend. Returns the number of singular values
+    /// kept.
+    fn pinv_write<Lp: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        tol: PinvTolerance<T::Real>,
+        p: &mut Slice<T, (D, D), Lp>,
+    ) -> Result<usize, PinvError>;
+}
+
+impl<T, D, L, B> Pinv<T, D, L> for B
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: SVD<T, D, L>,
+{
+    fn pinv(&self, a: &mut Slice<T, (D, D), L>, tol: PinvTolerance<T::Real>) -> PinvResult<T, D> {
+        let ash = *a.shape();
+        let p_shape = <(D, D) as Shape>::from_dims(&[ash.dim(1), ash.dim(0)]);
+        let mut p = Tensor::from_elem(p_shape, T::zero());
+        self.pinv_write(a, tol, &mut p)?;
+        Ok(p)
+    }
+
+    fn pinv_write<Lp: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        tol: PinvTolerance<T::Real>,
+        p: &mut Slice<T, (D, D), Lp>,
+    ) -> Result<usize, PinvError> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        assert_eq!(
+            (p.shape().dim(0), p.shape().dim(1)),
+            (n, m),
+            "The pseudo-inverse of an m × n matrix is n × m"
+        );
+
+        p.fill(T::zero());
+        if m == 0 || n == 0 {
+            return Ok(0);
+        }
+
+        let decomp = self.svd_thin(a)?;
+        let (s, u, vt) = (&decomp.s, &decomp.u, &decomp.vt);
+
+        // Singular values are sorted in descending order
+        let cutoff = tol.cutoff(m, n, s[[0, 0]].abs());
+        let k = m.min(n);
+        let rank = (0..k).take_while(|&l| s[[0, l]].abs() > cutoff).count();
+
+        // A⁺ = Σₗ vₗ σₗ⁻¹ uₗᴴ over the kept singular triplets
+        for l in 0..rank {
+            let inv_s = T::one() / s[[0, l]];
+            for i in 0..n {
+                let vi = vt[[l, i]].conj() * inv_s;
+                for j in 0..m {
+                    p[[i, j]] = p[[i, j]] + vi * u[[j, l]].conj();
+                }
+            }
+        }
+
+        Ok(rank)
+    }
+}
+
+/// Moore–Penrose pseudo-inverse of a Hermitian matrix
+///
+/// Implemented for every backend providing [`Eig`].
+pub trait PinvHermitian<T: ComplexFloat, D: Dim> {
+    /// Computes the pseudo-inverse of the Hermitian matrix A, of which
+    /// only the lower triangle is referenced. A is overwritten by the
+    /// backend.
+    fn pinvh<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        tol: PinvTolerance<T::Real>,
+    ) -> PinvResult<T, D>;
+
+    /// Computes the pseudo-inverse of the Hermitian matrix A into `p`, A
+    /// being overwritten by the backend. Returns the number of
+    /// eigenvalues kept.
+    fn pinvh_write<L: Layout, Lp: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        tol: PinvTolerance<T::Real>,
+        p: &mut Slice<T, (D, D), Lp>,
+    ) -> Result<usize, PinvError>;
+}
+
+impl<T, D, B> PinvHermitian<T, D> for B
+where
+    T: ComplexFloat,
+    D: Dim,
+    B: Eig<T, D, D>,
+{
+    fn pinvh<L: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        tol: PinvTolerance<T::Real>,
+    ) -> PinvResult<T, D> {
+        let mut p = Tensor::from_elem(*a.shape(), T::zero());
+        self.pinvh_write(a, tol, &mut p)?;
+        Ok(p)
+    }
+
+    fn pinvh_write<L: Layout, Lp: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        tol: PinvTolerance<T::Real>,
+        p: &mut Slice<T, (D, D), Lp>,
+    ) -> Result<usize, PinvError> {
+        let ash = *a.shape();
+        let n = ash.dim(0);
+        if n != ash.dim(1) {
+            return Err(PinvError::NotSquareMatrix);
+        }
+        assert_eq!(
+            (p.shape().dim(0), p.shape().dim(1)),
+            (n, n),
+            "A and its pseudo-inverse must have the same shape"
+        );
+
+        p.fill(T::zero());
+        if n == 0 {
+            return Ok(0);
+        }
+
+        let decomp = self.eigh_select(a, EigRange::Index(0..n))?;
+        let (w, v) = (&decomp.eigenvalues, &decomp.eigenvectors);
+
+        let wmax = w
+            .iter()
+            .fold(T::Real::zero(), |acc, x| acc.max(Float::abs(*x)));
+        let cutoff = tol.cutoff(n, n, wmax);
+
+        // A⁺ = Σₗ vₗ λₗ⁻¹ vₗᴴ over the kept eigenpairs
+        let mut rank = 0;
+        for l in (0..n).filter(|&l| Float::abs(w[[l]]) > cutoff) {
+            rank += 1;
+            let inv_w =
+                T::one() / <T as NumCast>::from(w[[l]]).expect("real values can be cast to T");
+            for i in 0..n {
+                let vi = v[[i, l]] * inv_w;
+                for j in 0..n {
+                    p[[i, j]] = p[[i, j]] + vi * v[[j, l]].conj();
+                }
+            }
+        }
+
+        Ok(rank)
+    }
+}
@@ -0,0 +1,331 @@
+//! Polar decomposition A = U H
+//!
+//! For an m × n matrix A, U is m × n with orthonormal columns (m ≥ n) or
+//! rows (m < n) and H = (Aᴴ A)^½ is n × n Hermitian positive
+//! semidefinite. U is the unitary matrix nearest to A in the Frobenius
+//! norm, which makes it the solution of orthogonal Procrustes problems and
+//! of Löwdin orthogonalization.
+//!
+//! Two paths are provided:
+//! - [`Polar`], from the thin SVD A = W Σ Vᴴ: U = W Vᴴ and H = V Σ Vᴴ.
+//!   Implemented for every backend providing [`SVD`].
+//! - [`PolarIterative`], from iterations converging to U: scaled Newton
+//!   for square nonsingular matrices and Halley for any shape. Implemented
+//!   for every backend providing [`MatMul`] and [`Solve`].
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg_faer::Faer;
+//!
+//!// Rotation by 90° scaled by 2: U is the rotation and H = 2 I
+//!let a = tensor![[0., -2.], [2., 0.0_f64]];
+//!let decomp = Faer.polar(&mut a.clone()).unwrap();
+//!assert!((decomp.u[[1, 0]] - 1.).abs() < 1e-12);
+//!assert!((decomp.h[[0, 0]] - 2.).abs() < 1e-12);
+//!
+//!let decomp = Faer.polar_newton(&a).unwrap();
+//!assert!((decomp.u[[1, 0]] - 1.).abs() < 1e-12);
+//!```
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::{Float, MulAdd, NumCast, One, Zero};
+use thiserror::Error;
+
+use crate::{
+    matmul::{MatMul, MatMulBuilder},
+    solve::{Solve, SolveError},
+    svd::{SVD, SVDError},
+};
+
+/// Maximum number of Newton or Halley iterations
+const MAX_ITERATIONS: usize = 100;
+
+/// Error types related to the polar decomposition
+#[derive(Debug, Error)]
+pub enum PolarError {
+    #[error("SVD failed: {0}")]
+    SVD(#[from] SVDError),
+
+    #[error("Linear solve failed: {0}")]
+    Solve(#[from] SolveError),
+
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+
+    #[error("Iteration did not converge within {iterations} iterations")]
+    DidNotConverge { iterations: usize },
+
+    /// The iterate contains NaN, typically for a singular or NaN input
+    #[error("Iteration broke down after {iterations} iterations")]
+    Breakdown { iterations: usize },
+}
+
+/// Holds the results of a polar decomposition A = U H
+pub struct PolarDecomp<T, D: Dim> {
+    /// m × n factor with orthonormal columns, or rows if m < n
+    pub u: Tensor<T, (D, D)>,
+    /// n × n Hermitian positive semidefinite factor
+    pub h: Tensor<T, (D, D)>,
+}
+
+/// Result type for the polar decomposition, returning either a
+/// `PolarDecomp` or a `PolarError`
+pub type PolarResult<T, D> = Result<PolarDecomp<T, D>, PolarError>;
+
+/// Polar decomposition from the SVD
+///
+/// Implemented for every backend providing [`SVD`].
+pub trait Polar<T: ComplexFloat, D: Dim, L: Layout> {
+    /// Computes the polar decomposition of the m × n matrix A, which is
+    /// overwritten by the backend
+    fn polar(&self, a: &mut Slice<T, (D, D), L>) -> PolarResult<T, D>;
+}
+
+impl<T, D, L, B> Polar<T, D, L> for B
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: SVD<T, D, L>,
+{
+    fn polar(&self, a: &mut Slice<T, (D, D), L>) -> PolarResult<T, D> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        let k = m.min(n);
+        let mut u = Tensor::from_elem(<(D, D) as Shape>::from_dims(&[m, n]), T::zero());
+        let mut h = Tensor::from_elem(<(D, D) as Shape>::from_dims(&[n, n]), T::zero());
+        if k == 0 {
+            return Ok(PolarDecomp { u, h });
+        }
+
+        let decomp = self.svd_thin(a)?;
+        let (s, w, vt) = (&decomp.s, &decomp.u, &decomp.vt);
+
+        // U = Σₗ wₗ vₗᴴ and H = Σₗ vₗ σₗ vₗᴴ over the singular triplets
+        for l in 0..k {
+            for j in 0..n {
+                for i in 0..m {
+                    u[[i, j]] = u[[i, j]] + w[[i, l]] * vt[[l, j]];
+                }
+                let sv = s[[0, l]] * vt[[l, j]];
+                for i in 0..n {
+                    h[[i, j]] = h[[i, j]] + vt[[l, i]].conj() * sv;
+                }
+            }
+        }
+
+        Ok(PolarDecomp { u, h })
+    }
+}
+
+/// Polar decomposition by iterations using only matrix products and
+/// linear solves
+///
+/// Implemented for every backend providing [`MatMul`] and [`Solve`]. The
+/// Newton iteration stops once the relative change of the iterate drops
+/// to the unit roundoff level, the Halley iteration once Xₖᴴ Xₖ is close
+/// enough to I or the iterate no longer changes.
+pub trait PolarIterative<T: ComplexFloat, D: Dim> {
+    /// Computes the polar decomposition of the square nonsingular matrix A
+    /// with the scaled Newton iteration Xₖ₊₁ = (ζₖ Xₖ + ζₖ⁻¹ Xₖ⁻ᴴ) / 2,
+    /// which converges quadratically
+    fn polar_newton<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> PolarResult<T, D>;
+
+    /// Computes the polar decomposition of the m × n matrix A, possibly
+    /// rank-deficient, with the Halley iteration
+    /// Xₖ₊₁ = Xₖ (3 I + Xₖᴴ Xₖ)(I + 3 Xₖᴴ Xₖ)⁻¹, which converges cubically.
+    /// For a rank-deficient A, U is a partial isometry mapping the row
+    /// space of A onto its column space.
+    fn polar_halley<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> PolarResult<T, D>;
+}
+
+impl<T, D, B> PolarIterative<T, D> for B
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    B: MatMul<T> + Solve<T, D, D>,
+{
+    fn polar_newton<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> PolarResult<T, D> {
+        let ash = *a.shape();
+        let n = ash.dim(0);
+        if ash.dim(1) != n {
+            return Err(PolarError::NotSquareMatrix);
+        }
+
+        let a = a.to_tensor();
+        let half = real::<T::Real>(0.5);
+        let mut scaling = true;
+        let u = iterate(&a, 2, |x| {
+            let inv = self.solve(&mut x.clone(), &identity::<T, D>(n))?.x;
+
+            // Frobenius norm scaling ζ = (‖X⁻¹‖ / ‖X‖)^½, dropped close
+            // to convergence where it would slow it down
+            let zeta = if scaling {
+                Float::sqrt(norm_fro(&inv) / norm_fro(x))
+            } else {
+                T::Real::one()
+            };
+            let next = Tensor::from_fn(*x.shape(), |i| {
+                let (y, z) = (x[[i[0], i[1]]], inv[[i[1], i[0]]].conj());
+                to_t::<T>(half) * (to_t::<T>(zeta) * y + z / to_t::<T>(zeta))
+            });
+            scaling = relative_change(x, &next) > real(1e-2);
+            Ok((next, None))
+        })?;
+
+        let h = hermitian_part(&self.matmul(&adjoint(&u), &a).eval());
+        Ok(PolarDecomp { u, h })
+    }
+
+    fn polar_halley<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> PolarResult<T, D> {
+        let ash = *a.shape();
+        let (m, n) = (ash.dim(0), ash.dim(1));
+        if m == 0 || n == 0 {
+            return Ok(PolarDecomp {
+                u: Tensor::from_elem(<(D, D) as Shape>::from_dims(&[m, n]), T::zero()),
+                h: Tensor::from_elem(<(D, D) as Shape>::from_dims(&[n, n]), T::zero()),
+            });
+        }
+        let a = a.to_tensor();
+
+        // A wide A is handled through the tall Aᴴ = U' H', with U = U'ᴴ
+        let tall = if m >= n { a.clone() } else { adjoint(&a) };
+        let (p, q) = (m.max(n), m.min(n));
+        let norm = norm_fro(&tall);
+
+        let u = if norm == T::Real::zero() {
+            Tensor::from_elem(<(D, D) as Shape>::from_dims(&[p, q]), T::zero())
+        } else {
+            // Scaling by the Frobenius norm brings the singular values into
+            // (0, 1], which the iteration maps monotonically to 1
+            let x0 = Tensor::from_fn(*tall.shape(), |i| tall[[i[0], i[1]]] / to_t(norm));
+            let (one, three) = (T::one(), to_t::<T>(real(3.)));
+            iterate(&x0, 3, |x| {
+                let xhx = self.matmul(&adjoint(x), x).eval();
+                let residual = norm_fro(&Tensor::from_fn(*xhx.shape(), |i| {
+                    if i[0] == i[1] {
+                        xhx[[i[0], i[1]]] - one
+                    } else {
+                        xhx[[i[0], i[1]]]
+                    }
+                }));
+                let mut lhs = Tensor::from_fn(*xhx.shape(), |i| three * xhx[[i[0], i[1]]]);
+                let mut rhs = xhx;
+                for i in 0..q {
+                    lhs[[i, i]] = lhs[[i, i]] + one;
+                    rhs[[i, i]] = rhs[[i, i]] + three;
+                }
+                // (3 I + XᴴX) and (I + 3 XᴴX)⁻¹ commute
+                let z = self.solve(&mut lhs, &rhs)?.x;
+                Ok((self.matmul(x, &z).eval(), Some(residual)))
+            })?
+        };
+
+        let u = if m >= n { u } else { adjoint(&u) };
+        let h = hermitian_part(&self.matmul(&adjoint(&u), &a).eval());
+        Ok(PolarDecomp { u, h })
+    }
+}
+
+/// Next iterate of a step and, when the step computes it, ‖XᴴX − I‖_F
+/// for the current iterate X
+type Step<T, D> = (Tensor<T, (D, D)>, Option<<T as ComplexFloat>::Real>);
+
+/// Runs `step` from `x0` until the iteration of the given `order` of
+/// convergence has reached the unit roundoff level.
+///
+/// With ‖XᴴX − I‖_F known, it stops once this residual is small enough or
+/// the iterate no longer changes, as for exactly rank-deficient matrices:
+/// small singular values only grow by a constant factor per step, keeping
+/// the change small long before they reach 1. Otherwise it stops once the
+/// relative change of the iterate is small enough, or stops decreasing.
+fn iterate<T: ComplexFloat, D: Dim>(
+    x0: &Tensor<T, (D, D)>,
+    order: i32,
+    mut step: impl FnMut(&Tensor<T, (D, D)>) -> Result<Step<T, D>, PolarError>,
+) -> Result<Tensor<T, (D, D)>, PolarError> {
+    if x0.is_empty() {
+        return Ok(x0.clone());
+    }
+    let n = x0.shape().dim(0).max(x0.shape().dim(1));
+    let roundoff = real::<T::Real>(n as f64) * T::Real::epsilon();
+    // The error of the next iterate is about the current change to the
+    // power `order`
+    let tol = Float::powf(roundoff, T::Real::one() / real::<T::Real>(order as f64));
+
+    let mut x = x0.clone();
+    let mut previous = T::Real::infinity();
+    for iteration in 1..=MAX_ITERATIONS {
+        let (next, residual) = step(&x)?;
+        let change = relative_change(&x, &next);
+        x = next;
+        if Float::is_nan(change) {
+            return Err(PolarError::Breakdown {
+                iterations: iteration,
+            });
+        }
+        let converged = match residual {
+            Some(residual) => residual <= tol || change <= roundoff,
+            None => change <= tol || (change <= real(1e-2) && change >= previous),
+        };
+        if converged {
+            return Ok(x);
+        }
+        previous = change;
+    }
+    Err(PolarError::DidNotConverge {
+        iterations: MAX_ITERATIONS,
+    })
+}
+
+fn real<R: Float>(x: f64) -> R {
+    <R as NumCast>::from(x).unwrap()
+}
+
+fn to_t<T: ComplexFloat>(x: T::Real) -> T {
+    <T as NumCast>::from(x).expect("real values can be cast to T")
+}
+
+fn identity<T: ComplexFloat, D: Dim>(n: usize) -> Tensor<T, (D, D)> {
+    Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+        if i[0] == i[1] { T::one() } else { T::zero() }
+    })
+}
+
+fn adjoint<T: ComplexFloat, D: Dim>(a: &Tensor<T, (D, D)>) -> Tensor<T, (D, D)> {
+    let ash = *a.shape();
+    Tensor::from_fn(
+        <(D, D) as Shape>::from_dims(&[ash.dim(1), ash.dim(0)]),
+        |i| a[[i[1], i[0]]].conj(),
+    )
+}
+
+/// (A + Aᴴ) / 2, removing the rounding errors that make A non-Hermitian
+fn hermitian_part<T: ComplexFloat, D: Dim>(a: &Tensor<T, (D, D)>) -> Tensor<T, (D, D)> {
+    let half = to_t::<T>(real(0.5));
+    Tensor::from_fn(*a.shape(), |i| {
+        (a[[i[0], i[1]]] + a[[i[1], i[0]]].conj()) * half
+    })
+}
+
+fn norm_fro<T: ComplexFloat, D: Dim>(a: &Tensor<T, (D, D)>) -> T::Real {
+    Float::sqrt(
+        a.iter()
+            .fold(T::Real::zero(), |acc, &x| acc + (x * x.conj()).re()),
+    )
+}
+
+/// ‖Y − X‖_F / ‖Y‖_F
+fn relative_change<T: ComplexFloat, D: Dim>(
+    x: &Tensor<T, (D, D)>,
+    y: &Tensor<T, (D, D)>,
+) -> T::Real {
+    let diff = x
+        .iter()
+        .zip(y.iter())
+        .fold(T::Real::zero(), |acc, (&a, &b)| {
+            acc + ((a - b) * (a - b).conj()).re()
+        });
+    Float::sqrt(diff) / norm_fro::<T, D>(y)
+}
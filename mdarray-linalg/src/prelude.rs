@@ -2,12 +2,28 @@
 //! namespace.
 
 pub use super::{
+    banded::BandSolve as _,
     eig::Eig as _,
-    lu::LU as _,
-    matmul::{ContractBuilder as _, MatMul as _, MatMulBuilder as _},
+    einsum::Einsum as _,
+    expm::Expm as _,
+    geneig::GenEig as _,
+    lstsq::Lstsq as _,
+    lu::{CholeskyFactor as _, LU as _, LdlFactor as _, LuFactor as _},
+    matmul::{
+        BatchedMatMul as _, BatchedMatMulBuilder as _, ContractBuilder as _, MatMul as _,
+        MatMulBuilder as _,
+    },
     matvec::{
-        Argmax as _, MatVec as _, MatVecBuilder as _, Outer as _, OuterBuilder as _, VecOps as _,
+        Argmax as _, MatVec as _, MatVecBuilder as _, Outer as _, OuterBuilder as _,
+        StructuredMatVec as _, VecOps as _,
     },
-    qr::QR as _,
-    svd::SVD as _,
+    ncon::Ncon as _,
+    norm::{Cond as _, MatrixNorm as _},
+    pinv::{Pinv as _, PinvHermitian as _},
+    polar::{Polar as _, PolarIterative as _},
+    qr::{QR as _, QRPivot as _},
+    reduction::Reduction as _,
+    svd::{RandomizedSVD as _, SVD as _, TruncatedSVD as _},
+    sylvester::{Sylvester as _, SylvesterTriangular as _},
+    trisolve::{TriSolve as _, TriSolveBuilder as _},
 };
@@ -1,9 +1,14 @@
 //! QR decomposition
-use mdarray::{Dim, Layout, Slice, Tensor};
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+
+/// Factors (Q, R) of a QR decomposition
+pub type QRFactors<T, D0, D1> = (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>);
 
 /// QR decomposition for orthogonal-triangular factorization
 pub trait QR<T, D0: Dim, D1: Dim> {
     /// Compute QR decomposition overwriting existing matrices
+    /// For an m × n matrix A, `q` is m × m and `r` is m × n.
     fn qr_write<L: Layout, Lq: Layout, Lr: Layout>(
         &self,
         a: &mut Slice<T, (D0, D1), L>,
@@ -12,8 +17,79 @@ pub trait QR<T, D0: Dim, D1: Dim> {
     );
 
     /// Compute QR decomposition with new allocated matrices
-    fn qr<L: Layout>(
+    fn qr<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> QRFactors<T, D0, D1>;
+
+    /// Compute thin (economy) QR decomposition overwriting existing matrices
+    /// For an m × n matrix A and k = min(m, n), `q` is m × k with
+    /// orthonormal columns and `r` is k × n.
+    fn qr_thin_write<L: Layout, Lq: Layout, Lr: Layout>(
+        &self,
+        a: &mut Slice<T, (D0, D1), L>,
+        q: &mut Slice<T, (D0, D1), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+    );
+
+    /// Compute thin (economy) QR decomposition with new allocated matrices
+    /// For an m × n matrix A and k = min(m, n), Q is m × k and R is k × n.
+    fn qr_thin<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> QRFactors<T, D0, D1>;
+}
+
+/// Holds the results of a QR decomposition with column pivoting A P = Q R
+pub struct QRPivotDecomp<T, D0: Dim, D1: Dim> {
+    /// Orthogonal (unitary) factor Q, m × m
+    pub q: Tensor<T, (D0, D0)>,
+    /// Upper triangular factor R, m × n, with |R₀₀| ≥ |R₁₁| ≥ …
+    pub r: Tensor<T, (D0, D1)>,
+    /// Column permutation: the j-th column of A P is the `perm[j]`-th column of A
+    pub perm: Vec<usize>,
+}
+
+impl<T: ComplexFloat, D0: Dim, D1: Dim> QRPivotDecomp<T, D0, D1> {
+    /// Numerical rank of A: the number of diagonal elements of R with
+    /// |Rᵢᵢ| > rtol · |R₀₀|
+    pub fn rank(&self, rtol: T::Real) -> usize {
+        pivoted_rank(&self.r, rtol)
+    }
+}
+
+/// Numerical rank read off the diagonal of a column-pivoted R factor:
+/// the number of diagonal elements with |Rᵢᵢ| > rtol · |R₀₀|
+pub fn pivoted_rank<T: ComplexFloat, D0: Dim, D1: Dim, L: Layout>(
+    r: &Slice<T, (D0, D1), L>,
+    rtol: T::Real,
+) -> usize {
+    let rsh = *r.shape();
+    let k = rsh.dim(0).min(rsh.dim(1));
+    leading_rank(k, |r00| rtol * r00, |i| r[[i, i]])
+}
+
+/// Number of leading values `d(i)`, i < k, sorted by decreasing modulus,
+/// above `cutoff(|d(0)|)`
+pub(crate) fn leading_rank<T: ComplexFloat>(
+    k: usize,
+    cutoff: impl FnOnce(T::Real) -> T::Real,
+    d: impl Fn(usize) -> T,
+) -> usize {
+    if k == 0 {
+        return 0;
+    }
+
+    let cutoff = cutoff(d(0).abs());
+    (0..k).take_while(|&i| d(i).abs() > cutoff).count()
+}
+
+/// Rank-revealing QR decomposition with column pivoting
+pub trait QRPivot<T, D0: Dim, D1: Dim> {
+    /// Compute A P = Q R overwriting existing matrices
+    /// `q` is m × m, `r` is m × n and `perm` has length n.
+    fn qr_pivot_write<L: Layout, Lq: Layout, Lr: Layout>(
         &self,
         a: &mut Slice<T, (D0, D1), L>,
-    ) -> (Tensor<T, (D0, D1)>, Tensor<T, (D0, D1)>);
+        q: &mut Slice<T, (D0, D0), Lq>,
+        r: &mut Slice<T, (D0, D1), Lr>,
+        perm: &mut [usize],
+    );
+
+    /// Compute A P = Q R with new allocated matrices
+    fn qr_pivot<L: Layout>(&self, a: &mut Slice<T, (D0, D1), L>) -> QRPivotDecomp<T, D0, D1>;
 }
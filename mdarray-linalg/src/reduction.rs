@@ -0,0 +1,131 @@
+//! Orthogonal reductions to condensed forms
+//!
+//! These are the first stage of dense eigenvalue and singular value
+//! solvers:
+//! - [`Reduction::hessenberg`]: A = Q H Qᴴ with H upper Hessenberg,
+//! - [`Reduction::tridiagonal`]: A = Q T Qᴴ for a Hermitian A, with T real
+//!   symmetric tridiagonal,
+//! - [`Reduction::bidiagonal`]: A = Q B Pᴴ with B real bidiagonal, upper
+//!   when A has at least as many rows as columns and lower otherwise.
+//!
+//! Q and P are unitary (orthogonal for real matrices) and are returned
+//! explicitly.
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::NumCast;
+use thiserror::Error;
+
+/// Error types related to the reductions
+#[derive(Debug, Error)]
+pub enum ReductionError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+}
+
+/// Holds the results of a Hessenberg reduction A = Q H Qᴴ
+pub struct HessenbergDecomp<T, D: Dim> {
+    /// Upper Hessenberg matrix, zero below the first subdiagonal
+    pub h: Tensor<T, (D, D)>,
+    /// Unitary factor
+    pub q: Tensor<T, (D, D)>,
+}
+
+/// Holds the results of a tridiagonal reduction A = Q T Qᴴ
+pub struct TridiagonalDecomp<T: ComplexFloat, D: Dim> {
+    /// Diagonal of T, of length n
+    pub d: Vec<T::Real>,
+    /// Subdiagonal of T, equal to its superdiagonal, of length n − 1
+    pub e: Vec<T::Real>,
+    /// Unitary factor
+    pub q: Tensor<T, (D, D)>,
+}
+
+impl<T: ComplexFloat, D: Dim> TridiagonalDecomp<T, D> {
+    /// Returns the n × n tridiagonal matrix T
+    pub fn t(&self) -> Tensor<T, (D, D)> {
+        let n = self.d.len();
+        Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+            let (r, c) = (i[0], i[1]);
+            if r == c {
+                real_to(self.d[r])
+            } else if r == c + 1 || c == r + 1 {
+                real_to(self.e[r.min(c)])
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+/// Holds the results of a bidiagonal reduction A = Q B Pᴴ of an m × n
+/// matrix, with k = min(m, n)
+pub struct BidiagonalDecomp<T: ComplexFloat, D: Dim> {
+    /// Diagonal of B, of length k
+    pub d: Vec<T::Real>,
+    /// Off-diagonal of B, of length k − 1: the superdiagonal if `upper`,
+    /// the subdiagonal otherwise
+    pub e: Vec<T::Real>,
+    /// Whether B is upper bidiagonal, which is the case when m ≥ n
+    pub upper: bool,
+    /// m × k factor with orthonormal columns
+    pub q: Tensor<T, (D, D)>,
+    /// k × n factor Pᴴ with orthonormal rows
+    pub pt: Tensor<T, (D, D)>,
+}
+
+impl<T: ComplexFloat, D: Dim> BidiagonalDecomp<T, D> {
+    /// Returns the k × k bidiagonal matrix B
+    pub fn b(&self) -> Tensor<T, (D, D)> {
+        let k = self.d.len();
+        Tensor::from_fn(<(D, D) as Shape>::from_dims(&[k, k]), |i| {
+            let (r, c) = (i[0], i[1]);
+            if r == c {
+                real_to(self.d[r])
+            } else if (self.upper && c == r + 1) || (!self.upper && r == c + 1) {
+                real_to(self.e[r.min(c)])
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+fn real_to<T: ComplexFloat>(x: T::Real) -> T {
+    <T as NumCast>::from(x).expect("real values can be cast to T")
+}
+
+/// Result type for the Hessenberg reduction
+pub type HessenbergResult<T, D> = Result<HessenbergDecomp<T, D>, ReductionError>;
+
+/// Result type for the tridiagonal reduction
+pub type TridiagonalResult<T, D> = Result<TridiagonalDecomp<T, D>, ReductionError>;
+
+/// Result type for the bidiagonal reduction
+pub type BidiagonalResult<T, D> = Result<BidiagonalDecomp<T, D>, ReductionError>;
+
+/// Orthogonal reductions to Hessenberg, tridiagonal and bidiagonal form
+pub trait Reduction<T: ComplexFloat, D: Dim> {
+    /// Reduces the square matrix A to upper Hessenberg form, A = Q H Qᴴ
+    fn hessenberg<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> HessenbergResult<T, D>;
+
+    /// Reduces the Hermitian matrix A to real symmetric tridiagonal form,
+    /// A = Q T Qᴴ. Only the lower triangle of A is referenced.
+    fn tridiagonal<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> TridiagonalResult<T, D>;
+
+    /// Reduces the m × n matrix A to real bidiagonal form, A = Q B Pᴴ
+    fn bidiagonal<L: Layout>(&self, a: &Slice<T, (D, D), L>) -> BidiagonalResult<T, D>;
+}
+
+/// Checks that A is square, returning n
+pub fn reduction_dims<T, D: Dim, L: Layout>(
+    a: &Slice<T, (D, D), L>,
+) -> Result<usize, ReductionError> {
+    let ash = *a.shape();
+    if ash.dim(0) != ash.dim(1) {
+        return Err(ReductionError::NotSquareMatrix);
+    }
+    Ok(ash.dim(0))
+}
@@ -0,0 +1,220 @@
+//! Numerical rank and orthonormal bases of the fundamental subspaces
+//!
+//! For an m × n matrix A of numerical rank r:
+//! - [`rank`] returns r,
+//! - [`orth`] returns an m × r orthonormal basis of the range of A,
+//! - [`null_space`] returns an n × (n − r) orthonormal basis of the null
+//!   space of A.
+//!
+//! They are computed from the SVD of A, singular values at or below
+//! max(atol, rtol · σ₀) being treated as zero as in [`Pinv`](crate::pinv::Pinv).
+//! The `_qr` variants use a QR decomposition with column pivoting
+//! instead, which is faster but only rank-revealing in practice: the
+//! cutoff then applies to the diagonal of R.
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::pinv::PinvTolerance;
+//!use mdarray_linalg::subspace::{null_space, rank};
+//!use mdarray_linalg_faer::Faer;
+//!
+//!let a = tensor![[1., 2., 3.], [2., 4., 6.]];
+//!let tol = PinvTolerance::default();
+//!assert_eq!(rank(&Faer, &mut a.clone(), tol).unwrap(), 1);
+//!
+//!let z = null_space(&Faer, &mut a.clone(), tol).unwrap();
+//!assert_eq!(*z.shape(), (3, 2));
+//!```
+use mdarray::{Dense, Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+
+use crate::{
+    pinv::PinvTolerance,
+    qr::{QRPivot, leading_rank},
+    svd::{SVD, SVDError},
+};
+
+/// The first `cols` columns of `q`, or its last ones when `from_end` is set
+fn columns<T: ComplexFloat, D: Dim>(
+    q: &Slice<T, (D, D), impl Layout>,
+    cols: usize,
+    from_end: bool,
+) -> Tensor<T, (D, D)> {
+    let (m, n) = (q.shape().dim(0), q.shape().dim(1));
+    let offset = if from_end { n - cols } else { 0 };
+    Tensor::from_fn(<(D, D) as Shape>::from_dims(&[m, cols]), |i| {
+        q[[i[0], offset + i[1]]]
+    })
+}
+
+/// n × n identity, the null space of an empty m × n matrix
+fn identity<T: ComplexFloat, D: Dim>(n: usize) -> Tensor<T, (D, D)> {
+    Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+        if i[0] == i[1] { T::one() } else { T::zero() }
+    })
+}
+
+/// Numerical rank of A, the number of singular values above the cutoff.
+/// A is overwritten by the backend.
+pub fn rank<T, D, L, B>(
+    bd: &B,
+    a: &mut Slice<T, (D, D), L>,
+    tol: PinvTolerance<T::Real>,
+) -> Result<usize, SVDError>
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: SVD<T, D, L>,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m == 0 || n == 0 {
+        return Ok(0);
+    }
+
+    let s = bd.svd_s(a)?;
+    Ok(leading_rank(
+        m.min(n),
+        |s0| tol.cutoff(m, n, s0),
+        |i| s[[0, i]],
+    ))
+}
+
+/// Orthonormal basis of the range of A, as an m × r matrix.
+/// A is overwritten by the backend.
+pub fn orth<T, D, L, B>(
+    bd: &B,
+    a: &mut Slice<T, (D, D), L>,
+    tol: PinvTolerance<T::Real>,
+) -> Result<Tensor<T, (D, D)>, SVDError>
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: SVD<T, D, L>,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m == 0 || n == 0 {
+        return Ok(Tensor::from_elem(
+            <(D, D) as Shape>::from_dims(&[m, 0]),
+            T::zero(),
+        ));
+    }
+
+    let decomp = bd.svd_thin(a)?;
+    let r = leading_rank(m.min(n), |s0| tol.cutoff(m, n, s0), |i| decomp.s[[0, i]]);
+    Ok(columns(&decomp.u, r, false))
+}
+
+/// Orthonormal basis of the null space of A, as an n × (n − r) matrix.
+/// A is overwritten by the backend.
+///
+/// Only a thin SVD is computed: a wide A is first padded with zero rows,
+/// which leaves its null space unchanged, so that Vᴴ is n × n.
+pub fn null_space<T, D, L, B>(
+    bd: &B,
+    a: &mut Slice<T, (D, D), L>,
+    tol: PinvTolerance<T::Real>,
+) -> Result<Tensor<T, (D, D)>, SVDError>
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: SVD<T, D, L> + SVD<T, D, Dense>,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m == 0 || n == 0 {
+        return Ok(identity(n));
+    }
+
+    let decomp = if m >= n {
+        bd.svd_thin(a)?
+    } else {
+        let mut padded = Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, n]), |i| {
+            if i[0] < m { a[[i[0], i[1]]] } else { T::zero() }
+        });
+        bd.svd_thin(&mut padded)?
+    };
+
+    // The last n − r rows of Vᴴ span the null space
+    let r = leading_rank(m.min(n), |s0| tol.cutoff(m, n, s0), |i| decomp.s[[0, i]]);
+    let vt = &decomp.vt;
+    Ok(Tensor::from_fn(
+        <(D, D) as Shape>::from_dims(&[n, n - r]),
+        |i| vt[[r + i[1], i[0]]].conj(),
+    ))
+}
+
+/// Numerical rank of A, the number of diagonal elements of the
+/// column-pivoted R factor above the cutoff. A is overwritten by the
+/// backend.
+pub fn rank_qr<T, D, L, B>(
+    bd: &B,
+    a: &mut Slice<T, (D, D), L>,
+    tol: PinvTolerance<T::Real>,
+) -> usize
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: QRPivot<T, D, D>,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m == 0 || n == 0 {
+        return 0;
+    }
+
+    let r = bd.qr_pivot(a).r;
+    leading_rank(m.min(n), |s0| tol.cutoff(m, n, s0), |i| r[[i, i]])
+}
+
+/// Orthonormal basis of the range of A from its column-pivoted QR
+/// decomposition, as an m × r matrix. A is overwritten by the backend.
+pub fn orth_qr<T, D, L, B>(
+    bd: &B,
+    a: &mut Slice<T, (D, D), L>,
+    tol: PinvTolerance<T::Real>,
+) -> Tensor<T, (D, D)>
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: QRPivot<T, D, D>,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m == 0 || n == 0 {
+        return Tensor::from_elem(<(D, D) as Shape>::from_dims(&[m, 0]), T::zero());
+    }
+
+    let decomp = bd.qr_pivot(a);
+    let r = leading_rank(m.min(n), |s0| tol.cutoff(m, n, s0), |i| decomp.r[[i, i]]);
+    columns(&decomp.q, r, false)
+}
+
+/// Orthonormal basis of the null space of A from the column-pivoted QR
+/// decomposition of Aᴴ, as an n × (n − r) matrix. A is left untouched.
+pub fn null_space_qr<T, D, L, B>(
+    bd: &B,
+    a: &Slice<T, (D, D), L>,
+    tol: PinvTolerance<T::Real>,
+) -> Tensor<T, (D, D)>
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: QRPivot<T, D, D>,
+{
+    let (m, n) = (a.shape().dim(0), a.shape().dim(1));
+    if m == 0 || n == 0 {
+        return identity(n);
+    }
+
+    // Aᴴ P = Q R: the first r columns of Q span the range of Aᴴ, the
+    // remaining ones its orthogonal complement, the null space of A
+    let mut a_h = Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, m]), |i| {
+        a[[i[1], i[0]]].conj()
+    });
+    let decomp = bd.qr_pivot(&mut a_h);
+    let r = leading_rank(m.min(n), |s0| tol.cutoff(n, m, s0), |i| decomp.r[[i, i]]);
+    columns(&decomp.q, n - r, true)
+}
@@ -1,7 +1,22 @@
 //! Singular Value Decomposition (SVD)
-use mdarray::{Dim, Layout, Slice, Tensor};
+//!
+//! Besides the full and thin decompositions of [`SVD`], this module
+//! provides truncated decompositions that only keep the dominant
+//! singular triplets:
+//! - [`TruncatedSVD`] truncates the thin SVD of any [`SVD`] backend,
+//! - [`RandomizedSVD`] approximates the dominant triplets with a randomized
+//!   range finder, using only [`MatMul`], [`QR`] and an SVD of a small matrix.
+use mdarray::{Dense, Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::{Float, MulAdd, NumCast, One, Zero};
+use rand::Rng;
 use thiserror::Error;
 
+use crate::{
+    matmul::{MatMul, MatMulBuilder},
+    qr::QR,
+};
+
 /// Error types related to singular value decomposition
 #[derive(Debug, Error)]
 pub enum SVDError {
@@ -55,4 +70,299 @@ pub trait SVD<T, D: Dim, L: Layout> {
         a: &mut Slice<T, (D, D), L>,
         s: &mut Slice<T, (D, D), Ls>,
     ) -> Result<(), SVDError>;
+
+    /// Compute thin (economy) SVD with new allocated matrices
+    /// For an m × n matrix A and k = min(m, n), `u` is m × k and `vt` is k × n.
+    fn svd_thin(&self, a: &mut Slice<T, (D, D), L>) -> SVDResult<T, D>;
+
+    /// Compute thin (economy) SVD, overwriting existing matrices
+    /// For an m × n matrix A and k = min(m, n):
+    /// - `s` is k × k and contains the singular values in its first row
+    /// - `u` is m × k and contains the first k left singular vectors
+    /// - `vt` is k × n and contains the first k right singular vectors (transposed)
+    fn svd_thin_write<Ls: Layout, Lu: Layout, Lvt: Layout>(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        s: &mut Slice<T, (D, D), Ls>,
+        u: &mut Slice<T, (D, D), Lu>,
+        vt: &mut Slice<T, (D, D), Lvt>,
+    ) -> Result<(), SVDError>;
+}
+
+/// Criteria used to truncate a singular value decomposition
+///
+/// The i-th singular value σᵢ is kept if `i < max_rank` and
+/// `σᵢ > rtol * σ₀`. Criteria left to `None` are not applied.
+///
+/// ```rust
+/// use mdarray_linalg::svd::SVDTruncation;
+///
+/// let trunc = SVDTruncation::default().max_rank(10).rtol(1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SVDTruncation<R> {
+    pub max_rank: Option<usize>,
+    pub rtol: Option<R>,
+}
+
+impl<R> SVDTruncation<R> {
+    /// Keep at most `max_rank` singular triplets
+    pub fn max_rank(self, max_rank: usize) -> Self {
+        Self {
+            max_rank: Some(max_rank),
+            ..self
+        }
+    }
+
+    /// Discard singular values smaller than or equal to `rtol` times the largest one
+    pub fn rtol(self, rtol: R) -> Self {
+        Self {
+            rtol: Some(rtol),
+            ..self
+        }
+    }
+}
+
+/// Parameters of the randomized range finder used by [`RandomizedSVD`]
+#[derive(Clone, Copy, Debug)]
+pub struct RandomizedSVDConfig {
+    /// Number of random samples drawn in addition to the target rank
+    pub oversampling: usize,
+    /// Number of power iterations, improving accuracy for slowly decaying spectra
+    pub power_iterations: usize,
+}
+
+impl Default for RandomizedSVDConfig {
+    fn default() -> Self {
+        Self {
+            oversampling: 10,
+            power_iterations: 2,
+        }
+    }
+}
+
+/// Holds the results of a truncated singular value decomposition
+///
+/// For a kept rank k, `s` is k × k and contains the singular values in
+/// its first row, `u` is m × k and `vt` is k × n.
+pub struct TruncatedSVDDecomp<T: ComplexFloat, D: Dim> {
+    pub s: Tensor<T, (D, D)>,
+    pub u: Tensor<T, (D, D)>,
+    pub vt: Tensor<T, (D, D)>,
+    /// Sum of the squares of the discarded singular values
+    pub discarded_weight: T::Real,
+}
+
+/// Result type for truncated singular value decomposition, returning
+/// either a `TruncatedSVDDecomp` or an `SVDError`
+pub type TruncatedSVDResult<T, D> = Result<TruncatedSVDDecomp<T, D>, SVDError>;
+
+/// Truncated singular value decomposition
+///
+/// Implemented for every backend providing [`SVD`].
+pub trait TruncatedSVD<T: ComplexFloat, D: Dim, L: Layout> {
+    /// Compute the thin SVD and keep only the singular triplets selected by `trunc`
+    fn svd_truncated(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        trunc: SVDTruncation<T::Real>,
+    ) -> TruncatedSVDResult<T, D>;
+}
+
+impl<T, D, L, B> TruncatedSVD<T, D, L> for B
+where
+    T: ComplexFloat,
+    D: Dim,
+    L: Layout,
+    B: SVD<T, D, L>,
+{
+    fn svd_truncated(
+        &self,
+        a: &mut Slice<T, (D, D), L>,
+        trunc: SVDTruncation<T::Real>,
+    ) -> TruncatedSVDResult<T, D> {
+        let decomp = self.svd_thin(a)?;
+
+        let k = decomp.s.shape().dim(1);
+        let rank = truncation_rank(&decomp.s, k, trunc);
+
+        let discarded_weight = (rank..k)
+            .map(|i| abs2(decomp.s[[0, i]]))
+            .fold(T::Real::zero(), |acc, x| acc + x);
+
+        Ok(truncate(decomp, rank, discarded_weight))
+    }
+}
+
+/// Randomized truncated singular value decomposition
+///
+/// The range of A is approximated by an orthonormal basis Q of A Ω,
+/// where Ω is a random n × (k + p) matrix, k the target rank and p the
+/// oversampling. The SVD of the small matrix Qᴴ A then yields the
+/// dominant singular triplets of A (Halko, Martinsson & Tropp, 2011).
+///
+/// Implemented for every backend providing [`MatMul`], [`QR`] and [`SVD`].
+/// When no single backend provides all three, see [`svd_randomized_with`].
+pub trait RandomizedSVD<T: ComplexFloat, D: Dim, L: Layout> {
+    /// Approximate the singular triplets selected by `trunc`
+    ///
+    /// The target rank is `trunc.max_rank`, or min(m, n) if it is not set.
+    /// The discarded weight is computed as ‖A‖²_F − Σ σᵢ² over the kept
+    /// singular values.
+    fn svd_randomized(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        trunc: SVDTruncation<T::Real>,
+        config: RandomizedSVDConfig,
+    ) -> TruncatedSVDResult<T, D>;
+}
+
+impl<T, D, L, B> RandomizedSVD<T, D, L> for B
+where
+    T: ComplexFloat + MulAdd<Output = T> + Default,
+    D: Dim,
+    L: Layout,
+    B: MatMul<T> + QR<T, D, D> + SVD<T, D, Dense>,
+{
+    fn svd_randomized(
+        &self,
+        a: &Slice<T, (D, D), L>,
+        trunc: SVDTruncation<T::Real>,
+        config: RandomizedSVDConfig,
+    ) -> TruncatedSVDResult<T, D> {
+        svd_randomized_with(self, self, self, a, trunc, config)
+    }
+}
+
+/// Randomized truncated SVD as in [`RandomizedSVD`], with the matrix
+/// products, QR and SVD of the small matrix each computed by its own
+/// backend, for instance `svd_randomized_with(&Blas, &Lapack::default(),
+/// &Lapack::default(), &a, trunc, config)`
+pub fn svd_randomized_with<T, D, L>(
+    mm: &impl MatMul<T>,
+    qr: &impl QR<T, D, D>,
+    svd: &impl SVD<T, D, Dense>,
+    a: &Slice<T, (D, D), L>,
+    trunc: SVDTruncation<T::Real>,
+    config: RandomizedSVDConfig,
+) -> TruncatedSVDResult<T, D>
+where
+    T: ComplexFloat + MulAdd<Output = T> + Default,
+    D: Dim,
+    L: Layout,
+{
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    let min_mn = m.min(n);
+
+    let target_rank = trunc.max_rank.unwrap_or(min_mn).min(min_mn);
+    let l = (target_rank + config.oversampling).min(min_mn);
+
+    let mut rng = rand::rng();
+    let omega = Tensor::<T, (D, D)>::from_fn(<(D, D) as Shape>::from_dims(&[n, l]), |_| {
+        <T as NumCast>::from(rng.random_range(-1.0..1.0)).unwrap()
+    });
+
+    // Orthonormal basis of the range of A Ω, refined by power iterations
+    let a_h = conj_transpose(a);
+    let mut q = orthonormal_basis(qr, mm.matmul(a, &omega).eval());
+    for _ in 0..config.power_iterations {
+        let z = orthonormal_basis(qr, mm.matmul(&a_h, &q).eval());
+        q = orthonormal_basis(qr, mm.matmul(a, &z).eval());
+    }
+
+    // SVD of the projection B = Qᴴ A, of size l × n
+    let mut b = mm.matmul(&conj_transpose(&q), a).eval();
+    let small = svd.svd_thin(&mut b)?;
+    let u = mm.matmul(&q, &small.u).eval();
+
+    let k = small.s.shape().dim(1);
+    let rank = truncation_rank(&small.s, target_rank.min(k), trunc);
+
+    let norm2 = a
+        .iter()
+        .map(|&x| abs2(x))
+        .fold(T::Real::zero(), |acc, x| acc + x);
+    let kept = (0..rank)
+        .map(|i| abs2(small.s[[0, i]]))
+        .fold(T::Real::zero(), |acc, x| acc + x);
+    let discarded_weight = (norm2 - kept).max(T::Real::zero());
+
+    Ok(truncate(
+        SVDDecomp {
+            s: small.s,
+            u,
+            vt: small.vt,
+        },
+        rank,
+        discarded_weight,
+    ))
+}
+
+/// Squared modulus |x|²
+fn abs2<T: ComplexFloat>(x: T) -> T::Real {
+    let r = x.abs();
+    r * r
+}
+
+/// Number of singular values, among the first `k`, that are kept by `trunc`
+fn truncation_rank<T: ComplexFloat, D: Dim>(
+    s: &Tensor<T, (D, D)>,
+    k: usize,
+    trunc: SVDTruncation<T::Real>,
+) -> usize {
+    let k = trunc.max_rank.map_or(k, |r| r.min(k));
+    match trunc.rtol {
+        Some(rtol) if k > 0 => {
+            let cutoff = rtol * s[[0, 0]].abs();
+            (0..k).take_while(|&i| s[[0, i]].abs() > cutoff).count()
+        }
+        _ => k,
+    }
+}
+
+/// Keep the first `rank` singular triplets of a thin decomposition
+fn truncate<T: ComplexFloat, D: Dim>(
+    decomp: SVDDecomp<T, D>,
+    rank: usize,
+    discarded_weight: T::Real,
+) -> TruncatedSVDDecomp<T, D> {
+    let SVDDecomp { s, u, vt } = decomp;
+    let (m, n) = (u.shape().dim(0), vt.shape().dim(1));
+
+    TruncatedSVDDecomp {
+        s: Tensor::from_fn(<(D, D) as Shape>::from_dims(&[rank, rank]), |i| {
+            if i[0] == 0 { s[[0, i[1]]] } else { T::zero() }
+        }),
+        u: Tensor::from_fn(<(D, D) as Shape>::from_dims(&[m, rank]), |i| {
+            u[[i[0], i[1]]]
+        }),
+        vt: Tensor::from_fn(<(D, D) as Shape>::from_dims(&[rank, n]), |i| {
+            vt[[i[0], i[1]]]
+        }),
+        discarded_weight,
+    }
+}
+
+/// Orthonormal basis of the range of the m × l matrix `y` (l ≤ m),
+/// given by the m × l factor Q of its thin QR decomposition
+fn orthonormal_basis<T, D, B>(bd: &B, mut y: Tensor<T, (D, D)>) -> Tensor<T, (D, D)>
+where
+    T: ComplexFloat + One,
+    D: Dim,
+    B: QR<T, D, D>,
+{
+    let (q, _) = bd.qr_thin(&mut y);
+    q
+}
+
+/// Conjugate transpose Aᴴ of a matrix, as a new tensor
+fn conj_transpose<T: ComplexFloat, D: Dim, L: Layout>(
+    a: &Slice<T, (D, D), L>,
+) -> Tensor<T, (D, D)> {
+    let ash = *a.shape();
+    let (m, n) = (ash.dim(0), ash.dim(1));
+    Tensor::from_fn(<(D, D) as Shape>::from_dims(&[n, m]), |i| {
+        a[[i[1], i[0]]].conj()
+    })
 }
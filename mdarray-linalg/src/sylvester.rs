@@ -0,0 +1,416 @@
+//! Sylvester, Lyapunov and Stein matrix equations
+//!
+//! - [`Sylvester::sylvester`]: A X + X B = C,
+//! - [`Sylvester::lyapunov`]: A X + X Aᴴ + Q = 0 (continuous-time
+//!   Lyapunov equation),
+//! - [`Sylvester::stein`]: A X Aᴴ − X + Q = 0 (discrete-time Lyapunov
+//!   equation).
+//!
+//! They are solved by the Bartels–Stewart algorithm: A and B are reduced
+//! to Schur form with [`Eig::schur`], upper quasi-triangular with 2 × 2
+//! diagonal blocks for complex conjugate eigenvalue pairs for real types
+//! and upper triangular for complex types. The transformed equation is
+//! then solved by block back substitution, for O(m³ + n³) operations
+//! overall. Backends provide the triangular stage of the Sylvester and
+//! Lyapunov equations through [`SylvesterTriangular`]. The changes of
+//! basis use the product of the [`Naive`] backend, or any [`MatMul`]
+//! backend through [`sylvester_with`], [`lyapunov_with`] and
+//! [`stein_with`].
+//!
+//! The Sylvester equation has a unique solution if and only if A and −B
+//! have no common eigenvalue. For the eigenvalues λ of A, the Lyapunov
+//! equation requires λᵢ + λ̄ⱼ ≠ 0 and the Stein equation λᵢ λ̄ⱼ ≠ 1.
+use std::{cmp::Ordering, ops::Range};
+
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use num_traits::MulAdd;
+use thiserror::Error;
+
+use crate::{
+    Naive,
+    eig::{Eig, SchurError},
+    matmul::{MatMul, MatMulBuilder},
+};
+
+/// Error types related to Sylvester, Lyapunov and Stein equations
+#[derive(Debug, Error)]
+pub enum SylvesterError {
+    #[error("Backend error code: {0}")]
+    BackendError(i32),
+
+    #[error("Schur decomposition failed: {0}")]
+    Schur(#[from] SchurError),
+
+    #[error("Matrix must be square")]
+    NotSquareMatrix,
+
+    #[error("Invalid matrix dimensions")]
+    InvalidDimensions,
+
+    /// The eigenvalues of the coefficients make the equation singular or
+    /// too close to singular
+    #[error("Equation is singular or nearly singular: it has no unique solution")]
+    Singular,
+}
+
+/// Result type for the matrix equations, returning the solution X
+pub type SylvesterResult<T, D> = Result<Tensor<T, (D, D)>, SylvesterError>;
+
+/// Triangular stage of the Bartels–Stewart algorithm
+pub trait SylvesterTriangular<T: ComplexFloat> {
+    /// Solves A X + X op(B) = C, overwriting the m × n matrix C with X.
+    /// op(B) is Bᴴ if `adjoint_b` and B otherwise. A and B are upper
+    /// triangular or, for real types, upper quasi-triangular in Schur
+    /// canonical form.
+    fn sylvester_triangular<D: Dim, La: Layout, Lb: Layout, Lc: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        b: &Slice<T, (D, D), Lb>,
+        adjoint_b: bool,
+        c: &mut Slice<T, (D, D), Lc>,
+    ) -> Result<(), SylvesterError>;
+}
+
+/// Solvers for Sylvester, Lyapunov and Stein equations
+///
+/// Implemented for every backend providing [`Eig`] and
+/// [`SylvesterTriangular`], the changes of basis using the [`Naive`]
+/// product. See [`sylvester_with`], [`lyapunov_with`] and [`stein_with`]
+/// to pick the backend of each stage. The coefficients are left
+/// untouched.
+pub trait Sylvester<T: ComplexFloat, D: Dim> {
+    /// Solves A X + X B = C for the m × n matrix X, with A m × m and B
+    /// n × n
+    fn sylvester<La: Layout, Lb: Layout, Lc: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        b: &Slice<T, (D, D), Lb>,
+        c: &Slice<T, (D, D), Lc>,
+    ) -> SylvesterResult<T, D>;
+
+    /// Solves the continuous-time Lyapunov equation A X + X Aᴴ + Q = 0
+    fn lyapunov<La: Layout, Lq: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        q: &Slice<T, (D, D), Lq>,
+    ) -> SylvesterResult<T, D>;
+
+    /// Solves the Stein (discrete-time Lyapunov) equation
+    /// A X Aᴴ − X + Q = 0
+    fn stein<La: Layout, Lq: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        q: &Slice<T, (D, D), Lq>,
+    ) -> SylvesterResult<T, D>;
+}
+
+impl<T, D, B> Sylvester<T, D> for B
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    B: Eig<T, D, D> + SylvesterTriangular<T>,
+{
+    fn sylvester<La: Layout, Lb: Layout, Lc: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        b: &Slice<T, (D, D), Lb>,
+        c: &Slice<T, (D, D), Lc>,
+    ) -> SylvesterResult<T, D> {
+        sylvester_with(self, self, &Naive, a, b, c)
+    }
+
+    fn lyapunov<La: Layout, Lq: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        q: &Slice<T, (D, D), Lq>,
+    ) -> SylvesterResult<T, D> {
+        lyapunov_with(self, self, &Naive, a, q)
+    }
+
+    fn stein<La: Layout, Lq: Layout>(
+        &self,
+        a: &Slice<T, (D, D), La>,
+        q: &Slice<T, (D, D), Lq>,
+    ) -> SylvesterResult<T, D> {
+        stein_with(self, &Naive, a, q)
+    }
+}
+
+/// Sylvester equation as in [`Sylvester::sylvester`], with the Schur
+/// forms, the triangular stage and the matrix products each computed by
+/// its own backend, for instance `sylvester_with(&Lapack::default(),
+/// &Lapack::default(), &Blas, &a, &b, &c)`
+pub fn sylvester_with<T, D, La, Lb, Lc>(
+    schur: &impl Eig<T, D, D>,
+    tri: &impl SylvesterTriangular<T>,
+    mm: &impl MatMul<T>,
+    a: &Slice<T, (D, D), La>,
+    b: &Slice<T, (D, D), Lb>,
+    c: &Slice<T, (D, D), Lc>,
+) -> SylvesterResult<T, D>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    La: Layout,
+    Lb: Layout,
+    Lc: Layout,
+{
+    let (m, n) = sylvester_dims(a, b, c)?;
+    if m == 0 || n == 0 {
+        return Ok(c.to_tensor());
+    }
+
+    // A = U Tₐ Uᴴ and B = V T_b Vᴴ turn the equation into
+    // Tₐ Y + Y T_b = Uᴴ C V with X = U Y Vᴴ
+    let sa = schur.schur(&mut a.to_tensor())?;
+    let sb = schur.schur(&mut b.to_tensor())?;
+    let mut y = to_schur_basis(mm, &sa.z, c, &sb.z);
+    tri.sylvester_triangular(&sa.t, &sb.t, false, &mut y)?;
+    Ok(from_schur_basis(mm, &sa.z, &y, &sb.z))
+}
+
+/// Lyapunov equation as in [`Sylvester::lyapunov`], with each stage
+/// computed by its own backend as in [`sylvester_with`]
+pub fn lyapunov_with<T, D, La, Lq>(
+    schur: &impl Eig<T, D, D>,
+    tri: &impl SylvesterTriangular<T>,
+    mm: &impl MatMul<T>,
+    a: &Slice<T, (D, D), La>,
+    q: &Slice<T, (D, D), Lq>,
+) -> SylvesterResult<T, D>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    La: Layout,
+    Lq: Layout,
+{
+    let n = sylvester_dims(a, a, q)?.0;
+    if n == 0 {
+        return Ok(q.to_tensor());
+    }
+
+    // Tₐ Y + Y Tₐᴴ = −Uᴴ Q U with X = U Y Uᴴ
+    let sa = schur.schur(&mut a.to_tensor())?;
+    let mut y = to_schur_basis(mm, &sa.z, q, &sa.z);
+    y.iter_mut().for_each(|x| *x = -*x);
+    tri.sylvester_triangular(&sa.t, &sa.t, true, &mut y)?;
+    Ok(from_schur_basis(mm, &sa.z, &y, &sa.z))
+}
+
+/// Stein equation as in [`Sylvester::stein`], with the Schur form and the
+/// matrix products each computed by its own backend as in
+/// [`sylvester_with`]. The triangular stage is always solved here.
+pub fn stein_with<T, D, La, Lq>(
+    schur: &impl Eig<T, D, D>,
+    mm: &impl MatMul<T>,
+    a: &Slice<T, (D, D), La>,
+    q: &Slice<T, (D, D), Lq>,
+) -> SylvesterResult<T, D>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    La: Layout,
+    Lq: Layout,
+{
+    let n = sylvester_dims(a, a, q)?.0;
+    if n == 0 {
+        return Ok(q.to_tensor());
+    }
+
+    // Tₐ Y Tₐᴴ − Y = −Uᴴ Q U with X = U Y Uᴴ
+    let sa = schur.schur(&mut a.to_tensor())?;
+    let mut y = to_schur_basis(mm, &sa.z, q, &sa.z);
+    y.iter_mut().for_each(|x| *x = -*x);
+    stein_triangular(&sa.t, &mut y)?;
+    Ok(from_schur_basis(mm, &sa.z, &y, &sa.z))
+}
+
+/// Checks that A is m × m, B is n × n and C is m × n, returning (m, n)
+pub fn sylvester_dims<T, D: Dim, La: Layout, Lb: Layout, Lc: Layout>(
+    a: &Slice<T, (D, D), La>,
+    b: &Slice<T, (D, D), Lb>,
+    c: &Slice<T, (D, D), Lc>,
+) -> Result<(usize, usize), SylvesterError> {
+    let (ash, bsh, csh) = (*a.shape(), *b.shape(), *c.shape());
+    let (m, n) = (ash.dim(0), bsh.dim(0));
+    if ash.dim(1) != m || bsh.dim(1) != n {
+        return Err(SylvesterError::NotSquareMatrix);
+    }
+    if csh.dim(0) != m || csh.dim(1) != n {
+        return Err(SylvesterError::InvalidDimensions);
+    }
+    Ok((m, n))
+}
+
+/// Diagonal blocks of an upper quasi-triangular matrix in Schur canonical
+/// form: 2 × 2 where the subdiagonal is nonzero and 1 × 1 elsewhere
+pub fn schur_blocks<T: ComplexFloat, D: Dim, L: Layout>(
+    t: &Slice<T, (D, D), L>,
+) -> Vec<Range<usize>> {
+    let n = t.shape().dim(0);
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < n {
+        let size = if i + 1 < n && !t[[i + 1, i]].is_zero() {
+            2
+        } else {
+            1
+        };
+        blocks.push(i..i + size);
+        i += size;
+    }
+    blocks
+}
+
+/// Solves in place the k × k system M x = r, of order at most 4 for the
+/// diagonal blocks of the triangular stages, by Gaussian elimination with
+/// partial pivoting. M is stored row by row.
+pub fn solve_block<T: ComplexFloat>(
+    m: &mut [T],
+    r: &mut [T],
+    k: usize,
+) -> Result<(), SylvesterError> {
+    for col in 0..k {
+        let p = (col..k)
+            .max_by(|&i, &j| {
+                m[i * k + col]
+                    .abs()
+                    .partial_cmp(&m[j * k + col].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        if m[p * k + col].is_zero() {
+            return Err(SylvesterError::Singular);
+        }
+        if p != col {
+            for j in 0..k {
+                m.swap(p * k + j, col * k + j);
+            }
+            r.swap(p, col);
+        }
+
+        for i in col + 1..k {
+            let l = m[i * k + col] / m[col * k + col];
+            for j in col..k {
+                m[i * k + j] = m[i * k + j] - l * m[col * k + j];
+            }
+            r[i] = r[i] - l * r[col];
+        }
+    }
+
+    for i in (0..k).rev() {
+        let s = (i + 1..k).fold(r[i], |acc, j| acc - m[i * k + j] * r[j]);
+        r[i] = s / m[i * k + i];
+    }
+    Ok(())
+}
+
+/// Solves T Y Tᴴ − Y = C, overwriting C with Y, for T in Schur form. The
+/// blocks of Y are computed from the bottom-right corner, block column by
+/// block column.
+fn stein_triangular<T: ComplexFloat, D: Dim>(
+    t: &Tensor<T, (D, D)>,
+    c: &mut Tensor<T, (D, D)>,
+) -> Result<(), SylvesterError> {
+    let n = t.shape().dim(0);
+    let blocks = schur_blocks(t);
+
+    for l in blocks.iter().rev() {
+        let q = l.len();
+
+        // z(i, b) = Σ_{j' ≥ l.end} Y(i, j') conj(T(j, j')) for the j = l.start + b
+        // of block column l, over the block columns already solved
+        let mut z = vec![T::zero(); n * q];
+        for i in 0..n {
+            for (b, j) in l.clone().enumerate() {
+                z[i * q + b] =
+                    (l.end..n).fold(T::zero(), |acc, jp| acc + c[[i, jp]] * t[[j, jp]].conj());
+            }
+        }
+        // Same sum from j' = l.start, filled in as the blocks of block
+        // column l are solved
+        let mut v = z.clone();
+
+        for k in blocks.iter().rev() {
+            let p = k.len();
+            let mut m = vec![T::zero(); p * q * p * q];
+            let mut r = vec![T::zero(); p * q];
+
+            for (a, i) in k.clone().enumerate() {
+                for (b, j) in l.clone().enumerate() {
+                    let known = k
+                        .clone()
+                        .fold(T::zero(), |acc, ip| acc + t[[i, ip]] * z[ip * q + b]);
+                    let known = (k.end..n).fold(known, |acc, ip| acc + t[[i, ip]] * v[ip * q + b]);
+                    r[a * q + b] = c[[i, j]] - known;
+
+                    for (ap, ip) in k.clone().enumerate() {
+                        for (bp, jp) in l.clone().enumerate() {
+                            let mut x = t[[i, ip]] * t[[j, jp]].conj();
+                            if (a, b) == (ap, bp) {
+                                x = x - T::one();
+                            }
+                            m[(a * q + b) * p * q + ap * q + bp] = x;
+                        }
+                    }
+                }
+            }
+
+            solve_block(&mut m, &mut r, p * q)?;
+            for (a, i) in k.clone().enumerate() {
+                for (b, j) in l.clone().enumerate() {
+                    c[[i, j]] = r[a * q + b];
+                }
+            }
+            for i in k.clone() {
+                for (b, j) in l.clone().enumerate() {
+                    v[i * q + b] = l
+                        .clone()
+                        .fold(z[i * q + b], |acc, jp| acc + c[[i, jp]] * t[[j, jp]].conj());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns Uᴴ C V
+fn to_schur_basis<T, D, L>(
+    mm: &impl MatMul<T>,
+    u: &Tensor<T, (D, D)>,
+    c: &Slice<T, (D, D), L>,
+    v: &Tensor<T, (D, D)>,
+) -> Tensor<T, (D, D)>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+    L: Layout,
+{
+    let uc = mm.matmul(&conj_transpose(u), c).eval();
+    mm.matmul(&uc, v).eval()
+}
+
+/// Returns U Y Vᴴ
+fn from_schur_basis<T, D>(
+    mm: &impl MatMul<T>,
+    u: &Tensor<T, (D, D)>,
+    y: &Tensor<T, (D, D)>,
+    v: &Tensor<T, (D, D)>,
+) -> Tensor<T, (D, D)>
+where
+    T: ComplexFloat + MulAdd<Output = T>,
+    D: Dim,
+{
+    let uy = mm.matmul(u, y).eval();
+    mm.matmul(&uy, &conj_transpose(v)).eval()
+}
+
+fn conj_transpose<T: ComplexFloat, D: Dim>(a: &Tensor<T, (D, D)>) -> Tensor<T, (D, D)> {
+    let ash = *a.shape();
+    Tensor::from_fn(
+        <(D, D) as Shape>::from_dims(&[ash.dim(1), ash.dim(0)]),
+        |i| a[[i[1], i[0]]].conj(),
+    )
+}
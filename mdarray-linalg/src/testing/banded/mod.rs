@@ -0,0 +1,183 @@
+use mdarray::{DTensor, tensor};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{assert_close, band_part, naive_matmul, random_cplx_matrix, random_matrix};
+use crate::banded::{BandMatrix, BandSolve, BandSolveError};
+
+/// Dense tridiagonal matrix with subdiagonal `dl`, diagonal `d` and
+/// superdiagonal `du`
+fn tridiagonal<T: ComplexFloat>(dl: &[T], d: &[T], du: &[T]) -> DTensor<T, 2> {
+    let n = d.len();
+    DTensor::<T, 2>::from_fn([n, n], |i| match (i[0], i[1]) {
+        (r, c) if r == c => d[r],
+        (r, c) if r == c + 1 => dl[c],
+        (r, c) if c == r + 1 => du[r],
+        _ => T::zero(),
+    })
+}
+
+/// Hermitian positive definite matrix with `kd` subdiagonals and
+/// superdiagonals: the band of B + Bᴴ made diagonally dominant
+fn hpd_band<T: ComplexFloat<Real = f64>>(b: &DTensor<T, 2>, kd: usize) -> DTensor<T, 2> {
+    let n = b.shape().0;
+    let shift = T::from(4 * (2 * kd + 1)).unwrap();
+    DTensor::<T, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        if r.abs_diff(c) > kd {
+            T::zero()
+        } else if r == c {
+            T::from(b[[r, r]].re()).unwrap() * T::from(2.).unwrap() + shift
+        } else {
+            b[[r, c]] + b[[c, r]].conj()
+        }
+    })
+}
+
+pub fn test_band_matrix() {
+    let a = band_part(&random_matrix(5, 5), 2, 1);
+    let band = BandMatrix::from_dense(&a, 2, 1);
+    assert_eq!((band.n(), band.kl(), band.ku()), (5, 2, 1));
+    assert_eq!(*band.band().shape(), (4, 5));
+    assert_eq!(band.to_dense(), a);
+
+    let mut band = BandMatrix::<f64>::zeros(3, 0, 1);
+    band.set(0, 1, 2.);
+    assert_eq!(band.get(0, 1), 2.);
+    assert_eq!(band.get(1, 0), 0.);
+    assert!(!band.in_band(1, 0));
+}
+
+pub fn test_solve_tridiagonal(bd: &impl BandSolve<f64>) {
+    let n = 7;
+    let dl: Vec<f64> = random_matrix(1, n - 1).into_iter().collect();
+    let du: Vec<f64> = random_matrix(1, n - 1).into_iter().collect();
+    let d: Vec<f64> = random_matrix(1, n).iter().map(|x| x + 3.).collect();
+
+    let b = random_matrix(n, 2);
+    let mut x = b.clone();
+    bd.solve_tridiagonal(&dl, &d, &du, &mut x).unwrap();
+    assert_close(&naive_matmul(&tridiagonal(&dl, &d, &du), &x), &b, 1e-10);
+}
+
+pub fn test_solve_tridiagonal_complex(bd: &impl BandSolve<Complex<f64>>) {
+    let n = 6;
+    let shift = Complex::new(3., 0.);
+    let dl: Vec<_> = random_cplx_matrix(1, n - 1).into_iter().collect();
+    let du: Vec<_> = random_cplx_matrix(1, n - 1).into_iter().collect();
+    let d: Vec<_> = random_cplx_matrix(1, n).iter().map(|x| x + shift).collect();
+
+    let b = random_cplx_matrix(n, 3);
+    let mut x = b.clone();
+    bd.solve_tridiagonal(&dl, &d, &du, &mut x).unwrap();
+    assert_close(&naive_matmul(&tridiagonal(&dl, &d, &du), &x), &b, 1e-10);
+}
+
+pub fn test_solve_tridiagonal_pd(bd: &impl BandSolve<Complex<f64>>) {
+    let n = 6;
+    let a = hpd_band(&random_cplx_matrix(n, n), 1);
+    let d: Vec<f64> = (0..n).map(|i| a[[i, i]].re).collect();
+    let e: Vec<_> = (0..n - 1).map(|i| a[[i + 1, i]]).collect();
+
+    let b = random_cplx_matrix(n, 2);
+    let mut x = b.clone();
+    bd.solve_tridiagonal_pd(&d, &e, &mut x).unwrap();
+    assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+}
+
+pub fn test_solve_band(bd: &impl BandSolve<f64>) {
+    let n = 8;
+    for (kl, ku) in [(2, 1), (0, 3), (3, 0)] {
+        let a = band_part(&random_matrix(n, n), kl, ku);
+        let b = random_matrix(n, 2);
+        let mut x = b.clone();
+        bd.solve_band(&BandMatrix::from_dense(&a, kl, ku), &mut x)
+            .unwrap();
+        assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+    }
+
+    // Requires row interchanges: A(0, 0) = 0
+    let mut a = band_part(&random_matrix(n, n), 1, 1);
+    a[[0, 0]] = 0.;
+    let b = random_matrix(n, 1);
+    let mut x = b.clone();
+    bd.solve_band(&BandMatrix::from_dense(&a, 1, 1), &mut x)
+        .unwrap();
+    assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+}
+
+pub fn test_solve_band_complex(bd: &impl BandSolve<Complex<f64>>) {
+    let n = 6;
+    let a = band_part(&random_cplx_matrix(n, n), 1, 2);
+    let b = random_cplx_matrix(n, 2);
+    let mut x = b.clone();
+    bd.solve_band(&BandMatrix::from_dense(&a, 1, 2), &mut x)
+        .unwrap();
+    assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+}
+
+pub fn test_solve_band_pd(bd: &impl BandSolve<f64>) {
+    let n = 8;
+    let kd = 2;
+    let a = hpd_band(&random_matrix(n, n), kd);
+
+    // Only the lower band is referenced
+    let mut band = BandMatrix::from_dense(&a, kd, 1);
+    for j in 1..n {
+        band.set(j - 1, j, f64::NAN);
+    }
+
+    let b = random_matrix(n, 3);
+    let mut x = b.clone();
+    bd.solve_band_pd(&band, &mut x).unwrap();
+    assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+}
+
+pub fn test_solve_band_pd_complex(bd: &impl BandSolve<Complex<f64>>) {
+    let n = 6;
+    let kd = 1;
+    let a = hpd_band(&random_cplx_matrix(n, n), kd);
+    let b = random_cplx_matrix(n, 2);
+    let mut x = b.clone();
+    bd.solve_band_pd(&BandMatrix::from_dense(&a, kd, 0), &mut x)
+        .unwrap();
+    assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+}
+
+pub fn test_band_singular(bd: &impl BandSolve<f64>) {
+    let mut b = random_matrix(3, 1);
+    let result = bd.solve_tridiagonal(&[1., 1.], &[1., 1., 0.], &[1., 0.], &mut b);
+    assert!(matches!(result, Err(BandSolveError::SingularMatrix { .. })));
+
+    let a = tensor![[1., 2., 0.], [2., 1., 3.], [0., 3., 1.]];
+    let result = bd.solve_tridiagonal_pd(&[1., 1., 1.], &[2., 3.], &mut b);
+    assert!(matches!(
+        result,
+        Err(BandSolveError::NotPositiveDefinite { order: 2 })
+    ));
+    let result = bd.solve_band_pd(&BandMatrix::from_dense(&a, 1, 0), &mut b);
+    assert!(matches!(
+        result,
+        Err(BandSolveError::NotPositiveDefinite { order: 2 })
+    ));
+
+    let zero = BandMatrix::<f64>::zeros(3, 1, 1);
+    let result = bd.solve_band(&zero, &mut b);
+    assert!(matches!(
+        result,
+        Err(BandSolveError::SingularMatrix { diagonal: 1 })
+    ));
+}
+
+pub fn test_band_invalid_dimensions(bd: &impl BandSolve<f64>) {
+    let mut b = random_matrix(4, 1);
+    let result = bd.solve_tridiagonal(&[1., 1.], &[2., 2., 2.], &[1., 1.], &mut b);
+    assert!(matches!(result, Err(BandSolveError::InvalidDimensions)));
+
+    let mut b = random_matrix(3, 1);
+    let result = bd.solve_tridiagonal(&[1.], &[2., 2., 2.], &[1., 1.], &mut b);
+    assert!(matches!(result, Err(BandSolveError::InvalidDimensions)));
+
+    let band = BandMatrix::<f64>::zeros(4, 1, 1);
+    let result = bd.solve_band(&band, &mut b);
+    assert!(matches!(result, Err(BandSolveError::InvalidDimensions)));
+}
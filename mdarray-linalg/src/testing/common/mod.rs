@@ -1,10 +1,13 @@
 // Helper module with common code for integration tests.
 // See https://doc.rust-lang.org/rust-by-example/testing/integration_testing.html
-use mdarray::{DSlice, DTensor, expr, tensor};
-use num_complex::ComplexFloat;
+use approx::assert_relative_eq;
+use mdarray::{DSlice, DTensor, DynRank, Shape, Tensor, expr, tensor};
+use num_complex::{Complex, ComplexFloat};
 use num_traits::Zero;
 use rand::Rng;
 
+use crate::identity;
+
 pub fn example_matrix(
     shape: [usize; 2],
 ) -> expr::FromFn<(usize, usize), impl FnMut(&[usize]) -> f64> {
@@ -53,6 +56,18 @@ pub fn random_matrix(m: usize, n: usize) -> DTensor<f64, 2> {
     DTensor::<f64, 2>::from_fn([m, n], |_| rng.random_range(0.0..1.0))
 }
 
+/// Generate a random complex matrix of size m x n, with real and
+/// imaginary parts in [-1, 1)
+pub fn random_cplx_matrix(m: usize, n: usize) -> DTensor<Complex<f64>, 2> {
+    let mut rng = rand::rng();
+    DTensor::<Complex<f64>, 2>::from_fn([m, n], |_| {
+        Complex::new(
+            rng.random::<f64>() * 2.0 - 1.0,
+            rng.random::<f64>() * 2.0 - 1.0,
+        )
+    })
+}
+
 /// Generate a rank-k matrix by multiplying m×k and k×n matrices
 pub fn rank_k_matrix(m: usize, n: usize, k: usize) -> DTensor<f64, 2> {
     assert!(k <= n.min(m));
@@ -84,3 +99,74 @@ pub fn naive_matmul<T: ComplexFloat + Zero>(a: &DSlice<T, 2>, b: &DSlice<T, 2>)
     }
     c
 }
+
+/// Conjugate transpose of a matrix
+pub fn adjoint<T: ComplexFloat>(a: &DTensor<T, 2>) -> DTensor<T, 2> {
+    let (m, n) = *a.shape();
+    DTensor::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]].conj())
+}
+
+/// Hermitian part (A + Aᴴ) / 2 of a square matrix
+pub fn hermitian<T: ComplexFloat>(a: &DTensor<T, 2>) -> DTensor<T, 2> {
+    let half = T::from(0.5).unwrap();
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| {
+        (a[[i[0], i[1]]] + a[[i[1], i[0]]].conj()) * half
+    })
+}
+
+/// Keep the lower triangle of A only, filling the upper one with NaN
+pub fn lower_only<T: ComplexFloat>(a: &DTensor<T, 2>) -> DTensor<T, 2> {
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| {
+        if i[1] > i[0] {
+            // NaN, for real and complex T alike
+            T::zero() / T::zero()
+        } else {
+            a[[i[0], i[1]]]
+        }
+    })
+}
+
+/// Random tensor with entries in [−1, 1)
+pub fn random_tensor(dims: &[usize]) -> Tensor<f64, DynRank> {
+    let mut rng = rand::rng();
+    Tensor::from_fn(dims, |_| rng.random_range(-1.0..1.0))
+}
+
+/// Random complex tensor with real and imaginary parts in [−1, 1)
+pub fn random_cplx_tensor(dims: &[usize]) -> Tensor<Complex<f64>, DynRank> {
+    let mut rng = rand::rng();
+    Tensor::from_fn(dims, |_| {
+        Complex::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0))
+    })
+}
+
+/// Band of A with `kl` subdiagonals and `ku` superdiagonals
+pub fn band_part<T: ComplexFloat>(a: &DTensor<T, 2>, kl: usize, ku: usize) -> DTensor<T, 2> {
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| {
+        let (r, c) = (i[0], i[1]);
+        if r <= c + kl && c <= r + ku {
+            a[[r, c]]
+        } else {
+            T::zero()
+        }
+    })
+}
+
+/// Check that A and B have the same shape and that their elements
+/// differ by at most ε
+pub fn assert_close<T: ComplexFloat<Real = f64>, S: Shape>(
+    a: &Tensor<T, S>,
+    b: &Tensor<T, S>,
+    epsilon: f64,
+) {
+    assert_eq!(a.shape(), b.shape());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert_relative_eq!((*x - *y).abs(), 0.0, epsilon = epsilon);
+    }
+}
+
+/// Check that the columns of Q are orthonormal
+pub fn check_orthonormal<T: ComplexFloat<Real = f64>>(q: &DTensor<T, 2>) {
+    let k = q.shape().1;
+    assert_close(&naive_matmul(&adjoint(q), q), &identity(k), 1e-10);
+}
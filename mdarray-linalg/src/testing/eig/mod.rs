@@ -5,7 +5,7 @@ use num_complex::{Complex, ComplexFloat};
 use super::common::{naive_matmul, random_matrix};
 use crate::{
     assert_complex_matrix_eq, assert_matrix_eq,
-    eig::{Eig, EigDecomp, SchurDecomp},
+    eig::{Eig, EigDecomp, EigError, EigRange, EighSelectDecomp, SchurDecomp},
     pretty_print,
 };
 
@@ -341,6 +341,129 @@ pub fn test_eigh_complex_hermitian(bd: &impl Eig<Complex<f64>, usize, usize>) {
     test_eigen_reconstruction(&a, &eigenvalues, &right_eigenvectors.unwrap());
 }
 
+/// Entries of the 1D Laplacian tridiag(−1, 2, −1)
+fn laplacian_entry(i: usize, j: usize) -> f64 {
+    if i == j {
+        2.0
+    } else if i.abs_diff(j) == 1 {
+        -1.0
+    } else {
+        0.0
+    }
+}
+
+/// Eigenvalues 2 − 2 cos(kπ / (n + 1)), k = 1, …, n of the n × n Laplacian
+fn laplacian_eigenvalues(n: usize) -> Vec<f64> {
+    (1..=n)
+        .map(|k| 2.0 - 2.0 * (k as f64 * std::f64::consts::PI / (n + 1) as f64).cos())
+        .collect()
+}
+
+/// Check A V = V Λ and Vᴴ V = I for the selected eigenpairs
+fn check_eigh_select<T: ComplexFloat<Real = f64>>(
+    a: &DTensor<T, 2>,
+    decomp: &EighSelectDecomp<T, usize, usize>,
+    expected: &[f64],
+) {
+    let n = a.shape().0;
+    let EighSelectDecomp {
+        eigenvalues,
+        eigenvectors: v,
+    } = decomp;
+    let k = expected.len();
+
+    assert_eq!(*eigenvalues.shape(), (k,));
+    assert_eq!(*v.shape(), (n, k));
+
+    for (j, λ) in expected.iter().enumerate() {
+        assert_relative_eq!(eigenvalues[j], *λ, epsilon = 1e-10);
+    }
+
+    let av = naive_matmul(a, v);
+    for i in 0..n {
+        for j in 0..k {
+            let r = av[[i, j]] - v[[i, j]] * T::from(eigenvalues[j]).unwrap();
+            assert_relative_eq!(r.abs(), 0.0, epsilon = 1e-10);
+        }
+    }
+
+    for j0 in 0..k {
+        for j1 in 0..k {
+            let dot = (0..n).fold(T::zero(), |acc, i| acc + v[[i, j0]].conj() * v[[i, j1]]);
+            let expected = if j0 == j1 { 1.0 } else { 0.0 };
+            assert_relative_eq!(dot.re(), expected, epsilon = 1e-10);
+            assert_relative_eq!(dot.im(), 0.0, epsilon = 1e-10);
+        }
+    }
+}
+
+pub fn test_eigh_select_index(bd: &impl Eig<f64, usize, usize>) {
+    let n = 12;
+    let a = DTensor::<f64, 2>::from_fn([n, n], |i| laplacian_entry(i[0], i[1]));
+    let expected = laplacian_eigenvalues(n);
+
+    let decomp = bd
+        .eigh_select(&mut a.clone(), EigRange::Index(0..3))
+        .expect("selected eigenvalue decomposition failed");
+    check_eigh_select(&a, &decomp, &expected[0..3]);
+
+    let decomp = bd
+        .eigh_select(&mut a.clone(), EigRange::Index(5..9))
+        .expect("selected eigenvalue decomposition failed");
+    check_eigh_select(&a, &decomp, &expected[5..9]);
+
+    let decomp = bd
+        .eigh_select(&mut a.clone(), EigRange::Index(4..4))
+        .expect("selected eigenvalue decomposition failed");
+    check_eigh_select(&a, &decomp, &[]);
+}
+
+pub fn test_eigh_select_value(bd: &impl Eig<f64, usize, usize>) {
+    let n = 12;
+    let a = DTensor::<f64, 2>::from_fn([n, n], |i| laplacian_entry(i[0], i[1]));
+    let expected: Vec<f64> = laplacian_eigenvalues(n)
+        .into_iter()
+        .filter(|&λ| 1.0 < λ && λ <= 3.0)
+        .collect();
+
+    let decomp = bd
+        .eigh_select(&mut a.clone(), EigRange::Value(1.0, 3.0))
+        .expect("selected eigenvalue decomposition failed");
+    check_eigh_select(&a, &decomp, &expected);
+}
+
+pub fn test_eigh_select_complex(bd: &impl Eig<Complex<f64>, usize, usize>) {
+    // D L Dᴴ with the unitary D = diag(e^{ik}) has the spectrum of L
+    let n = 10;
+    let a = DTensor::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        let phase = i[0] as f64 - i[1] as f64;
+        Complex::from_polar(laplacian_entry(i[0], i[1]), phase)
+    });
+    let expected = laplacian_eigenvalues(n);
+
+    let decomp = bd
+        .eigh_select(&mut a.clone(), EigRange::Index(n - 4..n))
+        .expect("selected eigenvalue decomposition failed");
+    check_eigh_select(&a, &decomp, &expected[n - 4..n]);
+
+    let decomp = bd
+        .eigh_select(&mut a.clone(), EigRange::Value(0.0, 1.0))
+        .expect("selected eigenvalue decomposition failed");
+    let below_one: Vec<f64> = expected.into_iter().filter(|&λ| λ <= 1.0).collect();
+    check_eigh_select(&a, &decomp, &below_one);
+}
+
+pub fn test_eigh_select_invalid_range(bd: &impl Eig<f64, usize, usize>) {
+    let n = 4;
+    let a = DTensor::<f64, 2>::from_fn([n, n], |i| laplacian_entry(i[0], i[1]));
+
+    let result = bd.eigh_select(&mut a.clone(), EigRange::Index(2..5));
+    assert!(matches!(result, Err(EigError::InvalidRange)));
+
+    let result = bd.eigh_select(&mut a.clone(), EigRange::Value(2.0, 1.0));
+    assert!(matches!(result, Err(EigError::InvalidRange)));
+}
+
 pub fn test_eig_full_non_square(bd: &impl Eig<f64, usize, usize>) {
     let n = 3;
     let m = 5;
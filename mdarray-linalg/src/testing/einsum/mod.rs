@@ -0,0 +1,159 @@
+use mdarray::{DynRank, Tensor};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{assert_close, random_cplx_tensor, random_tensor};
+use crate::einsum::{Einsum, EinsumError, parse_subscripts};
+
+/// Evaluates the summation by looping over all values of all labels
+fn reference_einsum<T: ComplexFloat>(
+    subscripts: &str,
+    operands: &[&Tensor<T, DynRank>],
+) -> Tensor<T, DynRank> {
+    let (inputs, output) = parse_subscripts(subscripts).unwrap();
+
+    let mut labels: Vec<(char, usize)> = Vec::new();
+    for (input, t) in inputs.iter().zip(operands) {
+        for (ax, &c) in input.iter().enumerate() {
+            if !labels.iter().any(|(l, _)| *l == c) {
+                labels.push((c, t.dim(ax)));
+            }
+        }
+    }
+    let dim = |c: &char| labels.iter().find(|(l, _)| l == c).unwrap().1;
+    let out_dims: Vec<usize> = output.iter().map(dim).collect();
+    let count = labels.iter().map(|(_, d)| d).product::<usize>();
+
+    let mut result = Tensor::from_elem(&out_dims[..], T::zero());
+    for mut flat in 0..count {
+        let mut values = vec![0; labels.len()];
+        for (v, (_, d)) in values.iter_mut().zip(&labels).rev() {
+            *v = flat % d;
+            flat /= d;
+        }
+        let value = |c: &char| values[labels.iter().position(|(l, _)| l == c).unwrap()];
+
+        let term = inputs
+            .iter()
+            .zip(operands)
+            .fold(T::one(), |acc, (input, t)| {
+                let index: Vec<usize> = input.iter().map(value).collect();
+                acc * t[&index[..]]
+            });
+        let index: Vec<usize> = output.iter().map(value).collect();
+        result[&index[..]] = result[&index[..]] + term;
+    }
+    result
+}
+
+fn check(bd: &impl Einsum<f64>, subscripts: &str, dims: &[&[usize]]) {
+    let operands: Vec<_> = dims.iter().map(|d| random_tensor(d)).collect();
+    let refs: Vec<_> = operands.iter().collect();
+    let views: Vec<_> = operands.iter().map(|t| &**t).collect();
+
+    let result = bd.einsum(subscripts, &views).unwrap();
+    assert_close(&result, &reference_einsum(subscripts, &refs), 1e-10);
+}
+
+pub fn test_einsum_matmul(bd: &impl Einsum<f64>) {
+    check(bd, "ij,jk->ik", &[&[3, 4], &[4, 5]]);
+    check(bd, "ij,jk->ki", &[&[3, 4], &[4, 5]]);
+    check(bd, "abc,cbd->ad", &[&[2, 3, 4], &[4, 3, 5]]);
+}
+
+pub fn test_einsum_implicit_output(bd: &impl Einsum<f64>) {
+    // The output is "ik", in alphabetical order
+    check(bd, "ij,jk", &[&[3, 4], &[4, 5]]);
+    check(bd, "kj,ji", &[&[3, 4], &[4, 5]]);
+}
+
+pub fn test_einsum_trace_diagonal(bd: &impl Einsum<f64>) {
+    check(bd, "ii->", &[&[4, 4]]);
+    check(bd, "ii->i", &[&[4, 4]]);
+    check(bd, "iij->j", &[&[3, 3, 5]]);
+    check(bd, "iij,jk->ik", &[&[3, 3, 4], &[4, 2]]);
+}
+
+pub fn test_einsum_batch(bd: &impl Einsum<f64>) {
+    check(bd, "bij,bjk->bik", &[&[5, 2, 3], &[5, 3, 4]]);
+    check(bd, "ij,ij->ij", &[&[3, 4], &[3, 4]]);
+    check(bd, "ij,ij->ji", &[&[3, 4], &[3, 4]]);
+    check(bd, "ibj,jbk->kbi", &[&[2, 3, 4], &[4, 3, 2]]);
+}
+
+pub fn test_einsum_single_operand(bd: &impl Einsum<f64>) {
+    check(bd, "ijk->kij", &[&[2, 3, 4]]);
+    check(bd, "ij->", &[&[3, 4]]);
+    check(bd, "ijk->j", &[&[2, 3, 4]]);
+}
+
+pub fn test_einsum_outer_and_full(bd: &impl Einsum<f64>) {
+    check(bd, "i,j->ij", &[&[3], &[4]]);
+    check(bd, "ij,ij->", &[&[3, 4], &[3, 4]]);
+    check(bd, "ij,kl->", &[&[2, 3], &[4, 2]]);
+}
+
+pub fn test_einsum_many_operands(bd: &impl Einsum<f64>) {
+    check(bd, "ij,jk,kl->il", &[&[2, 3], &[3, 4], &[4, 5]]);
+    check(bd, "ab,bc,ca->", &[&[3, 4], &[4, 5], &[5, 3]]);
+    check(
+        bd,
+        "aib,bjc,ckd,dla->ijkl",
+        &[&[2, 3, 2], &[2, 2, 3], &[3, 2, 2], &[2, 3, 2]],
+    );
+    check(bd, "ij,jk,jl->ikl", &[&[2, 3], &[3, 4], &[3, 2]]);
+}
+
+pub fn test_einsum_complex(bd: &impl Einsum<Complex<f64>>) {
+    let cases: [(&str, &[usize], &[usize]); 3] = [
+        ("ijk,kjl->il", &[3, 4, 2], &[2, 4, 5]),
+        ("ijk,kjl->jil", &[3, 4, 2], &[2, 4, 5]),
+        ("iji,ijk->jk", &[3, 4, 3], &[3, 4, 6]),
+    ];
+    for (subscripts, dims_a, dims_b) in cases {
+        let a = random_cplx_tensor(dims_a);
+        let b = random_cplx_tensor(dims_b);
+        let result = bd.einsum(subscripts, &[&a, &b]).unwrap();
+        assert_close(&result, &reference_einsum(subscripts, &[&a, &b]), 1e-10);
+    }
+}
+
+pub fn test_einsum_errors(bd: &impl Einsum<f64>) {
+    let a = random_tensor(&[3, 4]);
+    let b = random_tensor(&[5, 6]);
+
+    assert!(matches!(
+        bd.einsum("ij,j1->i", &[&a, &b]),
+        Err(EinsumError::InvalidSubscripts(_))
+    ));
+    assert!(matches!(
+        bd.einsum("ij->i", &[&a, &b]),
+        Err(EinsumError::OperandCount {
+            expected: 1,
+            got: 2
+        })
+    ));
+    assert!(matches!(
+        bd.einsum("ijk->i", &[&a]),
+        Err(EinsumError::RankMismatch {
+            operand: 0,
+            rank: 2,
+            labels: 3
+        })
+    ));
+    assert!(matches!(
+        bd.einsum("ij,jk->ik", &[&a, &b]),
+        Err(EinsumError::DimensionMismatch {
+            label: 'j',
+            first: 4,
+            second: 5
+        })
+    ));
+    assert!(matches!(
+        bd.einsum("ij->ik", &[&a]),
+        Err(EinsumError::UnknownOutputIndex('k'))
+    ));
+    assert!(matches!(
+        bd.einsum("ij->ii", &[&a]),
+        Err(EinsumError::RepeatedOutputIndex('i'))
+    ));
+}
@@ -0,0 +1,124 @@
+use mdarray::{DTensor, Dyn, tensor};
+use num_complex::Complex;
+
+use super::common::{assert_close, naive_matmul, random_matrix};
+use crate::{
+    expm::{Expm, ExpmError},
+    identity,
+};
+
+/// Random n × n matrix with entries in [0, c)
+fn random_scaled(n: usize, c: f64) -> DTensor<f64, 2> {
+    let a = random_matrix(n, n);
+    DTensor::<f64, 2>::from_fn([n, n], |i| c * a[[i[0], i[1]]])
+}
+
+pub fn test_expm_diagonal(bd: &impl Expm<f64, Dyn>) {
+    let d = [1.0, -2.0, 0.5, 7.0];
+    let a = DTensor::<f64, 2>::from_fn([4, 4], |i| if i[0] == i[1] { d[i[0]] } else { 0.0 });
+
+    let e = bd.expm(&a).expect("expm failed");
+
+    let expected =
+        DTensor::<f64, 2>::from_fn([4, 4], |i| if i[0] == i[1] { d[i[0]].exp() } else { 0.0 });
+    assert_close(&e, &expected, 1e-12 * 7.0f64.exp());
+}
+
+pub fn test_expm_nilpotent(bd: &impl Expm<f64, Dyn>) {
+    // exp(N) = I + N + N²/2 for N³ = 0
+    let a = tensor![[0., 1., 2.], [0., 0., 3.], [0., 0., 0.]];
+
+    let e = bd.expm(&a).expect("expm failed");
+
+    let expected = tensor![[1., 1., 3.5], [0., 1., 3.], [0., 0., 1.]];
+    assert_close(&e, &expected, 1e-13);
+}
+
+pub fn test_expm_rotation(bd: &impl Expm<f64, Dyn>) {
+    // A large angle requires scaling and squaring
+    for theta in [0.01, 0.7, 3.0, 25.0] {
+        let a = tensor![[0., -theta], [theta, 0.]];
+
+        let e = bd.expm(&a).expect("expm failed");
+
+        let expected = tensor![[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]];
+        assert_close(&e, &expected, 1e-12);
+    }
+}
+
+pub fn test_expm_inverse(bd: &impl Expm<f64, Dyn>) {
+    // exp(A) exp(−A) = I
+    let n = 6;
+    let a = random_scaled(n, 3.0);
+    let minus_a = DTensor::<f64, 2>::from_fn([n, n], |i| -a[[i[0], i[1]]]);
+
+    let e = bd.expm(&a).expect("expm failed");
+    let e_inv = bd.expm(&minus_a).expect("expm failed");
+
+    assert_close(&naive_matmul(&e, &e_inv), &identity(n), 1e-9);
+}
+
+pub fn test_expm_write(bd: &impl Expm<f64, Dyn>) {
+    let a = random_matrix(5, 5);
+    let mut e = DTensor::<f64, 2>::zeros([5, 5]);
+
+    bd.expm_write(&a, &mut e).expect("expm failed");
+
+    let expected = bd.expm(&a).expect("expm failed");
+    assert_close(&e, &expected, 1e-14);
+}
+
+pub fn test_expm_complex(bd: &impl Expm<Complex<f64>, Dyn>) {
+    // exp(iθ I + N) = e^{iθ} (I + N) for N² = 0
+    let theta = 4.0;
+    let z = Complex::new(0.0, theta);
+    let (zero, one) = (Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+    let a = tensor![[z, one], [zero, z]];
+
+    let e = bd.expm(&a).expect("expm failed");
+
+    let phase = z.exp();
+    let expected = tensor![[phase, phase], [zero, phase]];
+    assert_close(&e, &expected, 1e-12);
+}
+
+pub fn test_expm_frechet_commuting(bd: &impl Expm<f64, Dyn>) {
+    // L(A, A) = A exp(A)
+    let a = random_scaled(4, 2.0);
+
+    let (e, l) = bd.expm_frechet(&a, &a).expect("expm failed");
+
+    assert_close(&e, &bd.expm(&a).expect("expm failed"), 1e-10);
+    assert_close(&l, &naive_matmul(&a, &e), 1e-9);
+}
+
+pub fn test_expm_frechet_block(bd: &impl Expm<f64, Dyn>) {
+    // exp([[A, E], [0, A]]) = [[exp(A), L(A, E)], [0, exp(A)]]
+    let n = 4;
+    for scale in [0.1, 1.0, 6.0] {
+        let a = random_scaled(n, scale);
+        let e = random_matrix(n, n);
+
+        let (exp_a, l) = bd.expm_frechet(&a, &e).expect("expm failed");
+
+        let block = DTensor::<f64, 2>::from_fn([2 * n, 2 * n], |i| match (i[0] < n, i[1] < n) {
+            (true, true) => a[[i[0], i[1]]],
+            (true, false) => e[[i[0], i[1] - n]],
+            (false, true) => 0.0,
+            (false, false) => a[[i[0] - n, i[1] - n]],
+        });
+        let exp_block = bd.expm(&block).expect("expm failed");
+
+        let top_left = DTensor::<f64, 2>::from_fn([n, n], |i| exp_block[[i[0], i[1]]]);
+        let top_right = DTensor::<f64, 2>::from_fn([n, n], |i| exp_block[[i[0], i[1] + n]]);
+        let tol = 1e-11 * exp_block.iter().fold(1.0f64, |acc, x| acc.max(x.abs()));
+        assert_close(&exp_a, &top_left, tol);
+        assert_close(&l, &top_right, tol);
+    }
+}
+
+pub fn test_expm_not_square(bd: &impl Expm<f64, Dyn>) {
+    let a = random_matrix(2, 3);
+
+    assert!(matches!(bd.expm(&a), Err(ExpmError::NotSquareMatrix)));
+}
@@ -0,0 +1,230 @@
+use approx::assert_relative_eq;
+use mdarray::{DTensor, Dyn};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{adjoint, hermitian, naive_matmul, random_cplx_matrix, random_matrix};
+use crate::geneig::{GenEig, GenEigDecomp, GenEigError, GenEighDecomp};
+
+/// Well-conditioned Hermitian positive definite matrix M Mᴴ + n I
+fn positive_definite<T: ComplexFloat<Real = f64>>(m: &DTensor<T, 2>) -> DTensor<T, 2> {
+    let n = m.shape().0;
+    let mut b = naive_matmul(m, &adjoint(m));
+    for i in 0..n {
+        b[[i, i]] = b[[i, i]] + T::from(n).unwrap();
+    }
+    b
+}
+
+fn to_complex<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>) -> DTensor<Complex<f64>, 2> {
+    DTensor::<Complex<f64>, 2>::from_fn(*a.shape(), |i| {
+        let x = a[[i[0], i[1]]];
+        Complex::new(x.re(), x.im())
+    })
+}
+
+/// Check β A v = α B v for every eigenpair
+fn check_gen_eig_residual<T: ComplexFloat<Real = f64>>(
+    a: &DTensor<T, 2>,
+    b: &DTensor<T, 2>,
+    decomp: &GenEigDecomp<T, Dyn, Dyn>,
+) {
+    let n = a.shape().0;
+    let v = decomp
+        .right_eigenvectors
+        .as_ref()
+        .expect("eigenvectors were not computed");
+    let av = naive_matmul(&to_complex(a), v);
+    let bv = naive_matmul(&to_complex(b), v);
+
+    for j in 0..n {
+        let (alpha, beta) = (decomp.alpha[j], decomp.beta[j]);
+        let norm: f64 = (0..n).map(|i| v[[i, j]].norm_sqr()).sum::<f64>().sqrt();
+        assert!(norm > 1e-12, "Null vector found");
+
+        for i in 0..n {
+            let r = beta * av[[i, j]] - alpha * bv[[i, j]];
+            assert_relative_eq!(r.norm(), 0.0, epsilon = 1e-10);
+        }
+    }
+}
+
+/// Check A V = B V Λ and Vᴴ B V = I
+fn check_gen_eigh<T: ComplexFloat<Real = f64>>(
+    a: &DTensor<T, 2>,
+    b: &DTensor<T, 2>,
+    decomp: &GenEighDecomp<T, Dyn, Dyn>,
+) {
+    let n = a.shape().0;
+    let GenEighDecomp {
+        eigenvalues,
+        eigenvectors: v,
+    } = decomp;
+
+    for i in 1..n {
+        assert!(
+            eigenvalues[i - 1] <= eigenvalues[i],
+            "eigenvalues are not sorted"
+        );
+    }
+
+    let av = naive_matmul(a, v);
+    let bv = naive_matmul(b, v);
+    for i in 0..n {
+        for j in 0..n {
+            let r = av[[i, j]] - bv[[i, j]] * T::from(eigenvalues[j]).unwrap();
+            assert_relative_eq!(r.abs(), 0.0, epsilon = 1e-10);
+        }
+    }
+
+    let vhbv = naive_matmul(&adjoint(v), &bv);
+    for i in 0..n {
+        for j in 0..n {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(vhbv[[i, j]].re(), expected, epsilon = 1e-10);
+            assert_relative_eq!(vhbv[[i, j]].im(), 0.0, epsilon = 1e-10);
+        }
+    }
+}
+
+pub fn test_gen_eig_random(bd: &impl GenEig<f64, Dyn, Dyn>) {
+    let n = 5;
+    let a = random_matrix(n, n);
+    let b = random_matrix(n, n);
+
+    let decomp = bd
+        .gen_eig(&mut a.clone(), &mut b.clone())
+        .expect("generalized eigendecomposition failed");
+
+    check_gen_eig_residual(&a, &b, &decomp);
+}
+
+pub fn test_gen_eig_values(bd: &impl GenEig<f64, Dyn, Dyn>) {
+    // The eigenvalues of a triangular pencil are the ratios of the diagonals
+    let a = DTensor::<f64, 2>::from_fn([3, 3], |i| match (i[0], i[1]) {
+        (0, 0) => 2.0,
+        (1, 1) => -3.0,
+        (2, 2) => 8.0,
+        (r, c) if r < c => 1.0,
+        _ => 0.0,
+    });
+    let b = DTensor::<f64, 2>::from_fn([3, 3], |i| match (i[0], i[1]) {
+        (0, 0) => 1.0,
+        (1, 1) => 2.0,
+        (2, 2) => 4.0,
+        (r, c) if r < c => 0.5,
+        _ => 0.0,
+    });
+
+    let decomp = bd
+        .gen_eig_values(&mut a.clone(), &mut b.clone())
+        .expect("generalized eigendecomposition failed");
+    assert!(decomp.right_eigenvectors.is_none());
+
+    let mut eigenvalues: Vec<f64> = decomp.eigenvalues().iter().map(|z| z.re).collect();
+    eigenvalues.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    for (λ, expected) in eigenvalues.iter().zip([-1.5, 2.0, 2.0]) {
+        assert_relative_eq!(*λ, expected, epsilon = 1e-10);
+    }
+    for λ in decomp.eigenvalues().iter() {
+        assert_relative_eq!(λ.im, 0.0, epsilon = 1e-10);
+    }
+}
+
+pub fn test_gen_eig_infinite(bd: &impl GenEig<f64, Dyn, Dyn>) {
+    // B is singular: one eigenvalue is infinite
+    let a = DTensor::<f64, 2>::from_fn([3, 3], |i| match (i[0], i[1]) {
+        (0, 0) => 1.0,
+        (1, 1) => 2.0,
+        (2, 2) => 3.0,
+        (0, 2) => 1.0,
+        _ => 0.0,
+    });
+    let b = DTensor::<f64, 2>::from_fn([3, 3], |i| match (i[0], i[1]) {
+        (0, 0) | (1, 1) => 1.0,
+        _ => 0.0,
+    });
+
+    let decomp = bd
+        .gen_eig(&mut a.clone(), &mut b.clone())
+        .expect("generalized eigendecomposition failed");
+
+    check_gen_eig_residual(&a, &b, &decomp);
+
+    let infinite = decomp
+        .beta
+        .iter()
+        .filter(|beta| beta.norm() < 1e-12)
+        .count();
+    assert_eq!(infinite, 1);
+
+    let mut finite: Vec<f64> = decomp
+        .eigenvalues()
+        .iter()
+        .filter(|λ| λ.re.is_finite())
+        .map(|λ| λ.re)
+        .collect();
+    finite.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_eq!(finite.len(), 2);
+    assert_relative_eq!(finite[0], 1.0, epsilon = 1e-10);
+    assert_relative_eq!(finite[1], 2.0, epsilon = 1e-10);
+}
+
+pub fn test_gen_eig_complex(bd: &impl GenEig<Complex<f64>, Dyn, Dyn>) {
+    let n = 4;
+    let a = random_cplx_matrix(n, n);
+    let b = random_cplx_matrix(n, n);
+
+    let decomp = bd
+        .gen_eig(&mut a.clone(), &mut b.clone())
+        .expect("generalized eigendecomposition failed");
+
+    check_gen_eig_residual(&a, &b, &decomp);
+}
+
+pub fn test_gen_eig_not_square(bd: &impl GenEig<f64, Dyn, Dyn>) {
+    let a = random_matrix(3, 4);
+    let b = random_matrix(3, 4);
+
+    let result = bd.gen_eig(&mut a.clone(), &mut b.clone());
+    assert!(matches!(result, Err(GenEigError::NotSquareMatrix)));
+}
+
+pub fn test_gen_eigh_random(bd: &impl GenEig<f64, Dyn, Dyn>) {
+    let n = 6;
+    let a = hermitian(&random_matrix(n, n));
+    let b = positive_definite(&random_matrix(n, n));
+
+    let decomp = bd
+        .gen_eigh(&mut a.clone(), &mut b.clone())
+        .expect("generalized eigendecomposition failed");
+
+    check_gen_eigh(&a, &b, &decomp);
+}
+
+pub fn test_gen_eigh_complex(bd: &impl GenEig<Complex<f64>, Dyn, Dyn>) {
+    let n = 5;
+    let a = hermitian(&random_cplx_matrix(n, n));
+    let b = positive_definite(&random_cplx_matrix(n, n));
+
+    let decomp = bd
+        .gen_eigh(&mut a.clone(), &mut b.clone())
+        .expect("generalized eigendecomposition failed");
+
+    check_gen_eigh(&a, &b, &decomp);
+}
+
+pub fn test_gen_eigh_not_positive_definite(bd: &impl GenEig<f64, Dyn, Dyn>) {
+    let a = random_matrix(3, 3);
+    let a = hermitian(&a);
+    let b = DTensor::<f64, 2>::from_fn([3, 3], |i| match (i[0], i[1]) {
+        (0, 0) | (2, 2) => 1.0,
+        (1, 1) => -1.0,
+        _ => 0.0,
+    });
+
+    let result = bd.gen_eigh(&mut a.clone(), &mut b.clone());
+    assert!(matches!(
+        result,
+        Err(GenEigError::NotPositiveDefinite { order: 2 })
+    ));
+}
@@ -0,0 +1,177 @@
+use approx::assert_relative_eq;
+use mdarray::{DTensor, Dyn};
+use num_complex::{Complex, ComplexFloat};
+use rand::Rng;
+
+use super::common::{adjoint, naive_matmul, random_matrix, rank_k_matrix};
+use crate::lstsq::{Lstsq, LstsqError, LstsqResult};
+
+/// Check the normal equations Aᴴ (AX − B) = 0 satisfied by every
+/// least-squares solution
+fn check_normal_equations<T: ComplexFloat<Real = f64>>(
+    a: &DTensor<T, 2>,
+    x: &DTensor<T, 2>,
+    b: &DTensor<T, 2>,
+) {
+    let ax = naive_matmul(a, x);
+    let r = DTensor::<T, 2>::from_fn(*b.shape(), |i| ax[[i[0], i[1]]] - b[[i[0], i[1]]]);
+    let ahr = naive_matmul(&adjoint(a), &r);
+    for v in ahr.iter() {
+        assert_relative_eq!(v.abs(), 0.0, epsilon = 1e-10);
+    }
+}
+
+pub fn test_lstsq_overdetermined(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    let (m, n, nrhs) = (8, 3, 2);
+    let a = random_matrix(m, n);
+    let b = random_matrix(m, nrhs);
+
+    let LstsqResult {
+        x, residuals, rank, ..
+    } = bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+
+    assert_eq!(*x.shape(), (n, nrhs));
+    assert_eq!(rank, n);
+    check_normal_equations(&a, &x, &b);
+
+    let ax = naive_matmul(&a, &x);
+    for j in 0..nrhs {
+        let expected: f64 = (0..m).map(|i| (b[[i, j]] - ax[[i, j]]).powi(2)).sum();
+        assert_relative_eq!(residuals[j], expected, epsilon = 1e-10);
+    }
+}
+
+pub fn test_lstsq_consistent(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    let (m, n) = (6, 4);
+    let a = random_matrix(m, n);
+    let x0 = random_matrix(n, 1);
+    let b = naive_matmul(&a, &x0);
+
+    let LstsqResult { x, residuals, .. } =
+        bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+
+    for i in 0..n {
+        assert_relative_eq!(x[[i, 0]], x0[[i, 0]], epsilon = 1e-10);
+    }
+    assert_relative_eq!(residuals[0], 0.0, epsilon = 1e-20);
+}
+
+pub fn test_lstsq_underdetermined(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    // The minimum-norm solution of x₀ + x₁ = 2, 2 x₂ + 2 x₃ = 4 is (1, 1, 1, 1)
+    let a = DTensor::<f64, 2>::from_fn([2, 4], |i| match (i[0], i[1]) {
+        (0, 0) | (0, 1) => 1.0,
+        (1, 2) | (1, 3) => 2.0,
+        _ => 0.0,
+    });
+    let b = DTensor::<f64, 2>::from_fn([2, 1], |i| [2.0, 4.0][i[0]]);
+
+    let LstsqResult { x, rank, .. } = bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+
+    assert_eq!(*x.shape(), (4, 1));
+    assert_eq!(rank, 2);
+    for i in 0..4 {
+        assert_relative_eq!(x[[i, 0]], 1.0, epsilon = 1e-10);
+    }
+
+    // Random wide system: AX = B is solved exactly
+    let (m, n, nrhs) = (3, 7, 2);
+    let a = random_matrix(m, n);
+    let b = random_matrix(m, nrhs);
+    let LstsqResult { x, .. } = bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+    let ax = naive_matmul(&a, &x);
+    for i in 0..m {
+        for j in 0..nrhs {
+            assert_relative_eq!(ax[[i, j]], b[[i, j]], epsilon = 1e-10);
+        }
+    }
+}
+
+pub fn test_lstsq_write(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    let (m, n, nrhs) = (7, 4, 3);
+    let a = random_matrix(m, n);
+    let b = random_matrix(m, nrhs);
+
+    let mut b_work = b.clone();
+    let rank = bd
+        .lstsq_write(&mut a.clone(), &mut b_work)
+        .expect("least squares failed");
+    assert_eq!(rank, n);
+
+    let x = DTensor::<f64, 2>::from_fn([n, nrhs], |i| b_work[[i[0], i[1]]]);
+    check_normal_equations(&a, &x, &b);
+}
+
+/// Rank deficient systems, for backends returning the minimum-norm solution
+pub fn test_lstsq_rank_deficient(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    let (m, n, k) = (8, 5, 2);
+    let a = rank_k_matrix(m, n, k);
+    let b = random_matrix(m, 1);
+
+    let LstsqResult { x, rank, .. } = bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+
+    assert_eq!(rank, k);
+    check_normal_equations(&a, &x, &b);
+
+    // The minimum-norm solution lies in the row space of A, i.e. it is
+    // orthogonal to the null space of A.  Project x onto the row space by
+    // solving the problem again with B = A x.
+    let ax = naive_matmul(&a, &x);
+    let LstsqResult { x: x2, .. } = bd.lstsq(&mut a.clone(), &ax).expect("least squares failed");
+    for i in 0..n {
+        assert_relative_eq!(x[[i, 0]], x2[[i, 0]], epsilon = 1e-8);
+    }
+}
+
+/// Rank deficient systems, for backends that require A to have full rank
+pub fn test_lstsq_rank_deficient_error(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    let a = DTensor::<f64, 2>::from_fn([5, 3], |i| (i[0] + 1) as f64 * [1.0, 2.0, 3.0][i[1]]);
+    let b = random_matrix(5, 1);
+
+    match bd.lstsq(&mut a.clone(), &b) {
+        Err(LstsqError::RankDeficient { rank }) => assert_eq!(rank, 1),
+        Err(e) => panic!("unexpected error: {e}"),
+        Ok(_) => panic!("rank deficiency was not reported"),
+    }
+}
+
+pub fn test_lstsq_singular_values(bd: &impl Lstsq<f64, Dyn, Dyn>) {
+    // Diagonal matrix with known singular values
+    let a = DTensor::<f64, 2>::from_fn([4, 3], |i| {
+        if i[0] == i[1] {
+            [2.0, 5.0, 3.0][i[0]]
+        } else {
+            0.0
+        }
+    });
+    let b = random_matrix(4, 1);
+
+    let LstsqResult {
+        singular_values, ..
+    } = bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+
+    let s = singular_values.expect("singular values were not computed");
+    assert_eq!(s.len(), 3);
+    for (si, expected) in s.iter().zip([5.0, 3.0, 2.0]) {
+        assert_relative_eq!(*si, expected, epsilon = 1e-12);
+    }
+}
+
+pub fn test_lstsq_cplx_overdetermined(bd: &impl Lstsq<Complex<f64>, Dyn, Dyn>) {
+    let mut rng = rand::rng();
+    let (m, n, nrhs) = (7, 3, 2);
+    let mut random = |shape: [usize; 2]| {
+        DTensor::<Complex<f64>, 2>::from_fn(shape, |_| {
+            Complex::new(
+                rng.random::<f64>() * 2.0 - 1.0,
+                rng.random::<f64>() * 2.0 - 1.0,
+            )
+        })
+    };
+    let a = random([m, n]);
+    let b = random([m, nrhs]);
+
+    let LstsqResult { x, rank, .. } = bd.lstsq(&mut a.clone(), &b).expect("least squares failed");
+
+    assert_eq!(rank, n);
+    check_normal_equations(&a, &x, &b);
+}
@@ -1,9 +1,19 @@
 use approx::assert_relative_eq;
 use mdarray::{DSlice, DTensor, Dense, tensor};
-use num_complex::ComplexFloat;
-
-use super::common::{naive_matmul, random_matrix};
-use crate::{identity, lu::LU, pretty_print, transpose_in_place};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{
+    adjoint, assert_close, lower_only, naive_matmul, random_cplx_matrix, random_matrix,
+};
+use crate::{
+    identity, ipiv_to_perm_mat,
+    lu::{CholeskyFactor, Inertia, InvError, LU, LdlFactor, LdlFactors, LdlPivoting, LuFactor},
+    matmul::Triangle,
+    norm::{Norm, NormError},
+    pretty_print,
+    solve::SolveError,
+    transpose_in_place,
+};
 
 pub fn test_lu_reconstruction<T>(
     a: &DTensor<T, 2>,
@@ -310,3 +320,305 @@ pub fn test_cholesky_identity_matrix(bd: &impl LU<f64, usize, usize>) {
         }
     }
 }
+
+fn transpose<T: ComplexFloat>(a: &DTensor<T, 2>) -> DTensor<T, 2> {
+    let (m, n) = *a.shape();
+    DTensor::<T, 2>::from_fn([n, m], |i| a[[i[1], i[0]]])
+}
+
+pub fn test_lu_factors_reconstruction(bd: &impl LuFactor<f64, usize>) {
+    let n = 6;
+    let a = random_matrix(n, n);
+    let lu = bd.lu_factor(&a).unwrap();
+
+    // The pivot indices describe the row interchanges of P A = L U
+    let p = ipiv_to_perm_mat::<f64, usize, usize>(&lu.ipiv, n);
+    test_lu_reconstruction(&a, &lu.l(), &lu.u(), &p);
+}
+
+pub fn test_lu_factors_solve(bd: &impl LuFactor<f64, usize>) {
+    let n = 5;
+    let a = random_matrix(n, n);
+    let lu = bd.lu_factor(&a).unwrap();
+
+    // The same factors serve several right-hand sides
+    for k in [1, 3] {
+        let b = random_matrix(n, k);
+
+        let x = lu.solve(&b).unwrap();
+        assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+
+        let mut x = b.clone();
+        lu.solve_transpose_write(&mut x).unwrap();
+        assert_close(&naive_matmul(&transpose(&a), &x), &b, 1e-10);
+    }
+}
+
+pub fn test_lu_factors_solve_complex(bd: &impl LuFactor<Complex<f64>, usize>) {
+    let n = 4;
+    let a = random_cplx_matrix(n, n);
+    let b = random_cplx_matrix(n, 2);
+    let lu = bd.lu_factor(&a).unwrap();
+
+    let x = lu.solve(&b).unwrap();
+    assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+
+    // Aᵀ, not Aᴴ
+    let x = lu.solve_transpose(&b).unwrap();
+    assert_close(&naive_matmul(&transpose(&a), &x), &b, 1e-10);
+}
+
+pub fn test_lu_factors_det_inv(bd: &impl LuFactor<f64, usize>) {
+    let n = 4;
+    let a = random_matrix(n, n);
+    let lu = bd.lu_factor(&a).unwrap();
+
+    assert_relative_eq!(lu.det(), det_permutations(&a), epsilon = 1e-10);
+    assert_close(&naive_matmul(&a, &lu.inv().unwrap()), &identity(n), 1e-10);
+
+    let a = tensor![[0., 2.], [3., 0.]];
+    assert_relative_eq!(bd.lu_factor(&a).unwrap().det(), -6.);
+}
+
+pub fn test_lu_factors_rcond(bd: &impl LuFactor<f64, usize>) {
+    let n = 5;
+    let a = random_matrix(n, n);
+    let lu = bd.lu_factor(&a).unwrap();
+
+    let norm1 = |m: &DTensor<f64, 2>| {
+        (0..n)
+            .map(|j| (0..n).map(|i| m[[i, j]].abs()).sum::<f64>())
+            .fold(0., f64::max)
+    };
+    let anorm = norm1(&a);
+    let exact = 1. / (anorm * norm1(&lu.inv().unwrap()));
+
+    // ‖A⁻¹‖₁ is underestimated, by a small factor at most in practice
+    let rcond = lu.rcond(anorm, Norm::One).unwrap();
+    assert!(rcond >= exact * (1. - 1e-10));
+    assert!(rcond <= 10. * exact);
+
+    assert!(matches!(
+        lu.rcond(anorm, Norm::Frobenius),
+        Err(NormError::UnsupportedNorm(Norm::Frobenius))
+    ));
+}
+
+pub fn test_lu_factors_singular(bd: &impl LuFactor<f64, usize>) {
+    let a = tensor![[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]];
+    let lu = bd.lu_factor(&a).unwrap();
+
+    assert_relative_eq!(lu.det(), 0.);
+    assert!(matches!(
+        lu.solve(&tensor![[1.], [2.], [3.]]),
+        Err(SolveError::SingularMatrix { .. })
+    ));
+    assert!(matches!(lu.inv(), Err(InvError::Singular { .. })));
+    assert_relative_eq!(lu.rcond(1., Norm::One).unwrap(), 0.);
+}
+
+pub fn test_lu_factors_invalid_dimensions(bd: &impl LuFactor<f64, usize>) {
+    let a = random_matrix(3, 4);
+    assert!(matches!(
+        bd.lu_factor(&a),
+        Err(InvError::NotSquare { rows: 3, cols: 4 })
+    ));
+
+    let lu = bd.lu_factor(&random_matrix(3, 3)).unwrap();
+    assert!(matches!(
+        lu.solve(&random_matrix(4, 1)),
+        Err(SolveError::InvalidDimensions)
+    ));
+}
+
+/// Hermitian positive definite B Bᴴ + n I, with garbage in the triangle
+/// opposite to `triangle`, which must not be referenced
+fn random_hpd_triangle(
+    n: usize,
+    triangle: Triangle,
+) -> (DTensor<Complex<f64>, 2>, DTensor<Complex<f64>, 2>) {
+    let b = random_cplx_matrix(n, n);
+    let mut a = naive_matmul(&b, &adjoint(&b));
+    for i in 0..n {
+        a[[i, i]] += n as f64;
+    }
+
+    let mut stored = a.clone();
+    for i in 0..n {
+        for j in 0..n {
+            let outside = match triangle {
+                Triangle::Lower => j > i,
+                Triangle::Upper => j < i,
+            };
+            if outside {
+                stored[[i, j]] = Complex::new(f64::NAN, f64::NAN);
+            }
+        }
+    }
+    (a, stored)
+}
+
+pub fn test_cholesky_factors_solve(bd: &impl CholeskyFactor<f64, usize>) {
+    let n = 5;
+    let a = random_positive_definite_matrix(n);
+    let b = random_matrix(n, 3);
+
+    for triangle in [Triangle::Lower, Triangle::Upper] {
+        let chol = bd.cholesky_factor(&a, triangle).unwrap();
+        assert_eq!(chol.triangle, triangle);
+
+        let l = chol.l();
+        assert_close(&naive_matmul(&l, &transpose(&l)), &a, 1e-10);
+
+        let x = chol.solve(&b).unwrap();
+        assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+    }
+}
+
+pub fn test_cholesky_factors_complex(bd: &impl CholeskyFactor<Complex<f64>, usize>) {
+    let n = 4;
+    let b = random_cplx_matrix(n, 2);
+
+    for triangle in [Triangle::Lower, Triangle::Upper] {
+        let (a, stored) = random_hpd_triangle(n, triangle);
+        let chol = bd.cholesky_factor(&stored, triangle).unwrap();
+
+        let factor = &chol.factor;
+        let product = match triangle {
+            Triangle::Lower => naive_matmul(factor, &adjoint(factor)),
+            Triangle::Upper => naive_matmul(&adjoint(factor), factor),
+        };
+        assert_close(&product, &a, 1e-10);
+
+        let mut x = b.clone();
+        chol.solve_write(&mut x).unwrap();
+        assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+
+        assert_close(&naive_matmul(&a, &chol.inv().unwrap()), &identity(n), 1e-10);
+    }
+}
+
+pub fn test_cholesky_factors_inv_logdet(bd: &impl CholeskyFactor<f64, usize>) {
+    let n = 4;
+    let a = random_positive_definite_matrix(n);
+
+    for triangle in [Triangle::Lower, Triangle::Upper] {
+        let chol = bd.cholesky_factor(&a, triangle).unwrap();
+        assert_relative_eq!(chol.logdet(), det_permutations(&a).ln(), epsilon = 1e-10);
+        assert_close(&naive_matmul(&a, &chol.inv().unwrap()), &identity(n), 1e-10);
+    }
+
+    // Far beyond the range of f64, which det would overflow
+    let big = DTensor::<f64, 2>::from_fn([400, 400], |i| if i[0] == i[1] { 1e2 } else { 0. });
+    let chol = bd.cholesky_factor(&big, Triangle::Lower).unwrap();
+    assert_relative_eq!(chol.logdet(), 400. * 1e2_f64.ln(), epsilon = 1e-8);
+}
+
+pub fn test_cholesky_factors_not_positive_definite(bd: &impl CholeskyFactor<f64, usize>) {
+    let a = tensor![[1., 2., 0.], [2., 1., 0.], [0., 0., 1.]];
+    assert!(matches!(
+        bd.cholesky_factor(&a, Triangle::Lower),
+        Err(InvError::NotPositiveDefinite { lpm: 2 })
+    ));
+    assert!(matches!(
+        bd.cholesky_factor(&random_matrix(2, 3), Triangle::Upper),
+        Err(InvError::NotSquare { rows: 2, cols: 3 })
+    ));
+}
+
+/// Check that P A Pᵀ = L D Lᴴ
+fn check_ldl<T: ComplexFloat<Real = f64>, B>(a: &DTensor<T, 2>, ldl: &LdlFactors<'_, T, usize, B>) {
+    let n = ldl.n();
+    let pap = DTensor::<T, 2>::from_fn([n, n], |i| a[[ldl.perm[i[0]], ldl.perm[i[1]]]]);
+    let ldlh = naive_matmul(&naive_matmul(&ldl.l, &ldl.d_matrix()), &adjoint(&ldl.l));
+    assert_close(&ldlh, &pap, 1e-10);
+}
+
+/// Symmetric saddle point matrix [[H, Bᵀ], [B, 0]] with H positive definite
+fn kkt_matrix(n: usize, m: usize) -> DTensor<f64, 2> {
+    let h = random_positive_definite_matrix(n);
+    let b = random_matrix(m, n);
+    DTensor::<f64, 2>::from_fn([n + m, n + m], |i| match (i[0] < n, i[1] < n) {
+        (true, true) => h[[i[0], i[1]]],
+        (false, true) => b[[i[0] - n, i[1]]],
+        (true, false) => b[[i[1] - n, i[0]]],
+        (false, false) => 0.,
+    })
+}
+
+pub fn test_ldl_factors_solve(bd: &impl LdlFactor<f64, usize>) {
+    let a = kkt_matrix(4, 2);
+    let b = random_matrix(6, 2);
+
+    for pivoting in [LdlPivoting::BunchKaufman, LdlPivoting::Rook] {
+        let ldl = bd.ldl_factor(&lower_only(&a), pivoting).unwrap();
+        check_ldl(&a, &ldl);
+
+        let x = ldl.solve(&b).unwrap();
+        assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+    }
+}
+
+pub fn test_ldl_factors_complex(bd: &impl LdlFactor<Complex<f64>, usize>) {
+    let n = 5;
+    // Hermitian indefinite: C + Cᴴ with a zero diagonal
+    let c = random_cplx_matrix(n, n);
+    let a = DTensor::<Complex<f64>, 2>::from_fn([n, n], |i| {
+        if i[0] == i[1] {
+            Complex::new(0., 0.)
+        } else {
+            c[[i[0], i[1]]] + c[[i[1], i[0]]].conj()
+        }
+    });
+    let b = random_cplx_matrix(n, 3);
+
+    for pivoting in [LdlPivoting::BunchKaufman, LdlPivoting::Rook] {
+        let ldl = bd.ldl_factor(&lower_only(&a), pivoting).unwrap();
+        check_ldl(&a, &ldl);
+
+        let mut x = b.clone();
+        ldl.solve_write(&mut x).unwrap();
+        assert_close(&naive_matmul(&a, &x), &b, 1e-10);
+    }
+}
+
+pub fn test_ldl_factors_inertia(bd: &impl LdlFactor<f64, usize>) {
+    // A = S Λ Sᵀ has the inertia of Λ for any nonsingular S
+    let lambda = [3., -2., 1., -0.5, 4.];
+    let s = random_positive_definite_matrix(5);
+    let sl = DTensor::<f64, 2>::from_fn([5, 5], |i| s[[i[0], i[1]]] * lambda[i[1]]);
+    let a = naive_matmul(&sl, &transpose(&s));
+
+    let ldl = bd.ldl_factor(&a, LdlPivoting::default()).unwrap();
+    let expected = Inertia {
+        positive: 3,
+        negative: 2,
+        zero: 0,
+    };
+    assert_eq!(ldl.inertia(), expected);
+
+    // Saddle point matrix: n positive and m negative eigenvalues
+    let ldl = bd.ldl_factor(&kkt_matrix(4, 3), LdlPivoting::Rook).unwrap();
+    assert_eq!((ldl.inertia().positive, ldl.inertia().negative), (4, 3));
+}
+
+pub fn test_ldl_factors_singular(bd: &impl LdlFactor<f64, usize>) {
+    let a = tensor![[1., 1.], [1., 1.]];
+    let ldl = bd.ldl_factor(&a, LdlPivoting::default()).unwrap();
+
+    let expected = Inertia {
+        positive: 1,
+        negative: 0,
+        zero: 1,
+    };
+    assert_eq!(ldl.inertia(), expected);
+    assert!(matches!(
+        ldl.solve(&tensor![[1.], [1.]]),
+        Err(SolveError::SingularMatrix { .. })
+    ));
+
+    assert!(matches!(
+        bd.ldl_factor(&random_matrix(2, 3), LdlPivoting::default()),
+        Err(InvError::NotSquare { rows: 2, cols: 3 })
+    ));
+}
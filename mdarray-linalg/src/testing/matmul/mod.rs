@@ -1,8 +1,11 @@
 use mdarray::{DTensor, Tensor, expr, expr::Expression as _};
-use num_complex::Complex64;
+use num_complex::{Complex64, ComplexFloat};
 
 use super::common::*;
-use crate::{matmul::MatMul, prelude::*};
+use crate::{
+    matmul::{BatchedMatMul, MatMul},
+    prelude::*,
+};
 
 pub fn create_test_matrix_f64(
     shape: [usize; 2],
@@ -80,3 +83,107 @@ pub fn create_hermitian_matrix_complex(size: usize) -> DTensor<Complex64, 2> {
     }
     matrix
 }
+
+/// Reference (batch, m, n) product of (batch or 1, m, k) and (batch or 1,
+/// k, n) tensors
+fn batched_reference<T: ComplexFloat>(a: &DTensor<T, 3>, b: &DTensor<T, 3>) -> DTensor<T, 3> {
+    let ((ba, m, k), (bb, _, n)) = (*a.shape(), *b.shape());
+    DTensor::<T, 3>::from_fn([ba.max(bb), m, n], |i| {
+        let (ia, ib) = (i[0].min(ba - 1), i[0].min(bb - 1));
+        (0..k).fold(T::zero(), |acc, l| {
+            acc + a[[ia, i[1], l]] * b[[ib, l, i[2]]]
+        })
+    })
+}
+
+fn batched_test_tensor(shape: [usize; 3]) -> DTensor<f64, 3> {
+    DTensor::<f64, 3>::from_fn(shape, |i| ((i[0] + 1) * (2 * i[1] + 1) + i[2]) as f64 - 3.0)
+}
+
+pub fn test_batched_matmul_impl(backend: &impl BatchedMatMul<f64>) {
+    let a = batched_test_tensor([6, 3, 4]);
+    let b = batched_test_tensor([6, 4, 2]);
+    let expected = batched_reference(&a, &b).into_dyn();
+
+    assert_eq!(backend.batched_matmul(&a, &b).eval(), expected);
+    assert_eq!(
+        backend.batched_matmul(&a, &b).parallelize().eval(),
+        expected
+    );
+}
+
+pub fn test_batched_matmul_broadcast_impl(backend: &impl BatchedMatMul<f64>) {
+    let a = batched_test_tensor([5, 2, 3]);
+    let b = batched_test_tensor([1, 3, 4]);
+    let expected = batched_reference(&a, &b);
+
+    // Axis of length 1
+    let result = backend.batched_matmul(&a, &b).parallelize().eval();
+    assert_eq!(result, expected.clone().into_dyn());
+
+    // Missing axis, with dynamic rank operands
+    let b2 = b.reshape([3, 4]).to_tensor().into_dyn();
+    let result = backend.batched_matmul(&a.clone().into_dyn(), &b2).eval();
+    assert_eq!(result, expected.into_dyn());
+
+    // Leading axes of both operands broadcast against each other
+    let a4 = a.reshape([1, 5, 2, 3]).to_tensor();
+    let b4 = DTensor::<f64, 4>::from_fn([2, 1, 3, 4], |i| (i[0] + i[2] * i[3]) as f64);
+    let result = backend.batched_matmul(&a4, &b4).eval();
+    assert_eq!(result.dims(), &[2, 5, 2, 4]);
+    let result = result.into_shape([2, 5, 2, 4]);
+    for p in 0..2 {
+        let bp = b4.view(p, .., .., ..).to_tensor();
+        assert_eq!(
+            result.view(p, .., .., ..).to_tensor(),
+            batched_reference(&a, &bp)
+        );
+    }
+}
+
+pub fn test_batched_matmul_write_add_to_impl(backend: &impl BatchedMatMul<f64>) {
+    let a = batched_test_tensor([4, 3, 3]);
+    let b = batched_test_tensor([4, 3, 2]);
+    let expected = batched_reference(&a, &b);
+
+    let mut c = DTensor::<f64, 3>::from_elem([4, 3, 2], 7.0);
+    backend.batched_matmul(&a, &b).write(&mut c);
+    assert_eq!(c, expected);
+
+    backend
+        .batched_matmul(&a, &b)
+        .scale(2.0)
+        .parallelize()
+        .add_to(&mut c);
+    assert_eq!(c, expected.map(|x| 3.0 * x));
+}
+
+pub fn test_batched_matmul_strided_impl(backend: &impl BatchedMatMul<f64>) {
+    // Batch of transposed matrices, viewed without copying
+    let a = batched_test_tensor([3, 4, 2]).into_dyn();
+    let at = a.permute([0, 2, 1]);
+    let b = batched_test_tensor([3, 4, 5]);
+    let expected = batched_reference(&at.to_tensor().into_shape([3, 2, 4]).to_tensor(), &b);
+
+    assert_eq!(backend.batched_matmul(&at, &b).eval(), expected.into_dyn());
+}
+
+pub fn test_batched_matmul_mismatch_impl(backend: &impl BatchedMatMul<f64>) {
+    let a = batched_test_tensor([3, 2, 2]);
+    let b = batched_test_tensor([2, 2, 2]);
+    backend.batched_matmul(&a, &b).eval();
+}
+
+pub fn test_batched_matmul_complex_impl(backend: &impl BatchedMatMul<Complex64>) {
+    let a = DTensor::<Complex64, 3>::from_fn([3, 2, 3], |i| {
+        Complex64::new((i[0] + i[1]) as f64, i[2] as f64 - 1.0)
+    });
+    let b = DTensor::<Complex64, 3>::from_fn([3, 3, 2], |i| {
+        Complex64::new(i[2] as f64, (i[0] * i[1]) as f64)
+    });
+    let factor = Complex64::new(0.5, -2.0);
+    let expected = batched_reference(&a, &b).map(|x| factor * x);
+
+    let result = backend.batched_matmul(&a, &b).scale(factor).eval();
+    assert_eq!(result, expected.into_dyn());
+}
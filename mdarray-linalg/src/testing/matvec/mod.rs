@@ -1,9 +1,12 @@
 use mdarray::{DTensor, tensor};
-use num_complex::Complex;
+use num_complex::{Complex, ComplexFloat};
 
+use super::common::{assert_close, band_part, hermitian, random_cplx_matrix, random_matrix};
 use crate::{
+    banded::BandMatrix,
     matmul::{Triangle, Type},
-    matvec::{Argmax, MatVec, Outer, VecOps},
+    matvec::{Argmax, MatVec, Outer, StructuredMatVec, VecOps},
+    packed::PackedMatrix,
     prelude::*,
 };
 
@@ -398,3 +401,195 @@ pub fn test_argmax_abs(bd: impl Argmax<f64>) {
     println!("{idx:?}");
     assert_eq!(idx, vec![1, 1, 1]);
 }
+
+fn column<T: ComplexFloat>(a: &DTensor<T, 2>) -> DTensor<T, 1> {
+    DTensor::<T, 1>::from_fn([a.shape().0], |i| a[[i[0], 0]])
+}
+
+/// α·A·x + β·y computed densely
+fn dense_mv<T: ComplexFloat>(
+    alpha: T,
+    a: &DTensor<T, 2>,
+    x: &DTensor<T, 1>,
+    beta: T,
+    y: &DTensor<T, 1>,
+) -> DTensor<T, 1> {
+    let n = a.shape().1;
+    DTensor::<T, 1>::from_fn([a.shape().0], |i| {
+        let ax = (0..n).fold(T::zero(), |acc, j| acc + a[[i[0], j]] * x[[j]]);
+        alpha * ax + beta * y[[i[0]]]
+    })
+}
+
+/// Triangle of A, with a unit diagonal if `unit_diag`
+fn triangle_part<T: ComplexFloat>(
+    a: &DTensor<T, 2>,
+    triangle: Triangle,
+    unit_diag: bool,
+) -> DTensor<T, 2> {
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| {
+        let (r, c) = (i[0], i[1]);
+        match triangle {
+            _ if r == c && unit_diag => T::one(),
+            Triangle::Upper if r <= c => a[[r, c]],
+            Triangle::Lower if r >= c => a[[r, c]],
+            _ => T::zero(),
+        }
+    })
+}
+
+pub fn test_gbmv(bd: impl StructuredMatVec<f64>) {
+    let n = 7;
+    for (kl, ku) in [(2, 1), (0, 3), (1, 0)] {
+        let a = band_part(&random_matrix(n, n), kl, ku);
+        let x = column(&random_matrix(n, 1));
+        let mut y = column(&random_matrix(n, 1));
+        let expected = dense_mv(2., &a, &x, -0.5, &y);
+        bd.gbmv(2., &BandMatrix::from_dense(&a, kl, ku), &x, -0.5, &mut y);
+        assert_close(&y, &expected, 1e-10);
+    }
+}
+
+pub fn test_gbmv_complex(bd: impl StructuredMatVec<Complex<f64>>) {
+    let n = 6;
+    let (alpha, beta) = (Complex::new(1., 2.), Complex::new(0.5, -1.));
+    let a = band_part(&random_cplx_matrix(n, n), 1, 2);
+    let x = column(&random_cplx_matrix(n, 1));
+    let mut y = column(&random_cplx_matrix(n, 1));
+    let expected = dense_mv(alpha, &a, &x, beta, &y);
+    bd.gbmv(alpha, &BandMatrix::from_dense(&a, 1, 2), &x, beta, &mut y);
+    assert_close(&y, &expected, 1e-10);
+}
+
+pub fn test_hbmv(bd: impl StructuredMatVec<f64>) {
+    let n = 7;
+    let kd = 2;
+    let a = band_part(&hermitian(&random_matrix(n, n)), kd, kd);
+
+    // Only the lower band is referenced
+    let mut band = BandMatrix::from_dense(&a, kd, 1);
+    for j in 1..n {
+        band.set(j - 1, j, f64::NAN);
+    }
+
+    let x = column(&random_matrix(n, 1));
+    let mut y = column(&random_matrix(n, 1));
+    let expected = dense_mv(1.5, &a, &x, 2., &y);
+    bd.hbmv(1.5, &band, &x, 2., &mut y);
+    assert_close(&y, &expected, 1e-10);
+}
+
+pub fn test_hbmv_complex(bd: impl StructuredMatVec<Complex<f64>>) {
+    let n = 6;
+    let kd = 1;
+    let (alpha, beta) = (Complex::new(0.5, 1.), Complex::new(1., 0.));
+    let a = band_part(&hermitian(&random_cplx_matrix(n, n)), kd, kd);
+    let x = column(&random_cplx_matrix(n, 1));
+    let mut y = column(&random_cplx_matrix(n, 1));
+    let expected = dense_mv(alpha, &a, &x, beta, &y);
+    bd.hbmv(alpha, &BandMatrix::from_dense(&a, kd, 0), &x, beta, &mut y);
+    assert_close(&y, &expected, 1e-10);
+}
+
+pub fn test_tbmv(bd: impl StructuredMatVec<f64>) {
+    let n = 7;
+    let a = band_part(&random_matrix(n, n), 2, 1);
+    let band = BandMatrix::from_dense(&a, 2, 1);
+    for triangle in [Triangle::Upper, Triangle::Lower] {
+        for unit_diag in [false, true] {
+            let t = triangle_part(&a, triangle, unit_diag);
+            let x0 = column(&random_matrix(n, 1));
+            let mut x = x0.clone();
+            bd.tbmv(&band, triangle, unit_diag, &mut x);
+            assert_close(&x, &dense_mv(1., &t, &x0, 0., &x0), 1e-10);
+        }
+    }
+}
+
+pub fn test_tbmv_complex(bd: impl StructuredMatVec<Complex<f64>>) {
+    let n = 5;
+    let a = band_part(&random_cplx_matrix(n, n), 1, 2);
+    let band = BandMatrix::from_dense(&a, 1, 2);
+    for triangle in [Triangle::Upper, Triangle::Lower] {
+        let t = triangle_part(&a, triangle, false);
+        let x0 = column(&random_cplx_matrix(n, 1));
+        let mut x = x0.clone();
+        bd.tbmv(&band, triangle, false, &mut x);
+        let zero = Complex::new(0., 0.);
+        assert_close(
+            &x,
+            &dense_mv(Complex::new(1., 0.), &t, &x0, zero, &x0),
+            1e-10,
+        );
+    }
+}
+
+pub fn test_hpmv(bd: impl StructuredMatVec<f64>) {
+    let n = 6;
+    let a = hermitian(&random_matrix(n, n));
+    for triangle in [Triangle::Upper, Triangle::Lower] {
+        let x = column(&random_matrix(n, 1));
+        let mut y = column(&random_matrix(n, 1));
+        let expected = dense_mv(-1., &a, &x, 0.5, &y);
+        bd.hpmv(
+            -1.,
+            &PackedMatrix::from_dense(&a, triangle),
+            &x,
+            0.5,
+            &mut y,
+        );
+        assert_close(&y, &expected, 1e-10);
+    }
+}
+
+pub fn test_hpmv_complex(bd: impl StructuredMatVec<Complex<f64>>) {
+    let n = 5;
+    let (alpha, beta) = (Complex::new(1., -1.), Complex::new(0., 2.));
+    let a = hermitian(&random_cplx_matrix(n, n));
+    for triangle in [Triangle::Upper, Triangle::Lower] {
+        let x = column(&random_cplx_matrix(n, 1));
+        let mut y = column(&random_cplx_matrix(n, 1));
+        let expected = dense_mv(alpha, &a, &x, beta, &y);
+        bd.hpmv(
+            alpha,
+            &PackedMatrix::from_dense(&a, triangle),
+            &x,
+            beta,
+            &mut y,
+        );
+        assert_close(&y, &expected, 1e-10);
+    }
+}
+
+pub fn test_tpmv(bd: impl StructuredMatVec<f64>) {
+    let n = 6;
+    let a = random_matrix(n, n);
+    for triangle in [Triangle::Upper, Triangle::Lower] {
+        for unit_diag in [false, true] {
+            let t = triangle_part(&a, triangle, unit_diag);
+            let x0 = column(&random_matrix(n, 1));
+            let mut x = x0.clone();
+            bd.tpmv(&PackedMatrix::from_dense(&a, triangle), unit_diag, &mut x);
+            assert_close(&x, &dense_mv(1., &t, &x0, 0., &x0), 1e-10);
+        }
+    }
+}
+
+pub fn test_structured_beta_zero(bd: impl StructuredMatVec<f64>) {
+    // As in BLAS, y is not read when β = 0
+    let a = tensor![[2., 1.], [1., 2.]];
+    let x = tensor![1., -1.];
+    let mut y = tensor![f64::NAN, f64::NAN];
+    bd.gbmv(1., &BandMatrix::from_dense(&a, 1, 1), &x, 0., &mut y);
+    assert_eq!(y, tensor![1., -1.]);
+
+    let mut y = tensor![f64::NAN, f64::NAN];
+    bd.hpmv(
+        1.,
+        &PackedMatrix::from_dense(&a, Triangle::Upper),
+        &x,
+        0.,
+        &mut y,
+    );
+    assert_eq!(y, tensor![1., -1.]);
+}
@@ -2,12 +2,25 @@
 //!
 //! This is used by the backends to easily express tests that are actually run.
 
+pub mod banded;
 pub mod common;
 pub mod eig;
+pub mod einsum;
+pub mod expm;
+pub mod geneig;
+pub mod lstsq;
 pub mod lu;
 pub mod matmul;
 pub mod matvec;
+pub mod ncon;
+pub mod norm;
+pub mod pinv;
+pub mod polar;
 pub mod qr;
+pub mod reduction;
 pub mod solve;
+pub mod subspace;
 pub mod svd;
+pub mod sylvester;
 pub mod tensordot;
+pub mod trisolve;
@@ -0,0 +1,252 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use super::common::{assert_close, random_cplx_tensor, random_tensor};
+use crate::Naive;
+use crate::einsum::Einsum;
+use crate::ncon::{ContractionPath, Ncon, NconError, PathStrategy, ncon_labels, ncon_path};
+
+/// Contracts the network described by `subscripts` along every strategy
+/// and compares with the einsum of the Naive backend
+fn check(bd: &impl Ncon<f64>, subscripts: &str, dims: &[&[usize]]) {
+    let tensors: Vec<_> = dims.iter().map(|d| random_tensor(d)).collect();
+    let views: Vec<_> = tensors.iter().map(|t| &**t).collect();
+    let expected = Naive.einsum(subscripts, &views).unwrap();
+
+    let labels = ncon_labels(subscripts).unwrap();
+    let labels: Vec<&[i32]> = labels.iter().map(Vec::as_slice).collect();
+
+    assert_close(&bd.ncon(&views, &labels).unwrap(), &expected, 1e-10);
+    for strategy in [PathStrategy::Greedy, PathStrategy::Optimal] {
+        let path = ncon_path(&labels, dims, strategy).unwrap();
+        assert_close(
+            &bd.ncon_with_path(&views, &labels, &path).unwrap(),
+            &expected,
+            1e-10,
+        );
+    }
+}
+
+pub fn test_ncon_matrix_chain(bd: &impl Ncon<f64>) {
+    check(bd, "ij,jk->ik", &[&[3, 4], &[4, 5]]);
+    check(bd, "ij,jk,kl,lm->im", &[&[2, 6], &[6, 3], &[3, 7], &[7, 4]]);
+    check(bd, "ij,jk,kl->li", &[&[2, 3], &[3, 4], &[4, 5]]);
+}
+
+pub fn test_ncon_network(bd: &impl Ncon<f64>) {
+    // Ring of matrices, fully contracted
+    check(bd, "ab,bc,cd,da->", &[&[2, 3], &[3, 4], &[4, 2], &[2, 2]]);
+    // Matrix product state norm with open physical indices
+    check(
+        bd,
+        "pa,aqb,brc,cs,PA,AQB,BRC,CS->pqrsPQRS",
+        &[
+            &[2, 3],
+            &[3, 2, 3],
+            &[3, 2, 3],
+            &[3, 2],
+            &[2, 3],
+            &[3, 2, 3],
+            &[3, 2, 3],
+            &[3, 2],
+        ],
+    );
+    // Disconnected components need an outer product
+    check(
+        bd,
+        "ij,jk,lm,mn->ikln",
+        &[&[2, 3], &[3, 2], &[3, 4], &[4, 2]],
+    );
+}
+
+pub fn test_ncon_partial_trace(bd: &impl Ncon<f64>) {
+    check(bd, "iij,jk->k", &[&[3, 3, 4], &[4, 2]]);
+    check(bd, "ii->", &[&[4, 4]]);
+
+    let a = random_tensor(&[3, 4, 3]);
+    let trace = bd.ncon(&[&a], &[&[1, -1, 1]]).unwrap();
+    assert_close(&trace, &Naive.einsum("iji->j", &[&a]).unwrap(), 1e-10);
+}
+
+pub fn test_ncon_single_tensor(bd: &impl Ncon<f64>) {
+    // The open labels permute the axes
+    let a = random_tensor(&[2, 3, 4]);
+    let c = bd.ncon(&[&a], &[&[-3, -1, -2]]).unwrap();
+    assert_close(&c, &a.permute(vec![1, 2, 0]).to_tensor(), 1e-10);
+}
+
+pub fn test_ncon_complex(bd: &impl Ncon<Complex<f64>>) {
+    let a = random_cplx_tensor(&[3, 4, 2]);
+    let b = random_cplx_tensor(&[2, 4, 5]);
+    let c = random_cplx_tensor(&[5, 3]);
+    let labels: [&[i32]; 3] = [&[1, -2, 2], &[2, -1, 3], &[3, 1]];
+
+    let result = bd.ncon(&[&a, &b, &c], &labels).unwrap();
+    let expected = Naive.einsum("ijk,klm,mi->lj", &[&a, &b, &c]).unwrap();
+    assert_close(&result, &expected, 1e-10);
+}
+
+pub fn test_ncon_with_path(bd: &impl Ncon<f64>) {
+    let a = random_tensor(&[2, 3]);
+    let b = random_tensor(&[3, 4]);
+    let c = random_tensor(&[4, 5]);
+    let labels: [&[i32]; 3] = [&[-1, 1], &[1, 2], &[2, -2]];
+    let expected = Naive.einsum("ij,jk,kl->il", &[&a, &b, &c]).unwrap();
+
+    for steps in [
+        vec![(0, 1), (0, 1)],
+        vec![(1, 2), (0, 1)],
+        vec![(0, 2), (0, 1)],
+    ] {
+        let path = ContractionPath {
+            steps,
+            flops: 0.,
+            peak_memory: 0,
+        };
+        let result = bd.ncon_with_path(&[&a, &b, &c], &labels, &path).unwrap();
+        assert_close(&result, &expected, 1e-10);
+    }
+
+    for steps in [vec![(0, 1)], vec![(1, 0), (0, 1)], vec![(0, 1), (0, 2)]] {
+        let path = ContractionPath {
+            steps,
+            flops: 0.,
+            peak_memory: 0,
+        };
+        assert!(matches!(
+            bd.ncon_with_path(&[&a, &b, &c], &labels, &path),
+            Err(NconError::InvalidPath(_))
+        ));
+    }
+}
+
+pub fn test_ncon_path_cost() {
+    // Chain (10 × 2) · (2 × 10) · (10 × 2)
+    let labels: [&[i32]; 3] = [&[-1, 1], &[1, 2], &[2, -2]];
+    let dims: [&[usize]; 3] = [&[10, 2], &[2, 10], &[10, 2]];
+
+    for strategy in [
+        PathStrategy::Greedy,
+        PathStrategy::Optimal,
+        PathStrategy::Auto,
+    ] {
+        let path = ncon_path(&labels, &dims, strategy).unwrap();
+        assert_eq!(path.steps, [(1, 2), (0, 1)]);
+        assert_eq!(path.flops, 160.);
+        // The 60 input elements and the 2 × 2 intermediate
+        assert_eq!(path.peak_memory, 64);
+    }
+
+    // A single tensor needs no step, its partial trace holding 3 elements
+    let path = ncon_path(&[&[1, -1, 1]], &[&[2, 3, 2]], PathStrategy::Auto).unwrap();
+    assert!(path.steps.is_empty());
+    assert_eq!(path.flops, 0.);
+    assert_eq!(path.peak_memory, 15);
+}
+
+pub fn test_ncon_optimal_beats_greedy() {
+    let mut rng = rand::rng();
+    for _ in 0..10 {
+        // Random ring of 8 matrices
+        let n = 8;
+        let bonds: Vec<usize> = (0..n).map(|_| rng.random_range(1..8)).collect();
+        let labels: Vec<Vec<i32>> = (0..n)
+            .map(|t| vec![t as i32 + 1, (t + 1) as i32 % n as i32 + 1])
+            .collect();
+        let dims: Vec<Vec<usize>> = (0..n).map(|t| vec![bonds[t], bonds[(t + 1) % n]]).collect();
+        let labels: Vec<&[i32]> = labels.iter().map(Vec::as_slice).collect();
+        let dims: Vec<&[usize]> = dims.iter().map(Vec::as_slice).collect();
+
+        let greedy = ncon_path(&labels, &dims, PathStrategy::Greedy).unwrap();
+        let optimal = ncon_path(&labels, &dims, PathStrategy::Optimal).unwrap();
+        assert_eq!(greedy.steps.len(), n - 1);
+        assert_eq!(optimal.steps.len(), n - 1);
+        assert!(optimal.flops <= greedy.flops);
+    }
+}
+
+pub fn test_ncon_labels() {
+    assert_eq!(
+        ncon_labels("ij,jk,kl->li").unwrap(),
+        [vec![-2, 1], vec![1, 2], vec![2, -1]]
+    );
+    assert_eq!(ncon_labels("iij,jk").unwrap(), [vec![1, 1, 2], vec![2, -1]]);
+
+    // Batch and summed-out indices are not network edges
+    assert!(matches!(
+        ncon_labels("ij,ij->ij"),
+        Err(NconError::UnsupportedIndex('i'))
+    ));
+    assert!(matches!(
+        ncon_labels("ij->i"),
+        Err(NconError::UnsupportedIndex('j'))
+    ));
+    assert!(matches!(ncon_labels("ij,j1->i"), Err(NconError::Einsum(_))));
+}
+
+pub fn test_ncon_errors(bd: &impl Ncon<f64>) {
+    let a = random_tensor(&[3, 4]);
+    let b = random_tensor(&[4, 5]);
+
+    assert!(matches!(
+        bd.ncon(&[&a, &b], &[&[-1, 1]]),
+        Err(NconError::LabelCount {
+            tensors: 2,
+            labels: 1
+        })
+    ));
+    assert!(matches!(
+        bd.ncon(&[&a], &[&[-1, 1, 2]]),
+        Err(NconError::RankMismatch {
+            tensor: 0,
+            rank: 2,
+            labels: 3
+        })
+    ));
+    assert!(matches!(
+        bd.ncon(&[&a, &b], &[&[-1, 0], &[0, -2]]),
+        Err(NconError::ZeroLabel)
+    ));
+    assert!(matches!(
+        bd.ncon(&[&a, &b], &[&[1, -1], &[1, -2]]),
+        Err(NconError::DimensionMismatch {
+            label: 1,
+            first: 3,
+            second: 4
+        })
+    ));
+    assert!(matches!(
+        bd.ncon(&[&a, &a], &[&[-1, 1], &[-1, 1]]),
+        Err(NconError::LabelMultiplicity {
+            label: -1,
+            count: 2,
+            expected: 1
+        })
+    ));
+    assert!(matches!(
+        bd.ncon(&[&a, &b], &[&[-1, 1], &[2, -2]]),
+        Err(NconError::LabelMultiplicity {
+            label: 1,
+            count: 1,
+            expected: 2
+        })
+    ));
+    assert!(matches!(
+        bd.ncon(&[&a, &b], &[&[-1, 1], &[1, -3]]),
+        Err(NconError::MissingOpenLabel(-2))
+    ));
+    assert!(matches!(
+        bd.ncon::<mdarray::Dense>(&[], &[]),
+        Err(NconError::Empty)
+    ));
+
+    let labels: Vec<Vec<i32>> = (0..17).map(|t| vec![t + 1, (t + 1) % 17 + 1]).collect();
+    let labels: Vec<&[i32]> = labels.iter().map(Vec::as_slice).collect();
+    let dims: Vec<&[usize]> = vec![&[2, 2]; 17];
+    assert!(matches!(
+        ncon_path(&labels, &dims, PathStrategy::Optimal),
+        Err(NconError::TooManyTensors { count: 17, max: 16 })
+    ));
+    // Large networks fall back to the greedy search
+    assert!(ncon_path(&labels, &dims, PathStrategy::Auto).is_ok());
+}
@@ -0,0 +1,252 @@
+use approx::assert_relative_eq;
+use mdarray::{DTensor, tensor};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{adjoint, naive_matmul, random_cplx_matrix, random_matrix};
+use crate::norm::{Cond, MatrixNorm, Norm, NormError};
+
+/// Unit lower and upper triangular factors with a dominant diagonal in U
+fn lu_factors<T: ComplexFloat<Real = f64>>(r: &DTensor<T, 2>) -> (DTensor<T, 2>, DTensor<T, 2>) {
+    let n = r.shape().0;
+    let l = DTensor::<T, 2>::from_fn([n, n], |i| match i[0].cmp(&i[1]) {
+        std::cmp::Ordering::Greater => r[[i[0], i[1]]],
+        std::cmp::Ordering::Equal => T::one(),
+        std::cmp::Ordering::Less => T::zero(),
+    });
+    let u = DTensor::<T, 2>::from_fn([n, n], |i| match i[0].cmp(&i[1]) {
+        std::cmp::Ordering::Less => r[[i[0], i[1]]],
+        std::cmp::Ordering::Equal => r[[i[0], i[1]]] + T::from(n).unwrap(),
+        std::cmp::Ordering::Greater => T::zero(),
+    });
+    (l, u)
+}
+
+/// Lower triangular Cholesky factor with a positive diagonal
+fn cholesky_factor<T: ComplexFloat<Real = f64>>(r: &DTensor<T, 2>) -> DTensor<T, 2> {
+    let n = r.shape().0;
+    DTensor::<T, 2>::from_fn([n, n], |i| match i[0].cmp(&i[1]) {
+        std::cmp::Ordering::Greater => r[[i[0], i[1]]],
+        std::cmp::Ordering::Equal => T::from(1.0 + r[[i[0], i[1]]].abs()).unwrap(),
+        std::cmp::Ordering::Less => T::zero(),
+    })
+}
+
+/// Inverse by Gauss-Jordan elimination with partial pivoting
+fn inverse<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>) -> DTensor<T, 2> {
+    let n = a.shape().0;
+    let mut a = a.clone();
+    let mut inv =
+        DTensor::<T, 2>::from_fn([n, n], |i| if i[0] == i[1] { T::one() } else { T::zero() });
+
+    for k in 0..n {
+        let p = (k..n)
+            .max_by(|&i, &j| a[[i, k]].abs().total_cmp(&a[[j, k]].abs()))
+            .unwrap();
+        for j in 0..n {
+            let (x, y) = (a[[k, j]], a[[p, j]]);
+            a[[k, j]] = y;
+            a[[p, j]] = x;
+            let (x, y) = (inv[[k, j]], inv[[p, j]]);
+            inv[[k, j]] = y;
+            inv[[p, j]] = x;
+        }
+
+        let pivot = a[[k, k]];
+        for j in 0..n {
+            a[[k, j]] = a[[k, j]] / pivot;
+            inv[[k, j]] = inv[[k, j]] / pivot;
+        }
+        for i in (0..n).filter(|&i| i != k) {
+            let f = a[[i, k]];
+            for j in 0..n {
+                a[[i, j]] = a[[i, j]] - f * a[[k, j]];
+                inv[[i, j]] = inv[[i, j]] - f * inv[[k, j]];
+            }
+        }
+    }
+
+    inv
+}
+
+fn norm1<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>) -> f64 {
+    let (m, n) = *a.shape();
+    (0..n)
+        .map(|j| (0..m).map(|i| a[[i, j]].abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+/// The estimate of ‖A⁻¹‖ is a lower bound, almost always exact for small
+/// matrices
+fn check_rcond_estimate(estimate: f64, exact: f64) {
+    assert!(estimate <= exact * (1.0 + 1e-10));
+    assert!(estimate >= exact / 10.0);
+}
+
+pub fn test_matrix_norms(bd: &impl MatrixNorm<f64>) {
+    let a = tensor![[3., 0.], [4., -5.]];
+
+    // Singular values 3√5 and √5
+    let sqrt5 = 5f64.sqrt();
+    assert_relative_eq!(bd.matrix_norm(&a, Norm::One).unwrap(), 7.0);
+    assert_relative_eq!(bd.matrix_norm(&a, Norm::Inf).unwrap(), 9.0);
+    assert_relative_eq!(bd.matrix_norm(&a, Norm::MaxAbs).unwrap(), 5.0);
+    assert_relative_eq!(
+        bd.matrix_norm(&a, Norm::Frobenius).unwrap(),
+        50f64.sqrt(),
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        bd.matrix_norm(&a, Norm::Spectral).unwrap(),
+        3.0 * sqrt5,
+        epsilon = 1e-12
+    );
+    assert_relative_eq!(
+        bd.matrix_norm(&a, Norm::Nuclear).unwrap(),
+        4.0 * sqrt5,
+        epsilon = 1e-12
+    );
+}
+
+pub fn test_matrix_norms_rectangular(bd: &impl MatrixNorm<f64>) {
+    // Rank one matrix u vᵀ: all the unitarily invariant norms equal ‖u‖ ‖v‖
+    let u = random_matrix(5, 1);
+    let v = random_matrix(1, 3);
+    let a = naive_matmul(&u, &v);
+    let at = adjoint(&a);
+    let expected =
+        u.iter().map(|x| x * x).sum::<f64>().sqrt() * v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    for m in [&a, &at] {
+        for norm in [Norm::Frobenius, Norm::Spectral, Norm::Nuclear] {
+            assert_relative_eq!(bd.matrix_norm(m, norm).unwrap(), expected, epsilon = 1e-10);
+        }
+    }
+
+    // ‖A‖₁ = ‖Aᵀ‖∞
+    assert_relative_eq!(
+        bd.matrix_norm(&a, Norm::One).unwrap(),
+        bd.matrix_norm(&at, Norm::Inf).unwrap(),
+        epsilon = 1e-12
+    );
+}
+
+pub fn test_matrix_norms_complex(bd: &impl MatrixNorm<Complex<f64>>) {
+    let a = tensor![
+        [Complex::new(3., 4.), Complex::new(0., 0.)],
+        [Complex::new(0., 1.), Complex::new(0., -2.)]
+    ];
+
+    assert_relative_eq!(bd.matrix_norm(&a, Norm::One).unwrap(), 6.0);
+    assert_relative_eq!(bd.matrix_norm(&a, Norm::Inf).unwrap(), 5.0);
+    assert_relative_eq!(bd.matrix_norm(&a, Norm::MaxAbs).unwrap(), 5.0);
+    assert_relative_eq!(
+        bd.matrix_norm(&a, Norm::Frobenius).unwrap(),
+        30f64.sqrt(),
+        epsilon = 1e-12
+    );
+
+    // Singular values of a random complex matrix and of its adjoint agree
+    let b = random_cplx_matrix(4, 6);
+    let bh = adjoint(&b);
+    for norm in [Norm::Spectral, Norm::Nuclear] {
+        assert_relative_eq!(
+            bd.matrix_norm(&b, norm).unwrap(),
+            bd.matrix_norm(&bh, norm).unwrap(),
+            epsilon = 1e-10
+        );
+    }
+    let spectral = bd.matrix_norm(&b, Norm::Spectral).unwrap();
+    let frobenius = bd.matrix_norm(&b, Norm::Frobenius).unwrap();
+    let nuclear = bd.matrix_norm(&b, Norm::Nuclear).unwrap();
+    assert!(spectral <= frobenius && frobenius <= nuclear);
+    assert!(frobenius <= 2.0 * spectral);
+}
+
+pub fn test_rcond_lu(bd: &(impl Cond<f64> + MatrixNorm<f64>)) {
+    let n = 8;
+    let (l, u) = lu_factors(&random_matrix(n, n));
+    let a = naive_matmul(&l, &u);
+    let ainv = inverse(&a);
+
+    let anorm = bd.matrix_norm(&a, Norm::One).unwrap();
+    let rcond = bd.rcond_lu(&l, &u, anorm, Norm::One).unwrap();
+    check_rcond_estimate(1.0 / (rcond * anorm), norm1(&ainv));
+
+    let anorm = bd.matrix_norm(&a, Norm::Inf).unwrap();
+    let rcond = bd.rcond_lu(&l, &u, anorm, Norm::Inf).unwrap();
+    check_rcond_estimate(1.0 / (rcond * anorm), norm1(&adjoint(&ainv)));
+
+    // Same estimate from L and U packed in a single matrix
+    let lu = DTensor::<f64, 2>::from_fn([n, n], |i| {
+        if i[0] > i[1] {
+            l[[i[0], i[1]]]
+        } else {
+            u[[i[0], i[1]]]
+        }
+    });
+    assert_relative_eq!(
+        bd.rcond_lu_packed(&lu, anorm, Norm::Inf).unwrap(),
+        rcond,
+        epsilon = 1e-12
+    );
+}
+
+pub fn test_rcond_lu_complex(bd: &(impl Cond<Complex<f64>> + MatrixNorm<Complex<f64>>)) {
+    let n = 6;
+    let (l, u) = lu_factors(&random_cplx_matrix(n, n));
+    let a = naive_matmul(&l, &u);
+    let ainv = inverse(&a);
+
+    let anorm = bd.matrix_norm(&a, Norm::One).unwrap();
+    let rcond = bd.rcond_lu(&l, &u, anorm, Norm::One).unwrap();
+    check_rcond_estimate(1.0 / (rcond * anorm), norm1(&ainv));
+}
+
+pub fn test_rcond_lu_ill_conditioned(bd: &impl Cond<f64>) {
+    let l = tensor![[1., 0.], [1., 1.]];
+    let u = tensor![[1., 1.], [0., 1e-10]];
+
+    // A = [[1, 1], [1, 1 + 1e-10]]
+    let rcond = bd.rcond_lu(&l, &u, 2.0 + 1e-10, Norm::One).unwrap();
+    assert!(rcond < 1e-9);
+
+    let singular = tensor![[1., 1.], [0., 0.]];
+    assert_eq!(bd.rcond_lu(&l, &singular, 2.0, Norm::One).unwrap(), 0.0);
+
+    let identity = tensor![[1., 0.], [0., 1.]];
+    assert_relative_eq!(
+        bd.rcond_lu(&identity, &identity, 1.0, Norm::Inf).unwrap(),
+        1.0
+    );
+}
+
+pub fn test_rcond_lu_unsupported_norm(bd: &impl Cond<f64>) {
+    let l = tensor![[1., 0.], [0.5, 1.]];
+    let u = tensor![[2., 1.], [0., 1.]];
+
+    let result = bd.rcond_lu(&l, &u, 3.0, Norm::Frobenius);
+    assert!(matches!(
+        result,
+        Err(NormError::UnsupportedNorm(Norm::Frobenius))
+    ));
+}
+
+pub fn test_rcond_cholesky(bd: &(impl Cond<f64> + MatrixNorm<f64>)) {
+    let n = 8;
+    let l = cholesky_factor(&random_matrix(n, n));
+    let a = naive_matmul(&l, &adjoint(&l));
+
+    let anorm = bd.matrix_norm(&a, Norm::One).unwrap();
+    let rcond = bd.rcond_cholesky(&l, anorm).unwrap();
+    check_rcond_estimate(1.0 / (rcond * anorm), norm1(&inverse(&a)));
+}
+
+pub fn test_rcond_cholesky_complex(bd: &(impl Cond<Complex<f64>> + MatrixNorm<Complex<f64>>)) {
+    let n = 6;
+    let l = cholesky_factor(&random_cplx_matrix(n, n));
+    let a = naive_matmul(&l, &adjoint(&l));
+
+    let anorm = bd.matrix_norm(&a, Norm::One).unwrap();
+    let rcond = bd.rcond_cholesky(&l, anorm).unwrap();
+    check_rcond_estimate(1.0 / (rcond * anorm), norm1(&inverse(&a)));
+}
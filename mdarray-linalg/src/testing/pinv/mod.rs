@@ -0,0 +1,125 @@
+use approx::assert_relative_eq;
+use mdarray::{DTensor, Dense, tensor};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{
+    adjoint, assert_close, naive_matmul, random_cplx_matrix, random_matrix, rank_k_matrix,
+};
+use crate::pinv::{Pinv, PinvError, PinvHermitian, PinvTolerance};
+
+/// Check the four Penrose conditions A P A = A, P A P = P, (A P)ᴴ = A P
+/// and (P A)ᴴ = P A
+fn check_penrose<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, p: &DTensor<T, 2>) {
+    let (m, n) = *a.shape();
+    assert_eq!(*p.shape(), (n, m));
+
+    let ap = naive_matmul(a, p);
+    let pa = naive_matmul(p, a);
+    assert_close(&naive_matmul(&ap, a), a, 1e-8);
+    assert_close(&naive_matmul(&pa, p), p, 1e-8);
+    assert_close(&adjoint(&ap), &ap, 1e-8);
+    assert_close(&adjoint(&pa), &pa, 1e-8);
+}
+
+pub fn test_pinv_square(bd: &impl Pinv<f64, usize, Dense>) {
+    let n = 5;
+    let a = random_matrix(n, n);
+    let p = bd.pinv(&mut a.clone(), PinvTolerance::default()).unwrap();
+
+    // A full rank square matrix has A⁺ = A⁻¹
+    let identity = DTensor::<f64, 2>::from_fn([n, n], |i| (i[0] == i[1]) as u8 as f64);
+    assert_close(&naive_matmul(&a, &p), &identity, 1e-8);
+}
+
+pub fn test_pinv_rectangular(bd: &impl Pinv<f64, usize, Dense>) {
+    for (m, n) in [(6, 3), (3, 6)] {
+        let a = random_matrix(m, n);
+        let p = bd.pinv(&mut a.clone(), PinvTolerance::default()).unwrap();
+        check_penrose(&a, &p);
+    }
+}
+
+pub fn test_pinv_rank_deficient(bd: &impl Pinv<f64, usize, Dense>) {
+    let a = rank_k_matrix(7, 5, 2);
+    let mut p = DTensor::<f64, 2>::zeros([5, 7]);
+    let rank = bd
+        .pinv_write(&mut a.clone(), PinvTolerance::default(), &mut p)
+        .unwrap();
+
+    assert_eq!(rank, 2);
+    check_penrose(&a, &p);
+}
+
+pub fn test_pinv_tolerance(bd: &impl Pinv<f64, usize, Dense>) {
+    let a = tensor![[1., 0., 0.], [0., 1e-3, 0.], [0., 0., 1e-12], [0., 0., 0.]];
+
+    // The default cutoff 4 ε σ₀ keeps 1e-12
+    let p = bd.pinv(&mut a.clone(), PinvTolerance::default()).unwrap();
+    assert_relative_eq!(p[[2, 2]], 1e12, max_relative = 1e-6);
+
+    let p = bd
+        .pinv(&mut a.clone(), PinvTolerance::default().rtol(1e-6))
+        .unwrap();
+    let expected = tensor![[1., 0., 0., 0.], [0., 1e3, 0., 0.], [0., 0., 0., 0.]];
+    assert_close(&p, &expected, 1e-8);
+
+    let mut p = DTensor::<f64, 2>::zeros([3, 4]);
+    let rank = bd
+        .pinv_write(&mut a.clone(), PinvTolerance::default().atol(1e-2), &mut p)
+        .unwrap();
+    assert_eq!(rank, 1);
+    let expected = tensor![[1., 0., 0., 0.], [0., 0., 0., 0.], [0., 0., 0., 0.]];
+    assert_close(&p, &expected, 1e-8);
+
+    // The largest of both cutoffs is applied
+    let tol = PinvTolerance::default().rtol(1e-6).atol(1e-15);
+    let rank = bd.pinv_write(&mut a.clone(), tol, &mut p).unwrap();
+    assert_eq!(rank, 2);
+}
+
+pub fn test_pinv_complex(bd: &impl Pinv<Complex<f64>, usize, Dense>) {
+    for (m, n) in [(5, 3), (3, 5)] {
+        let a = random_cplx_matrix(m, n);
+        let p = bd.pinv(&mut a.clone(), PinvTolerance::default()).unwrap();
+        check_penrose(&a, &p);
+    }
+}
+
+pub fn test_pinvh(bd: &(impl PinvHermitian<f64, usize> + Pinv<f64, usize, Dense>)) {
+    // Indefinite symmetric matrix of rank 3: B S Bᵀ with S = diag(2, -1, 1)
+    let b = random_matrix(6, 3);
+    let s = tensor![[2., 0., 0.], [0., -1., 0.], [0., 0., 1.]];
+    let a = naive_matmul(&naive_matmul(&b, &s), &adjoint(&b));
+
+    let mut ph = DTensor::<f64, 2>::zeros([6, 6]);
+    let rank = bd
+        .pinvh_write(
+            &mut a.clone(),
+            PinvTolerance::default().rtol(1e-10),
+            &mut ph,
+        )
+        .unwrap();
+    assert_eq!(rank, 3);
+    check_penrose(&a, &ph);
+
+    let p = bd
+        .pinv(&mut a.clone(), PinvTolerance::default().rtol(1e-10))
+        .unwrap();
+    assert_close(&ph, &p, 1e-8);
+}
+
+pub fn test_pinvh_complex(bd: &impl PinvHermitian<Complex<f64>, usize>) {
+    let b = random_cplx_matrix(5, 2);
+    let a = naive_matmul(&b, &adjoint(&b));
+
+    let p = bd
+        .pinvh(&mut a.clone(), PinvTolerance::default().rtol(1e-10))
+        .unwrap();
+    check_penrose(&a, &p);
+}
+
+pub fn test_pinvh_not_square(bd: &impl PinvHermitian<f64, usize>) {
+    let mut a = random_matrix(3, 4);
+    let result = bd.pinvh(&mut a, PinvTolerance::default());
+    assert!(matches!(result, Err(PinvError::NotSquareMatrix)));
+}
@@ -0,0 +1,174 @@
+use mdarray::{DTensor, Dense, tensor};
+use num_complex::{Complex, ComplexFloat};
+use rand::Rng;
+
+use super::common::{
+    adjoint, assert_close, naive_matmul, random_cplx_matrix, random_matrix, rank_k_matrix,
+};
+use crate::{
+    identity,
+    polar::{Polar, PolarDecomp, PolarError, PolarIterative},
+};
+
+/// Check A = U H with H Hermitian positive semidefinite and H² = Aᴴ A,
+/// which characterizes H
+fn check_factors<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, decomp: &PolarDecomp<T, usize>) {
+    let (m, n) = *a.shape();
+    let (u, h) = (&decomp.u, &decomp.h);
+    assert_eq!(*u.shape(), (m, n));
+    assert_eq!(*h.shape(), (n, n));
+
+    assert_close(&naive_matmul(u, h), a, 1e-8);
+    assert_close(&adjoint(h), h, 1e-8);
+    assert_close(&naive_matmul(h, h), &naive_matmul(&adjoint(a), a), 1e-8);
+
+    let mut rng = rand::rng();
+    for _ in 0..10 {
+        let x = DTensor::<T, 2>::from_fn([n, 1], |_| {
+            T::from(rng.random::<f64>() * 2.0 - 1.0).unwrap()
+        });
+        let xhx = naive_matmul(&adjoint(&x), &naive_matmul(h, &x));
+        assert!(xhx[[0, 0]].re() >= -1e-10);
+    }
+}
+
+/// Check the factors and that U has orthonormal columns, or rows if m < n
+fn check_polar<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, decomp: &PolarDecomp<T, usize>) {
+    check_factors(a, decomp);
+
+    let (m, n) = *a.shape();
+    let u = &decomp.u;
+    if m >= n {
+        assert_close(&naive_matmul(&adjoint(u), u), &identity(n), 1e-8);
+    } else {
+        assert_close(&naive_matmul(u, &adjoint(u)), &identity(m), 1e-8);
+    }
+}
+
+pub fn test_polar_square(bd: &impl Polar<f64, usize, Dense>) {
+    let a = random_matrix(5, 5);
+    let decomp = bd.polar(&mut a.clone()).unwrap();
+    check_polar(&a, &decomp);
+}
+
+pub fn test_polar_rectangular(bd: &impl Polar<f64, usize, Dense>) {
+    for (m, n) in [(6, 3), (3, 6)] {
+        let a = random_matrix(m, n);
+        let decomp = bd.polar(&mut a.clone()).unwrap();
+        check_polar(&a, &decomp);
+    }
+}
+
+pub fn test_polar_rank_deficient(bd: &impl Polar<f64, usize, Dense>) {
+    // U still has orthonormal columns, completing those of the range
+    let a = rank_k_matrix(6, 4, 2);
+    let decomp = bd.polar(&mut a.clone()).unwrap();
+    check_polar(&a, &decomp);
+}
+
+pub fn test_polar_known(bd: &impl Polar<f64, usize, Dense>) {
+    // Rotation by 90° times diag(2, 3)
+    let a = tensor![[0., -3.], [2., 0.]];
+    let decomp = bd.polar(&mut a.clone()).unwrap();
+
+    assert_close(&decomp.u, &tensor![[0., -1.], [1., 0.]], 1e-8);
+    assert_close(&decomp.h, &tensor![[2., 0.], [0., 3.]], 1e-8);
+}
+
+pub fn test_polar_complex(bd: &impl Polar<Complex<f64>, usize, Dense>) {
+    for (m, n) in [(4, 4), (5, 3), (3, 5)] {
+        let a = random_cplx_matrix(m, n);
+        let decomp = bd.polar(&mut a.clone()).unwrap();
+        check_polar(&a, &decomp);
+    }
+}
+
+pub fn test_polar_newton(bd: &impl PolarIterative<f64, usize>) {
+    let a = random_matrix(6, 6);
+    let decomp = bd.polar_newton(&a).unwrap();
+    check_polar(&a, &decomp);
+}
+
+pub fn test_polar_newton_complex(bd: &impl PolarIterative<Complex<f64>, usize>) {
+    let a = random_cplx_matrix(5, 5);
+    let decomp = bd.polar_newton(&a).unwrap();
+    check_polar(&a, &decomp);
+}
+
+pub fn test_polar_newton_not_square(bd: &impl PolarIterative<f64, usize>) {
+    let a = random_matrix(4, 3);
+    assert!(matches!(
+        bd.polar_newton(&a),
+        Err(PolarError::NotSquareMatrix)
+    ));
+}
+
+pub fn test_polar_halley(bd: &impl PolarIterative<f64, usize>) {
+    for (m, n) in [(5, 5), (7, 4), (4, 7)] {
+        let a = random_matrix(m, n);
+        let decomp = bd.polar_halley(&a).unwrap();
+        check_polar(&a, &decomp);
+    }
+}
+
+pub fn test_polar_halley_complex(bd: &impl PolarIterative<Complex<f64>, usize>) {
+    for (m, n) in [(4, 4), (6, 3), (3, 6)] {
+        let a = random_cplx_matrix(m, n);
+        let decomp = bd.polar_halley(&a).unwrap();
+        check_polar(&a, &decomp);
+    }
+}
+
+pub fn test_polar_halley_rank_deficient(bd: &impl PolarIterative<f64, usize>) {
+    let a = rank_k_matrix(6, 4, 2);
+    let decomp = bd.polar_halley(&a).unwrap();
+    check_factors(&a, &decomp);
+
+    let zero = DTensor::<f64, 2>::zeros([3, 2]);
+    let decomp = bd.polar_halley(&zero).unwrap();
+    assert_close(&decomp.u, &zero, 1e-8);
+    assert_close(&decomp.h, &DTensor::<f64, 2>::zeros([2, 2]), 1e-8);
+}
+
+pub fn test_polar_halley_ill_conditioned(bd: &impl PolarIterative<f64, usize>) {
+    // W diag(1, 1/κ) Vᵀ with rotations W and V: the small singular value
+    // only triples at each step and must still be driven to 1
+    let rotation = |t: f64| tensor![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+    for kappa in [1e8, 1e10] {
+        let s = tensor![[1., 0.], [0., 1. / kappa]];
+        let a = naive_matmul(&naive_matmul(&rotation(0.3), &s), &rotation(-1.1));
+        let decomp = bd.polar_halley(&a).unwrap();
+        check_polar(&a, &decomp);
+    }
+}
+
+pub fn test_polar_iterative_edge_cases(bd: &impl PolarIterative<f64, usize>) {
+    let empty = DTensor::<f64, 2>::zeros([0, 0]);
+    let decomp = bd.polar_newton(&empty).unwrap();
+    assert_eq!((*decomp.u.shape(), *decomp.h.shape()), ((0, 0), (0, 0)));
+
+    let decomp = bd.polar_halley(&DTensor::<f64, 2>::zeros([3, 0])).unwrap();
+    assert_eq!((*decomp.u.shape(), *decomp.h.shape()), ((3, 0), (0, 0)));
+
+    let a = tensor![[1., f64::NAN], [0., 1.]];
+    assert!(matches!(
+        bd.polar_newton(&a),
+        Err(PolarError::Breakdown { iterations: 1 })
+    ));
+    assert!(matches!(
+        bd.polar_halley(&a),
+        Err(PolarError::Breakdown { iterations: 1 })
+    ));
+}
+
+pub fn test_polar_paths_agree(bd: &(impl Polar<f64, usize, Dense> + PolarIterative<f64, usize>)) {
+    let a = random_matrix(5, 5);
+    let svd = bd.polar(&mut a.clone()).unwrap();
+    let newton = bd.polar_newton(&a).unwrap();
+    let halley = bd.polar_halley(&a).unwrap();
+
+    assert_close(&newton.u, &svd.u, 1e-8);
+    assert_close(&newton.h, &svd.h, 1e-8);
+    assert_close(&halley.u, &svd.u, 1e-8);
+    assert_close(&halley.h, &svd.h, 1e-8);
+}
@@ -3,8 +3,11 @@ use mdarray::DTensor;
 use num_complex::Complex;
 use rand::prelude::*;
 
-use super::common::naive_matmul;
-use crate::{assert_complex_matrix_eq, assert_matrix_eq, pretty_print, qr::QR};
+use super::common::{naive_matmul, random_matrix, rank_k_matrix};
+use crate::{
+    assert_complex_matrix_eq, assert_matrix_eq, pretty_print,
+    qr::{QR, QRPivot, QRPivotDecomp},
+};
 
 pub fn test_qr_random_matrix(bd: &impl QR<f64, usize, usize>) {
     let (m, n) = (5, 5);
@@ -80,3 +83,180 @@ where
 
     assert_matrix_eq!(a, reconstructed);
 }
+
+pub fn test_qr_tall_matrix(bd: &impl QR<f64, usize, usize>) {
+    test_qr_reconstruction(bd, &random_matrix(6, 4));
+}
+
+pub fn test_qr_wide_matrix(bd: &impl QR<f64, usize, usize>) {
+    test_qr_reconstruction(bd, &random_matrix(4, 6));
+}
+
+/// Checks A = Q R with Q m × k having orthonormal columns and R k × n
+/// upper triangular, k = min(m, n)
+fn test_qr_thin_reconstruction(bd: &impl QR<f64, usize, usize>, a: &DTensor<f64, 2>) {
+    let (m, n) = *a.shape();
+    let k = m.min(n);
+
+    let (q, r) = bd.qr_thin(&mut a.clone());
+    assert_eq!(*q.shape(), (m, k));
+    assert_eq!(*r.shape(), (k, n));
+
+    let reconstructed = naive_matmul(&q, &r);
+    assert_matrix_eq!(a, reconstructed);
+
+    let qt = DTensor::<f64, 2>::from_fn([k, m], |i| q[[i[1], i[0]]]);
+    let id = DTensor::<f64, 2>::from_fn([k, k], |i| if i[0] == i[1] { 1. } else { 0. });
+    let qtq = naive_matmul(&qt, &q);
+    assert_matrix_eq!(id, qtq);
+
+    for i in 0..k {
+        for j in 0..i {
+            assert_relative_eq!(r[[i, j]], 0.);
+        }
+    }
+}
+
+pub fn test_qr_thin_tall_matrix(bd: &impl QR<f64, usize, usize>) {
+    // A few long vectors to orthogonalize
+    test_qr_thin_reconstruction(bd, &random_matrix(200, 8));
+}
+
+pub fn test_qr_thin_wide_matrix(bd: &impl QR<f64, usize, usize>) {
+    test_qr_thin_reconstruction(bd, &random_matrix(4, 7));
+}
+
+pub fn test_qr_thin_square_matrix(bd: &impl QR<f64, usize, usize>) {
+    test_qr_thin_reconstruction(bd, &random_matrix(5, 5));
+}
+
+pub fn test_qr_thin_write(bd: &impl QR<f64, usize, usize>) {
+    let (m, n) = (9, 3);
+    let a = random_matrix(m, n);
+
+    let mut q = DTensor::<f64, 2>::zeros([m, n]);
+    let mut r = DTensor::<f64, 2>::zeros([n, n]);
+
+    bd.qr_thin_write(&mut a.clone(), &mut q, &mut r);
+    let reconstructed = naive_matmul(&q, &r);
+    assert_matrix_eq!(a, reconstructed);
+}
+
+/// Checks that R is upper triangular for both the full and thin factors
+pub fn test_qr_upper_triangular(bd: &impl QR<f64, usize, usize>) {
+    let a = random_matrix(6, 4);
+
+    let (_, r) = bd.qr(&mut a.clone());
+    let (_, r_thin) = bd.qr_thin(&mut a.clone());
+
+    for r in [r, r_thin] {
+        let (k, n) = *r.shape();
+        for i in 0..k {
+            for j in 0..i.min(n) {
+                assert_relative_eq!(r[[i, j]], 0.);
+            }
+        }
+    }
+}
+
+pub fn test_qr_thin_complex_matrix(bd: &impl QR<Complex<f64>, usize, usize>) {
+    let (m, n) = (7, 3);
+    let mut rng = rand::rng();
+
+    let a = DTensor::<Complex<f64>, 2>::from_fn([m, n], |_| {
+        Complex::new(rng.random::<f64>(), rng.random::<f64>())
+    });
+
+    let (q, r) = bd.qr_thin(&mut a.clone());
+    assert_eq!(*q.shape(), (m, n));
+    assert_eq!(*r.shape(), (n, n));
+
+    let reconstructed = naive_matmul(&q, &r);
+    assert_complex_matrix_eq!(a, reconstructed);
+}
+
+/// Columns of A reordered by the pivoting permutation, i.e. A P
+fn permute_columns<T: Copy>(a: &DTensor<T, 2>, perm: &[usize]) -> DTensor<T, 2> {
+    let (m, n) = *a.shape();
+    DTensor::<T, 2>::from_fn([m, n], |i| a[[i[0], perm[i[1]]]])
+}
+
+fn test_qr_pivot_reconstruction(bd: &impl QRPivot<f64, usize, usize>, a: &DTensor<f64, 2>) {
+    let (m, n) = *a.shape();
+
+    let QRPivotDecomp { q, r, perm } = bd.qr_pivot(&mut a.clone());
+
+    let mut sorted = perm.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+
+    let ap = permute_columns(a, &perm);
+    assert_matrix_eq!(ap, naive_matmul(&q, &r));
+
+    // Q is orthogonal
+    let qt = DTensor::<f64, 2>::from_fn([m, m], |i| q[[i[1], i[0]]]);
+    let id = DTensor::<f64, 2>::from_fn([m, m], |i| if i[0] == i[1] { 1. } else { 0. });
+    let qtq = naive_matmul(&qt, &q);
+    assert_matrix_eq!(id, qtq);
+
+    // R is upper triangular with a non-increasing diagonal
+    for i in 0..m {
+        for j in 0..i.min(n) {
+            assert_relative_eq!(r[[i, j]], 0.);
+        }
+    }
+    for i in 1..m.min(n) {
+        assert!(r[[i, i]].abs() <= r[[i - 1, i - 1]].abs() + 1e-12);
+    }
+
+    let mut q = DTensor::<f64, 2>::zeros([m, m]);
+    let mut r = DTensor::<f64, 2>::zeros([m, n]);
+    let mut perm = vec![0; n];
+
+    bd.qr_pivot_write(&mut a.clone(), &mut q, &mut r, &mut perm);
+    let ap = permute_columns(a, &perm);
+    assert_matrix_eq!(ap, naive_matmul(&q, &r));
+}
+
+pub fn test_qr_pivot_square_matrix(bd: &impl QRPivot<f64, usize, usize>) {
+    test_qr_pivot_reconstruction(bd, &random_matrix(5, 5));
+}
+
+pub fn test_qr_pivot_tall_matrix(bd: &impl QRPivot<f64, usize, usize>) {
+    test_qr_pivot_reconstruction(bd, &random_matrix(7, 4));
+}
+
+pub fn test_qr_pivot_wide_matrix(bd: &impl QRPivot<f64, usize, usize>) {
+    test_qr_pivot_reconstruction(bd, &random_matrix(4, 7));
+}
+
+pub fn test_qr_pivot_rank(bd: &impl QRPivot<f64, usize, usize>) {
+    let (m, n, k) = (8, 6, 3);
+    let a = rank_k_matrix(m, n, k);
+
+    let decomp = bd.qr_pivot(&mut a.clone());
+    assert_eq!(decomp.rank(1e-10), k);
+
+    // The trailing rows of R vanish beyond the numerical rank
+    for i in k..m.min(n) {
+        for j in i..n {
+            assert!(decomp.r[[i, j]].abs() < 1e-10);
+        }
+    }
+
+    let decomp = bd.qr_pivot(&mut random_matrix(m, n));
+    assert_eq!(decomp.rank(1e-10), n);
+}
+
+pub fn test_qr_pivot_complex_matrix(bd: &impl QRPivot<Complex<f64>, usize, usize>) {
+    let (m, n) = (4, 3);
+    let mut rng = rand::rng();
+
+    let a = DTensor::<Complex<f64>, 2>::from_fn([m, n], |_| {
+        Complex::new(rng.random::<f64>(), rng.random::<f64>())
+    });
+
+    let QRPivotDecomp { q, r, perm } = bd.qr_pivot(&mut a.clone());
+    let ap = permute_columns(&a, &perm);
+    assert_complex_matrix_eq!(ap, naive_matmul(&q, &r));
+}
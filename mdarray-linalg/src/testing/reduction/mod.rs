@@ -0,0 +1,100 @@
+use mdarray::DTensor;
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{
+    adjoint, assert_close, check_orthonormal, hermitian, lower_only, naive_matmul,
+    random_cplx_matrix, random_matrix,
+};
+use crate::reduction::{Reduction, ReductionError};
+
+fn check_hessenberg<T: ComplexFloat<Real = f64>>(bd: &impl Reduction<T, usize>, a: &DTensor<T, 2>) {
+    let n = a.shape().0;
+    let decomp = bd.hessenberg(a).unwrap();
+    assert_eq!(*decomp.h.shape(), (n, n));
+
+    for i in 0..n {
+        for j in 0..i.saturating_sub(1) {
+            assert!(decomp.h[[i, j]].is_zero());
+        }
+    }
+    check_orthonormal(&decomp.q);
+
+    let qhqh = naive_matmul(&naive_matmul(&decomp.q, &decomp.h), &adjoint(&decomp.q));
+    assert_close(&qhqh, a, 1e-10);
+}
+
+pub fn test_hessenberg(bd: &impl Reduction<f64, usize>) {
+    for n in [1, 2, 6] {
+        check_hessenberg(bd, &random_matrix(n, n));
+    }
+}
+
+pub fn test_hessenberg_complex(bd: &impl Reduction<Complex<f64>, usize>) {
+    check_hessenberg(bd, &random_cplx_matrix(5, 5));
+}
+
+fn check_tridiagonal<T: ComplexFloat<Real = f64>>(
+    bd: &impl Reduction<T, usize>,
+    a: &DTensor<T, 2>,
+) {
+    let n = a.shape().0;
+    let a = hermitian(a);
+    let decomp = bd.tridiagonal(&lower_only(&a)).unwrap();
+    assert_eq!(decomp.d.len(), n);
+    assert_eq!(decomp.e.len(), n - 1);
+    check_orthonormal(&decomp.q);
+
+    let qtqh = naive_matmul(&naive_matmul(&decomp.q, &decomp.t()), &adjoint(&decomp.q));
+    assert_close(&qtqh, &a, 1e-10);
+}
+
+pub fn test_tridiagonal(bd: &impl Reduction<f64, usize>) {
+    for n in [1, 2, 6] {
+        check_tridiagonal(bd, &random_matrix(n, n));
+    }
+}
+
+pub fn test_tridiagonal_complex(bd: &impl Reduction<Complex<f64>, usize>) {
+    check_tridiagonal(bd, &random_cplx_matrix(5, 5));
+}
+
+fn check_bidiagonal<T: ComplexFloat<Real = f64>>(bd: &impl Reduction<T, usize>, a: &DTensor<T, 2>) {
+    let (m, n) = *a.shape();
+    let k = m.min(n);
+    let decomp = bd.bidiagonal(a).unwrap();
+    assert_eq!(decomp.upper, m >= n);
+    assert_eq!(decomp.d.len(), k);
+    assert_eq!(decomp.e.len(), k - 1);
+    assert_eq!(*decomp.q.shape(), (m, k));
+    assert_eq!(*decomp.pt.shape(), (k, n));
+
+    check_orthonormal(&decomp.q);
+    check_orthonormal(&adjoint(&decomp.pt));
+
+    let qbp = naive_matmul(&naive_matmul(&decomp.q, &decomp.b()), &decomp.pt);
+    assert_close(&qbp, a, 1e-10);
+}
+
+pub fn test_bidiagonal(bd: &impl Reduction<f64, usize>) {
+    for (m, n) in [(6, 4), (4, 6), (5, 5), (3, 1)] {
+        check_bidiagonal(bd, &random_matrix(m, n));
+    }
+}
+
+pub fn test_bidiagonal_complex(bd: &impl Reduction<Complex<f64>, usize>) {
+    for (m, n) in [(5, 3), (3, 5)] {
+        check_bidiagonal(bd, &random_cplx_matrix(m, n));
+    }
+}
+
+pub fn test_reduction_not_square(bd: &impl Reduction<f64, usize>) {
+    let a = random_matrix(3, 4);
+    assert!(matches!(
+        bd.hessenberg(&a),
+        Err(ReductionError::NotSquareMatrix)
+    ));
+    assert!(matches!(
+        bd.tridiagonal(&a),
+        Err(ReductionError::NotSquareMatrix)
+    ));
+}
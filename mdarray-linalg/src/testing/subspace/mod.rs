@@ -0,0 +1,118 @@
+use approx::assert_relative_eq;
+use mdarray::{DTensor, Dense, tensor};
+use num_complex::{Complex, ComplexFloat};
+use rand::Rng;
+
+use super::common::{adjoint, check_orthonormal, naive_matmul, rank_k_matrix};
+use crate::{
+    pinv::PinvTolerance,
+    qr::QRPivot,
+    subspace::{null_space, null_space_qr, orth, orth_qr, rank, rank_qr},
+    svd::SVD,
+};
+
+fn random_cplx_rank_k(m: usize, n: usize, k: usize) -> DTensor<Complex<f64>, 2> {
+    let mut rng = rand::rng();
+    let mut random = |r, c| {
+        DTensor::<Complex<f64>, 2>::from_fn([r, c], |_| {
+            Complex::new(
+                rng.random::<f64>() * 2.0 - 1.0,
+                rng.random::<f64>() * 2.0 - 1.0,
+            )
+        })
+    };
+    let b = random(m, k);
+    let c = random(k, n);
+    naive_matmul(&b, &c)
+}
+
+/// Check that A Z = 0 for an n × (n − r) orthonormal Z
+fn check_null_space<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, z: &DTensor<T, 2>, r: usize) {
+    let n = a.shape().1;
+    assert_eq!(*z.shape(), (n, n - r));
+    check_orthonormal(z);
+    for x in naive_matmul(a, z).iter() {
+        assert_relative_eq!(x.abs(), 0.0, epsilon = 1e-10);
+    }
+}
+
+/// Check that the m × r orthonormal Q spans the range of A: Q Qᴴ A = A
+fn check_range<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, q: &DTensor<T, 2>, r: usize) {
+    let m = a.shape().0;
+    assert_eq!(*q.shape(), (m, r));
+    check_orthonormal(q);
+    let projected = naive_matmul(q, &naive_matmul(&adjoint(q), a));
+    for (x, y) in projected.iter().zip(a.iter()) {
+        assert_relative_eq!((*x - *y).abs(), 0.0, epsilon = 1e-10);
+    }
+}
+
+pub fn test_rank(bd: &impl SVD<f64, usize, Dense>) {
+    let tol = PinvTolerance::default();
+    for (m, n, k) in [(6, 4, 2), (4, 6, 3), (5, 5, 5)] {
+        let a = rank_k_matrix(m, n, k);
+        assert_eq!(rank(bd, &mut a.clone(), tol).unwrap(), k);
+    }
+
+    let a = tensor![[1., 0.], [0., 1e-8]];
+    assert_eq!(rank(bd, &mut a.clone(), tol).unwrap(), 2);
+    assert_eq!(rank(bd, &mut a.clone(), tol.rtol(1e-6)).unwrap(), 1);
+    assert_eq!(rank(bd, &mut a.clone(), tol.atol(2.0)).unwrap(), 0);
+}
+
+pub fn test_orth_null_space(bd: &impl SVD<f64, usize, Dense>) {
+    let tol = PinvTolerance::default();
+    for (m, n, k) in [(6, 4, 2), (3, 5, 3), (4, 4, 1)] {
+        let a = rank_k_matrix(m, n, k);
+
+        let q = orth(bd, &mut a.clone(), tol).unwrap();
+        check_range(&a, &q, k);
+
+        let z = null_space(bd, &mut a.clone(), tol).unwrap();
+        check_null_space(&a, &z, k);
+    }
+}
+
+pub fn test_orth_null_space_complex(bd: &impl SVD<Complex<f64>, usize, Dense>) {
+    let tol = PinvTolerance::default();
+    let a = random_cplx_rank_k(5, 4, 2);
+
+    assert_eq!(rank(bd, &mut a.clone(), tol).unwrap(), 2);
+    check_range(&a, &orth(bd, &mut a.clone(), tol).unwrap(), 2);
+    check_null_space(&a, &null_space(bd, &mut a.clone(), tol).unwrap(), 2);
+}
+
+pub fn test_subspace_full_rank(bd: &impl SVD<f64, usize, Dense>) {
+    let tol = PinvTolerance::default();
+    let a = rank_k_matrix(5, 3, 3);
+
+    // Empty null space with consistent shape
+    let z = null_space(bd, &mut a.clone(), tol).unwrap();
+    assert_eq!(*z.shape(), (3, 0));
+
+    // Zero matrix: empty range, the whole space as null space
+    let zero = DTensor::<f64, 2>::zeros([3, 4]);
+    assert_eq!(rank(bd, &mut zero.clone(), tol).unwrap(), 0);
+    assert_eq!(*orth(bd, &mut zero.clone(), tol).unwrap().shape(), (3, 0));
+    check_null_space(&zero, &null_space(bd, &mut zero.clone(), tol).unwrap(), 0);
+}
+
+pub fn test_subspace_qr(bd: &impl QRPivot<f64, usize, usize>) {
+    let tol = PinvTolerance::default().rtol(1e-10);
+    for (m, n, k) in [(6, 4, 2), (3, 5, 3), (5, 5, 4)] {
+        let a = rank_k_matrix(m, n, k);
+
+        assert_eq!(rank_qr(bd, &mut a.clone(), tol), k);
+        check_range(&a, &orth_qr(bd, &mut a.clone(), tol), k);
+        check_null_space(&a, &null_space_qr(bd, &a, tol), k);
+    }
+}
+
+pub fn test_subspace_qr_complex(bd: &impl QRPivot<Complex<f64>, usize, usize>) {
+    let tol = PinvTolerance::default().rtol(1e-10);
+    let a = random_cplx_rank_k(4, 6, 2);
+
+    assert_eq!(rank_qr(bd, &mut a.clone(), tol), 2);
+    check_range(&a, &orth_qr(bd, &mut a.clone(), tol), 2);
+    check_null_space(&a, &null_space_qr(bd, &a, tol), 2);
+}
@@ -3,10 +3,16 @@ use mdarray::{DTensor, Dense, Dyn};
 use num_complex::{Complex, ComplexFloat};
 use rand::Rng;
 
-use super::common::naive_matmul;
+use super::common::{naive_matmul, rank_k_matrix};
 use crate::{
-    assert_complex_matrix_eq, assert_matrix_eq, pretty_print,
-    svd::{SVD, SVDDecomp},
+    assert_complex_matrix_eq, assert_matrix_eq,
+    matmul::MatMul,
+    pretty_print,
+    qr::QR,
+    svd::{
+        RandomizedSVD, RandomizedSVDConfig, SVD, SVDDecomp, SVDTruncation, TruncatedSVD,
+        TruncatedSVDDecomp, svd_randomized_with,
+    },
 };
 
 fn test_svd_reconstruction<T>(bd: &impl SVD<T, Dyn, Dense>, a: &DTensor<T, 2>, debug_print: bool)
@@ -153,3 +159,225 @@ pub fn test_svd_cplx_random_matrix(bd: &impl SVD<Complex<f64>, Dyn, Dense>) {
 
     assert_complex_matrix_eq!(a, usvt);
 }
+
+/// Compute the thin SVD of `a`, check the shapes of the factors and
+/// return the reconstruction U × Σ × Vᵀ.
+fn svd_thin_reconstruct<T>(bd: &impl SVD<T, Dyn, Dense>, a: &DTensor<T, 2>) -> DTensor<T, 2>
+where
+    T: ComplexFloat + Default + Copy,
+{
+    let (m, n) = (a.shape().0, a.shape().1);
+    let k = m.min(n);
+
+    let SVDDecomp { s, u, vt } = bd.svd_thin(&mut a.clone()).expect("SVD failed");
+
+    assert_eq!(*s.shape(), (k, k));
+    assert_eq!(*u.shape(), (m, k));
+    assert_eq!(*vt.shape(), (k, n));
+
+    let mut sigma = DTensor::<T, 2>::zeros([k, k]);
+    for i in 0..k {
+        sigma[[i, i]] = s[[0, i]];
+    }
+
+    naive_matmul(&naive_matmul(&u, &sigma), &vt)
+}
+
+pub fn test_svd_thin_tall_matrix(bd: &impl SVD<f64, Dyn, Dense>) {
+    let mut rng = rand::rng();
+    let (m, n) = (7, 3);
+    let a = DTensor::<f64, 2>::from_fn([m, n], |_| rng.random::<f64>());
+    let usvt = svd_thin_reconstruct(bd, &a);
+    assert_matrix_eq!(a, usvt);
+}
+
+pub fn test_svd_thin_wide_matrix(bd: &impl SVD<f64, Dyn, Dense>) {
+    let mut rng = rand::rng();
+    let (m, n) = (3, 6);
+    let a = DTensor::<f64, 2>::from_fn([m, n], |_| rng.random::<f64>());
+    let usvt = svd_thin_reconstruct(bd, &a);
+    assert_matrix_eq!(a, usvt);
+}
+
+pub fn test_svd_thin_cplx_matrix(bd: &impl SVD<Complex<f64>, Dyn, Dense>) {
+    let mut rng = rand::rng();
+    let (m, n) = (6, 4);
+    let a = DTensor::<Complex<f64>, 2>::from_fn([m, n], |_| {
+        Complex::new(
+            rng.random::<f64>() * 2.0 - 1.0,
+            rng.random::<f64>() * 2.0 - 1.0,
+        )
+    });
+    let usvt = svd_thin_reconstruct(bd, &a);
+    assert_complex_matrix_eq!(a, usvt);
+}
+
+pub fn test_svd_thin_write(bd: &impl SVD<f64, Dyn, Dense>) {
+    let (m, n) = (5, 3);
+    let a = DTensor::<f64, 2>::from_fn([m, n], |i| (i[0] * i[1] + i[0]) as f64);
+
+    let mut s = DTensor::<f64, 2>::zeros([n, n]);
+    let mut u = DTensor::<f64, 2>::zeros([m, n]);
+    let mut vt = DTensor::<f64, 2>::zeros([n, n]);
+    bd.svd_thin_write(&mut a.clone(), &mut s, &mut u, &mut vt)
+        .expect("SVD failed");
+
+    // U has orthonormal columns
+    let utu = naive_matmul(&DTensor::<f64, 2>::from_fn([n, m], |i| u[[i[1], i[0]]]), &u);
+    for i in 0..n {
+        for j in 0..n {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(utu[[i, j]], expected, epsilon = 1e-10);
+        }
+    }
+
+    let mut sigma = DTensor::<f64, 2>::zeros([n, n]);
+    for i in 0..n {
+        sigma[[i, i]] = s[[0, i]];
+    }
+    let usvt = naive_matmul(&naive_matmul(&u, &sigma), &vt);
+    assert_matrix_eq!(a, usvt);
+}
+
+pub fn test_svd_thin_write_invalid_vt(bd: &impl SVD<f64, Dyn, Dense>) {
+    // Vᵀ must be min(m, n) × n, here 3 × 3
+    let (m, n) = (5, 3);
+    let a = DTensor::<f64, 2>::from_fn([m, n], |i| (i[0] * i[1] + i[0]) as f64);
+    let mut s = DTensor::<f64, 2>::zeros([n, n]);
+    let mut u = DTensor::<f64, 2>::zeros([m, n]);
+    let mut vt = DTensor::<f64, 2>::zeros([m, n]);
+    let _ = bd.svd_thin_write(&mut a.clone(), &mut s, &mut u, &mut vt);
+}
+
+fn truncated_reconstruct(decomp: &TruncatedSVDDecomp<f64, Dyn>) -> DTensor<f64, 2> {
+    let k = decomp.s.shape().0;
+    let mut sigma = DTensor::<f64, 2>::zeros([k, k]);
+    for i in 0..k {
+        sigma[[i, i]] = decomp.s[[0, i]];
+    }
+    naive_matmul(&naive_matmul(&decomp.u, &sigma), &decomp.vt)
+}
+
+pub fn test_svd_truncated_max_rank(bd: &impl TruncatedSVD<f64, Dyn, Dense>) {
+    let (m, n, k) = (8, 6, 3);
+    let a = DTensor::<f64, 2>::from_fn([m, n], |i| 1.0 / (i[0] + i[1] + 1) as f64);
+
+    let full = bd
+        .svd_truncated(&mut a.clone(), SVDTruncation::default())
+        .expect("SVD failed");
+    assert_eq!(full.s.shape().0, n);
+    assert_relative_eq!(full.discarded_weight, 0.0);
+
+    let trunc = bd
+        .svd_truncated(&mut a.clone(), SVDTruncation::default().max_rank(k))
+        .expect("SVD failed");
+
+    assert_eq!(*trunc.s.shape(), (k, k));
+    assert_eq!(*trunc.u.shape(), (m, k));
+    assert_eq!(*trunc.vt.shape(), (k, n));
+
+    let expected: f64 = (k..n).map(|i| full.s[[0, i]] * full.s[[0, i]]).sum();
+    assert_relative_eq!(trunc.discarded_weight, expected, epsilon = 1e-12);
+
+    // The Frobenius error of the best rank-k approximation is the discarded weight
+    let ak = truncated_reconstruct(&trunc);
+    let err: f64 = (0..m)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| (a[[i, j]] - ak[[i, j]]).powi(2))
+        .sum();
+    assert_relative_eq!(err, trunc.discarded_weight, epsilon = 1e-10);
+}
+
+pub fn test_svd_truncated_rtol(bd: &impl TruncatedSVD<f64, Dyn, Dense>) {
+    let (m, n, k) = (7, 5, 2);
+    let a = rank_k_matrix(m, n, k);
+
+    let trunc = bd
+        .svd_truncated(&mut a.clone(), SVDTruncation::default().rtol(1e-10))
+        .expect("SVD failed");
+
+    assert_eq!(*trunc.s.shape(), (k, k));
+    assert!(trunc.discarded_weight < 1e-16);
+    assert_matrix_eq!(a, truncated_reconstruct(&trunc));
+}
+
+/// Randomized SVD of a matrix keeping at most `k` singular values
+type Randomized<'a> =
+    &'a dyn Fn(&DTensor<f64, 2>, usize, RandomizedSVDConfig) -> TruncatedSVDDecomp<f64, Dyn>;
+
+/// Check that the randomized SVD of a rank-k matrix reconstructs it
+fn randomized_low_rank(
+    svd_randomized: Randomized,
+    a: &DTensor<f64, 2>,
+    k: usize,
+) -> TruncatedSVDDecomp<f64, Dyn> {
+    let (m, n) = *a.shape();
+    let rand = svd_randomized(a, k, RandomizedSVDConfig::default());
+
+    assert_eq!(*rand.s.shape(), (k, k));
+    assert_eq!(*rand.u.shape(), (m, k));
+    assert_eq!(*rand.vt.shape(), (k, n));
+    assert_matrix_eq!(a, truncated_reconstruct(&rand), 1e-8);
+    assert!(rand.discarded_weight < 1e-8);
+    rand
+}
+
+/// Check that the discarded weight is the squared error of the approximation
+fn randomized_discarded_weight(svd_randomized: Randomized) {
+    let (m, n, k) = (30, 20, 5);
+    let a = DTensor::<f64, 2>::from_fn([m, n], |i| 1.0 / (i[0] + i[1] + 1) as f64);
+
+    let config = RandomizedSVDConfig {
+        oversampling: 10,
+        power_iterations: 4,
+    };
+    let rand = svd_randomized(&a, k, config);
+
+    let ak = truncated_reconstruct(&rand);
+    let err: f64 = (0..m)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| (a[[i, j]] - ak[[i, j]]).powi(2))
+        .sum();
+    assert_relative_eq!(err, rand.discarded_weight, epsilon = 1e-10);
+}
+
+pub fn test_svd_randomized_low_rank<B>(bd: &B)
+where
+    B: RandomizedSVD<f64, Dyn, Dense> + TruncatedSVD<f64, Dyn, Dense>,
+{
+    let (m, n, k) = (40, 30, 4);
+    let a = rank_k_matrix(m, n, k);
+    let svd_randomized = |a: &DTensor<f64, 2>, k, config| {
+        bd.svd_randomized(a, SVDTruncation::default().max_rank(k), config)
+            .expect("SVD failed")
+    };
+    let rand = randomized_low_rank(&svd_randomized, &a, k);
+
+    let exact = bd
+        .svd_truncated(&mut a.clone(), SVDTruncation::default().max_rank(k))
+        .expect("SVD failed");
+    for i in 0..k {
+        assert_relative_eq!(rand.s[[0, i]], exact.s[[0, i]], epsilon = 1e-8);
+    }
+}
+
+pub fn test_svd_randomized_discarded_weight(bd: &impl RandomizedSVD<f64, Dyn, Dense>) {
+    randomized_discarded_weight(&|a, k, config| {
+        bd.svd_randomized(a, SVDTruncation::default().max_rank(k), config)
+            .expect("SVD failed")
+    });
+}
+
+pub fn test_svd_randomized_with(
+    mm: &impl MatMul<f64>,
+    qr: &impl QR<f64, Dyn, Dyn>,
+    svd: &impl SVD<f64, Dyn, Dense>,
+) {
+    let svd_randomized = |a: &DTensor<f64, 2>, k, config| {
+        svd_randomized_with(mm, qr, svd, a, SVDTruncation::default().max_rank(k), config)
+            .expect("SVD failed")
+    };
+    let (m, n, k) = (30, 40, 3);
+    randomized_low_rank(&svd_randomized, &rank_k_matrix(m, n, k), k);
+    randomized_discarded_weight(&svd_randomized);
+}
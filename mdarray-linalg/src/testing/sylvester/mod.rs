@@ -0,0 +1,259 @@
+use mdarray::{DTensor, tensor};
+use num_complex::{Complex, ComplexFloat};
+
+use super::common::{
+    adjoint, assert_close, hermitian, naive_matmul, random_cplx_matrix, random_matrix,
+};
+use crate::{
+    eig::Eig,
+    matmul::MatMul,
+    sylvester::{
+        Sylvester, SylvesterError, SylvesterTriangular, lyapunov_with, stein_with, sylvester_with,
+    },
+};
+
+fn add<T: ComplexFloat>(a: &DTensor<T, 2>, b: &DTensor<T, 2>) -> DTensor<T, 2> {
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| a[[i[0], i[1]]] + b[[i[0], i[1]]])
+}
+
+/// A + s I
+fn shift<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, s: f64) -> DTensor<T, 2> {
+    let s = T::from(s).unwrap();
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| {
+        a[[i[0], i[1]]] + if i[0] == i[1] { s } else { T::zero() }
+    })
+}
+
+fn scale<T: ComplexFloat<Real = f64>>(a: &DTensor<T, 2>, s: f64) -> DTensor<T, 2> {
+    let s = T::from(s).unwrap();
+    DTensor::<T, 2>::from_fn(*a.shape(), |i| a[[i[0], i[1]]] * s)
+}
+
+/// Random real upper quasi-triangular matrix in Schur canonical form,
+/// with a 2 × 2 block [[d, b], [c, d]] with b c < 0 at every even `i`
+/// listed in `blocks`, and diagonal entries around `s`
+fn quasi_triangular(n: usize, blocks: &[usize], s: f64) -> DTensor<f64, 2> {
+    let mut t = DTensor::<f64, 2>::from_fn([n, n], |i| {
+        if i[0] <= i[1] {
+            0.5 * random_matrix(1, 1)[[0, 0]]
+        } else {
+            0.
+        }
+    });
+    for i in 0..n {
+        t[[i, i]] += s;
+    }
+    for &i in blocks {
+        t[[i + 1, i + 1]] = t[[i, i]];
+        t[[i + 1, i]] = -t[[i, i + 1]].abs() - 0.5;
+        t[[i, i + 1]] = t[[i, i + 1]].abs() + 0.5;
+    }
+    t
+}
+
+fn upper_triangular_cplx(n: usize, s: f64) -> DTensor<Complex<f64>, 2> {
+    let a = random_cplx_matrix(n, n);
+    DTensor::<Complex<f64>, 2>::from_fn([n, n], |i| match i[0].cmp(&i[1]) {
+        std::cmp::Ordering::Greater => Complex::new(0., 0.),
+        std::cmp::Ordering::Equal => a[[i[0], i[1]]] + s,
+        std::cmp::Ordering::Less => a[[i[0], i[1]]],
+    })
+}
+
+pub fn test_sylvester_triangular(bd: &impl SylvesterTriangular<f64>) {
+    let a = quasi_triangular(5, &[0, 3], 2.);
+    let b = quasi_triangular(4, &[1], 2.);
+    for adjoint_b in [false, true] {
+        let op_b = if adjoint_b { adjoint(&b) } else { b.clone() };
+        let c = random_matrix(5, 4);
+        let mut x = c.clone();
+        bd.sylvester_triangular(&a, &b, adjoint_b, &mut x).unwrap();
+        assert_close(
+            &add(&naive_matmul(&a, &x), &naive_matmul(&x, &op_b)),
+            &c,
+            1e-9,
+        );
+    }
+}
+
+pub fn test_sylvester_triangular_complex(bd: &impl SylvesterTriangular<Complex<f64>>) {
+    let a = upper_triangular_cplx(4, 2.);
+    let b = upper_triangular_cplx(3, 2.);
+    for adjoint_b in [false, true] {
+        let op_b = if adjoint_b { adjoint(&b) } else { b.clone() };
+        let c = random_cplx_matrix(4, 3);
+        let mut x = c.clone();
+        bd.sylvester_triangular(&a, &b, adjoint_b, &mut x).unwrap();
+        assert_close(
+            &add(&naive_matmul(&a, &x), &naive_matmul(&x, &op_b)),
+            &c,
+            1e-9,
+        );
+    }
+}
+
+pub fn test_sylvester_triangular_singular(bd: &impl SylvesterTriangular<f64>) {
+    // A and −B share the eigenvalue 1
+    let a = tensor![[1., 1.], [0., 2.]];
+    let b = tensor![[-1., 0.], [0., 3.]];
+    let mut c = random_matrix(2, 2);
+    assert!(matches!(
+        bd.sylvester_triangular(&a, &b, false, &mut c),
+        Err(SylvesterError::Singular)
+    ));
+}
+
+pub fn test_sylvester(
+    schur: &impl Eig<f64, usize, usize>,
+    tri: &impl SylvesterTriangular<f64>,
+    mm: &impl MatMul<f64>,
+) {
+    // The eigenvalues of A and B lie in the disk of radius 5 around 6
+    let a = shift(&random_matrix(5, 5), 6.);
+    let b = shift(&random_matrix(3, 3), 6.);
+    let c = random_matrix(5, 3);
+    let x = sylvester_with(schur, tri, mm, &a, &b, &c).unwrap();
+    assert_close(&add(&naive_matmul(&a, &x), &naive_matmul(&x, &b)), &c, 1e-9);
+}
+
+pub fn test_sylvester_complex(
+    schur: &impl Eig<Complex<f64>, usize, usize>,
+    tri: &impl SylvesterTriangular<Complex<f64>>,
+    mm: &impl MatMul<Complex<f64>>,
+) {
+    let a = shift(&random_cplx_matrix(4, 4), 9.);
+    let b = shift(&random_cplx_matrix(5, 5), 11.);
+    let c = random_cplx_matrix(4, 5);
+    let x = sylvester_with(schur, tri, mm, &a, &b, &c).unwrap();
+    assert_close(&add(&naive_matmul(&a, &x), &naive_matmul(&x, &b)), &c, 1e-9);
+}
+
+/// Check that X solves A X + X Aᴴ + Q = 0
+fn check_lyapunov<T: ComplexFloat<Real = f64>>(
+    a: &DTensor<T, 2>,
+    q: &DTensor<T, 2>,
+    x: &DTensor<T, 2>,
+) {
+    let residual = add(&naive_matmul(a, x), &naive_matmul(x, &adjoint(a)));
+    assert_close(&residual, &scale(q, -1.), 1e-9);
+    assert_close(x, &adjoint(x), 1e-9);
+}
+
+pub fn test_lyapunov(
+    schur: &impl Eig<f64, usize, usize>,
+    tri: &impl SylvesterTriangular<f64>,
+    mm: &impl MatMul<f64>,
+) {
+    // Stable A: the eigenvalues have real parts at most −1
+    let n = 6;
+    let a = shift(&random_matrix(n, n), -(n as f64) - 1.);
+    let q = hermitian(&random_matrix(n, n));
+    check_lyapunov(&a, &q, &lyapunov_with(schur, tri, mm, &a, &q).unwrap());
+}
+
+pub fn test_lyapunov_complex(
+    schur: &impl Eig<Complex<f64>, usize, usize>,
+    tri: &impl SylvesterTriangular<Complex<f64>>,
+    mm: &impl MatMul<Complex<f64>>,
+) {
+    let n = 4;
+    let a = shift(&random_cplx_matrix(n, n), -2. * n as f64 - 1.);
+    let q = hermitian(&random_cplx_matrix(n, n));
+    check_lyapunov(&a, &q, &lyapunov_with(schur, tri, mm, &a, &q).unwrap());
+}
+
+/// Check that X solves A X Aᴴ − X + Q = 0
+fn check_stein<T: ComplexFloat<Real = f64>>(
+    a: &DTensor<T, 2>,
+    q: &DTensor<T, 2>,
+    x: &DTensor<T, 2>,
+) {
+    let axa = naive_matmul(&naive_matmul(a, x), &adjoint(a));
+    assert_close(&add(&axa, &scale(q, -1.)), x, 1e-9);
+    assert_close(x, &adjoint(x), 1e-9);
+}
+
+pub fn test_stein(schur: &impl Eig<f64, usize, usize>, mm: &impl MatMul<f64>) {
+    // Spectral radius of A at most 1/2
+    let n = 6;
+    let a = scale(&random_matrix(n, n), 0.5 / n as f64);
+    let q = hermitian(&random_matrix(n, n));
+    check_stein(&a, &q, &stein_with(schur, mm, &a, &q).unwrap());
+
+    // Complex conjugate eigenvalue pairs with modulus above one
+    let a = tensor![[0., 2., 0.], [-2., 0., 0.], [0., 0., 0.5]];
+    let q = hermitian(&random_matrix(3, 3));
+    check_stein(&a, &q, &stein_with(schur, mm, &a, &q).unwrap());
+}
+
+pub fn test_stein_complex(
+    schur: &impl Eig<Complex<f64>, usize, usize>,
+    mm: &impl MatMul<Complex<f64>>,
+) {
+    let n = 5;
+    let a = scale(&random_cplx_matrix(n, n), 0.4 / n as f64);
+    let q = hermitian(&random_cplx_matrix(n, n));
+    check_stein(&a, &q, &stein_with(schur, mm, &a, &q).unwrap());
+}
+
+/// Same equations as above through the [`Sylvester`] trait of a single
+/// backend
+pub fn test_sylvester_equations(bd: &impl Sylvester<f64, usize>) {
+    let a = shift(&random_matrix(5, 5), 6.);
+    let b = shift(&random_matrix(3, 3), 6.);
+    let c = random_matrix(5, 3);
+    let x = bd.sylvester(&a, &b, &c).unwrap();
+    assert_close(&add(&naive_matmul(&a, &x), &naive_matmul(&x, &b)), &c, 1e-9);
+
+    let n = 6;
+    let q = hermitian(&random_matrix(n, n));
+    let a = shift(&random_matrix(n, n), -(n as f64) - 1.);
+    check_lyapunov(&a, &q, &bd.lyapunov(&a, &q).unwrap());
+
+    let a = scale(&random_matrix(n, n), 0.5 / n as f64);
+    check_stein(&a, &q, &bd.stein(&a, &q).unwrap());
+}
+
+pub fn test_sylvester_equations_complex(bd: &impl Sylvester<Complex<f64>, usize>) {
+    let a = shift(&random_cplx_matrix(4, 4), 9.);
+    let b = shift(&random_cplx_matrix(5, 5), 11.);
+    let c = random_cplx_matrix(4, 5);
+    let x = bd.sylvester(&a, &b, &c).unwrap();
+    assert_close(&add(&naive_matmul(&a, &x), &naive_matmul(&x, &b)), &c, 1e-9);
+
+    let n = 4;
+    let q = hermitian(&random_cplx_matrix(n, n));
+    let a = shift(&random_cplx_matrix(n, n), -2. * n as f64 - 1.);
+    check_lyapunov(&a, &q, &bd.lyapunov(&a, &q).unwrap());
+
+    let a = scale(&random_cplx_matrix(n, n), 0.4 / n as f64);
+    check_stein(&a, &q, &bd.stein(&a, &q).unwrap());
+}
+
+pub fn test_sylvester_invalid_dimensions(
+    schur: &impl Eig<f64, usize, usize>,
+    tri: &impl SylvesterTriangular<f64>,
+    mm: &impl MatMul<f64>,
+) {
+    let a = random_matrix(3, 3);
+    let b = random_matrix(2, 2);
+    assert!(matches!(
+        sylvester_with(
+            schur,
+            tri,
+            mm,
+            &random_matrix(3, 2),
+            &b,
+            &random_matrix(3, 2)
+        ),
+        Err(SylvesterError::NotSquareMatrix)
+    ));
+    assert!(matches!(
+        sylvester_with(schur, tri, mm, &a, &b, &random_matrix(2, 3)),
+        Err(SylvesterError::InvalidDimensions)
+    ));
+    assert!(matches!(
+        lyapunov_with(schur, tri, mm, &a, &random_matrix(3, 2)),
+        Err(SylvesterError::InvalidDimensions)
+    ));
+}
@@ -1,4 +1,4 @@
-use mdarray::tensor;
+use mdarray::{Tensor, tensor};
 
 use crate::matmul::{ContractBuilder, MatMul};
 
@@ -92,40 +92,207 @@ pub fn tensordot_outer_should_match_manual_kronecker_impl(backend: &impl MatMul<
     assert_eq!(result, expected);
 }
 
-// --- Test write functionality ---
+// --- Writing into existing tensors ---
 
-// fn tensordot_write_impl(backend: &impl MatMul<f64>) {
-//     let a = tensor![[1., 2.], [3., 4.]].into_dyn();
-//     let b = tensor![[5., 6.], [7., 8.]].into_dyn();
-//     let expected = tensor![[19., 22.], [43., 50.]].into_dyn();
+pub fn tensordot_write_impl(backend: &impl MatMul<f64>) {
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let expected = tensor![[19., 22.], [43., 50.]].into_dyn();
 
-//     let mut c = tensor![[0., 0.], [0., 0.]].into_dyn();
-//     backend
-//         .contract(&a, &b, vec![1], vec![0])
-//         .write(&mut c);
+    let mut c = tensor![[f64::NAN, 0.], [0., 0.]].into_dyn();
+    backend.contract(&a, &b, vec![1], vec![0]).write(&mut c);
+    assert_eq!(c, expected);
+}
 
-//     assert_eq!(c, expected);
-// }
+pub fn tensordot_write_all_axes_impl(backend: &impl MatMul<f64>) {
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let expected = tensor![[70.0]].into_dyn();
 
-// #[test]
-// fn tensordot_write() {
-//     tensordot_write_impl(&Naive);
-//     tensordot_write_impl(&Blas);
-// }
+    let mut c = tensor![[0.0]].into_dyn();
+    backend.contract_all(&a, &b).write(&mut c);
+    assert_eq!(c, expected);
+}
 
-// fn tensordot_write_all_axes_impl(backend: &impl MatMul<f64>) {
-//     let a = tensor![[1., 2.], [3., 4.]].into_dyn();
-//     let b = tensor![[5., 6.], [7., 8.]].into_dyn();
-//     let expected = tensor![[70.0]].into_dyn();
+pub fn tensordot_add_to_impl(backend: &impl MatMul<f64>) {
+    // C += 2 A B
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let expected = tensor![[39., 45.], [87., 101.]].into_dyn();
+
+    let mut c = tensor![[1., 1.], [1., 1.]].into_dyn();
+    backend.contract_n(&a, &b, 1).scale(2.).add_to(&mut c);
+    assert_eq!(c, expected);
+}
+
+pub fn tensordot_add_to_scaled_impl(backend: &impl MatMul<f64>) {
+    // C := 3 C + A B
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let expected = tensor![[22., 25.], [46., 53.]].into_dyn();
 
-//     let mut c = tensor![[0.0]].into_dyn();
-//     backend.contract_all(&a, &b).write(&mut c);
+    let mut c = tensor![[1., 1.], [1., 1.]].into_dyn();
+    backend
+        .contract(&a, &b, vec![1], vec![0])
+        .add_to_scaled(&mut c, 3.);
+    assert_eq!(c, expected);
 
-//     assert_eq!(c, expected);
-// }
+    // C is not read when β = 0, NaN and Inf included
+    let mut c = tensor![[f64::NAN, f64::INFINITY], [1., 1.]].into_dyn();
+    backend
+        .contract(&a, &b, vec![1], vec![0])
+        .add_to_scaled(&mut c, 0.);
+    assert_eq!(c, tensor![[19., 22.], [43., 50.]].into_dyn());
 
-// #[test]
-// fn tensordot_write_all_axes() {
-//     tensordot_write_all_axes_impl(&Naive);
-//     tensordot_write_all_axes_impl(&Blas);
-// }
+    // Same through the temporary, C (i, k, j) not being a matrix with rows
+    // (i, j)
+    let a = tensor![[[1., 2.], [3., 4.]], [[5., 6.], [7., 8.]]].into_dyn();
+    let product = || {
+        backend
+            .contract(&a, &b, vec![2], vec![0])
+            .output_order(vec![0, 2, 1])
+    };
+    let expected = product().eval();
+    let mut c = tensor![[[f64::NAN; 2]; 2]; 2].into_dyn();
+    product().add_to_scaled(&mut c, 0.);
+    assert_eq!(c, expected);
+}
+
+pub fn tensordot_write_strided_impl(backend: &impl MatMul<f64>) {
+    // Writing into a transposed view gives (A B)ᵀ
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let expected = tensor![[19., 43.], [22., 50.]].into_dyn();
+
+    let mut c = tensor![[0., 0.], [0., 0.]].into_dyn();
+    backend
+        .contract(&a, &b, vec![1], vec![0])
+        .write(&mut c.permute_mut(vec![1, 0]));
+    assert_eq!(c, expected);
+}
+
+// --- Output axis order ---
+
+pub fn tensordot_output_order_impl(backend: &impl MatMul<f64>) {
+    // Default result axes are (a0, a2, b1), reordered to (b1, a0, a2)
+    let a = Tensor::from_fn(&[2, 3, 4][..], |i| (i[0] * 12 + i[1] * 4 + i[2]) as f64);
+    let b = Tensor::from_fn(&[3, 5][..], |i| (i[0] * 5 + i[1]) as f64 - 4.);
+
+    let default = backend.contract(&a, &b, vec![1], vec![0]).eval();
+    let expected = default.permute(vec![2, 0, 1]).to_tensor();
+    assert_eq!(expected.dims(), &[5, 2, 4]);
+
+    let result = backend
+        .contract(&a, &b, vec![1], vec![0])
+        .output_order(vec![2, 0, 1])
+        .eval();
+    assert_eq!(result, expected);
+
+    let mut c = Tensor::from_elem(&[5, 2, 4][..], 1.);
+    backend
+        .contract(&a, &b, vec![1], vec![0])
+        .output_order(vec![2, 0, 1])
+        .scale(-1.)
+        .add_to(&mut c);
+    assert_eq!(c, expected.map(|x| 1. - x));
+
+    // The axes of the product rows are swapped in C, which cannot be
+    // viewed as a matrix
+    let expected = default.permute(vec![1, 0, 2]).to_tensor();
+    let mut c = Tensor::from_elem(&[4, 2, 5][..], 1.);
+    backend
+        .contract(&a, &b, vec![1], vec![0])
+        .output_order(vec![1, 0, 2])
+        .add_to_scaled(&mut c, 2.);
+    assert_eq!(c, expected.map(|x| 2. + x));
+}
+
+pub fn tensordot_output_order_invalid_should_panic_impl(
+    backend: &(impl MatMul<f64> + std::panic::RefUnwindSafe),
+) {
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let result = std::panic::catch_unwind(|| {
+        backend
+            .contract_n(&a, &b, 1)
+            .output_order(vec![0, 0])
+            .eval()
+    });
+    assert!(result.is_err());
+}
+
+pub fn tensordot_write_shape_mismatch_should_panic_impl(
+    backend: &(impl MatMul<f64> + std::panic::RefUnwindSafe),
+) {
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let result = std::panic::catch_unwind(|| {
+        let mut c = tensor![[0., 0., 0.], [0., 0., 0.]].into_dyn();
+        backend.contract_n(&a, &b, 1).write(&mut c);
+    });
+    assert!(result.is_err());
+}
+
+// --- Strided operands ---
+
+pub fn tensordot_transposed_operands_impl(backend: &impl MatMul<f64>) {
+    // Transposed views are multiplied without copy
+    let a = tensor![[1., 2.], [3., 4.]].into_dyn();
+    let b = tensor![[5., 6.], [7., 8.]].into_dyn();
+    let a_t = a.permute(vec![1, 0]);
+    let b_t = b.permute(vec![1, 0]);
+
+    let result = backend.contract(&a_t, &b, vec![1], vec![0]).eval();
+    assert_eq!(result, tensor![[26., 30.], [38., 44.]].into_dyn());
+
+    let result = backend.contract(&a, &b_t, vec![1], vec![0]).eval();
+    assert_eq!(result, tensor![[17., 23.], [39., 53.]].into_dyn());
+
+    let result = backend.contract(&a_t, &b_t, vec![1], vec![0]).eval();
+    assert_eq!(result, tensor![[23., 31.], [34., 46.]].into_dyn());
+
+    // Contracting the leading axis of both is Aᵀ B
+    let result = backend.contract(&a, &b, vec![0], vec![0]).eval();
+    assert_eq!(result, tensor![[26., 30.], [38., 44.]].into_dyn());
+}
+
+/// Permutations viewing A and B, then their contracted axes
+type StridedCase = (
+    &'static [usize],
+    &'static [usize],
+    &'static [usize],
+    &'static [usize],
+);
+
+pub fn tensordot_strided_operands_impl(backend: &impl MatMul<f64>) {
+    // Strided views must give the same result as their dense copies
+    let a = Tensor::from_fn(&[2, 3, 4][..], |i| (i[0] * 12 + i[1] * 4 + i[2]) as f64);
+    let b = Tensor::from_fn(&[4, 3, 5][..], |i| {
+        (i[0] * 15 + i[1] * 5 + i[2]) as f64 - 20.
+    });
+
+    let cases: [StridedCase; 6] = [
+        // Contracted axes leading in a and trailing in b
+        (&[2, 0, 1], &[2, 1, 0], &[0], &[2]),
+        // Contracted axes in the reverse of their memory order
+        (&[0, 1, 2], &[0, 1, 2], &[2, 1], &[0, 1]),
+        (&[2, 0, 1], &[0, 1, 2], &[2, 0], &[1, 0]),
+        // Kept axes that are not nested in memory need a copy
+        (&[1, 0, 2], &[0, 1, 2], &[0], &[1]),
+        (&[0, 1, 2], &[0, 2, 1], &[1, 2], &[2, 0]),
+        (&[2, 1, 0], &[1, 2, 0], &[0, 1], &[2, 0]),
+    ];
+    for (perm_a, perm_b, axes_a, axes_b) in cases {
+        let a_view = a.permute(perm_a.to_vec());
+        let b_view = b.permute(perm_b.to_vec());
+        let (a_copy, b_copy) = (a_view.to_tensor(), b_view.to_tensor());
+
+        let expected = backend
+            .contract(&a_copy, &b_copy, axes_a.to_vec(), axes_b.to_vec())
+            .eval();
+        let result = backend
+            .contract(&a_view, &b_view, axes_a.to_vec(), axes_b.to_vec())
+            .eval();
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,233 @@
+use approx::assert_relative_eq;
+use mdarray::{DTensor, tensor};
+use num_complex::{Complex, ComplexFloat};
+use rand::Rng;
+
+use super::common::{naive_matmul, random_matrix};
+use crate::{
+    assert_matrix_eq,
+    matmul::{Side, Triangle},
+    trisolve::{Transpose, TriSolve, TriSolveBuilder, TriSolveError},
+};
+
+/// Random n × n matrix with a dominant diagonal, so that both of its
+/// triangles are well conditioned
+fn triangular_test_matrix(n: usize) -> DTensor<f64, 2> {
+    let mut a = random_matrix(n, n);
+    for i in 0..n {
+        a[[i, i]] += n as f64;
+    }
+    a
+}
+
+/// Dense op(A) built from the referenced triangle of A only
+fn dense_op<T: ComplexFloat>(
+    a: &DTensor<T, 2>,
+    triangle: Triangle,
+    trans: Transpose,
+    unit_diag: bool,
+) -> DTensor<T, 2> {
+    let n = a.shape().0;
+    let tri = DTensor::<T, 2>::from_fn([n, n], |i| {
+        let (r, c) = (i[0], i[1]);
+        let stored = match triangle {
+            Triangle::Lower => r >= c,
+            Triangle::Upper => r <= c,
+        };
+        if r == c && unit_diag {
+            T::one()
+        } else if stored {
+            a[[r, c]]
+        } else {
+            T::zero()
+        }
+    });
+    DTensor::<T, 2>::from_fn([n, n], |i| match trans {
+        Transpose::NoTrans => tri[[i[0], i[1]]],
+        Transpose::Trans => tri[[i[1], i[0]]],
+        Transpose::ConjTrans => tri[[i[1], i[0]]].conj(),
+    })
+}
+
+fn configure<'a, B: TriSolveBuilder<'a, T, mdarray::Dense, usize>, T: 'a>(
+    builder: B,
+    side: Side,
+    triangle: Triangle,
+    trans: Transpose,
+    unit_diag: bool,
+) -> B {
+    let builder = builder.side(side);
+    let builder = match triangle {
+        Triangle::Lower => builder.lower(),
+        Triangle::Upper => builder.upper(),
+    };
+    let builder = match trans {
+        Transpose::NoTrans => builder,
+        Transpose::Trans => builder.transpose(),
+        Transpose::ConjTrans => builder.conj_transpose(),
+    };
+    if unit_diag {
+        builder.unit_diagonal()
+    } else {
+        builder
+    }
+}
+
+fn check_trsm(
+    bd: &impl TriSolve<f64>,
+    side: Side,
+    triangle: Triangle,
+    trans: Transpose,
+    unit_diag: bool,
+) {
+    let (n, nrhs) = (5, 3);
+    let a = triangular_test_matrix(n);
+    let b = match side {
+        Side::Left => random_matrix(n, nrhs),
+        Side::Right => random_matrix(nrhs, n),
+    };
+
+    let x = configure(bd.trisolve(&a), side, triangle, trans, unit_diag)
+        .eval(&b)
+        .unwrap();
+
+    let op_a = dense_op(&a, triangle, trans, unit_diag);
+    let reconstructed = match side {
+        Side::Left => naive_matmul(&op_a, &x),
+        Side::Right => naive_matmul(&x, &op_a),
+    };
+    assert_matrix_eq!(b, reconstructed);
+}
+
+pub fn test_trsm_left_lower(bd: &impl TriSolve<f64>) {
+    check_trsm(bd, Side::Left, Triangle::Lower, Transpose::NoTrans, false);
+}
+
+pub fn test_trsm_left_upper(bd: &impl TriSolve<f64>) {
+    check_trsm(bd, Side::Left, Triangle::Upper, Transpose::NoTrans, false);
+}
+
+pub fn test_trsm_transpose(bd: &impl TriSolve<f64>) {
+    check_trsm(bd, Side::Left, Triangle::Lower, Transpose::Trans, false);
+    check_trsm(bd, Side::Left, Triangle::Upper, Transpose::Trans, false);
+}
+
+pub fn test_trsm_right(bd: &impl TriSolve<f64>) {
+    for triangle in [Triangle::Lower, Triangle::Upper] {
+        for trans in [Transpose::NoTrans, Transpose::Trans] {
+            check_trsm(bd, Side::Right, triangle, trans, false);
+        }
+    }
+}
+
+pub fn test_trsm_unit_diagonal(bd: &impl TriSolve<f64>) {
+    check_trsm(bd, Side::Left, Triangle::Lower, Transpose::NoTrans, true);
+    check_trsm(bd, Side::Right, Triangle::Upper, Transpose::Trans, true);
+}
+
+pub fn test_trsm_scale_write_in_place(bd: &impl TriSolve<f64>) {
+    let a = tensor![[2., 0.], [1., 4.]];
+    let b = tensor![[2., 4.], [9., 10.]];
+    let expected = tensor![[2., 4.], [4., 4.]];
+
+    let x = bd.trisolve(&a).lower().scale(2.).eval(&b).unwrap();
+    assert_matrix_eq!(expected, x);
+
+    let mut x = DTensor::<f64, 2>::zeros([2, 2]);
+    bd.trisolve(&a).lower().scale(2.).write(&b, &mut x).unwrap();
+    assert_matrix_eq!(expected, x);
+
+    let mut x = b.clone();
+    bd.trisolve(&a).lower().scale(2.).in_place(&mut x).unwrap();
+    assert_matrix_eq!(expected, x);
+}
+
+pub fn test_trsv(bd: &impl TriSolve<f64>) {
+    let n = 6;
+    let a = triangular_test_matrix(n);
+    let b = random_matrix(n, 1);
+    let b_vec = DTensor::<f64, 1>::from_fn([n], |i| b[[i[0], 0]]);
+
+    for triangle in [Triangle::Lower, Triangle::Upper] {
+        for trans in [Transpose::NoTrans, Transpose::Trans] {
+            let x = configure(bd.trisolve(&a), Side::Left, triangle, trans, false)
+                .eval_vec(&b_vec)
+                .unwrap();
+            let x = DTensor::<f64, 2>::from_fn([n, 1], |i| x[[i[0]]]);
+
+            let op_a = dense_op(&a, triangle, trans, false);
+            let reconstructed = naive_matmul(&op_a, &x);
+            assert_matrix_eq!(b, reconstructed);
+        }
+    }
+
+    let mut x = DTensor::<f64, 1>::zeros([n]);
+    bd.trisolve(&a).upper().write_vec(&b_vec, &mut x).unwrap();
+    let mut y = b_vec.clone();
+    bd.trisolve(&a).upper().in_place_vec(&mut y).unwrap();
+    for i in 0..n {
+        assert_relative_eq!(x[[i]], y[[i]], epsilon = 1e-12);
+    }
+}
+
+pub fn test_trsm_complex(bd: &impl TriSolve<Complex<f64>>) {
+    let (n, nrhs) = (4, 3);
+    let mut rng = rand::rng();
+    let mut a = DTensor::<Complex<f64>, 2>::from_fn([n, n], |_| {
+        Complex::new(rng.random::<f64>(), rng.random::<f64>())
+    });
+    for i in 0..n {
+        a[[i, i]] += n as f64;
+    }
+
+    for side in [Side::Left, Side::Right] {
+        for trans in [Transpose::NoTrans, Transpose::Trans, Transpose::ConjTrans] {
+            let b = match side {
+                Side::Left => DTensor::<Complex<f64>, 2>::from_fn([n, nrhs], |_| {
+                    Complex::new(rng.random::<f64>(), rng.random::<f64>())
+                }),
+                Side::Right => DTensor::<Complex<f64>, 2>::from_fn([nrhs, n], |_| {
+                    Complex::new(rng.random::<f64>(), rng.random::<f64>())
+                }),
+            };
+
+            let x = configure(bd.trisolve(&a), side, Triangle::Upper, trans, false)
+                .eval(&b)
+                .unwrap();
+
+            let op_a = dense_op(&a, Triangle::Upper, trans, false);
+            let reconstructed = match side {
+                Side::Left => naive_matmul(&op_a, &x),
+                Side::Right => naive_matmul(&x, &op_a),
+            };
+            let (m, k) = *b.shape();
+            for i in 0..m {
+                for j in 0..k {
+                    assert!((b[[i, j]] - reconstructed[[i, j]]).norm() < 1e-10);
+                }
+            }
+        }
+    }
+}
+
+pub fn test_trsm_singular(bd: &impl TriSolve<f64>) {
+    let a = tensor![[2., 0., 0.], [1., 0., 0.], [3., 5., 4.]];
+    let b = tensor![[1., 2.], [3., 4.], [5., 6.]];
+
+    let mut x = b.clone();
+    assert_eq!(
+        bd.trisolve(&a).lower().in_place(&mut x),
+        Err(TriSolveError::SingularMatrix { diagonal: 1 })
+    );
+    assert_eq!(x, b);
+
+    let b_vec = tensor![1., 2., 3.];
+    assert_eq!(
+        bd.trisolve(&a).upper().eval_vec(&b_vec),
+        Err(TriSolveError::SingularMatrix { diagonal: 1 })
+    );
+
+    // The diagonal is not read with a unit diagonal
+    let x = bd.trisolve(&a).lower().unit_diagonal().eval(&b).unwrap();
+    assert_matrix_eq!(x, tensor![[1., 2.], [2., 2.], [-8., -10.]]);
+}
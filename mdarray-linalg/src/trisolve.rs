@@ -0,0 +1,193 @@
+//! Triangular solves op(A) X = αB and X op(A) = αB
+//!
+//! A is a square triangular matrix, op(A) is A, Aᵀ or Aᴴ, and only the
+//! triangle selected with [`TriSolveBuilder::upper`] or
+//! [`TriSolveBuilder::lower`] is referenced.  An exactly zero diagonal
+//! element of A is reported as [`TriSolveError::SingularMatrix`], with B
+//! left untouched.
+//!
+//!```rust
+//!use mdarray::tensor;
+//!use mdarray_linalg::prelude::*;
+//!use mdarray_linalg::Naive;
+//!
+//!let l = tensor![[2., 0.], [1., 4.]];
+//!let b = tensor![[2., 4.], [9., 10.]];
+//!
+//!// X = L⁻¹ B
+//!let x = Naive.trisolve(&l).lower().eval(&b).unwrap();
+//!assert_eq!(x, tensor![[1., 2.], [2., 2.]]);
+//!
+//!// X = L⁻ᵀ B, i.e. with the upper triangular Lᵀ
+//!let x = Naive.trisolve(&l).lower().transpose().eval(&b).unwrap();
+//!assert_eq!(x, tensor![[-0.125, 0.75], [2.25, 2.5]]);
+//!
+//!// Vectors are overwritten in place: x ← L⁻¹ x
+//!let mut x = tensor![2., 9.];
+//!Naive.trisolve(&l).lower().in_place_vec(&mut x).unwrap();
+//!assert_eq!(x, tensor![1., 2.]);
+//!```
+use mdarray::{Dim, Layout, Shape, Slice, Tensor};
+use num_complex::ComplexFloat;
+use thiserror::Error;
+
+use crate::matmul::{Side, Triangle};
+
+/// Error types related to triangular solves
+#[derive(Debug, Error, PartialEq)]
+pub enum TriSolveError {
+    /// A has an exactly zero diagonal element, and no unit diagonal was requested
+    #[error("Triangular matrix is singular: diagonal element {diagonal} is zero")]
+    SingularMatrix { diagonal: usize },
+}
+
+/// Operation applied to the triangular matrix before solving
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Transpose {
+    /// op(A) = A
+    #[default]
+    NoTrans,
+    /// op(A) = Aᵀ
+    Trans,
+    /// op(A) = Aᴴ
+    ConjTrans,
+}
+
+/// Options of a triangular solve, collected by [`TriSolveBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriSolveOptions<T> {
+    /// `Side::Left` solves op(A) X = αB, `Side::Right` solves X op(A) = αB
+    pub side: Side,
+    /// Triangle of A that is referenced
+    pub triangle: Triangle,
+    pub trans: Transpose,
+    /// Assume that the diagonal of A is made of ones, without reading it
+    pub unit_diag: bool,
+    pub alpha: T,
+}
+
+impl<T: ComplexFloat> Default for TriSolveOptions<T> {
+    fn default() -> Self {
+        Self {
+            side: Side::Left,
+            triangle: Triangle::Lower,
+            trans: Transpose::NoTrans,
+            unit_diag: false,
+            alpha: T::one(),
+        }
+    }
+}
+
+/// Triangular solve with multiple (`trsm`) or single (`trsv`) right-hand sides
+pub trait TriSolve<T: ComplexFloat> {
+    /// Starts a solve with the square triangular matrix `a`
+    /// By default A is lower triangular and X = A⁻¹ B is computed.
+    fn trisolve<'a, La, D>(
+        &self,
+        a: &'a Slice<T, (D, D), La>,
+    ) -> impl TriSolveBuilder<'a, T, La, D>
+    where
+        T: 'a,
+        La: Layout,
+        D: Dim;
+}
+
+/// Builder interface for configuring triangular solves
+pub trait TriSolveBuilder<'a, T, La, D>
+where
+    T: 'a,
+    La: Layout,
+    D: Dim,
+{
+    /// Solves op(A) X = αB (`Side::Left`, default) or X op(A) = αB (`Side::Right`).
+    /// Vector right-hand sides are always solved on the left.
+    fn side(self, side: Side) -> Self;
+
+    /// Uses the upper triangle of A
+    fn upper(self) -> Self;
+
+    /// Uses the lower triangle of A (default)
+    fn lower(self) -> Self;
+
+    /// Solves with op(A) = Aᵀ
+    fn transpose(self) -> Self;
+
+    /// Solves with op(A) = Aᴴ
+    fn conj_transpose(self) -> Self;
+
+    /// Assumes that the diagonal of A is made of ones
+    fn unit_diagonal(self) -> Self;
+
+    /// Multiplies the right-hand side by a scalar factor.
+    fn scale(self, alpha: T) -> Self;
+
+    /// Returns a new owned matrix containing the solution X.
+    fn eval<Lb: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &Slice<T, (D0, D1), Lb>,
+    ) -> Result<Tensor<T, (D0, D1)>, TriSolveError>;
+
+    /// Overwrites `x` with the solution, B is left untouched.
+    fn write<Lb: Layout, Lx: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &Slice<T, (D0, D1), Lb>,
+        x: &mut Slice<T, (D0, D1), Lx>,
+    ) -> Result<(), TriSolveError>;
+
+    /// Overwrites B with the solution X.
+    fn in_place<Lb: Layout, D0: Dim, D1: Dim>(
+        self,
+        b: &mut Slice<T, (D0, D1), Lb>,
+    ) -> Result<(), TriSolveError>;
+
+    /// Returns a new owned vector containing the solution x of op(A) x = αb.
+    fn eval_vec<Lb: Layout, D0: Dim>(
+        self,
+        b: &Slice<T, (D0,), Lb>,
+    ) -> Result<Tensor<T, (D0,)>, TriSolveError>;
+
+    /// Overwrites `x` with the solution of op(A) x = αb, b is left untouched.
+    fn write_vec<Lb: Layout, Lx: Layout, D0: Dim>(
+        self,
+        b: &Slice<T, (D0,), Lb>,
+        x: &mut Slice<T, (D0,), Lx>,
+    ) -> Result<(), TriSolveError>;
+
+    /// Overwrites b with the solution of op(A) x = αb.
+    fn in_place_vec<Lb: Layout, D0: Dim>(
+        self,
+        b: &mut Slice<T, (D0,), Lb>,
+    ) -> Result<(), TriSolveError>;
+}
+
+/// Checks that A is n × n with n matching the side of B it is applied to
+pub fn trisolve_dims<T, La: Layout, Lb: Layout, D: Dim, D0: Dim, D1: Dim>(
+    a: &Slice<T, (D, D), La>,
+    b: &Slice<T, (D0, D1), Lb>,
+    side: Side,
+) {
+    let ash = *a.shape();
+    let bsh = *b.shape();
+    let n = ash.dim(0);
+    assert_eq!(n, ash.dim(1), "A must be square");
+    let nb = match side {
+        Side::Left => bsh.dim(0),
+        Side::Right => bsh.dim(1),
+    };
+    assert_eq!(n, nb, "A and B have incompatible dimensions");
+}
+
+/// Returns the first exactly zero diagonal element of A as an error,
+/// unless the diagonal is assumed to be made of ones
+pub fn trisolve_check_diagonal<T: ComplexFloat, La: Layout, D: Dim>(
+    a: &Slice<T, (D, D), La>,
+    unit_diag: bool,
+) -> Result<(), TriSolveError> {
+    if unit_diag {
+        return Ok(());
+    }
+    match (0..a.shape().dim(0)).find(|&i| a[[i, i]] == T::zero()) {
+        Some(diagonal) => Err(TriSolveError::SingularMatrix { diagonal }),
+        None => Ok(()),
+    }
+}
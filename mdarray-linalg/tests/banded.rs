@@ -0,0 +1,52 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::banded::*;
+
+#[test]
+fn solve_tridiagonal() {
+    test_solve_tridiagonal(&Naive);
+}
+
+#[test]
+fn solve_tridiagonal_complex() {
+    test_solve_tridiagonal_complex(&Naive);
+}
+
+#[test]
+fn solve_tridiagonal_pd() {
+    test_solve_tridiagonal_pd(&Naive);
+}
+
+#[test]
+fn solve_band() {
+    test_solve_band(&Naive);
+}
+
+#[test]
+fn solve_band_complex() {
+    test_solve_band_complex(&Naive);
+}
+
+#[test]
+fn solve_band_pd() {
+    test_solve_band_pd(&Naive);
+}
+
+#[test]
+fn solve_band_pd_complex() {
+    test_solve_band_pd_complex(&Naive);
+}
+
+#[test]
+fn band_singular() {
+    test_band_singular(&Naive);
+}
+
+#[test]
+fn band_invalid_dimensions() {
+    test_band_invalid_dimensions(&Naive);
+}
+
+#[test]
+fn band_matrix() {
+    test_band_matrix();
+}
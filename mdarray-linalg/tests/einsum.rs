@@ -0,0 +1,46 @@
+use mdarray_linalg::{Naive, testing::einsum::*};
+
+#[test]
+fn einsum_matmul() {
+    test_einsum_matmul(&Naive);
+}
+
+#[test]
+fn einsum_implicit_output() {
+    test_einsum_implicit_output(&Naive);
+}
+
+#[test]
+fn einsum_trace_diagonal() {
+    test_einsum_trace_diagonal(&Naive);
+}
+
+#[test]
+fn einsum_batch() {
+    test_einsum_batch(&Naive);
+}
+
+#[test]
+fn einsum_single_operand() {
+    test_einsum_single_operand(&Naive);
+}
+
+#[test]
+fn einsum_outer_and_full() {
+    test_einsum_outer_and_full(&Naive);
+}
+
+#[test]
+fn einsum_many_operands() {
+    test_einsum_many_operands(&Naive);
+}
+
+#[test]
+fn einsum_complex() {
+    test_einsum_complex(&Naive);
+}
+
+#[test]
+fn einsum_errors() {
+    test_einsum_errors(&Naive);
+}
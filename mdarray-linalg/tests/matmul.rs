@@ -0,0 +1,32 @@
+use mdarray_linalg::{Naive, testing::matmul::*};
+
+#[test]
+fn batched_matmul() {
+    test_batched_matmul_impl(&Naive);
+}
+
+#[test]
+fn batched_matmul_broadcast() {
+    test_batched_matmul_broadcast_impl(&Naive);
+}
+
+#[test]
+fn batched_matmul_write_add_to() {
+    test_batched_matmul_write_add_to_impl(&Naive);
+}
+
+#[test]
+fn batched_matmul_strided() {
+    test_batched_matmul_strided_impl(&Naive);
+}
+
+#[test]
+fn batched_matmul_complex() {
+    test_batched_matmul_complex_impl(&Naive);
+}
+
+#[test]
+#[should_panic]
+fn batched_matmul_mismatch() {
+    test_batched_matmul_mismatch_impl(&Naive);
+}
@@ -84,3 +84,53 @@ fn argmax_abs() {
 fn argmax_write_real() {
     test_argmax_write_real(Naive)
 }
+
+#[test]
+fn gbmv() {
+    test_gbmv(Naive)
+}
+
+#[test]
+fn gbmv_complex() {
+    test_gbmv_complex(Naive)
+}
+
+#[test]
+fn hbmv() {
+    test_hbmv(Naive)
+}
+
+#[test]
+fn hbmv_complex() {
+    test_hbmv_complex(Naive)
+}
+
+#[test]
+fn tbmv() {
+    test_tbmv(Naive)
+}
+
+#[test]
+fn tbmv_complex() {
+    test_tbmv_complex(Naive)
+}
+
+#[test]
+fn hpmv() {
+    test_hpmv(Naive)
+}
+
+#[test]
+fn hpmv_complex() {
+    test_hpmv_complex(Naive)
+}
+
+#[test]
+fn tpmv() {
+    test_tpmv(Naive)
+}
+
+#[test]
+fn structured_beta_zero() {
+    test_structured_beta_zero(Naive)
+}
@@ -0,0 +1,51 @@
+use mdarray_linalg::{Naive, testing::ncon::*};
+
+#[test]
+fn ncon_matrix_chain() {
+    test_ncon_matrix_chain(&Naive);
+}
+
+#[test]
+fn ncon_network() {
+    test_ncon_network(&Naive);
+}
+
+#[test]
+fn ncon_partial_trace() {
+    test_ncon_partial_trace(&Naive);
+}
+
+#[test]
+fn ncon_single_tensor() {
+    test_ncon_single_tensor(&Naive);
+}
+
+#[test]
+fn ncon_complex() {
+    test_ncon_complex(&Naive);
+}
+
+#[test]
+fn ncon_with_path() {
+    test_ncon_with_path(&Naive);
+}
+
+#[test]
+fn ncon_path_cost() {
+    test_ncon_path_cost();
+}
+
+#[test]
+fn ncon_optimal_beats_greedy() {
+    test_ncon_optimal_beats_greedy();
+}
+
+#[test]
+fn ncon_labels() {
+    test_ncon_labels();
+}
+
+#[test]
+fn ncon_errors() {
+    test_ncon_errors(&Naive);
+}
@@ -0,0 +1,47 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::norm::*;
+
+#[test]
+fn matrix_norms() {
+    test_matrix_norms(&Naive);
+}
+
+#[test]
+fn matrix_norms_rectangular() {
+    test_matrix_norms_rectangular(&Naive);
+}
+
+#[test]
+fn matrix_norms_complex() {
+    test_matrix_norms_complex(&Naive);
+}
+
+#[test]
+fn rcond_lu() {
+    test_rcond_lu(&Naive);
+}
+
+#[test]
+fn rcond_lu_complex() {
+    test_rcond_lu_complex(&Naive);
+}
+
+#[test]
+fn rcond_lu_ill_conditioned() {
+    test_rcond_lu_ill_conditioned(&Naive);
+}
+
+#[test]
+fn rcond_lu_unsupported_norm() {
+    test_rcond_lu_unsupported_norm(&Naive);
+}
+
+#[test]
+fn rcond_cholesky() {
+    test_rcond_cholesky(&Naive);
+}
+
+#[test]
+fn rcond_cholesky_complex() {
+    test_rcond_cholesky_complex(&Naive);
+}
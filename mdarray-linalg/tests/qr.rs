@@ -15,3 +15,43 @@ fn qr_structured_matrix() {
 fn qr_complex_matrix() {
     test_qr_complex_matrix(&Naive);
 }
+
+#[test]
+fn qr_tall_matrix() {
+    test_qr_tall_matrix(&Naive);
+}
+
+#[test]
+fn qr_wide_matrix() {
+    test_qr_wide_matrix(&Naive);
+}
+
+#[test]
+fn qr_thin_tall_matrix() {
+    test_qr_thin_tall_matrix(&Naive);
+}
+
+#[test]
+fn qr_thin_wide_matrix() {
+    test_qr_thin_wide_matrix(&Naive);
+}
+
+#[test]
+fn qr_thin_square_matrix() {
+    test_qr_thin_square_matrix(&Naive);
+}
+
+#[test]
+fn qr_upper_triangular() {
+    test_qr_upper_triangular(&Naive);
+}
+
+#[test]
+fn qr_thin_write() {
+    test_qr_thin_write(&Naive);
+}
+
+#[test]
+fn qr_thin_complex_matrix() {
+    test_qr_thin_complex_matrix(&Naive);
+}
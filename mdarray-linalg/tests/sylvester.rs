@@ -0,0 +1,17 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::sylvester::*;
+
+#[test]
+fn sylvester_triangular() {
+    test_sylvester_triangular(&Naive);
+}
+
+#[test]
+fn sylvester_triangular_complex() {
+    test_sylvester_triangular_complex(&Naive);
+}
+
+#[test]
+fn sylvester_triangular_singular() {
+    test_sylvester_triangular_singular(&Naive);
+}
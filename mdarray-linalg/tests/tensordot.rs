@@ -50,3 +50,59 @@ fn tensordot_mismatched_dimensions_should_panic() {
 fn tensordot_outer_should_match_manual_kronecker() {
     tensordot_outer_should_match_manual_kronecker_impl(&Naive);
 }
+
+// --- Writing into existing tensors ---
+
+#[test]
+fn tensordot_write() {
+    tensordot_write_impl(&Naive);
+}
+
+#[test]
+fn tensordot_write_all_axes() {
+    tensordot_write_all_axes_impl(&Naive);
+}
+
+#[test]
+fn tensordot_add_to() {
+    tensordot_add_to_impl(&Naive);
+}
+
+#[test]
+fn tensordot_add_to_scaled() {
+    tensordot_add_to_scaled_impl(&Naive);
+}
+
+#[test]
+fn tensordot_write_strided() {
+    tensordot_write_strided_impl(&Naive);
+}
+
+#[test]
+fn tensordot_write_shape_mismatch_should_panic() {
+    tensordot_write_shape_mismatch_should_panic_impl(&Naive);
+}
+
+// --- Output axis order ---
+
+#[test]
+fn tensordot_output_order() {
+    tensordot_output_order_impl(&Naive);
+}
+
+#[test]
+fn tensordot_output_order_invalid_should_panic() {
+    tensordot_output_order_invalid_should_panic_impl(&Naive);
+}
+
+// --- Strided operands ---
+
+#[test]
+fn tensordot_transposed_operands() {
+    tensordot_transposed_operands_impl(&Naive);
+}
+
+#[test]
+fn tensordot_strided_operands() {
+    tensordot_strided_operands_impl(&Naive);
+}
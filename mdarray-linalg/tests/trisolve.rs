@@ -0,0 +1,47 @@
+use mdarray_linalg::Naive;
+use mdarray_linalg::testing::trisolve::*;
+
+#[test]
+fn trsm_left_lower() {
+    test_trsm_left_lower(&Naive);
+}
+
+#[test]
+fn trsm_left_upper() {
+    test_trsm_left_upper(&Naive);
+}
+
+#[test]
+fn trsm_transpose() {
+    test_trsm_transpose(&Naive);
+}
+
+#[test]
+fn trsm_right() {
+    test_trsm_right(&Naive);
+}
+
+#[test]
+fn trsm_unit_diagonal() {
+    test_trsm_unit_diagonal(&Naive);
+}
+
+#[test]
+fn trsm_scale_write_in_place() {
+    test_trsm_scale_write_in_place(&Naive);
+}
+
+#[test]
+fn trsv() {
+    test_trsv(&Naive);
+}
+
+#[test]
+fn trsm_complex() {
+    test_trsm_complex(&Naive);
+}
+
+#[test]
+fn trsm_singular() {
+    test_trsm_singular(&Naive);
+}